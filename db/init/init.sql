@@ -1,4 +1,5 @@
 CREATE EXTENSION IF NOT EXISTS pg_stat_statements;
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
 
 CREATE TABLE tb_image(
     id SERIAL PRIMARY KEY,
@@ -20,6 +21,7 @@ CREATE TABLE tb_user(
 
     is_active BOOLEAN DEFAULT TRUE,
     is_admin BOOLEAN DEFAULT FALSE,
+    email_verified_at TIMESTAMP,
 
     created_at TIMESTAMP DEFAULT NOW(),
     updated_at TIMESTAMP,
@@ -27,6 +29,35 @@ CREATE TABLE tb_user(
     FOREIGN KEY (profile_id) REFERENCES tb_image(id)
 );
 
+-- 로그인 수단: 이메일은 username, 소셜 로그인은 제공자의 사용자 식별자
+CREATE TABLE tb_user_identity(
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    provider VARCHAR(8) NOT NULL,
+    provider_subject VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP DEFAULT NOW(),
+
+    UNIQUE (provider, provider_subject),
+    UNIQUE (user_id, provider),
+    FOREIGN KEY (user_id) REFERENCES tb_user(id) ON DELETE CASCADE
+);
+
+-- 로그인 세션: refresh 토큰의 jti, 폐기되면 재발급 불가
+CREATE TABLE tb_session(
+    jti VARCHAR(32) PRIMARY KEY,
+    user_id INT NOT NULL,
+    family_id VARCHAR(32) NOT NULL,
+    expired_at TIMESTAMP NOT NULL,
+    rotated_at TIMESTAMP,
+    revoked_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT NOW(),
+
+    FOREIGN KEY (user_id) REFERENCES tb_user(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_tb_session_user ON tb_session (user_id);
+CREATE INDEX idx_tb_session_family ON tb_session (family_id);
+
 CREATE TABLE tb_book_type(
     id SMALLSERIAL PRIMARY KEY,
     name VARCHAR(4) NOT NULL
@@ -50,6 +81,25 @@ CREATE TABLE tb_user_book_role(
     FOREIGN KEY (book_id) REFERENCES tb_book(id)
 );
 
+-- 가계부 초대 코드: 만료 시각, 사용 횟수 제한
+CREATE TABLE tb_book_invite(
+    id SERIAL PRIMARY KEY,
+    book_id INT NOT NULL,
+    code VARCHAR(32) NOT NULL UNIQUE,
+    role VARCHAR(8) NOT NULL,
+    max_uses INT NOT NULL,
+    use_count INT NOT NULL DEFAULT 0,
+    is_revoked BOOLEAN NOT NULL DEFAULT FALSE,
+    expired_at TIMESTAMP NOT NULL,
+
+    created_by INT NOT NULL,
+    created_at TIMESTAMP DEFAULT NOW(),
+
+    FOREIGN KEY (book_id) REFERENCES tb_book(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES tb_user(id),
+    CHECK (use_count <= max_uses)
+);
+
 CREATE TABLE tb_base_category(
     id SMALLSERIAL PRIMARY KEY,
     type_id SMALLINT NOT NULL,
@@ -91,11 +141,40 @@ CREATE TABLE tb_asset(
     code VARCHAR(32),
     memo VARCHAR(16),
     balance BIGINT NOT NULL,
+    -- 기록과 무관한 시작 잔액, 재계산 시 기준
+    opening_balance BIGINT NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP,
+
+    FOREIGN KEY (book_id) REFERENCES tb_book(id),
+    FOREIGN KEY (sub_category_id) REFERENCES tb_sub_category(id)
+);
+
+CREATE TABLE tb_recurring_record(
+    id SERIAL PRIMARY KEY,
+    book_id INT NOT NULL,
+    sub_category_id INT NOT NULL,
+    amount INTEGER NOT NULL,
+    memo VARCHAR(32),
+    asset_id INT,
+
+    -- 반복 규칙: start_dt 기준 cycle_interval 주기마다 생성
+    cycle VARCHAR(8) NOT NULL, -- daily, weekly, monthly, yearly
+    cycle_interval SMALLINT NOT NULL DEFAULT 1,
+    start_dt TIMESTAMP NOT NULL,
+    end_dt TIMESTAMP,
+
+    -- 다음 생성 회차
+    next_seq INT NOT NULL DEFAULT 0,
+    next_dt TIMESTAMP NOT NULL,
+    is_paused BOOLEAN NOT NULL DEFAULT FALSE,
 
     created_at TIMESTAMP DEFAULT NOW(),
     updated_at TIMESTAMP,
 
     FOREIGN KEY (book_id) REFERENCES tb_book(id),
+    FOREIGN KEY (asset_id) REFERENCES tb_asset(id),
     FOREIGN KEY (sub_category_id) REFERENCES tb_sub_category(id)
 );
 
@@ -109,15 +188,137 @@ CREATE TABLE tb_record(
 
     asset_id INT,
 
+    -- 이체: 서로 연결된 기록 id, 입금 여부
+    transfer_id BIGINT,
+    is_transfer_in BOOLEAN NOT NULL DEFAULT FALSE,
+
+    -- 반복 기록에서 생성된 경우
+    recurring_id INT,
+
     target_dt TIMESTAMP NOT NULL,
-    created_at TIMESTAMP DEFAULT NOW(),
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
     updated_at TIMESTAMP,
 
     FOREIGN KEY (book_id) REFERENCES tb_book(id),
     FOREIGN KEY (asset_id) REFERENCES tb_asset(id),
+    FOREIGN KEY (sub_category_id) REFERENCES tb_sub_category(id),
+    FOREIGN KEY (transfer_id) REFERENCES tb_record(id),
+    FOREIGN KEY (recurring_id) REFERENCES tb_recurring_record(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_tb_record_memo_trgm ON tb_record USING GIN (memo gin_trgm_ops);
+-- 같은 회차 중복 생성 방지
+CREATE UNIQUE INDEX idx_tb_record_recurring ON tb_record (recurring_id, target_dt);
+
+-- 분할 기록: 한 기록을 여러 소분류로 나눔, 금액 합계는 기록 금액과 같아야 함
+CREATE TABLE tb_record_line(
+    id BIGSERIAL PRIMARY KEY,
+    record_id BIGINT NOT NULL,
+    sub_category_id INT NOT NULL,
+    amount INTEGER NOT NULL,
+
+    FOREIGN KEY (record_id) REFERENCES tb_record(id) ON DELETE CASCADE,
     FOREIGN KEY (sub_category_id) REFERENCES tb_sub_category(id)
 );
 
+CREATE INDEX idx_tb_record_line_record ON tb_record_line (record_id);
+
+-- 커플 가계부 정산: 기록별 결제자와 분담 방식
+CREATE TABLE tb_record_split(
+    record_id BIGINT PRIMARY KEY,
+    payer_id INT NOT NULL,
+    split_type VARCHAR(8) NOT NULL, -- equal, percent, fixed
+
+    FOREIGN KEY (record_id) REFERENCES tb_record(id) ON DELETE CASCADE,
+    FOREIGN KEY (payer_id) REFERENCES tb_user(id)
+);
+
+-- 분담 구성원: value는 percent면 비율, fixed면 금액, equal이면 0
+CREATE TABLE tb_record_share(
+    record_id BIGINT NOT NULL,
+    user_id INT NOT NULL,
+    value INTEGER NOT NULL DEFAULT 0,
+
+    PRIMARY KEY (record_id, user_id),
+    FOREIGN KEY (record_id) REFERENCES tb_record_split(record_id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES tb_user(id)
+);
+
+-- 정산 완료 기간 [start_dt, end_dt)
+CREATE TABLE tb_settlement(
+    id SERIAL PRIMARY KEY,
+    book_id INT NOT NULL,
+    start_dt TIMESTAMP NOT NULL,
+    end_dt TIMESTAMP NOT NULL,
+    settled_by INT NOT NULL,
+    created_at TIMESTAMP DEFAULT NOW(),
+
+    FOREIGN KEY (book_id) REFERENCES tb_book(id) ON DELETE CASCADE,
+    FOREIGN KEY (settled_by) REFERENCES tb_user(id),
+    CHECK (start_dt < end_dt)
+);
+
+-- 기업 가계부 복식부기: book_id가 NULL이면 기본 계정과목
+CREATE TABLE tb_account(
+    id SERIAL PRIMARY KEY,
+    book_id INT,
+    code VARCHAR(8) NOT NULL,
+    name VARCHAR(16) NOT NULL,
+    class VARCHAR(16) NOT NULL, -- asset, liability, equity, revenue, expense
+
+    FOREIGN KEY (book_id) REFERENCES tb_book(id) ON DELETE CASCADE
+);
+
+CREATE TABLE tb_journal_entry(
+    id BIGSERIAL PRIMARY KEY,
+    book_id INT NOT NULL,
+    memo VARCHAR(32),
+
+    target_dt TIMESTAMP NOT NULL,
+    created_by INT NOT NULL,
+    created_at TIMESTAMP DEFAULT NOW(),
+
+    FOREIGN KEY (book_id) REFERENCES tb_book(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES tb_user(id)
+);
+
+CREATE INDEX idx_tb_journal_entry_book ON tb_journal_entry (book_id, target_dt);
+
+-- 분개 한 줄은 차변, 대변 중 한쪽에만 금액, 분개의 차변 합계와 대변 합계는 같아야 함
+CREATE TABLE tb_journal_line(
+    id BIGSERIAL PRIMARY KEY,
+    entry_id BIGINT NOT NULL,
+    account_id INT NOT NULL,
+    debit BIGINT NOT NULL DEFAULT 0,
+    credit BIGINT NOT NULL DEFAULT 0,
+
+    FOREIGN KEY (entry_id) REFERENCES tb_journal_entry(id) ON DELETE CASCADE,
+    FOREIGN KEY (account_id) REFERENCES tb_account(id),
+    CHECK (debit >= 0 AND credit >= 0 AND (debit = 0) <> (credit = 0))
+);
+
+CREATE INDEX idx_tb_journal_line_entry ON tb_journal_line (entry_id);
+
+-- 월 예산: 대분류, 소분류 중 하나에 설정
+CREATE TABLE tb_budget(
+    id SERIAL PRIMARY KEY,
+    book_id INT NOT NULL,
+    base_id SMALLINT,
+    sub_category_id INT,
+    amount INTEGER NOT NULL,
+
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP,
+
+    FOREIGN KEY (book_id) REFERENCES tb_book(id),
+    FOREIGN KEY (base_id) REFERENCES tb_base_category(id) ON DELETE CASCADE,
+    FOREIGN KEY (sub_category_id) REFERENCES tb_sub_category(id) ON DELETE CASCADE,
+    CHECK ((base_id IS NULL) <> (sub_category_id IS NULL))
+);
+
+CREATE UNIQUE INDEX idx_tb_budget_base ON tb_budget (book_id, base_id) WHERE base_id IS NOT NULL;
+CREATE UNIQUE INDEX idx_tb_budget_sub ON tb_budget (book_id, sub_category_id) WHERE sub_category_id IS NOT NULL;
+
 CREATE TABLE tb_connect(
     id SERIAL PRIMARY KEY,
     name VARCHAR(32) NOT NULL UNIQUE
@@ -171,6 +372,18 @@ INSERT INTO tb_base_category(type_id, is_record, is_income, name, color) VALUES
     -- (3, TRUE, TRUE, '외상매출금', '000000'), -- 6
     -- (3, TRUE, TRUE, '매출금', '000000'), -- 6
 
+INSERT INTO tb_account(code, name, class) VALUES
+    ('101', '현금', 'asset'), -- 1
+    ('102', '유동자산', 'asset'), -- 2
+    ('103', '외상매출금', 'asset'), -- 3
+    ('104', '고정자산', 'asset'), -- 4
+    ('201', '외상매입금', 'liability'), -- 5
+    ('202', '차입금', 'liability'), -- 6
+    ('301', '자본금', 'equity'), -- 7
+    ('401', '매출금', 'revenue'), -- 8
+    ('501', '매입금', 'expense'), -- 9
+    ('502', '판매관리비', 'expense'); -- 10
+
 -- 현금을 신경써야할까?
 -- 토스
 -- 계좌 (입출금, 저축) 대출 증권(종합위탁, CMA, ISA - 개인종합자산관리계좌) 연금(확정기여형 DC, DP) 포인트 기타 (부동산 자동차 현금) 보험 
@@ -220,10 +433,15 @@ INSERT INTO tb_record (book_id, sub_category_id, amount, memo, target_dt, create
         (2, 17, 15000, '감자탕', '2024-08-05T18:30:20', NOW(), NULL), -- base 9
         (2, 16, 15000, '감자탕', '2024-09-21T18:30:20', NOW(), NULL); -- base 8
 
-INSERT INTO tb_user(login_type, username, password, email) VALUES
-    ('email', 'test_user', 'test_password', 'test@test.test'),
-    ('email', 'viewer_user', 'test_password', 'test22@test.test'),
-    ('email', 'no_role_user', 'test_password', 'test33@test.test');
+INSERT INTO tb_user(login_type, username, password, email, email_verified_at) VALUES
+    ('email', 'test_user', 'test_password', 'test@test.test', NOW()),
+    ('email', 'viewer_user', 'test_password', 'test22@test.test', NOW()),
+    ('email', 'no_role_user', 'test_password', 'test33@test.test', NOW());
+
+INSERT INTO tb_user_identity(user_id, provider, provider_subject) VALUES
+    (1, 'email', 'test_user'),
+    (2, 'email', 'viewer_user'),
+    (3, 'email', 'no_role_user');
 
 INSERT INTO tb_user_book_role(book_id, user_id, role) VALUES
     (1, 1, 'owner'),
@@ -231,4 +449,27 @@ INSERT INTO tb_user_book_role(book_id, user_id, role) VALUES
     (2, 3, 'owner');
 
 INSERT INTO tb_base_category (type_id, book_id, is_record, is_income, name, color) VALUES
-    (1, 1, TRUE, FALSE, '테스트 카테고리', '337712'); -- 11
\ No newline at end of file
+    (1, 1, TRUE, FALSE, '테스트 카테고리', '337712'); -- 11
+INSERT INTO tb_asset (book_id, sub_category_id, code, memo, balance, opening_balance) VALUES
+    (1, 1, '1002-123-456789', '월급통장', 0, 0); -- 1
+INSERT INTO tb_diary (book_id, title, content, target_dt) VALUES
+    (1, '첫 일기', '감자탕 먹은 날', '2024-09-23T21:00:00'); -- 1
+INSERT INTO tb_recurring_record (book_id, sub_category_id, amount, memo, cycle, start_dt, next_dt) VALUES
+    (1, 16, 17000, '넷플릭스', 'monthly', '2024-09-25T09:00:00', '2024-09-25T09:00:00'); -- 1
+INSERT INTO tb_budget (book_id, base_id, sub_category_id, amount) VALUES
+    (2, 9, NULL, 100000), -- 1 변동지출
+    (2, NULL, 17, 50000); -- 2 식비
+
+-- 기존 데이터 이관
+-- 로그인 수단 테이블 이전 사용자: 이메일은 username, 소셜 로그인은 username의 "{로그인 방식}_" 뒤 식별자
+-- 이미 있는 행은 건너뛰므로 다시 실행해도 안전
+INSERT INTO tb_user_identity(user_id, provider, provider_subject)
+    SELECT id, login_type,
+        CASE WHEN login_type = 'email' THEN username
+            ELSE SUBSTRING(username FROM LENGTH(login_type) + 2) END
+    FROM tb_user
+    ON CONFLICT DO NOTHING;
+
+-- 이메일 인증 도입 전 가입한 사용자는 가입 시각에 인증된 것으로 간주, 도입 시 한 번만 실행
+UPDATE tb_user SET email_verified_at = COALESCE(created_at, NOW())
+    WHERE email_verified_at IS NULL;
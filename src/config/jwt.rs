@@ -1,6 +1,7 @@
 pub struct AuthConfig {
     jwt_access: String,
     jwt_refresh: String,
+    jwt_email: String,
 }
 
 impl AuthConfig {
@@ -10,14 +11,19 @@ impl AuthConfig {
     pub fn get_refresh(&self) -> &str {
         &self.jwt_refresh
     }
+    pub fn get_email(&self) -> &str {
+        &self.jwt_email
+    }
 }
 
 pub fn get_config() -> AuthConfig {
     let jwt_access = std::env::var("JWT_ACCESS").expect("set JWT_ACCESS env variable");
     let jwt_refresh = std::env::var("JWT_REFRESH").expect("set JWT_REFRESH env variable");
+    let jwt_email = std::env::var("JWT_EMAIL").expect("set JWT_EMAIL env variable");
 
     AuthConfig {
         jwt_access,
         jwt_refresh,
+        jwt_email,
     }
 }
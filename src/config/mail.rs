@@ -0,0 +1,88 @@
+use std::{env, time::Duration};
+
+/// 메일 발송 방식, 파일 기록은 MAIL_BACKEND=file로 지정한 경우만 사용
+pub enum MailBackend {
+    Smtp { host: String },
+    File { path: String },
+}
+
+/// 인증 메일 발송 설정
+pub struct MailConfig {
+    backend: MailBackend,
+    smtp_port: u16,
+    smtp_username: Option<String>,
+    smtp_password: Option<String>,
+    smtp_tls: bool,
+    smtp_timeout: Duration,
+    from: String,
+    verify_url: String,
+}
+
+impl MailConfig {
+    pub fn get_backend(&self) -> &MailBackend {
+        &self.backend
+    }
+    pub fn get_smtp_port(&self) -> u16 {
+        self.smtp_port
+    }
+    pub fn get_smtp_username(&self) -> Option<&str> {
+        self.smtp_username.as_deref()
+    }
+    pub fn get_smtp_password(&self) -> Option<&str> {
+        self.smtp_password.as_deref()
+    }
+    pub fn get_smtp_tls(&self) -> bool {
+        self.smtp_tls
+    }
+    pub fn get_smtp_timeout(&self) -> Duration {
+        self.smtp_timeout
+    }
+    pub fn get_from(&self) -> &str {
+        &self.from
+    }
+    pub fn get_verify_url(&self) -> &str {
+        &self.verify_url
+    }
+}
+
+pub fn get_mail_config() -> MailConfig {
+    // SMTP 설정이 없으면 메일이 파일에만 남지 않도록 시작하지 않음
+    let backend = match env::var("MAIL_BACKEND").as_deref() {
+        Ok("file") => MailBackend::File {
+            path: env::var("MAIL_FILE_PATH").unwrap_or_else(|_| "mail.log".to_string()),
+        },
+        Ok("smtp") | Err(_) => MailBackend::Smtp {
+            host: env::var("SMTP_HOST").expect("set SMTP_HOST env variable or MAIL_BACKEND=file"),
+        },
+        Ok(backend) => panic!("MAIL_BACKEND must be smtp or file: {}", backend),
+    };
+    let smtp_port = env::var("SMTP_PORT")
+        .ok()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(465);
+    let smtp_username = env::var("SMTP_USERNAME").ok();
+    let smtp_password = env::var("SMTP_PASSWORD").ok();
+    // 465 포트처럼 연결부터 TLS를 사용하는 서버가 기본, false면 STARTTLS로 전환
+    let smtp_tls = env::var("SMTP_TLS")
+        .map(|tls| tls != "false")
+        .unwrap_or(true);
+    let smtp_timeout = env::var("SMTP_TIMEOUT_SECS")
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::from_secs(30));
+    let from = env::var("MAIL_FROM").unwrap_or_else(|_| "noreply@localhost".to_string());
+    let verify_url = env::var("MAIL_VERIFY_URL")
+        .unwrap_or_else(|_| "http://localhost:3000/api/v1/auth/verify-email".to_string());
+
+    MailConfig {
+        backend,
+        smtp_port,
+        smtp_username,
+        smtp_password,
+        smtp_tls,
+        smtp_timeout,
+        from,
+        verify_url,
+    }
+}
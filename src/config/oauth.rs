@@ -0,0 +1,97 @@
+use std::env;
+
+/// 소셜 로그인 사용자 정보, 토큰 정보 조회 주소, 테스트에서는 로컬 서버로 변경 가능
+///
+/// 토큰이 이 앱에 발급됐는지 확인하는 클라이언트 id, 설정하지 않으면 해당 로그인은 거부
+pub struct OAuthConfig {
+    google_url: String,
+    google_token_url: String,
+    google_client_id: Option<String>,
+    kakao_url: String,
+    kakao_token_url: String,
+    kakao_app_id: Option<String>,
+    naver_url: String,
+    naver_token_url: String,
+    naver_client_id: Option<String>,
+    meta_url: String,
+    meta_token_url: String,
+    meta_app_id: Option<String>,
+    meta_app_secret: Option<String>,
+}
+
+impl OAuthConfig {
+    pub fn get_google_url(&self) -> &str {
+        &self.google_url
+    }
+    pub fn get_google_token_url(&self) -> &str {
+        &self.google_token_url
+    }
+    pub fn get_google_client_id(&self) -> Option<&str> {
+        self.google_client_id.as_deref()
+    }
+    pub fn get_kakao_url(&self) -> &str {
+        &self.kakao_url
+    }
+    pub fn get_kakao_token_url(&self) -> &str {
+        &self.kakao_token_url
+    }
+    pub fn get_kakao_app_id(&self) -> Option<&str> {
+        self.kakao_app_id.as_deref()
+    }
+    pub fn get_naver_url(&self) -> &str {
+        &self.naver_url
+    }
+    pub fn get_naver_token_url(&self) -> &str {
+        &self.naver_token_url
+    }
+    pub fn get_naver_client_id(&self) -> Option<&str> {
+        self.naver_client_id.as_deref()
+    }
+    pub fn get_meta_url(&self) -> &str {
+        &self.meta_url
+    }
+    pub fn get_meta_token_url(&self) -> &str {
+        &self.meta_token_url
+    }
+    pub fn get_meta_app_id(&self) -> Option<&str> {
+        self.meta_app_id.as_deref()
+    }
+    pub fn get_meta_app_secret(&self) -> Option<&str> {
+        self.meta_app_secret.as_deref()
+    }
+}
+
+pub fn get_oauth_config() -> OAuthConfig {
+    let google_url = env::var("OAUTH_GOOGLE_URL")
+        .unwrap_or_else(|_| "https://www.googleapis.com/oauth2/v3/userinfo".to_string());
+    let google_token_url = env::var("OAUTH_GOOGLE_TOKEN_URL")
+        .unwrap_or_else(|_| "https://oauth2.googleapis.com/tokeninfo".to_string());
+    let kakao_url = env::var("OAUTH_KAKAO_URL")
+        .unwrap_or_else(|_| "https://kapi.kakao.com/v2/user/me".to_string());
+    let kakao_token_url = env::var("OAUTH_KAKAO_TOKEN_URL")
+        .unwrap_or_else(|_| "https://kapi.kakao.com/v1/user/access_token_info".to_string());
+    let naver_url = env::var("OAUTH_NAVER_URL")
+        .unwrap_or_else(|_| "https://openapi.naver.com/v1/nid/me".to_string());
+    let naver_token_url = env::var("OAUTH_NAVER_TOKEN_URL")
+        .unwrap_or_else(|_| "https://openapi.naver.com/v1/nid/verify?info=true".to_string());
+    let meta_url =
+        env::var("OAUTH_META_URL").unwrap_or_else(|_| "https://graph.facebook.com/me".to_string());
+    let meta_token_url = env::var("OAUTH_META_TOKEN_URL")
+        .unwrap_or_else(|_| "https://graph.facebook.com/debug_token".to_string());
+
+    OAuthConfig {
+        google_url,
+        google_token_url,
+        google_client_id: env::var("OAUTH_GOOGLE_CLIENT_ID").ok(),
+        kakao_url,
+        kakao_token_url,
+        kakao_app_id: env::var("OAUTH_KAKAO_APP_ID").ok(),
+        naver_url,
+        naver_token_url,
+        naver_client_id: env::var("OAUTH_NAVER_CLIENT_ID").ok(),
+        meta_url,
+        meta_token_url,
+        meta_app_id: env::var("OAUTH_META_APP_ID").ok(),
+        meta_app_secret: env::var("OAUTH_META_APP_SECRET").ok(),
+    }
+}
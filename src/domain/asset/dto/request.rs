@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::asset::entity::{Asset, UpdateAsset},
+    global::constants::FieldUpdate,
+};
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct NewAsset {
+    book_id: i32,
+    sub_category_id: i32,
+    code: Option<String>,
+    memo: Option<String>,
+    balance: Option<i64>,
+}
+
+impl NewAsset {
+    pub fn new(
+        book_id: i32,
+        sub_category_id: i32,
+        code: Option<String>,
+        memo: Option<String>,
+        balance: Option<i64>,
+    ) -> Self {
+        Self {
+            book_id,
+            sub_category_id,
+            code,
+            memo,
+            balance,
+        }
+    }
+
+    pub fn to_entity(&self) -> Asset {
+        Asset::new(
+            self.book_id,
+            self.sub_category_id,
+            self.balance.unwrap_or(0),
+        )
+        .code(self.code.clone())
+        .memo(self.memo.clone())
+        .build()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct EditAsset {
+    sub_category_id: Option<i32>,
+    code: Option<String>,
+    memo: Option<String>,
+    balance: Option<i64>,
+}
+
+impl EditAsset {
+    pub fn new(
+        sub_category_id: Option<i32>,
+        code: Option<String>,
+        memo: Option<String>,
+        balance: Option<i64>,
+    ) -> Self {
+        Self {
+            sub_category_id,
+            code,
+            memo,
+            balance,
+        }
+    }
+
+    pub fn to_update(self) -> UpdateAsset {
+        let sub_category_id = match self.sub_category_id {
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        let code = match &self.code {
+            Some(v) if v == "NULL" => FieldUpdate::SetNone,
+            Some(v) => FieldUpdate::Set(v.to_string()),
+            None => FieldUpdate::NoChange,
+        };
+        let memo = match &self.memo {
+            Some(v) if v == "NULL" => FieldUpdate::SetNone,
+            Some(v) => FieldUpdate::Set(v.to_string()),
+            None => FieldUpdate::NoChange,
+        };
+        let balance = match self.balance {
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        UpdateAsset::new(sub_category_id, code, memo, balance)
+    }
+}
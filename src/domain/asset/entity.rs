@@ -0,0 +1,121 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::global::constants::FieldUpdate;
+
+#[derive(Deserialize, Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct Asset {
+    id: Option<i32>,
+    book_id: i32,
+    sub_category_id: i32,
+    code: Option<String>,
+    memo: Option<String>,
+    balance: i64,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+impl Asset {
+    pub fn new(book_id: i32, sub_category_id: i32, balance: i64) -> Self {
+        Self {
+            id: None,
+            book_id,
+            sub_category_id,
+            code: None,
+            memo: None,
+            balance,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn id(mut self, id: Option<i32>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn code(mut self, code: Option<String>) -> Self {
+        self.code = code;
+        self
+    }
+
+    pub fn memo(mut self, memo: Option<String>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    pub fn build(&self) -> Self {
+        Self {
+            id: self.id,
+            book_id: self.book_id,
+            sub_category_id: self.sub_category_id,
+            code: self.code.clone(),
+            memo: self.memo.clone(),
+            balance: self.balance,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    pub fn get_id(&self) -> i32 {
+        self.id.unwrap_or(-1)
+    }
+    pub fn get_book_id(&self) -> i32 {
+        self.book_id
+    }
+    pub fn get_sub_category_id(&self) -> i32 {
+        self.sub_category_id
+    }
+    pub fn get_code(&self) -> &Option<String> {
+        &self.code
+    }
+    pub fn get_memo(&self) -> &Option<String> {
+        &self.memo
+    }
+    pub fn get_balance(&self) -> i64 {
+        self.balance
+    }
+    pub fn get_created_at(&self) -> &Option<NaiveDateTime> {
+        &self.created_at
+    }
+    pub fn get_updated_at(&self) -> &Option<NaiveDateTime> {
+        &self.updated_at
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct UpdateAsset {
+    sub_category_id: FieldUpdate<i32>,
+    code: FieldUpdate<String>,
+    memo: FieldUpdate<String>,
+    balance: FieldUpdate<i64>,
+}
+
+impl UpdateAsset {
+    pub fn new(
+        sub_category_id: FieldUpdate<i32>,
+        code: FieldUpdate<String>,
+        memo: FieldUpdate<String>,
+        balance: FieldUpdate<i64>,
+    ) -> Self {
+        Self {
+            sub_category_id,
+            code,
+            memo,
+            balance,
+        }
+    }
+
+    pub fn get_sub_category_id(&self) -> &FieldUpdate<i32> {
+        &self.sub_category_id
+    }
+    pub fn get_code(&self) -> &FieldUpdate<String> {
+        &self.code
+    }
+    pub fn get_memo(&self) -> &FieldUpdate<String> {
+        &self.memo
+    }
+    pub fn get_balance(&self) -> &FieldUpdate<i64> {
+        &self.balance
+    }
+}
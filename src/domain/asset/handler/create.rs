@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use axum::{response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::asset::{dto::request::NewAsset, usecase::create::CreateAssetUsecase};
+
+pub async fn create_asset<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Json(new_asset): Json<NewAsset>,
+) -> impl IntoResponse
+where
+    T: CreateAssetUsecase,
+{
+    match usecase.create_asset(user_id, new_asset).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "성공", "asset_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, extract::Request, routing::post, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::create_asset;
+    use crate::{
+        domain::asset::{dto::request::NewAsset, usecase::create::CreateAssetUsecase},
+        global::errors::CustomError,
+    };
+
+    mock! {
+        CreateAssetUsecaseImpl {}
+
+        #[async_trait]
+        impl CreateAssetUsecase for CreateAssetUsecaseImpl {
+            async fn create_asset(&self, user_id: i32, new_asset: NewAsset) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockCreateAssetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/asset",
+                post(create_asset::<MockCreateAssetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_req(new_asset: &NewAsset) -> Request<String> {
+        Request::builder()
+            .method("POST")
+            .uri("/api/v1/asset")
+            .header("content-type", "application/json")
+            .body(to_string(&new_asset).unwrap())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_create_asset_status() {
+        // Arrange
+        let user_id = 1;
+        let new_asset = NewAsset::new(1, 1, None, Some("월급통장".to_string()), Some(150000));
+
+        let mut mock_usecase = MockCreateAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_create_asset()
+            .with(predicate::eq(user_id), predicate::eq(new_asset.clone()))
+            .returning(|_, _| Ok(1));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_asset);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 201);
+    }
+
+    #[tokio::test]
+    async fn check_create_asset_body() {
+        // Arrange
+        let user_id = 1;
+        let new_asset = NewAsset::new(1, 1, None, Some("월급통장".to_string()), Some(150000));
+
+        let mut mock_usecase = MockCreateAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_create_asset()
+            .with(predicate::eq(user_id), predicate::eq(new_asset.clone()))
+            .returning(|_, _| Ok(1));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_asset);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json["asset_id"], 1);
+    }
+
+    #[tokio::test]
+    async fn check_category_not_found() {
+        // Arrange
+        let user_id = 1;
+        let new_asset = NewAsset::new(1, -32, None, None, None);
+
+        let mut mock_usecase = MockCreateAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_create_asset()
+            .with(predicate::eq(user_id), predicate::eq(new_asset.clone()))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Category".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_asset);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404)
+    }
+
+    #[tokio::test]
+    async fn check_no_role() {
+        // Arrange
+        let user_id = 1;
+        let new_asset = NewAsset::new(1, 1, None, None, None);
+
+        let mut mock_usecase = MockCreateAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_create_asset()
+            .with(predicate::eq(user_id), predicate::eq(new_asset.clone()))
+            .returning(|_, _| Err(Box::new(CustomError::Unauthorized("AssetRole".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_asset);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::asset::usecase::delete::DeleteAssetUsecase;
+
+pub async fn delete_asset<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(asset_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: DeleteAssetUsecase,
+{
+    match usecase.delete_asset(user_id, asset_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::delete, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::asset::{handler::delete::delete_asset, usecase::delete::DeleteAssetUsecase},
+        global::errors::CustomError,
+    };
+
+    mock! {
+        DeleteAssetUsecaseImpl {}
+
+        #[async_trait]
+        impl DeleteAssetUsecase for DeleteAssetUsecaseImpl {
+            async fn delete_asset(&self, user_id: i32, asset_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockDeleteAssetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/asset/:asset_id",
+                delete(delete_asset::<MockDeleteAssetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(asset_id: i32) -> Request {
+        Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/v1/asset/{}", asset_id))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_delete_asset_status() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+        let mut mock_usecase = MockDeleteAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_asset()
+            .with(predicate::eq(user_id), predicate::eq(asset_id))
+            .returning(|_, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(asset_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_delete_asset_body() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+        let mut mock_usecase = MockDeleteAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_asset()
+            .with(predicate::eq(user_id), predicate::eq(asset_id))
+            .returning(|_, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(asset_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["message"], "성공");
+    }
+
+    #[tokio::test]
+    async fn check_id_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+        let mut mock_usecase = MockDeleteAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_asset()
+            .with(predicate::eq(user_id), predicate::eq(no_id))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Asset".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(no_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404);
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{delete, get, patch, post},
+    Extension, Router,
+};
+
+use sqlx::PgPool;
+
+mod create;
+mod delete;
+mod read;
+mod update;
+
+use create::create_asset;
+use delete::delete_asset;
+use read::{read_asset, read_assets};
+use update::{recalculate_balance, update_asset};
+
+use super::{
+    repository::{
+        delete::DeleteAssetRepoImpl, get_asset::GetAssetRepoImpl, save::SaveAssetRepoImpl,
+        update::UpdateAssetRepoImpl,
+    },
+    usecase::{
+        create::CreateAssetUsecaseImpl, delete::DeleteAssetUsecaseImpl, read::ReadAssetUsecaseImpl,
+        update::UpdateAssetUsecaseImpl,
+    },
+};
+
+pub fn create_router(pool: &Arc<PgPool>) -> Router {
+    let repository = SaveAssetRepoImpl::new(pool);
+    let usecase = CreateAssetUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/",
+            post(create_asset::<CreateAssetUsecaseImpl<SaveAssetRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn read_router(pool: &Arc<PgPool>) -> Router {
+    let repository = GetAssetRepoImpl::new(pool);
+    let usecase = ReadAssetUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/list/:book_id",
+            get(read_assets::<ReadAssetUsecaseImpl<GetAssetRepoImpl>>),
+        )
+        .route(
+            "/:asset_id",
+            get(read_asset::<ReadAssetUsecaseImpl<GetAssetRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn update_router(pool: &Arc<PgPool>) -> Router {
+    let repository = UpdateAssetRepoImpl::new(pool);
+    let usecase = UpdateAssetUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:asset_id",
+            patch(update_asset::<UpdateAssetUsecaseImpl<UpdateAssetRepoImpl>>),
+        )
+        .route(
+            "/recalculate/:asset_id",
+            patch(recalculate_balance::<UpdateAssetUsecaseImpl<UpdateAssetRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn delete_router(pool: &Arc<PgPool>) -> Router {
+    let repository = DeleteAssetRepoImpl::new(pool);
+    let usecase = DeleteAssetUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:asset_id",
+            delete(delete_asset::<DeleteAssetUsecaseImpl<DeleteAssetRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::asset::usecase::read::ReadAssetUsecase;
+
+pub async fn read_assets<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: ReadAssetUsecase,
+{
+    match usecase.read_assets(user_id, book_id).await {
+        Ok(assets) => (StatusCode::OK, Json(json!(assets))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+pub async fn read_asset<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(asset_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: ReadAssetUsecase,
+{
+    match usecase.read_asset(user_id, asset_id).await {
+        Ok(asset) => (StatusCode::OK, Json(json!(asset))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::get, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::{read_asset, read_assets};
+    use crate::{
+        domain::asset::{entity::Asset, usecase::read::ReadAssetUsecase},
+        global::errors::CustomError,
+    };
+
+    mock! {
+        ReadAssetUsecaseImpl {}
+
+        #[async_trait]
+        impl ReadAssetUsecase for ReadAssetUsecaseImpl {
+            async fn read_assets(&self, user_id: i32, book_id: i32) -> Result<Vec<Asset>, Box<CustomError>>;
+            async fn read_asset(&self, user_id: i32, asset_id: i32) -> Result<Asset, Box<CustomError>>;
+        }
+    }
+
+    fn test_assets() -> Vec<Asset> {
+        vec![
+            Asset::new(1, 1, 150000).id(Some(1)).build(),
+            Asset::new(1, 2, 3000000).id(Some(2)).build(),
+        ]
+    }
+
+    fn _create_list_app(user_id: i32, mock_usecase: MockReadAssetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/asset/list/:book_id",
+                get(read_assets::<MockReadAssetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_list_req(book_id: i32) -> Request {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/api/v1/asset/list/{}", book_id))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockReadAssetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/asset/:asset_id",
+                get(read_asset::<MockReadAssetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_req(asset_id: i32) -> Request {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/api/v1/asset/{}", asset_id))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_assets_status() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+
+        let mut mock_usecase = MockReadAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_read_assets()
+            .with(predicate::eq(user_id), predicate::eq(book_id))
+            .returning(|_, _| Ok(test_assets()));
+
+        let app = _create_list_app(user_id, mock_usecase);
+        let req = _create_list_req(book_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_read_assets_body() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+
+        let mut mock_usecase = MockReadAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_read_assets()
+            .with(predicate::eq(user_id), predicate::eq(book_id))
+            .returning(|_, _| Ok(test_assets()));
+
+        let app = _create_list_app(user_id, mock_usecase);
+        let req = _create_list_req(book_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json[1]["balance"], 3000000);
+    }
+
+    #[tokio::test]
+    async fn check_read_asset_body() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+        let mut mock_usecase = MockReadAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_read_asset()
+            .with(predicate::eq(user_id), predicate::eq(asset_id))
+            .returning(|_, i| Ok(Asset::new(1, 1, 150000).id(Some(i)).build()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(asset_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["id"], asset_id);
+    }
+
+    #[tokio::test]
+    async fn check_read_asset_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+        let mut mock_usecase = MockReadAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_read_asset()
+            .with(predicate::eq(user_id), predicate::eq(no_id))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Asset".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(no_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404);
+    }
+}
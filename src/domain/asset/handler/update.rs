@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::asset::{dto::request::EditAsset, usecase::update::UpdateAssetUsecase};
+
+pub async fn update_asset<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(asset_id): Path<i32>,
+    Json(edit_asset): Json<EditAsset>,
+) -> impl IntoResponse
+where
+    T: UpdateAssetUsecase,
+{
+    match usecase.update_asset(user_id, asset_id, edit_asset).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+pub async fn recalculate_balance<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(asset_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: UpdateAssetUsecase,
+{
+    match usecase.recalculate_balance(user_id, asset_id).await {
+        Ok(balance) => (
+            StatusCode::OK,
+            Json(json!({"message": "성공", "balance": balance})),
+        )
+            .into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::patch, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::asset::{dto::request::EditAsset, usecase::update::UpdateAssetUsecase},
+        global::errors::CustomError,
+    };
+
+    use super::{recalculate_balance, update_asset};
+
+    mock! {
+        UpdateAssetUsecaseImpl {}
+
+        #[async_trait]
+        impl UpdateAssetUsecase for UpdateAssetUsecaseImpl {
+            async fn update_asset(&self, user_id: i32, asset_id: i32, edit_asset: EditAsset) -> Result<(), Box<CustomError>>;
+            async fn recalculate_balance(&self, user_id: i32, asset_id: i32) -> Result<i64, Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockUpdateAssetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/asset/:asset_id",
+                patch(update_asset::<MockUpdateAssetUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/asset/recalculate/:asset_id",
+                patch(recalculate_balance::<MockUpdateAssetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(asset_id: i32, edit_asset: &EditAsset) -> Request {
+        Request::builder()
+            .method("PATCH")
+            .uri(format!("/api/v1/asset/{}", asset_id))
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(edit_asset).unwrap()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_update_asset_status() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+        let edit_asset = EditAsset::new(None, None, Some("NULL".to_string()), Some(32000));
+
+        let mut mock_usecase = MockUpdateAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_update_asset()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(asset_id),
+                predicate::eq(edit_asset.clone()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(asset_id, &edit_asset);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_update_asset_body() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+        let edit_asset = EditAsset::new(None, None, Some("NULL".to_string()), Some(32000));
+
+        let mut mock_usecase = MockUpdateAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_update_asset()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(asset_id),
+                predicate::eq(edit_asset.clone()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(asset_id, &edit_asset);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json["message"], "성공")
+    }
+
+    #[tokio::test]
+    async fn check_update_asset_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+        let edit_asset = EditAsset::new(None, None, None, Some(32000));
+
+        let mut mock_usecase = MockUpdateAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_update_asset()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(no_id),
+                predicate::eq(edit_asset.clone()),
+            )
+            .returning(|_, _, _| Err(Box::new(CustomError::NotFound("Asset".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(no_id, &edit_asset);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404)
+    }
+
+    #[tokio::test]
+    async fn check_recalculate_balance_body() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+
+        let mut mock_usecase = MockUpdateAssetUsecaseImpl::new();
+        mock_usecase
+            .expect_recalculate_balance()
+            .with(predicate::eq(user_id), predicate::eq(asset_id))
+            .returning(|_, _| Ok(18000));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = Request::builder()
+            .method("PATCH")
+            .uri(format!("/api/v1/asset/recalculate/{}", asset_id))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json["balance"], 18000)
+    }
+}
@@ -0,0 +1,7 @@
+mod dto;
+pub(crate) mod entity;
+mod handler;
+mod repository;
+mod usecase;
+
+pub mod route;
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::global::{constants::DeleteResult, errors::CustomError};
+
+pub struct DeleteAssetRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait DeleteAssetRepo: Send + Sync {
+    async fn delete_asset(&self, user_id: i32, asset_id: i32) -> Result<(), Box<CustomError>>;
+}
+
+impl DeleteAssetRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl DeleteAssetRepo for DeleteAssetRepoImpl {
+    async fn delete_asset(&self, user_id: i32, asset_id: i32) -> Result<(), Box<CustomError>> {
+        delete_asset(&self.pool, user_id, asset_id).await
+    }
+}
+
+async fn delete_asset(pool: &PgPool, user_id: i32, asset_id: i32) -> Result<(), Box<CustomError>> {
+    // 자산을 참조하는 기록, 반복 기록은 남겨두고 연결만 해제
+    let result = sqlx::query_as::<_, DeleteResult>(
+        r"
+        WITH AssetExists AS (
+            SELECT book_id
+            FROM tb_asset
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT a.book_id
+            FROM AssetExists AS a
+            JOIN tb_user_book_role AS br ON a.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role != 'viewer'
+        ),
+        UnlinkRecord AS (
+            UPDATE tb_record SET asset_id = NULL
+            WHERE asset_id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+        ),
+        UnlinkRecurring AS (
+            UPDATE tb_recurring_record SET asset_id = NULL
+            WHERE asset_id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+        ),
+        DeleteAsset AS (
+            DELETE FROM tb_asset
+            WHERE id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM AssetExists) AS is_exist,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT COUNT(*) FROM DeleteAsset) AS delete_count
+        ",
+    )
+    .bind(user_id)
+    .bind(asset_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(DeleteAsset {}): {:?}", asset_id, &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Asset".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("AssetRole".to_string())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::asset::{
+            entity::Asset,
+            repository::{get_asset::get_by_id, save::save_asset},
+        },
+        global::errors::CustomError,
+    };
+
+    use super::delete_asset;
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_delete_asset_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let asset = Asset::new(1, 1, 0);
+
+        let new_id = save_asset(&pool, user_id, asset).await.unwrap();
+
+        // Act
+        let result = delete_asset(&pool, user_id, new_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, new_id).await;
+        assert!(row.is_err())
+    }
+
+    #[tokio::test]
+    async fn check_linked_record_kept() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let asset = Asset::new(1, 1, 0);
+        let new_id = save_asset(&pool, user_id, asset).await.unwrap();
+
+        let record_id: i64 = sqlx::query_scalar(
+            "INSERT INTO tb_record (book_id, sub_category_id, amount, target_dt, asset_id)
+            VALUES (1, 17, 15000, $1, $2) RETURNING id",
+        )
+        .bind(NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap())
+        .bind(new_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        // Act
+        let result = delete_asset(&pool, user_id, new_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let asset_id: Option<i32> =
+            sqlx::query_scalar("SELECT asset_id FROM tb_record WHERE id = $1")
+                .bind(record_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(asset_id, None);
+    }
+
+    #[tokio::test]
+    async fn check_asset_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+
+        // Act
+        let result = delete_asset(&pool, user_id, no_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        println!("{:?}", result);
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let viewer_id = 2;
+        let asset_id = 1;
+
+        // Act
+        let result = delete_asset(&pool, viewer_id, asset_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        println!("{:?}", result);
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::{domain::asset::entity::Asset, global::errors::CustomError};
+
+pub struct GetAssetRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait GetAssetRepo: Send + Sync {
+    async fn get_list(&self, user_id: i32, book_id: i32) -> Result<Vec<Asset>, Box<CustomError>>;
+    async fn get_by_id(&self, user_id: i32, asset_id: i32) -> Result<Asset, Box<CustomError>>;
+}
+
+impl GetAssetRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl GetAssetRepo for GetAssetRepoImpl {
+    async fn get_list(&self, user_id: i32, book_id: i32) -> Result<Vec<Asset>, Box<CustomError>> {
+        get_list(&self.pool, user_id, book_id).await
+    }
+    async fn get_by_id(&self, user_id: i32, asset_id: i32) -> Result<Asset, Box<CustomError>> {
+        get_by_id(&self.pool, user_id, asset_id).await
+    }
+}
+
+async fn get_list(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<Asset>, Box<CustomError>> {
+    let rows = sqlx::query_as::<_, Asset>(
+        "
+        SELECT a.*
+        FROM tb_asset AS a
+        JOIN tb_user_book_role AS br ON a.book_id = br.book_id
+        WHERE br.user_id = $1 AND a.book_id = $2
+        ORDER BY a.id
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetAssets): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn get_by_id(
+    pool: &PgPool,
+    user_id: i32,
+    asset_id: i32,
+) -> Result<Asset, Box<CustomError>> {
+    let row = sqlx::query_as::<_, Asset>(
+        "
+        SELECT a.* FROM tb_asset AS a
+        JOIN tb_user_book_role AS br ON a.book_id = br.book_id
+        WHERE br.user_id = $1 AND a.id = $2
+    ",
+    )
+    .bind(user_id)
+    .bind(asset_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetAsset {}): {:?}", asset_id, &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            sqlx::Error::RowNotFound => CustomError::NotFound("Asset".to_string()),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::database::create_connection_pool,
+        domain::asset::{
+            entity::Asset,
+            repository::get_asset::{get_by_id, get_list},
+        },
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_get_list_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let book_id = 1;
+
+        // Act
+        let result = get_list(&pool, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        let rows = sqlx::query_as::<_, Asset>("SELECT * FROM tb_asset WHERE book_id = $1")
+            .bind(book_id)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), rows.len());
+    }
+
+    #[tokio::test]
+    async fn check_get_list_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let book_id = 1;
+
+        // Act
+        let result = get_list(&pool, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn check_get_by_id_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2; // viewer
+        let asset_id = 1;
+
+        // Act
+        let result = get_by_id(&pool, user_id, asset_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        let row = sqlx::query_as::<_, Asset>("SELECT * FROM tb_asset WHERE id = $1")
+            .bind(asset_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(result.get_sub_category_id(), row.get_sub_category_id())
+    }
+
+    #[tokio::test]
+    async fn check_get_by_id_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+
+        // Act
+        let result = get_by_id(&pool, user_id, no_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        println!("{:?}", result.as_ref().err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::{Error, PgPool};
+
+use crate::{domain::asset::entity::Asset, global::errors::CustomError};
+
+pub struct SaveAssetRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait SaveAssetRepo: Send + Sync {
+    async fn save_asset(&self, user_id: i32, asset: Asset) -> Result<i32, Box<CustomError>>;
+}
+
+impl SaveAssetRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl SaveAssetRepo for SaveAssetRepoImpl {
+    async fn save_asset(&self, user_id: i32, asset: Asset) -> Result<i32, Box<CustomError>> {
+        save_asset(&self.pool, user_id, asset).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct InsertAsset {
+    is_authorized: bool,
+    asset_id: Option<i32>,
+    is_category_exist: bool,
+}
+
+impl InsertAsset {
+    fn get_authorized(&self) -> bool {
+        self.is_authorized
+    }
+    fn get_asset_id(&self) -> Option<i32> {
+        self.asset_id
+    }
+    fn get_category_exist(&self) -> bool {
+        self.is_category_exist
+    }
+}
+
+pub async fn save_asset(
+    pool: &PgPool,
+    user_id: i32,
+    asset: Asset,
+) -> Result<i32, Box<CustomError>> {
+    let result = sqlx::query_as::<_, InsertAsset>(
+        r#"
+        WITH AuthorityCheck AS (
+            SELECT book_id
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2 AND role != 'viewer'
+        ),
+        CategoryCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM tb_sub_category AS sc
+                JOIN tb_base_category AS bc ON bc.id = sc.base_id
+                LEFT JOIN AuthorityCheck AS ac ON bc.book_id = ac.book_id
+                WHERE sc.id = $3
+                    AND bc.is_record = false
+                    AND (ac.book_id IS NOT NULL OR bc.book_id IS NULL)
+            ) AS is_category_exist
+        ),
+        InsertAsset AS (
+            INSERT INTO tb_asset (book_id, sub_category_id, code, memo, balance, opening_balance, created_at)
+                SELECT book_id, $3, $4, $5, $6, $6, NOW()
+                    FROM AuthorityCheck
+                    WHERE book_id IS NOT NULL
+                        AND (SELECT is_category_exist FROM CategoryCheck) = true
+            RETURNING id
+        )
+        SELECT
+            (SELECT id FROM InsertAsset) AS asset_id,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT is_category_exist FROM CategoryCheck) AS is_category_exist;
+    "#,
+    )
+    .bind(user_id)
+    .bind(asset.get_book_id())
+    .bind(asset.get_sub_category_id())
+    .bind(asset.get_code())
+    .bind(asset.get_memo())
+    .bind(asset.get_balance())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Save(Asset): {:?}", e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("AssetRole".to_string())));
+    } else if !result.get_category_exist() {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    }
+
+    Ok(result.get_asset_id().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::database::create_connection_pool,
+        domain::asset::{entity::Asset, repository::save::save_asset},
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_save_asset_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let sub_category_id = 1; // 입출금
+        let asset = Asset::new(1, sub_category_id, 150000).memo(Some("생활비 통장".to_string()));
+
+        // Act
+        let result = save_asset(&pool, user_id, asset).await;
+        let inserted_id = result.map_err(|e| println!("{:?}", e)).unwrap();
+
+        // Assert
+        let row = sqlx::query_as::<_, Asset>("SELECT * FROM tb_asset WHERE id = $1")
+            .bind(inserted_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(inserted_id, row.get_id());
+        assert_eq!(row.get_balance(), 150000);
+        assert_eq!(&None, row.get_code());
+    }
+
+    #[tokio::test]
+    async fn check_category_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_category_id = -32; // 없는 카테고리, 다른 사람 카테고리
+        let asset = Asset::new(1, no_category_id, 0);
+
+        // Act
+        let result = save_asset(&pool, user_id, asset).await;
+
+        // Assert
+        assert!(result.is_err());
+        println!("{:?}", result.as_ref().err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_record_category() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let record_category_id = 17; // 식비, 자산 카테고리가 아님
+        let asset = Asset::new(1, record_category_id, 0);
+
+        // Act
+        let result = save_asset(&pool, user_id, asset).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2;
+        let book_id = 1; // 읽기전용 가계부
+        let asset = Asset::new(book_id, 1, 0);
+
+        // Act
+        let result = save_asset(&pool, user_id, asset).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,622 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::{
+    domain::asset::entity::UpdateAsset,
+    global::{constants::FieldUpdate, errors::CustomError},
+};
+
+pub struct UpdateAssetRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait UpdateAssetRepo: Send + Sync {
+    async fn update_asset(
+        &self,
+        user_id: i32,
+        asset_id: i32,
+        edit_asset: UpdateAsset,
+    ) -> Result<(), Box<CustomError>>;
+    async fn recalculate_balance(
+        &self,
+        user_id: i32,
+        asset_id: i32,
+    ) -> Result<i64, Box<CustomError>>;
+}
+
+impl UpdateAssetRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl UpdateAssetRepo for UpdateAssetRepoImpl {
+    async fn update_asset(
+        &self,
+        user_id: i32,
+        asset_id: i32,
+        edit_asset: UpdateAsset,
+    ) -> Result<(), Box<CustomError>> {
+        update_asset(&self.pool, user_id, asset_id, edit_asset).await
+    }
+    async fn recalculate_balance(
+        &self,
+        user_id: i32,
+        asset_id: i32,
+    ) -> Result<i64, Box<CustomError>> {
+        recalculate_balance(&self.pool, user_id, asset_id).await
+    }
+}
+
+fn make_query(index: &mut i32, field_name: &str) -> String {
+    *index += 1;
+    format!("{} = ${}, ", field_name, index)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct UpdateAssetResult {
+    is_exist: bool,
+    is_authorized: bool,
+    update_count: i64,
+    is_category_exist: bool,
+}
+
+impl UpdateAssetResult {
+    fn get_exist(&self) -> bool {
+        self.is_exist
+    }
+    fn get_authorized(&self) -> bool {
+        self.is_authorized
+    }
+    fn get_count(&self) -> i64 {
+        self.update_count
+    }
+    fn get_category_exist(&self) -> bool {
+        self.is_category_exist
+    }
+}
+
+async fn update_asset(
+    pool: &PgPool,
+    user_id: i32,
+    asset_id: i32,
+    edit_asset: UpdateAsset,
+) -> Result<(), Box<CustomError>> {
+    let mut query = r"
+        WITH AssetExists AS (
+            SELECT book_id
+            FROM tb_asset
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT a.book_id
+            FROM AssetExists AS a
+            JOIN tb_user_book_role AS br ON a.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role != 'viewer'
+        ),"
+    .to_string();
+
+    let mut index = 2;
+    let mut ctg_check = false;
+
+    let mut update_query = r"
+        UpdateAsset AS (
+            UPDATE tb_asset SET "
+        .to_string();
+
+    if let FieldUpdate::Set(_) = edit_asset.get_sub_category_id() {
+        update_query.push_str(&make_query(&mut index, "sub_category_id"));
+        query.push_str(&format!(
+            r"
+            CategoryCheck AS (
+                SELECT EXISTS (
+                    SELECT 1
+                    FROM tb_sub_category AS sc
+                    JOIN tb_base_category AS bc ON bc.id = sc.base_id
+                    LEFT JOIN AssetExists AS a ON bc.book_id = a.book_id
+                    WHERE sc.id = ${}
+                        AND bc.is_record = false
+                        AND (a.book_id IS NOT NULL OR bc.book_id IS NULL)
+                ) AS is_category_exist
+            ),",
+            index
+        ));
+        ctg_check = true;
+    };
+    match edit_asset.get_code() {
+        FieldUpdate::Set(_) | FieldUpdate::SetNone => {
+            update_query.push_str(&make_query(&mut index, "code"));
+        }
+        _ => {}
+    }
+    match edit_asset.get_memo() {
+        FieldUpdate::Set(_) | FieldUpdate::SetNone => {
+            update_query.push_str(&make_query(&mut index, "memo"));
+        }
+        _ => {}
+    }
+    if let FieldUpdate::Set(_) = edit_asset.get_balance() {
+        update_query.push_str(&make_query(&mut index, "balance"));
+        // 직접 수정한 잔액은 시작 잔액에 반영해 재계산 후에도 유지
+        update_query.push_str(&format!(
+            "opening_balance = opening_balance + (${} - balance), ",
+            index
+        ));
+    };
+
+    if index == 2 {
+        return Err(Box::new(CustomError::NoFieldUpdate("Asset".to_string())));
+    }
+
+    update_query.push_str(
+        &("
+            updated_at = NOW()
+            WHERE id = $2
+                AND EXISTS (SELECT book_id FROM AuthorityCheck) = true
+            "
+        .to_string()
+            + if ctg_check {
+                "AND (SELECT is_category_exist FROM CategoryCheck) = true\n"
+            } else {
+                ""
+            }),
+    );
+
+    query.push_str(
+        &(update_query
+            + "RETURNING id
+        )" + "
+            SELECT
+                EXISTS (SELECT 1 FROM AssetExists) AS is_exist,
+                EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+                (SELECT COUNT(*) FROM UpdateAsset) AS update_count\n"
+            + if ctg_check {
+                ",(SELECT is_category_exist FROM CategoryCheck) AS is_category_exist\n"
+            } else {
+                ",true AS is_category_exist"
+            }
+            + ";"),
+    );
+
+    let mut query_builder = sqlx::query_as::<_, UpdateAssetResult>(&query)
+        .bind(user_id)
+        .bind(asset_id);
+
+    if let FieldUpdate::Set(v) = edit_asset.get_sub_category_id() {
+        query_builder = query_builder.bind(v);
+    }
+    match edit_asset.get_code() {
+        FieldUpdate::Set(v) => {
+            query_builder = query_builder.bind(v);
+        }
+        FieldUpdate::SetNone => {
+            query_builder = query_builder.bind(None::<String>);
+        }
+        _ => {}
+    }
+    match edit_asset.get_memo() {
+        FieldUpdate::Set(v) => {
+            query_builder = query_builder.bind(v);
+        }
+        FieldUpdate::SetNone => {
+            query_builder = query_builder.bind(None::<String>);
+        }
+        _ => {}
+    }
+    if let FieldUpdate::Set(v) = edit_asset.get_balance() {
+        query_builder = query_builder.bind(v);
+    }
+
+    let result = query_builder.fetch_one(pool).await.map_err(|e| {
+        let err_msg = format!("Update(Asset {}): {}", asset_id, e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Asset".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("AssetRole".to_string())));
+    } else if !result.get_category_exist() {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    } else if result.get_count() == 0 {
+        return Err(Box::new(CustomError::NotFound("Asset".to_string())));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct RecalculateResult {
+    is_exist: bool,
+    is_authorized: bool,
+    balance: Option<i64>,
+}
+
+impl RecalculateResult {
+    fn get_exist(&self) -> bool {
+        self.is_exist
+    }
+    fn get_authorized(&self) -> bool {
+        self.is_authorized
+    }
+    fn get_balance(&self) -> Option<i64> {
+        self.balance
+    }
+}
+
+async fn recalculate_balance(
+    pool: &PgPool,
+    user_id: i32,
+    asset_id: i32,
+) -> Result<i64, Box<CustomError>> {
+    // 시작 잔액에 연결된 기록 전체를 더해 다시 계산 (수입, 이체 입금 +, 그 외 -)
+    let result = sqlx::query_as::<_, RecalculateResult>(
+        r"
+        WITH AssetExists AS (
+            SELECT book_id
+            FROM tb_asset
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT a.book_id
+            FROM AssetExists AS a
+            JOIN tb_user_book_role AS br ON a.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role != 'viewer'
+        ),
+        RecordSum AS (
+            SELECT COALESCE(
+                SUM(CASE WHEN r.is_transfer_in OR (r.transfer_id IS NULL AND bc.is_income)
+                    THEN r.amount ELSE -r.amount END),
+                0
+            )::BIGINT AS balance
+            FROM tb_record AS r
+            JOIN tb_sub_category AS sc ON sc.id = r.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            WHERE r.asset_id = $2
+        ),
+        UpdateAsset AS (
+            UPDATE tb_asset SET
+                balance = opening_balance + (SELECT balance FROM RecordSum),
+                updated_at = NOW()
+            WHERE id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+            RETURNING balance
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM AssetExists) AS is_exist,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT balance FROM UpdateAsset) AS balance;
+        ",
+    )
+    .bind(user_id)
+    .bind(asset_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Recalculate(Asset {}): {}", asset_id, e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Asset".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("AssetRole".to_string())));
+    }
+
+    Ok(result.get_balance().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::asset::{
+            entity::{Asset, UpdateAsset},
+            repository::{
+                get_asset::get_by_id,
+                save::save_asset,
+                update::{recalculate_balance, update_asset},
+            },
+        },
+        global::{constants::FieldUpdate, errors::CustomError},
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    async fn _save_sample(pool: &PgPool, user_id: i32) -> i32 {
+        let sub_category_id = 1; // 입출금
+        let asset = Asset::new(1, sub_category_id, 0).memo(Some("수정용 자산".to_string()));
+        save_asset(&pool, user_id, asset).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_update_asset_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let change_balance: i64 = 32000;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_asset = UpdateAsset::new(
+            FieldUpdate::Set(2), // 저축
+            FieldUpdate::NoChange,
+            FieldUpdate::SetNone,
+            FieldUpdate::Set(change_balance),
+        );
+
+        // Act
+        let result = update_asset(&pool, user_id, new_id, edit_asset).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+
+        assert_eq!(row.get_balance(), change_balance);
+        assert_eq!(row.get_sub_category_id(), 2);
+        assert_eq!(row.get_memo(), &None);
+    }
+
+    #[tokio::test]
+    async fn check_no_field_to_update() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_asset = UpdateAsset::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+
+        // Act
+        let result = update_asset(&pool, user_id, new_id, edit_asset).await;
+
+        // Assert
+        assert!(result.is_err());
+        println!("{:?}", result.as_ref().err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NoFieldUpdate(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_asset_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+        let edit_asset = UpdateAsset::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(15000),
+        );
+
+        // Act
+        let result = update_asset(&pool, user_id, no_id, edit_asset).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_category_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_asset = UpdateAsset::new(
+            FieldUpdate::Set(17), // 자산 카테고리가 아닌 경우
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+
+        // Act
+        let result = update_asset(&pool, user_id, new_id, edit_asset).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let viewer_id = 2;
+        let asset_id = 1;
+        let edit_asset = UpdateAsset::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(15000),
+        );
+
+        // Act
+        let result = update_asset(&pool, viewer_id, asset_id, edit_asset).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_updated_at_changed() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_asset = UpdateAsset::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::Set("1002-000-000000".to_string()),
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+        let last_time = Utc::now().naive_utc();
+
+        // Act
+        let result = update_asset(&pool, user_id, new_id, edit_asset).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let updated_asset = get_by_id(&pool, user_id, new_id).await.unwrap();
+        assert!(last_time < updated_asset.get_updated_at().unwrap())
+    }
+
+    #[tokio::test]
+    async fn check_recalculate_balance_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        sqlx::query(
+            "INSERT INTO tb_record (book_id, sub_category_id, amount, target_dt, asset_id) VALUES
+                (1, 9, 30000, '2024-09-08', $1),
+                (1, 17, 12000, '2024-09-08', $1)",
+        )
+        .bind(new_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // Act
+        let result = recalculate_balance(&pool, user_id, new_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+
+        assert_eq!(result.unwrap(), 30000 - 12000);
+        assert_eq!(row.get_balance(), 30000 - 12000);
+    }
+
+    #[tokio::test]
+    async fn check_recalculate_keep_opening_balance() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let asset = Asset::new(1, 1, 150000);
+        let new_id = save_asset(&pool, user_id, asset).await.unwrap();
+
+        // Act
+        let result = recalculate_balance(&pool, user_id, new_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        // 연결된 기록이 없으면 시작 잔액 그대로
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+        assert_eq!(result.unwrap(), 150000);
+        assert_eq!(row.get_balance(), 150000);
+    }
+
+    #[tokio::test]
+    async fn check_recalculate_after_balance_edit() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let new_id = _save_sample(&pool, user_id).await;
+        sqlx::query(
+            "INSERT INTO tb_record (book_id, sub_category_id, amount, target_dt, asset_id) VALUES
+                (1, 17, 12000, '2024-09-08', $1)",
+        )
+        .bind(new_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        recalculate_balance(&pool, user_id, new_id).await.unwrap();
+        let edit_asset = UpdateAsset::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(50000),
+        );
+        update_asset(&pool, user_id, new_id, edit_asset)
+            .await
+            .unwrap();
+
+        // Act
+        let result = recalculate_balance(&pool, user_id, new_id).await;
+
+        // Assert
+        // 직접 수정한 잔액 유지
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 50000);
+    }
+
+    #[tokio::test]
+    async fn check_recalculate_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let viewer_id = 2;
+        let asset_id = 1;
+
+        // Act
+        let result = recalculate_balance(&pool, viewer_id, asset_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
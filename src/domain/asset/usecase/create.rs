@@ -0,0 +1,116 @@
+use axum::async_trait;
+
+use crate::{
+    domain::asset::{dto::request::NewAsset, repository::save::SaveAssetRepo},
+    global::errors::CustomError,
+};
+
+pub struct CreateAssetUsecaseImpl<T>
+where
+    T: SaveAssetRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait CreateAssetUsecase: Send + Sync {
+    async fn create_asset(
+        &self,
+        user_id: i32,
+        new_asset: NewAsset,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl<T> CreateAssetUsecaseImpl<T>
+where
+    T: SaveAssetRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> CreateAssetUsecase for CreateAssetUsecaseImpl<T>
+where
+    T: SaveAssetRepo,
+{
+    async fn create_asset(
+        &self,
+        user_id: i32,
+        new_asset: NewAsset,
+    ) -> Result<i32, Box<CustomError>> {
+        create_asset(&self.repository, user_id, new_asset).await
+    }
+}
+
+async fn create_asset<T>(
+    repository: &T,
+    user_id: i32,
+    new_asset: NewAsset,
+) -> Result<i32, Box<CustomError>>
+where
+    T: SaveAssetRepo,
+{
+    repository.save_asset(user_id, new_asset.to_entity()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::domain::asset::{
+        dto::request::NewAsset, entity::Asset, repository::save::SaveAssetRepo,
+        usecase::create::create_asset,
+    };
+    use crate::global::errors::CustomError;
+
+    mock! {
+        SaveAssetRepoImpl {}
+
+        #[async_trait]
+        impl SaveAssetRepo for SaveAssetRepoImpl {
+            async fn save_asset(&self, user_id: i32, asset: Asset) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_create_asset_success() {
+        // Arrange
+        let user_id = 1;
+        let new_asset = NewAsset::new(
+            1,
+            1,
+            Some("1002-123-456789".to_string()),
+            Some("월급통장".to_string()),
+            None,
+        );
+
+        let mut mock_repo = MockSaveAssetRepoImpl::new();
+        mock_repo
+            .expect_save_asset()
+            .with(predicate::eq(user_id), predicate::eq(new_asset.to_entity()))
+            .returning(|_, _| Ok(1));
+
+        // Act
+        let result = create_asset(&mock_repo, user_id, new_asset).await;
+        assert!(result.is_ok());
+        let inserted_id = result.unwrap();
+
+        // Assert
+        assert_eq!(inserted_id, 1);
+    }
+
+    #[tokio::test]
+    async fn check_default_balance() {
+        // Arrange
+        let new_asset = NewAsset::new(1, 1, None, None, None);
+
+        // Act
+        let asset = new_asset.to_entity();
+
+        // Assert
+        assert_eq!(asset.get_balance(), 0);
+    }
+}
@@ -0,0 +1,83 @@
+use axum::async_trait;
+
+use crate::{domain::asset::repository::delete::DeleteAssetRepo, global::errors::CustomError};
+
+pub struct DeleteAssetUsecaseImpl<T>
+where
+    T: DeleteAssetRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait DeleteAssetUsecase: Send + Sync {
+    async fn delete_asset(&self, user_id: i32, asset_id: i32) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> DeleteAssetUsecaseImpl<T>
+where
+    T: DeleteAssetRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> DeleteAssetUsecase for DeleteAssetUsecaseImpl<T>
+where
+    T: DeleteAssetRepo,
+{
+    async fn delete_asset(&self, user_id: i32, asset_id: i32) -> Result<(), Box<CustomError>> {
+        delete_asset(&self.repository, user_id, asset_id).await
+    }
+}
+
+async fn delete_asset<T>(
+    repository: &T,
+    user_id: i32,
+    asset_id: i32,
+) -> Result<(), Box<CustomError>>
+where
+    T: DeleteAssetRepo,
+{
+    repository.delete_asset(user_id, asset_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{domain::asset::repository::delete::DeleteAssetRepo, global::errors::CustomError};
+
+    use super::delete_asset;
+
+    mock! {
+        DeleteAssetRepoImpl {}
+
+        #[async_trait]
+        impl DeleteAssetRepo for DeleteAssetRepoImpl{
+            async fn delete_asset(&self, user_id: i32, asset_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_delete_asset_success() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+
+        let mut mock_repo = MockDeleteAssetRepoImpl::new();
+        mock_repo
+            .expect_delete_asset()
+            .with(predicate::eq(user_id), predicate::eq(asset_id))
+            .returning(|_, _| Ok(()));
+
+        // Act
+        let result = delete_asset(&mock_repo, user_id, asset_id).await;
+
+        // Assert
+        assert!(result.is_ok())
+    }
+}
@@ -0,0 +1,141 @@
+use axum::async_trait;
+
+use crate::{
+    domain::asset::{entity::Asset, repository::get_asset::GetAssetRepo},
+    global::errors::CustomError,
+};
+
+pub struct ReadAssetUsecaseImpl<T>
+where
+    T: GetAssetRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait ReadAssetUsecase: Send + Sync {
+    async fn read_assets(&self, user_id: i32, book_id: i32)
+        -> Result<Vec<Asset>, Box<CustomError>>;
+    async fn read_asset(&self, user_id: i32, asset_id: i32) -> Result<Asset, Box<CustomError>>;
+}
+
+impl<T> ReadAssetUsecaseImpl<T>
+where
+    T: GetAssetRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> ReadAssetUsecase for ReadAssetUsecaseImpl<T>
+where
+    T: GetAssetRepo,
+{
+    async fn read_assets(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Asset>, Box<CustomError>> {
+        read_assets(&self.repository, user_id, book_id).await
+    }
+
+    async fn read_asset(&self, user_id: i32, asset_id: i32) -> Result<Asset, Box<CustomError>> {
+        read_asset(&self.repository, user_id, asset_id).await
+    }
+}
+
+async fn read_assets<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<Asset>, Box<CustomError>>
+where
+    T: GetAssetRepo,
+{
+    repository.get_list(user_id, book_id).await
+}
+
+async fn read_asset<T>(
+    repository: &T,
+    user_id: i32,
+    asset_id: i32,
+) -> Result<Asset, Box<CustomError>>
+where
+    T: GetAssetRepo,
+{
+    repository.get_by_id(user_id, asset_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::asset::{
+            entity::Asset,
+            repository::get_asset::GetAssetRepo,
+            usecase::read::{read_asset, read_assets},
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        GetAssetRepoImpl {}
+
+        #[async_trait]
+        impl GetAssetRepo for GetAssetRepoImpl {
+            async fn get_list(&self, user_id: i32, book_id: i32) -> Result<Vec<Asset>, Box<CustomError>>;
+            async fn get_by_id(&self, user_id: i32, asset_id: i32) -> Result<Asset, Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_read_assets_success() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+
+        let mut mock_repo = MockGetAssetRepoImpl::new();
+        mock_repo
+            .expect_get_list()
+            .with(predicate::eq(user_id), predicate::eq(book_id))
+            .returning(|_, b| {
+                Ok(vec![
+                    Asset::new(b, 1, 150000).id(Some(1)).build(),
+                    Asset::new(b, 2, 3000000).id(Some(2)).build(),
+                ])
+            });
+
+        // Act
+        let result = read_assets(&mock_repo, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_read_asset_success() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+
+        let mut mock_repo = MockGetAssetRepoImpl::new();
+        mock_repo
+            .expect_get_by_id()
+            .with(predicate::eq(user_id), predicate::eq(asset_id))
+            .returning(|_, i| Ok(Asset::new(1, 1, 150000).id(Some(i)).build()));
+
+        // Act
+        let result = read_asset(&mock_repo, user_id, asset_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.get_id(), asset_id);
+    }
+}
@@ -0,0 +1,153 @@
+use axum::async_trait;
+
+use crate::{
+    domain::asset::{dto::request::EditAsset, repository::update::UpdateAssetRepo},
+    global::errors::CustomError,
+};
+
+pub struct UpdateAssetUsecaseImpl<T>
+where
+    T: UpdateAssetRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait UpdateAssetUsecase: Send + Sync {
+    async fn update_asset(
+        &self,
+        user_id: i32,
+        asset_id: i32,
+        edit_asset: EditAsset,
+    ) -> Result<(), Box<CustomError>>;
+    async fn recalculate_balance(
+        &self,
+        user_id: i32,
+        asset_id: i32,
+    ) -> Result<i64, Box<CustomError>>;
+}
+
+impl<T> UpdateAssetUsecaseImpl<T>
+where
+    T: UpdateAssetRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> UpdateAssetUsecase for UpdateAssetUsecaseImpl<T>
+where
+    T: UpdateAssetRepo,
+{
+    async fn update_asset(
+        &self,
+        user_id: i32,
+        asset_id: i32,
+        edit_asset: EditAsset,
+    ) -> Result<(), Box<CustomError>> {
+        update_asset(&self.repository, user_id, asset_id, edit_asset).await
+    }
+    async fn recalculate_balance(
+        &self,
+        user_id: i32,
+        asset_id: i32,
+    ) -> Result<i64, Box<CustomError>> {
+        recalculate_balance(&self.repository, user_id, asset_id).await
+    }
+}
+
+async fn update_asset<T>(
+    repository: &T,
+    user_id: i32,
+    asset_id: i32,
+    edit_asset: EditAsset,
+) -> Result<(), Box<CustomError>>
+where
+    T: UpdateAssetRepo,
+{
+    repository
+        .update_asset(user_id, asset_id, edit_asset.to_update())
+        .await
+}
+
+async fn recalculate_balance<T>(
+    repository: &T,
+    user_id: i32,
+    asset_id: i32,
+) -> Result<i64, Box<CustomError>>
+where
+    T: UpdateAssetRepo,
+{
+    repository.recalculate_balance(user_id, asset_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::asset::{
+            dto::request::EditAsset, entity::UpdateAsset, repository::update::UpdateAssetRepo,
+        },
+        global::errors::CustomError,
+    };
+
+    use super::{recalculate_balance, update_asset};
+
+    mock! {
+        UpdateAssetRepoImpl {}
+
+        #[async_trait]
+        impl UpdateAssetRepo for UpdateAssetRepoImpl {
+            async fn update_asset(&self, user_id: i32, asset_id: i32, edit_asset: UpdateAsset) -> Result<(), Box<CustomError>>;
+            async fn recalculate_balance(&self, user_id: i32, asset_id: i32) -> Result<i64, Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_update_asset_success() {
+        // Arrange
+        let edit_asset = EditAsset::new(None, None, Some("NULL".to_string()), Some(32000));
+        let user_id = 1;
+
+        let asset_id = 1;
+
+        let mut mock_repo = MockUpdateAssetRepoImpl::new();
+        mock_repo
+            .expect_update_asset()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(asset_id),
+                predicate::eq(edit_asset.clone().to_update()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        // Act
+        let result = update_asset(&mock_repo, user_id, asset_id, edit_asset).await;
+
+        // Assert
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok())
+    }
+
+    #[tokio::test]
+    async fn check_recalculate_balance_success() {
+        // Arrange
+        let user_id = 1;
+        let asset_id = 1;
+
+        let mut mock_repo = MockUpdateAssetRepoImpl::new();
+        mock_repo
+            .expect_recalculate_balance()
+            .with(predicate::eq(user_id), predicate::eq(asset_id))
+            .returning(|_, _| Ok(18000));
+
+        // Act
+        let result = recalculate_balance(&mock_repo, user_id, asset_id).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 18000)
+    }
+}
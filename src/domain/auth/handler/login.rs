@@ -13,18 +13,23 @@ use serde_json::json;
 use crate::{
     config::jwt::AuthConfig,
     domain::{
-        auth::{usecase::login::LoginUserUsecase, utils::jwt::create_jwt},
+        auth::{
+            usecase::{login::LoginUserUsecase, session::SessionUsecase},
+            utils::jwt::{create_jwt, create_refresh_jwt, REFRESH_EXP_MINS},
+        },
         user::dto::request::LoginInfo,
     },
 };
 
-pub async fn login<T>(
+pub async fn login<T, S>(
     Extension(usecase): Extension<Arc<T>>,
+    Extension(session_usecase): Extension<Arc<S>>,
     Extension(auth_config): Extension<Arc<AuthConfig>>,
     Json(login_info): Json<LoginInfo>,
 ) -> impl IntoResponse
 where
     T: LoginUserUsecase,
+    S: SessionUsecase,
 {
     // 입력값 검증
     let user_info = match usecase.login(login_info).await {
@@ -32,6 +37,12 @@ where
         Err(e) => return e.as_ref().into_response(),
     };
 
+    // 세션 생성
+    let jti = match session_usecase.create_session(user_info.get_id()).await {
+        Ok(jti) => jti,
+        Err(e) => return e.as_ref().into_response(),
+    };
+
     // 토큰 생성
     let access_token = create_jwt(
         user_info.get_id(),
@@ -40,11 +51,12 @@ where
         60, // 1시간
     )
     .unwrap();
-    let refresh_token = create_jwt(
+    let refresh_token = create_refresh_jwt(
         user_info.get_id(),
         Some(user_info.get_username().to_string()),
+        &jti,
         auth_config.get_refresh(),
-        43200, // 30일
+        REFRESH_EXP_MINS,
     )
     .unwrap();
 
@@ -85,7 +97,7 @@ mod tests {
     use crate::{
         config::jwt::get_config,
         domain::{
-            auth::usecase::login::LoginUserUsecase,
+            auth::usecase::{login::LoginUserUsecase, session::SessionUsecase},
             user::dto::{
                 request::{LoginInfo, LoginType},
                 response::UserInfo,
@@ -105,6 +117,18 @@ mod tests {
         }
     }
 
+    mock! {
+        SessionUsecaseImpl {}
+
+        #[async_trait]
+        impl SessionUsecase for SessionUsecaseImpl {
+            async fn create_session(&self, user_id: i32) -> Result<String, Arc<CustomError>>;
+            async fn rotate_session(&self, user_id: i32, jti: &str) -> Result<String, Arc<CustomError>>;
+            async fn revoke_session(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+            async fn revoke_all_sessions(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
     fn _create_ok_mock(login_info: &LoginInfo, user_id: i32) -> MockLoginUserUsecaseImpl {
         let mut mock_usecase = MockLoginUserUsecaseImpl::new();
         mock_usecase
@@ -133,12 +157,18 @@ mod tests {
     }
 
     fn _create_app(mock_usecase: MockLoginUserUsecaseImpl) -> Router {
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session
+            .expect_create_session()
+            .returning(|_| Ok("test_jti".to_string()));
+
         Router::new()
             .route(
                 "/api/v1/auth/login",
-                post(login::<MockLoginUserUsecaseImpl>),
+                post(login::<MockLoginUserUsecaseImpl, MockSessionUsecaseImpl>),
             )
             .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(Arc::new(mock_session)))
             .layer(Extension(Arc::new(get_config())))
     }
 
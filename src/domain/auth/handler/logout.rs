@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use axum::{
+    http::{header, Response},
+    response::IntoResponse,
+    Extension,
+};
+use axum_extra::extract::{cookie::Cookie, CookieJar};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    config::jwt::AuthConfig,
+    domain::auth::{
+        usecase::session::SessionUsecase,
+        utils::jwt::{decode_jwt, TokenClaims},
+    },
+    global::errors::CustomError,
+};
+
+// access, refresh 쿠키 삭제
+fn clear_cookies() -> axum::response::Response {
+    let access_cookie = Cookie::build(("Authorization", "")).path("/").removal();
+    let refresh_cookie = Cookie::build(("refresh", "")).path("/").removal();
+
+    let mut response = Response::new(json!({"message": "성공"}).to_string());
+
+    let headers = response.headers_mut();
+    headers.append(
+        header::SET_COOKIE,
+        access_cookie.to_string().parse().unwrap(),
+    );
+    headers.append(
+        header::SET_COOKIE,
+        refresh_cookie.to_string().parse().unwrap(),
+    );
+
+    (StatusCode::OK, response).into_response()
+}
+
+fn get_refresh_claims(cookie_jar: &CookieJar, auth_config: &AuthConfig) -> Option<TokenClaims> {
+    let token = cookie_jar.get("refresh")?;
+    decode_jwt(token.value(), auth_config.get_refresh()).ok()
+}
+
+/// 현재 세션 폐기, 토큰이 없거나 유효하지 않아도 쿠키는 삭제
+pub async fn logout<S>(
+    Extension(session_usecase): Extension<Arc<S>>,
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
+    cookie_jar: CookieJar,
+) -> impl IntoResponse
+where
+    S: SessionUsecase,
+{
+    if let Some(TokenClaims {
+        sub,
+        jti: Some(jti),
+        ..
+    }) = get_refresh_claims(&cookie_jar, &auth_config)
+    {
+        if let Err(e) = session_usecase.revoke_session(sub, &jti).await {
+            return e.as_ref().into_response();
+        }
+    }
+
+    clear_cookies()
+}
+
+/// 모든 기기에서 로그아웃, 유효한 세션에서만 요청 가능
+pub async fn logout_all<S>(
+    Extension(session_usecase): Extension<Arc<S>>,
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
+    cookie_jar: CookieJar,
+) -> impl IntoResponse
+where
+    S: SessionUsecase,
+{
+    let (user_id, jti) = match get_refresh_claims(&cookie_jar, &auth_config) {
+        Some(TokenClaims {
+            sub,
+            jti: Some(jti),
+            ..
+        }) => (sub, jti),
+        _ => return CustomError::Unauthorized("Refresh".to_string()).into_response(),
+    };
+
+    if let Err(e) = session_usecase.revoke_all_sessions(user_id, &jti).await {
+        return e.as_ref().into_response();
+    }
+
+    clear_cookies()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        async_trait, body::Body, extract::Request, http::HeaderValue, routing::post, Extension,
+        Router,
+    };
+    use mockall::{mock, predicate};
+    use tower::ServiceExt;
+
+    use crate::{
+        config::jwt::get_config,
+        domain::auth::{usecase::session::SessionUsecase, utils::jwt::create_refresh_jwt},
+        global::errors::CustomError,
+    };
+
+    use super::{logout, logout_all};
+
+    mock! {
+        SessionUsecaseImpl {}
+
+        #[async_trait]
+        impl SessionUsecase for SessionUsecaseImpl {
+            async fn create_session(&self, user_id: i32) -> Result<String, Arc<CustomError>>;
+            async fn rotate_session(&self, user_id: i32, jti: &str) -> Result<String, Arc<CustomError>>;
+            async fn revoke_session(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+            async fn revoke_all_sessions(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    fn _create_app(mock_session: MockSessionUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/auth/logout",
+                post(logout::<MockSessionUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/auth/logout/all",
+                post(logout_all::<MockSessionUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_session)))
+            .layer(Extension(Arc::new(get_config())))
+    }
+
+    fn _create_req(uri: &str, refresh: Option<&str>) -> Request {
+        let builder = Request::builder().method("POST").uri(uri);
+        let builder = match refresh {
+            Some(refresh) => builder.header(
+                "Cookie",
+                HeaderValue::from_str(&("refresh=".to_string() + refresh)).unwrap(),
+            ),
+            None => builder,
+        };
+        builder.body(Body::empty()).unwrap()
+    }
+
+    fn _refresh(id: i32) -> String {
+        create_refresh_jwt(id, None, "test_jti", get_config().get_refresh(), 76400).unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_logout() {
+        // Arrange
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session
+            .expect_revoke_session()
+            .with(predicate::eq(1), predicate::eq("test_jti"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let app = _create_app(mock_session);
+        let req = _create_req("/api/v1/auth/logout", Some(&_refresh(1)));
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+        let set_cookies: Vec<String> = response
+            .headers()
+            .get_all("set-cookie")
+            .into_iter()
+            .map(|v| v.to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(set_cookies.len(), 2);
+        assert!(set_cookies.iter().all(|c| c.contains("Max-Age=0")));
+    }
+
+    #[tokio::test]
+    async fn check_logout_without_cookie() {
+        // Arrange
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session.expect_revoke_session().never();
+        let app = _create_app(mock_session);
+        let req = _create_req("/api/v1/auth/logout", None);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_logout_all() {
+        // Arrange
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session
+            .expect_revoke_all_sessions()
+            .with(predicate::eq(1), predicate::eq("test_jti"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let app = _create_app(mock_session);
+        let req = _create_req("/api/v1/auth/logout/all", Some(&_refresh(1)));
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_logout_all_revoked() {
+        // Arrange
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session
+            .expect_revoke_all_sessions()
+            .returning(|_, _| Err(Arc::new(CustomError::Unauthorized("Session".to_string()))));
+        let app = _create_app(mock_session);
+        let req = _create_req("/api/v1/auth/logout/all", Some(&_refresh(1)));
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+
+    #[tokio::test]
+    async fn check_logout_all_without_cookie() {
+        // Arrange
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session.expect_revoke_all_sessions().never();
+        let app = _create_app(mock_session);
+        let req = _create_req("/api/v1/auth/logout/all", None);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+}
@@ -1,44 +1,83 @@
 use std::sync::Arc;
 
-use axum::{routing::post, Extension, Router};
+use axum::{
+    routing::{get, post},
+    Extension, Router,
+};
 use sqlx::PgPool;
 
 mod login;
+mod logout;
 mod refresh;
 mod signup;
+mod verify_email;
 
 use login::login;
+use logout::{logout, logout_all};
 use refresh::refresh_token;
 use signup::signup;
+use verify_email::verify_email;
 
-use crate::domain::user::repository::{
-    get_by_id::GetUserByIdRepoImpl, get_by_username::GetUserByUsernameRepoImpl,
-    save::SaveUserRepoImpl,
+use crate::{
+    config::oauth::OAuthConfig,
+    domain::user::repository::{
+        get_by_id::GetUserByIdRepoImpl, identity::IdentityRepoImpl, save::SaveUserRepoImpl,
+        verify_email::VerifyEmailRepoImpl,
+    },
 };
 
-use super::usecase::{
-    login::LoginUserUsecaseImpl, refresh::RefreshTokenUsecaseImpl, signup::SignupUserUsecaseImpl,
+use super::{
+    repository::session::SessionRepoImpl,
+    usecase::{
+        login::LoginUserUsecaseImpl, refresh::RefreshTokenUsecaseImpl, session::SessionUsecaseImpl,
+        signup::SignupUserUsecaseImpl, verify_email::VerifyEmailUsecaseImpl,
+    },
+    utils::{oauth::OAuthProviders, verification::EmailVerifier},
 };
 
-pub fn signup_router(pool: &Arc<PgPool>) -> Router {
+pub fn signup_router(pool: &Arc<PgPool>, verifier: &Arc<EmailVerifier>) -> Router {
     let repository = SaveUserRepoImpl::new(&pool);
-    let usecase = SignupUserUsecaseImpl::new(repository);
+    let usecase = SignupUserUsecaseImpl::new(repository, verifier);
 
     Router::new()
         .route("/", post(signup::<SignupUserUsecaseImpl<SaveUserRepoImpl>>))
         .layer(Extension(Arc::new(usecase)))
 }
 
-pub fn login_router(pool: &Arc<PgPool>) -> Router {
+pub fn login_router(
+    pool: &Arc<PgPool>,
+    oauth_config: &Arc<OAuthConfig>,
+    verifier: &Arc<EmailVerifier>,
+) -> Router {
     // login user
-    let get_repo = GetUserByUsernameRepoImpl::new(pool);
-    let save_repo = SaveUserRepoImpl::new(pool);
-    let usecase = LoginUserUsecaseImpl::new(get_repo, save_repo);
+    let repository = IdentityRepoImpl::new(pool);
+    let providers = OAuthProviders::new(oauth_config);
+    let usecase = LoginUserUsecaseImpl::new(repository, providers, verifier);
+    let session_usecase = SessionUsecaseImpl::new(SessionRepoImpl::new(pool));
 
     Router::new()
         .route(
             "/login",
-            post(login::<LoginUserUsecaseImpl<GetUserByUsernameRepoImpl, SaveUserRepoImpl>>),
+            post(
+                login::<
+                    LoginUserUsecaseImpl<IdentityRepoImpl>,
+                    SessionUsecaseImpl<SessionRepoImpl>,
+                >,
+            ),
+        )
+        .layer(Extension(Arc::new(usecase)))
+        .layer(Extension(Arc::new(session_usecase)))
+}
+
+// verify email
+pub fn verify_email_router(pool: &Arc<PgPool>, verifier: &Arc<EmailVerifier>) -> Router {
+    let repository = VerifyEmailRepoImpl::new(pool);
+    let usecase = VerifyEmailUsecaseImpl::new(repository, verifier);
+
+    Router::new()
+        .route(
+            "/verify-email",
+            get(verify_email::<VerifyEmailUsecaseImpl<VerifyEmailRepoImpl>>),
         )
         .layer(Extension(Arc::new(usecase)))
 }
@@ -47,11 +86,35 @@ pub fn login_router(pool: &Arc<PgPool>) -> Router {
 pub fn refresh_router(pool: &Arc<PgPool>) -> Router {
     let repository = GetUserByIdRepoImpl::new(pool);
     let usecase = RefreshTokenUsecaseImpl::new(repository);
+    let session_usecase = SessionUsecaseImpl::new(SessionRepoImpl::new(pool));
 
     Router::new()
         .route(
             "/refresh",
-            post(refresh_token::<RefreshTokenUsecaseImpl<GetUserByIdRepoImpl>>),
+            post(
+                refresh_token::<
+                    RefreshTokenUsecaseImpl<GetUserByIdRepoImpl>,
+                    SessionUsecaseImpl<SessionRepoImpl>,
+                >,
+            ),
+        )
+        .layer(Extension(Arc::new(usecase)))
+        .layer(Extension(Arc::new(session_usecase)))
+}
+
+// logout
+pub fn logout_router(pool: &Arc<PgPool>) -> Router {
+    let repository = SessionRepoImpl::new(pool);
+    let usecase = SessionUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/logout",
+            post(logout::<SessionUsecaseImpl<SessionRepoImpl>>),
+        )
+        .route(
+            "/logout/all",
+            post(logout_all::<SessionUsecaseImpl<SessionRepoImpl>>),
         )
         .layer(Extension(Arc::new(usecase)))
 }
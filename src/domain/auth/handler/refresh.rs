@@ -14,19 +14,21 @@ use serde_json::json;
 use crate::{
     config::jwt::AuthConfig,
     domain::auth::{
-        usecase::refresh::RefreshTokenUsecase,
-        utils::jwt::{create_jwt, decode_jwt},
+        usecase::{refresh::RefreshTokenUsecase, session::SessionUsecase},
+        utils::jwt::{create_jwt, create_refresh_jwt, decode_jwt, REFRESH_EXP_MINS},
     },
     global::errors::CustomError,
 };
 
-pub async fn refresh_token<T>(
+pub async fn refresh_token<T, S>(
     Extension(usecase): Extension<Arc<T>>,
+    Extension(session_usecase): Extension<Arc<S>>,
     Extension(auth_config): Extension<Arc<AuthConfig>>,
     cookie_jar: CookieJar,
 ) -> impl IntoResponse
 where
     T: RefreshTokenUsecase,
+    S: SessionUsecase,
 {
     let token = cookie_jar
         .get("refresh")
@@ -44,12 +46,23 @@ where
     };
 
     let id = claims.sub;
+    let jti = match claims.jti {
+        Some(jti) => jti,
+        None => return CustomError::Unauthorized("Refresh".to_string()).into_response(),
+    };
 
+    // 사용자 확인이 실패하면 세션을 교체하지 않음
     let user_info = match usecase.refresh(id).await {
         Ok(info) => info,
         Err(e) => return e.as_ref().into_response(),
     };
 
+    // refresh 토큰은 한 번만 사용, 폐기, 만료, 재사용된 토큰은 재발급 불가
+    let jti = match session_usecase.rotate_session(id, &jti).await {
+        Ok(new_jti) => new_jti,
+        Err(e) => return e.as_ref().into_response(),
+    };
+
     // 토큰 생성
     let access_token = create_jwt(
         user_info.get_id(),
@@ -58,11 +71,12 @@ where
         60, // 1시간
     )
     .unwrap();
-    let refresh_token = create_jwt(
+    let refresh_token = create_refresh_jwt(
         user_info.get_id(),
         Some(user_info.get_username().to_string()),
+        &jti,
         auth_config.get_refresh(),
-        43200, // 30일
+        REFRESH_EXP_MINS,
     )
     .unwrap();
 
@@ -106,7 +120,10 @@ mod tests {
     use crate::{
         config::jwt::get_config,
         domain::{
-            auth::{usecase::refresh::RefreshTokenUsecase, utils::jwt::create_jwt},
+            auth::{
+                usecase::{refresh::RefreshTokenUsecase, session::SessionUsecase},
+                utils::jwt::{create_jwt, create_refresh_jwt, decode_jwt},
+            },
             user::dto::{request::LoginType, response::UserInfo},
         },
         global::errors::CustomError,
@@ -123,7 +140,28 @@ mod tests {
         }
     }
 
+    mock! {
+        SessionUsecaseImpl {}
+
+        #[async_trait]
+        impl SessionUsecase for SessionUsecaseImpl {
+            async fn create_session(&self, user_id: i32) -> Result<String, Arc<CustomError>>;
+            async fn rotate_session(&self, user_id: i32, jti: &str) -> Result<String, Arc<CustomError>>;
+            async fn revoke_session(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+            async fn revoke_all_sessions(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
     fn _create_app(id: i32) -> Router {
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session
+            .expect_rotate_session()
+            .with(predicate::eq(id), predicate::eq("test_jti"))
+            .returning(|_, _| Ok("next_jti".to_string()));
+        _create_session_app(id, mock_session)
+    }
+
+    fn _create_session_app(id: i32, mock_session: MockSessionUsecaseImpl) -> Router {
         let mut mock_usecase = MockRefreshTokenUsecaseImpl::new();
         mock_usecase
             .expect_refresh()
@@ -143,9 +181,10 @@ mod tests {
         Router::new()
             .route(
                 "/api/v1/auth/refresh",
-                post(refresh_token::<MockRefreshTokenUsecaseImpl>),
+                post(refresh_token::<MockRefreshTokenUsecaseImpl, MockSessionUsecaseImpl>),
             )
             .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(Arc::new(mock_session)))
             .layer(Extension(Arc::new(get_config())))
     }
 
@@ -165,7 +204,8 @@ mod tests {
     async fn check_refresh_token_status() {
         // Arrange
         let id = 1;
-        let refresh = create_jwt(id, None, get_config().get_refresh(), 76400).unwrap();
+        let refresh =
+            create_refresh_jwt(id, None, "test_jti", get_config().get_refresh(), 76400).unwrap();
 
         let app = _create_app(id);
         let req = _create_req(&refresh);
@@ -181,7 +221,8 @@ mod tests {
     async fn check_refresh_token_body() {
         // Arrange
         let id = 1;
-        let refresh = create_jwt(id, None, get_config().get_refresh(), 76400).unwrap();
+        let refresh =
+            create_refresh_jwt(id, None, "test_jti", get_config().get_refresh(), 76400).unwrap();
 
         let app = _create_app(id);
         let req = _create_req(&refresh);
@@ -207,7 +248,8 @@ mod tests {
     async fn check_refresh_token_cookie() {
         // Arrange
         let id = 1;
-        let refresh = create_jwt(id, None, get_config().get_refresh(), 76400).unwrap();
+        let refresh =
+            create_refresh_jwt(id, None, "test_jti", get_config().get_refresh(), 76400).unwrap();
 
         let app = _create_app(id);
         let req = _create_req(&refresh);
@@ -235,11 +277,41 @@ mod tests {
         assert!(cookie_str.contains("refresh"));
     }
 
+    #[tokio::test]
+    async fn check_refresh_token_rotated() {
+        // Arrange
+        let id = 1;
+        let refresh =
+            create_refresh_jwt(id, None, "test_jti", get_config().get_refresh(), 76400).unwrap();
+
+        let app = _create_app(id);
+        let req = _create_req(&refresh);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        // 새 refresh 토큰은 교체된 jti를 가짐
+        let new_refresh = response
+            .headers()
+            .get_all("set-cookie")
+            .into_iter()
+            .map(|v| v.to_str().unwrap().to_string())
+            .find_map(|c| {
+                c.strip_prefix("refresh=")
+                    .map(|c| c.split(';').next().unwrap().to_string())
+            })
+            .unwrap();
+        let claims = decode_jwt(&new_refresh, get_config().get_refresh()).unwrap();
+        assert_eq!(claims.jti, Some("next_jti".to_string()));
+    }
+
     #[tokio::test]
     async fn check_refresh_token_not_valid() {
         // Arrange
         let id = 1;
-        let refresh = create_jwt(id, None, "invalid-secret-code", 3600).unwrap();
+        let refresh =
+            create_refresh_jwt(id, None, "test_jti", "invalid-secret-code", 3600).unwrap();
 
         let app = _create_app(id);
         let req = _create_req(&refresh);
@@ -269,4 +341,72 @@ mod tests {
         // Assert
         assert_eq!(response.status(), 401)
     }
+
+    #[tokio::test]
+    async fn check_refresh_token_without_jti() {
+        // Arrange
+        let id = 1;
+        let refresh = create_jwt(id, None, get_config().get_refresh(), 76400).unwrap();
+
+        let app = _create_app(id);
+        let req = _create_req(&refresh);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+
+    #[tokio::test]
+    async fn check_refresh_token_revoked() {
+        // Arrange
+        let id = 1;
+        let refresh =
+            create_refresh_jwt(id, None, "test_jti", get_config().get_refresh(), 76400).unwrap();
+
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session
+            .expect_rotate_session()
+            .returning(|_, _| Err(Arc::new(CustomError::Unauthorized("Session".to_string()))));
+        let app = _create_session_app(id, mock_session);
+        let req = _create_req(&refresh);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+
+    #[tokio::test]
+    async fn check_session_kept_when_refresh_failed() {
+        // Arrange
+        let id = 1;
+        let refresh =
+            create_refresh_jwt(id, None, "test_jti", get_config().get_refresh(), 76400).unwrap();
+
+        let mut mock_usecase = MockRefreshTokenUsecaseImpl::new();
+        mock_usecase
+            .expect_refresh()
+            .returning(|_| Err(Arc::new(CustomError::NotFound("User".to_string()))));
+        let mut mock_session = MockSessionUsecaseImpl::new();
+        mock_session.expect_rotate_session().never();
+
+        let app = Router::new()
+            .route(
+                "/api/v1/auth/refresh",
+                post(refresh_token::<MockRefreshTokenUsecaseImpl, MockSessionUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(Arc::new(mock_session)))
+            .layer(Extension(Arc::new(get_config())));
+        let req = _create_req(&refresh);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404)
+    }
 }
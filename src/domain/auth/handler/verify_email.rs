@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::domain::auth::usecase::verify_email::VerifyEmailUsecase;
+
+#[derive(Deserialize)]
+pub(super) struct Params {
+    token: String,
+}
+
+pub(crate) async fn verify_email<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    params: Query<Params>,
+) -> impl IntoResponse
+where
+    T: VerifyEmailUsecase,
+{
+    tracing::debug!("CALL: Verify Email");
+
+    match usecase.verify_email(&params.token).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::get, Extension, Router};
+    use mockall::{mock, predicate};
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::auth::usecase::verify_email::VerifyEmailUsecase, global::errors::CustomError,
+    };
+
+    use super::verify_email;
+
+    mock! {
+        VerifyEmailUsecaseImpl {}
+
+        #[async_trait]
+        impl VerifyEmailUsecase for VerifyEmailUsecaseImpl {
+            async fn verify_email(&self, token: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    fn _create_app() -> Router {
+        let mut mock_usecase = MockVerifyEmailUsecaseImpl::new();
+        mock_usecase
+            .expect_verify_email()
+            .with(predicate::eq("valid_token"))
+            .returning(|_| Ok(()));
+        mock_usecase.expect_verify_email().returning(|_| {
+            Err(Arc::new(CustomError::Unauthorized(
+                "EmailToken".to_string(),
+            )))
+        });
+
+        Router::new()
+            .route(
+                "/api/v1/auth/verify-email",
+                get(verify_email::<MockVerifyEmailUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+    }
+
+    #[tokio::test]
+    async fn check_verify_email() {
+        // Arrange
+        let app = _create_app();
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/auth/verify-email?token=valid_token")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_verify_email_expired() {
+        // Arrange
+        let app = _create_app();
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/auth/verify-email?token=expired_token")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+}
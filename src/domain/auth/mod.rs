@@ -1,4 +1,5 @@
 mod handler;
+mod repository;
 mod usecase;
 pub(crate) mod utils;
 
@@ -0,0 +1,389 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::global::errors::CustomError;
+
+pub struct SessionRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait SessionRepo: Send + Sync {
+    async fn save_session(
+        &self,
+        user_id: i32,
+        jti: &str,
+        expire_mins: i32,
+    ) -> Result<(), Arc<CustomError>>;
+    async fn rotate_session(
+        &self,
+        user_id: i32,
+        jti: &str,
+        new_jti: &str,
+        expire_mins: i32,
+    ) -> Result<(), Arc<CustomError>>;
+    async fn revoke_session(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+    async fn revoke_all_sessions(&self, user_id: i32, jti: &str) -> Result<u64, Arc<CustomError>>;
+}
+
+impl SessionRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl SessionRepo for SessionRepoImpl {
+    async fn save_session(
+        &self,
+        user_id: i32,
+        jti: &str,
+        expire_mins: i32,
+    ) -> Result<(), Arc<CustomError>> {
+        save_session(&self.pool, user_id, jti, expire_mins).await
+    }
+    async fn rotate_session(
+        &self,
+        user_id: i32,
+        jti: &str,
+        new_jti: &str,
+        expire_mins: i32,
+    ) -> Result<(), Arc<CustomError>> {
+        rotate_session(&self.pool, user_id, jti, new_jti, expire_mins).await
+    }
+    async fn revoke_session(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>> {
+        revoke_session(&self.pool, user_id, jti).await
+    }
+    async fn revoke_all_sessions(&self, user_id: i32, jti: &str) -> Result<u64, Arc<CustomError>> {
+        revoke_all_sessions(&self.pool, user_id, jti).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Arc<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Arc::new(err)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct RotateTarget {
+    family_id: String,
+    is_rotated: bool,
+    is_revoked: bool,
+    is_expired: bool,
+}
+
+/// 로그인마다 새 세션 계열 생성, 계열 id는 첫 jti
+pub async fn save_session(
+    pool: &PgPool,
+    user_id: i32,
+    jti: &str,
+    expire_mins: i32,
+) -> Result<(), Arc<CustomError>> {
+    sqlx::query(
+        "
+        INSERT INTO tb_session (jti, user_id, family_id, expired_at)
+        VALUES ($1, $2, $1, NOW() + make_interval(mins => $3))
+    ",
+    )
+    .bind(jti)
+    .bind(user_id)
+    .bind(expire_mins)
+    .execute(pool)
+    .await
+    .map_err(|e| to_custom_error("SaveSession", e))?;
+
+    Ok(())
+}
+
+/// refresh 토큰은 한 번만 사용 가능, 이미 교체된 토큰이 다시 오면 세션 계열 전체 폐기
+pub async fn rotate_session(
+    pool: &PgPool,
+    user_id: i32,
+    jti: &str,
+    new_jti: &str,
+    expire_mins: i32,
+) -> Result<(), Arc<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("RotateSession", e))?;
+
+    // 같은 토큰으로 동시에 요청하면 행 잠금으로 하나만 교체
+    let target = sqlx::query_as::<_, RotateTarget>(
+        "
+        SELECT
+            family_id,
+            rotated_at IS NOT NULL AS is_rotated,
+            revoked_at IS NOT NULL AS is_revoked,
+            expired_at <= NOW() AS is_expired
+        FROM tb_session
+        WHERE jti = $1 AND user_id = $2
+        FOR UPDATE
+    ",
+    )
+    .bind(jti)
+    .bind(user_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RotateSession", e))?;
+
+    let target = match target {
+        Some(target) => target,
+        None => return Err(Arc::new(CustomError::Unauthorized("Session".to_string()))),
+    };
+
+    if target.is_rotated {
+        // 재사용 감지, 탈취된 토큰일 수 있으므로 계열 전체 폐기
+        sqlx::query(
+            "
+            UPDATE tb_session
+            SET revoked_at = NOW()
+            WHERE family_id = $1 AND revoked_at IS NULL
+        ",
+        )
+        .bind(&target.family_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| to_custom_error("RotateSession", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| to_custom_error("RotateSession", e))?;
+
+        tracing::warn!(
+            "Refresh token reuse detected: user {}, session family {}",
+            user_id,
+            target.family_id
+        );
+        return Err(Arc::new(CustomError::Unauthorized("Session".to_string())));
+    } else if target.is_revoked || target.is_expired {
+        return Err(Arc::new(CustomError::Unauthorized("Session".to_string())));
+    }
+
+    sqlx::query(
+        "
+        UPDATE tb_session
+        SET rotated_at = NOW()
+        WHERE jti = $1
+    ",
+    )
+    .bind(jti)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RotateSession", e))?;
+
+    sqlx::query(
+        "
+        INSERT INTO tb_session (jti, user_id, family_id, expired_at)
+        VALUES ($1, $2, $3, NOW() + make_interval(mins => $4))
+    ",
+    )
+    .bind(new_jti)
+    .bind(user_id)
+    .bind(&target.family_id)
+    .bind(expire_mins)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RotateSession", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("RotateSession", e))?;
+
+    Ok(())
+}
+
+/// 토큰이 속한 세션 계열 폐기, 이미 폐기된 세션이면 아무것도 하지 않음
+pub async fn revoke_session(
+    pool: &PgPool,
+    user_id: i32,
+    jti: &str,
+) -> Result<(), Arc<CustomError>> {
+    sqlx::query(
+        "
+        UPDATE tb_session
+        SET revoked_at = NOW()
+        WHERE user_id = $2 AND revoked_at IS NULL AND family_id = (
+            SELECT family_id
+            FROM tb_session
+            WHERE jti = $1 AND user_id = $2
+        )
+    ",
+    )
+    .bind(jti)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .map_err(|e| to_custom_error("RevokeSession", e))?;
+
+    Ok(())
+}
+
+/// 요청한 세션이 유효할 때만 사용자의 모든 세션 폐기
+pub async fn revoke_all_sessions(
+    pool: &PgPool,
+    user_id: i32,
+    jti: &str,
+) -> Result<u64, Arc<CustomError>> {
+    let result = sqlx::query(
+        "
+        WITH SessionCheck AS (
+            SELECT 1
+            FROM tb_session
+            WHERE jti = $2 AND user_id = $1
+                AND rotated_at IS NULL AND revoked_at IS NULL AND expired_at > NOW()
+        )
+        UPDATE tb_session
+        SET revoked_at = NOW()
+        WHERE user_id = $1 AND revoked_at IS NULL AND EXISTS (SELECT 1 FROM SessionCheck)
+    ",
+    )
+    .bind(user_id)
+    .bind(jti)
+    .execute(pool)
+    .await
+    .map_err(|e| to_custom_error("RevokeAllSessions", e))?;
+
+    if result.rows_affected() == 0 {
+        return Err(Arc::new(CustomError::Unauthorized("Session".to_string())));
+    }
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{config::database::create_connection_pool, global::errors::CustomError};
+
+    use super::{revoke_all_sessions, revoke_session, rotate_session, save_session};
+
+    fn _jti() -> String {
+        uuid::Uuid::new_v4().simple().to_string()
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_rotate_session() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let (jti, next_jti, last_jti) = (_jti(), _jti(), _jti());
+        save_session(&pool, 1, &jti, 60).await.unwrap();
+
+        // Act
+        let result = rotate_session(&pool, 1, &jti, &next_jti, 60).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        // 새로 발급된 토큰으로 다시 교체 가능
+        let result = rotate_session(&pool, 1, &next_jti, &last_jti, 60).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_rotate_session_other_user() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let jti = _jti();
+        save_session(&pool, 1, &jti, 60).await.unwrap();
+
+        // Act
+        let result = rotate_session(&pool, 2, &jti, &_jti(), 60).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_rotate_session_reuse() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let (jti, next_jti) = (_jti(), _jti());
+        save_session(&pool, 1, &jti, 60).await.unwrap();
+        rotate_session(&pool, 1, &jti, &next_jti, 60).await.unwrap();
+
+        // Act
+        let result = rotate_session(&pool, 1, &jti, &_jti(), 60).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+
+        // 재사용이 감지되면 정상 발급된 토큰도 폐기됨
+        let result = rotate_session(&pool, 1, &next_jti, &_jti(), 60).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn check_revoke_session() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let (jti, next_jti) = (_jti(), _jti());
+        save_session(&pool, 1, &jti, 60).await.unwrap();
+        rotate_session(&pool, 1, &jti, &next_jti, 60).await.unwrap();
+
+        // Act
+        let result = revoke_session(&pool, 1, &next_jti).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        // 폐기된 세션은 재발급 불가
+        let result = rotate_session(&pool, 1, &next_jti, &_jti(), 60).await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_revoke_all_sessions() {
+        // Arrange
+        // ref) init.sql, 다른 테스트와 겹치지 않게 3번 사용자 사용
+        let pool = create_connection_pool().await;
+        let jtis: Vec<String> = (0..3).map(|_| _jti()).collect();
+        for jti in jtis.iter() {
+            save_session(&pool, 3, jti, 60).await.unwrap();
+        }
+
+        // Act
+        let result = revoke_all_sessions(&pool, 3, &jtis[0]).await;
+
+        // Assert
+        assert!(result.map_err(|e| println!("{:?}", e)).unwrap() >= 3);
+        for jti in jtis.iter() {
+            let result = rotate_session(&pool, 3, jti, &_jti(), 60).await;
+            assert!(result.is_err());
+        }
+
+        // 폐기된 세션으로는 다시 요청할 수 없음
+        let result = revoke_all_sessions(&pool, 3, &jtis[0]).await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
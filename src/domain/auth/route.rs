@@ -3,14 +3,31 @@ use std::sync::Arc;
 use axum::{Extension, Router};
 use sqlx::PgPool;
 
-use crate::config::jwt::AuthConfig;
+use crate::config::{jwt::AuthConfig, mail::MailConfig, oauth::OAuthConfig};
 
-use super::handler::{login_router, refresh_router, signup_router};
+use super::{
+    handler::{login_router, logout_router, refresh_router, signup_router, verify_email_router},
+    utils::{mailer::get_mailer, verification::EmailVerifier},
+};
+
+pub fn get_router(
+    pool: &Arc<PgPool>,
+    auth_config: &Arc<AuthConfig>,
+    oauth_config: &Arc<OAuthConfig>,
+    mail_config: &Arc<MailConfig>,
+) -> Router {
+    // 가입, 로그인에서 인증 메일 발송
+    let verifier = Arc::new(EmailVerifier::new(
+        get_mailer(mail_config),
+        auth_config.get_email(),
+        mail_config.get_verify_url(),
+    ));
 
-pub fn get_router(pool: &Arc<PgPool>, auth_config: &Arc<AuthConfig>) -> Router {
     Router::new()
-        .merge(login_router(&pool))
+        .merge(login_router(&pool, &oauth_config, &verifier))
         .merge(refresh_router(&pool))
-        .merge(signup_router(&pool))
+        .merge(logout_router(&pool))
+        .merge(signup_router(&pool, &verifier))
+        .merge(verify_email_router(&pool, &verifier))
         .layer(Extension(auth_config.clone()))
 }
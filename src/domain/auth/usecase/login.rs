@@ -3,27 +3,28 @@ use std::sync::Arc;
 use axum::async_trait;
 
 use crate::{
-    domain::user::{
-        dto::{
-            request::{LoginInfo, LoginType},
-            response::UserInfo,
+    domain::{
+        auth::utils::{
+            oauth::{OAuthProvider, OAuthProviders},
+            verification::EmailVerifier,
         },
-        repository::{
-            get_by_username::GetUserByUsernameRepo,
-            save::{save_user, SaveUserRepo},
+        user::{
+            dto::{request::LoginInfo, response::UserInfo},
+            entity::User,
+            repository::identity::IdentityRepo,
+            utils::password_hash::{hash_password, hash_password_fixed, verify_password},
         },
-        utils::password_hash::{hash_password, hash_password_fixed, verify_password},
     },
     global::errors::CustomError,
 };
 
-pub struct LoginUserUsecaseImpl<T, U>
+pub struct LoginUserUsecaseImpl<T>
 where
-    T: GetUserByUsernameRepo,
-    U: SaveUserRepo,
+    T: IdentityRepo,
 {
-    login_repo: T,
-    save_repo: U,
+    repository: T,
+    providers: OAuthProviders,
+    verifier: Arc<EmailVerifier>,
 }
 
 #[async_trait]
@@ -31,27 +32,27 @@ pub trait LoginUserUsecase: Send + Sync {
     async fn login(&self, login_info: LoginInfo) -> Result<UserInfo, Arc<CustomError>>;
 }
 
-impl<T, U> LoginUserUsecaseImpl<T, U>
+impl<T> LoginUserUsecaseImpl<T>
 where
-    T: GetUserByUsernameRepo,
-    U: SaveUserRepo,
+    T: IdentityRepo,
 {
-    pub fn new(login_repo: T, save_repo: U) -> Self {
+    pub fn new(repository: T, providers: OAuthProviders, verifier: &Arc<EmailVerifier>) -> Self {
         Self {
-            login_repo,
-            save_repo,
+            repository,
+            providers,
+            verifier: verifier.clone(),
         }
     }
 }
 
 #[async_trait]
-impl<T, U> LoginUserUsecase for LoginUserUsecaseImpl<T, U>
+impl<T> LoginUserUsecase for LoginUserUsecaseImpl<T>
 where
-    T: GetUserByUsernameRepo,
-    U: SaveUserRepo,
+    T: IdentityRepo,
 {
     async fn login(&self, login_info: LoginInfo) -> Result<UserInfo, Arc<CustomError>> {
-        _login(&self.login_repo, &self.save_repo, login_info).await
+        let provider = self.providers.get(login_info.get_login_type());
+        _login(&self.repository, provider, &self.verifier, login_info).await
     }
 }
 
@@ -65,38 +66,105 @@ fn _hash_password(password: &str) -> Result<String, argon2::password_hash::Error
     hash_password_fixed(password.as_bytes(), "fixedsaltfortest") // valid base64 string it's crazy
 }
 
-async fn _login<T, U>(
-    login_repo: &T,
-    save_repo: &U,
+async fn _login<T>(
+    repository: &T,
+    provider: Option<&dyn OAuthProvider>,
+    verifier: &EmailVerifier,
     login_info: LoginInfo,
 ) -> Result<UserInfo, Arc<CustomError>>
 where
-    T: GetUserByUsernameRepo,
-    U: SaveUserRepo,
+    T: IdentityRepo,
 {
-    let result = login_repo.get_by_username(login_info.get_username()).await;
-    let user;
-    if let Err(e) = result {
-        user = match login_info.get_login_type() {
-            LoginType::Email => Err(e),
-            _ => {
-                let oauth_user = login_info.to_entity();
-                let new_id = save_repo.save_user(oauth_user.clone()).await?;
-                Ok(oauth_user.id(new_id).build())
+    let provider = match provider {
+        Some(provider) => provider,
+        None => {
+            let user = repository
+                .get_by_identity("email", login_info.get_username())
+                .await?;
+
+            // 비밀번호 체크
+            if !verify_password(user.get_password(), login_info.get_password().as_bytes()).unwrap()
+            {
+                return Err(Arc::new(CustomError::ValidationError(
+                    "Password".to_string(),
+                )));
             }
-        }?;
-    } else {
-        user = result.unwrap();
-    }
 
-    // 비밀번호 체크
-    if !verify_password(user.get_password(), login_info.get_password().as_bytes()).unwrap() {
-        return Err(Arc::new(CustomError::ValidationError(
-            "Password".to_string(),
-        )));
+            // 이메일 인증 전이면 로그인 제한, 인증 메일은 최근에 보내지 않았을 때만 다시 보냄
+            if user.get_email_verified_at().is_none() {
+                let user_id = user.get_id().unwrap();
+                if verifier.send(user_id, user.get_email()).await.is_err() {
+                    tracing::warn!("Verification mail not sent: user {}", user_id);
+                }
+                return Err(Arc::new(CustomError::Unauthorized(
+                    "EmailVerification".to_string(),
+                )));
+            }
+            return Ok(user.to_info());
+        }
+    };
+
+    // 소셜 로그인은 제공자가 확인한 식별자만 신뢰
+    let access_token = match login_info.get_access_token() {
+        Some(token) => token,
+        None => {
+            return Err(Arc::new(CustomError::ValidationError(
+                "AccessToken".to_string(),
+            )))
+        }
+    };
+    let oauth_user = provider.verify(access_token).await?;
+
+    // 연결된 로그인 수단으로 사용자 조회
+    let login_type = login_info.get_login_type().to_string();
+    let error = match repository
+        .get_by_identity(&login_type, oauth_user.get_subject())
+        .await
+    {
+        Ok(user) => return Ok(user.to_info()),
+        Err(e) => e,
+    };
+    if !matches!(*error, CustomError::NotFound(_)) {
+        return Err(error);
     }
 
-    Ok(user.to_info())
+    // 첫 로그인이면 가입, 비밀번호 로그인은 불가능하도록 임의 값 사용
+    let password = _hash_password(&uuid::Uuid::new_v4().simple().to_string()).map_err(|e| {
+        let err_msg = format!("Error(OAuthLogin-hashing): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        Arc::new(CustomError::Unexpected(anyhow::Error::msg(
+            "failed to hashing password",
+        )))
+    })?;
+    let nickname = match login_info.get_nickname() {
+        Some(nickname) => nickname.to_string(),
+        None => oauth_user
+            .get_email()
+            .split('@')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(12)
+            .collect(),
+    };
+
+    // username은 제공자마다 식별자가 겹칠 수 있으므로 로그인 방식을 붙여 사용
+    let user = User::new(
+        format!("{}_{}", login_type, oauth_user.get_subject()),
+        password,
+        nickname,
+        oauth_user.get_email().to_string(),
+        login_type,
+    )
+    // 제공자가 확인한 이메일
+    .email_verified_at(Some(chrono::Utc::now().naive_utc()))
+    .build();
+    let new_id = repository
+        .save_user(user.clone(), oauth_user.get_subject())
+        .await?;
+
+    Ok(user.id(new_id).build().to_info())
 }
 
 #[cfg(test)]
@@ -107,10 +175,17 @@ mod tests {
     use mockall::{mock, predicate};
 
     use crate::{
-        domain::user::{
-            dto::request::{LoginInfo, LoginType},
-            entity::User,
-            repository::{get_by_username::GetUserByUsernameRepo, save::SaveUserRepo},
+        domain::{
+            auth::utils::{
+                mailer::Mailer,
+                oauth::{OAuthProvider, OAuthUser},
+                verification::EmailVerifier,
+            },
+            user::{
+                dto::request::{LoginInfo, LoginType},
+                entity::{Identity, User},
+                repository::identity::IdentityRepo,
+            },
         },
         global::errors::CustomError,
     };
@@ -118,44 +193,56 @@ mod tests {
     use super::{_hash_password, _login};
 
     mock! {
-        LoginUserRepoImpl {}
+        IdentityRepoImpl {}
 
         #[async_trait]
-        impl GetUserByUsernameRepo for LoginUserRepoImpl {
-            async fn get_by_username(&self, username: &str) -> Result<User, Arc<CustomError>>;
+        impl IdentityRepo for IdentityRepoImpl {
+            async fn get_by_identity(&self, provider: &str, provider_subject: &str) -> Result<User, Arc<CustomError>>;
+            async fn save_user(&self, user: User, provider_subject: &str) -> Result<i32, Arc<CustomError>>;
+            async fn get_identities(&self, user_id: i32) -> Result<Vec<Identity>, Arc<CustomError>>;
+            async fn link_identity(&self, user_id: i32, identity: &Identity) -> Result<(), Arc<CustomError>>;
+            async fn unlink_identity(&self, user_id: i32, provider: &str) -> Result<(), Arc<CustomError>>;
         }
     }
 
     mock! {
-        SaveUserRepoImpl {}
+        OAuthProviderImpl {}
 
         #[async_trait]
-        impl SaveUserRepo for SaveUserRepoImpl {
-            async fn save_user(&self, user: User) -> Result<i32, Arc<CustomError>>;
+        impl OAuthProvider for OAuthProviderImpl {
+            async fn verify(&self, access_token: &str) -> Result<OAuthUser, Arc<CustomError>>;
         }
     }
 
-    fn _get_save_repo(id: Option<i32>) -> MockSaveUserRepoImpl {
-        let mut repo = MockSaveUserRepoImpl::new();
+    mock! {
+        MailerImpl {}
 
-        if let Some(user_id) = id {
-            repo.expect_save_user().returning(move |_| Ok(user_id));
-        };
+        #[async_trait]
+        impl Mailer for MailerImpl {
+            async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    fn _get_verifier(times: usize) -> EmailVerifier {
+        let mut mailer = MockMailerImpl::new();
+        mailer
+            .expect_send()
+            .times(times)
+            .returning(|_, _, _| Ok(()));
 
-        repo
+        EmailVerifier::new(Arc::new(mailer), "test_email", "http://test/verify")
     }
 
-    fn _get_login_repo(
-        username: &str,
-        pw: String,
-        login_type: String,
-        user_id: i32,
-    ) -> MockLoginUserRepoImpl {
-        let mut mock_login_repo = MockLoginUserRepoImpl::new();
-        mock_login_repo
-            .expect_get_by_username()
-            .with(predicate::eq(username.to_string()))
-            .returning(move |un| {
+    fn _get_repo(provider: &str, subject: &str, pw: String, user_id: i32) -> MockIdentityRepoImpl {
+        let login_type = provider.to_string();
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo
+            .expect_get_by_identity()
+            .with(
+                predicate::eq(provider.to_string()),
+                predicate::eq(subject.to_string()),
+            )
+            .returning(move |_, un| {
                 Ok(User::new(
                     un.to_string(),
                     _hash_password(pw.as_str()).unwrap(),
@@ -164,10 +251,46 @@ mod tests {
                     login_type.clone(),
                 )
                 .id(user_id)
+                .email_verified_at(Some(chrono::Utc::now().naive_utc()))
                 .build())
             });
+        mock_repo.expect_save_user().never();
+
+        mock_repo
+    }
+
+    fn _get_new_user_repo() -> MockIdentityRepoImpl {
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo
+            .expect_get_by_identity()
+            .returning(|_, _| Err(Arc::new(CustomError::NotFound("User".to_string()))));
+
+        mock_repo
+    }
 
-        mock_login_repo
+    fn _get_provider(subject: &str, email: &str) -> MockOAuthProviderImpl {
+        let user = OAuthUser::new(subject, email);
+        let mut provider = MockOAuthProviderImpl::new();
+        provider
+            .expect_verify()
+            .with(predicate::eq("valid_token"))
+            .returning(move |_| Ok(user.clone()));
+        provider
+            .expect_verify()
+            .returning(|_| Err(Arc::new(CustomError::Unauthorized("OAuth".to_string()))));
+
+        provider
+    }
+
+    fn _oauth_info(login_type: LoginType, access_token: Option<&str>) -> LoginInfo {
+        LoginInfo::new(
+            "client_claimed_id".to_string(),
+            "any_pw".to_string(),
+            login_type,
+            Some("client_claimed@test.test".to_string()),
+            Some("testnick".to_string()),
+            access_token.map(|t| t.to_string()),
+        )
     }
 
     #[tokio::test]
@@ -184,16 +307,10 @@ mod tests {
             None,
         );
 
-        let mock_login_repo = _get_login_repo(
-            username,
-            "valid_pw".to_string(),
-            "email".to_string(),
-            user_id,
-        );
-        let mock_save_repo = _get_save_repo(Some(1));
+        let mock_repo = _get_repo("email", username, "valid_pw".to_string(), user_id);
 
         // Act
-        let result = _login(&mock_login_repo, &mock_save_repo, login_info).await;
+        let result = _login(&mock_repo, None, &_get_verifier(0), login_info).await;
         assert!(result.clone().map_err(|e| println!("{:?}", e)).is_ok());
         let result = result.unwrap();
 
@@ -202,61 +319,90 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn check_naver_signup_login_success() {
+    async fn check_login_email_not_verified() {
         // Arrange
-        let username = "navertest_unique_id";
-        let user_id = 2;
+        let username = "not_verified@test.test";
         let login_info = LoginInfo::new(
             username.to_string(),
-            "valid_pw_naver".to_string(),
-            LoginType::Naver,
-            Some("naver_test_email@test.test".to_string()),
-            Some("testnick".to_string()),
+            "valid_pw".to_string(),
+            LoginType::Email,
+            None,
+            None,
             None,
         );
 
-        let mock_login_repo = _get_login_repo(
-            username,
-            "valid_pw_naver".to_string(),
-            "naver".to_string(),
-            user_id,
-        );
-        let mock_save_repo = _get_save_repo(Some(user_id));
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo.expect_get_by_identity().returning(|_, un| {
+            Ok(User::new(
+                un.to_string(),
+                _hash_password("valid_pw").unwrap(),
+                "testnick".to_string(),
+                un.to_string(),
+                "email".to_string(),
+            )
+            .id(6)
+            .build())
+        });
+        // 인증 메일 재전송
+        let verifier = _get_verifier(1);
+
+        // Act
+        let result = _login(&mock_repo, None, &verifier, login_info).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_naver_login_success() {
+        // Arrange
+        let user_id = 2;
+        let login_info = _oauth_info(LoginType::Naver, Some("valid_token"));
+
+        // 클라이언트가 보낸 아이디가 아닌 제공자가 확인한 식별자로 조회
+        let mock_repo = _get_repo("naver", "naver_subject", "random_pw".to_string(), user_id);
+        let provider = _get_provider("naver_subject", "naver@test.test");
 
         // Act
-        let result = _login(&mock_login_repo, &mock_save_repo, login_info).await;
+        let result = _login(&mock_repo, Some(&provider), &_get_verifier(0), login_info).await;
         assert!(result.clone().map_err(|e| println!("{:?}", e)).is_ok());
         let result = result.unwrap();
 
         // Assert
-        // 생성 여부 체크
         assert_eq!(result.get_id(), user_id)
     }
 
     #[tokio::test]
-    async fn check_google_signup_login_success() {
+    async fn check_google_login_linked_email_user() {
         // Arrange
-        let username = "googletest_unique_id";
+        // 이메일로 가입한 뒤 구글을 연결한 사용자
         let user_id = 3;
-        let login_info = LoginInfo::new(
-            username.to_string(),
-            "valid_pw_google".to_string(),
-            LoginType::Google,
-            Some("google_test_email@test.test".to_string()),
-            Some("testnick".to_string()),
-            None,
-        );
+        let login_info = _oauth_info(LoginType::Google, Some("valid_token"));
 
-        let mock_login_repo = _get_login_repo(
-            username,
-            "valid_pw_google".to_string(),
-            "google".to_string(),
-            user_id,
-        );
-        let mock_save_repo = _get_save_repo(Some(user_id));
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo
+            .expect_get_by_identity()
+            .with(predicate::eq("google"), predicate::eq("google_subject"))
+            .returning(move |_, _| {
+                Ok(User::new(
+                    "email_user@test.test".to_string(),
+                    "hashed_pw".to_string(),
+                    "testnick".to_string(),
+                    "email_user@test.test".to_string(),
+                    "email".to_string(),
+                )
+                .id(user_id)
+                .build())
+            });
+        mock_repo.expect_save_user().never();
+        let provider = _get_provider("google_subject", "google@test.test");
 
         // Act
-        let result = _login(&mock_login_repo, &mock_save_repo, login_info).await;
+        let result = _login(&mock_repo, Some(&provider), &_get_verifier(0), login_info).await;
         assert!(result.clone().map_err(|e| println!("{:?}", e)).is_ok());
         let result = result.unwrap();
 
@@ -267,58 +413,56 @@ mod tests {
     #[tokio::test]
     async fn check_kakao_signup_login_success() {
         // Arrange
-        let username = "kakaotest_unique_id";
         let user_id = 4;
-        let login_info = LoginInfo::new(
-            username.to_string(),
-            "valid_pw_kakao".to_string(),
-            LoginType::Kakao,
-            Some("kakao_test_email@test.test".to_string()),
-            Some("testnick".to_string()),
-            None,
-        );
+        let login_info = _oauth_info(LoginType::Kakao, Some("valid_token"));
 
-        let mock_login_repo = _get_login_repo(
-            username,
-            "valid_pw_kakao".to_string(),
-            "kakao".to_string(),
-            user_id,
-        );
-        let mock_save_repo = _get_save_repo(Some(user_id));
+        // 검증된 이메일과 식별자로 가입
+        let mut mock_repo = _get_new_user_repo();
+        mock_repo
+            .expect_save_user()
+            .withf(|user, subject| {
+                user.get_username() == "kakao_1234567890"
+                    && user.get_email() == "kakao@test.test"
+                    && user.get_login_type() == "kakao"
+                    && user.get_email_verified_at().is_some()
+                    && subject == "1234567890"
+            })
+            .returning(move |_, _| Ok(user_id));
+        let provider = _get_provider("1234567890", "kakao@test.test");
 
         // Act
-        let result = _login(&mock_login_repo, &mock_save_repo, login_info).await;
+        let result = _login(&mock_repo, Some(&provider), &_get_verifier(0), login_info).await;
         assert!(result.clone().map_err(|e| println!("{:?}", e)).is_ok());
         let result = result.unwrap();
 
         // Assert
+        // 생성 여부 체크
         assert_eq!(result.get_id(), user_id)
     }
 
     #[tokio::test]
     async fn check_meta_signup_login_success() {
         // Arrange
-        let username = "metatest_unique_id";
         let user_id = 5;
         let login_info = LoginInfo::new(
-            username.to_string(),
-            "valid_pw_meta".to_string(),
+            "client_claimed_id".to_string(),
+            "any_pw".to_string(),
             LoginType::Meta,
-            Some("meta_test_email@test.test".to_string()),
-            Some("testnick".to_string()),
             None,
+            None,
+            Some("valid_token".to_string()),
         );
 
-        let mock_login_repo = _get_login_repo(
-            username,
-            "valid_pw_meta".to_string(),
-            "meta".to_string(),
-            user_id,
-        );
-        let mock_save_repo = _get_save_repo(Some(user_id));
+        // 닉네임이 없으면 이메일 앞부분을 12자까지 사용
+        let mut mock_repo = _get_new_user_repo();
+        mock_repo
+            .expect_save_user()
+            .withf(|user, _| user.get_nickname() == "meta_nicknam")
+            .returning(move |_, _| Ok(user_id));
+        let provider = _get_provider("meta_subject", "meta_nickname_long@test.test");
 
         // Act
-        let result = _login(&mock_login_repo, &mock_save_repo, login_info).await;
+        let result = _login(&mock_repo, Some(&provider), &_get_verifier(0), login_info).await;
         assert!(result.clone().map_err(|e| println!("{:?}", e)).is_ok());
         let result = result.unwrap();
 
@@ -339,16 +483,11 @@ mod tests {
             None,
         );
 
-        let mut mock_repo = MockLoginUserRepoImpl::new();
-        mock_repo
-            .expect_get_by_username()
-            .with(predicate::eq(username))
-            .returning(|_| Err(Arc::new(CustomError::NotFound("User".to_string()))));
-
-        let mock_save_repo = _get_save_repo(None);
+        let mut mock_repo = _get_new_user_repo();
+        mock_repo.expect_save_user().never();
 
         // Act
-        let result = _login(&mock_repo, &mock_save_repo, login_info).await;
+        let result = _login(&mock_repo, None, &_get_verifier(0), login_info).await;
 
         // Assert
         assert!(result.is_err())
@@ -367,46 +506,54 @@ mod tests {
             None,
         );
 
-        let mock_login_repo = _get_login_repo(
-            username,
-            "valid_pw_email".to_string(),
-            "email".to_string(),
-            394,
-        );
-        let mock_save_repo = _get_save_repo(None);
+        let mock_repo = _get_repo("email", username, "valid_pw_email".to_string(), 394);
 
         // Act
-        let result = _login(&mock_login_repo, &mock_save_repo, login_info).await;
+        let result = _login(&mock_repo, None, &_get_verifier(0), login_info).await;
 
         // Assert
         assert!(result.is_err())
     }
 
     #[tokio::test]
-    async fn check_pw_incorrect_oauth() {
+    async fn check_oauth_invalid_token() {
         // Arrange
-        let username = "pw_incorrect_oauth@test.test";
-        let login_info = LoginInfo::new(
-            username.to_string(),
-            "invalid_pw_meta".to_string(),
-            LoginType::Naver,
-            None,
-            None,
-            None,
-        );
+        let login_info = _oauth_info(LoginType::Kakao, Some("forged_token"));
 
-        let mock_login_repo = _get_login_repo(
-            username,
-            "valid_pw_naver".to_string(),
-            "naver".to_string(),
-            3766,
-        );
-        let mock_save_repo = _get_save_repo(None);
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo.expect_get_by_identity().never();
+        mock_repo.expect_save_user().never();
+        let provider = _get_provider("1234567890", "kakao@test.test");
 
         // Act
-        let result = _login(&mock_login_repo, &mock_save_repo, login_info).await;
+        let result = _login(&mock_repo, Some(&provider), &_get_verifier(0), login_info).await;
 
         // Assert
-        assert!(result.is_err())
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_oauth_without_token() {
+        // Arrange
+        let login_info = _oauth_info(LoginType::Google, None);
+
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo.expect_get_by_identity().never();
+        let mut provider = MockOAuthProviderImpl::new();
+        provider.expect_verify().never();
+
+        // Act
+        let result = _login(&mock_repo, Some(&provider), &_get_verifier(0), login_info).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
     }
 }
@@ -1,3 +1,5 @@
 pub(super) mod login;
 pub(super) mod refresh;
+pub(super) mod session;
 pub(super) mod signup;
+pub(super) mod verify_email;
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::{
+    domain::auth::{repository::session::SessionRepo, utils::jwt::REFRESH_EXP_MINS},
+    global::errors::CustomError,
+};
+
+pub struct SessionUsecaseImpl<T>
+where
+    T: SessionRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait SessionUsecase: Send + Sync {
+    async fn create_session(&self, user_id: i32) -> Result<String, Arc<CustomError>>;
+    async fn rotate_session(&self, user_id: i32, jti: &str) -> Result<String, Arc<CustomError>>;
+    async fn revoke_session(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+    async fn revoke_all_sessions(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+}
+
+impl<T> SessionUsecaseImpl<T>
+where
+    T: SessionRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> SessionUsecase for SessionUsecaseImpl<T>
+where
+    T: SessionRepo,
+{
+    async fn create_session(&self, user_id: i32) -> Result<String, Arc<CustomError>> {
+        create_session(&self.repository, user_id).await
+    }
+    async fn rotate_session(&self, user_id: i32, jti: &str) -> Result<String, Arc<CustomError>> {
+        rotate_session(&self.repository, user_id, jti).await
+    }
+    async fn revoke_session(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>> {
+        self.repository.revoke_session(user_id, jti).await
+    }
+    async fn revoke_all_sessions(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>> {
+        let count = self.repository.revoke_all_sessions(user_id, jti).await?;
+        tracing::debug!("Revoke {} sessions of user {}", count, user_id);
+
+        Ok(())
+    }
+}
+
+async fn create_session<T>(repository: &T, user_id: i32) -> Result<String, Arc<CustomError>>
+where
+    T: SessionRepo,
+{
+    let jti = uuid::Uuid::new_v4().simple().to_string();
+
+    repository
+        .save_session(user_id, &jti, REFRESH_EXP_MINS as i32)
+        .await?;
+
+    Ok(jti)
+}
+
+/// 사용한 refresh 토큰을 새 jti로 교체
+async fn rotate_session<T>(
+    repository: &T,
+    user_id: i32,
+    jti: &str,
+) -> Result<String, Arc<CustomError>>
+where
+    T: SessionRepo,
+{
+    let new_jti = uuid::Uuid::new_v4().simple().to_string();
+
+    repository
+        .rotate_session(user_id, jti, &new_jti, REFRESH_EXP_MINS as i32)
+        .await?;
+
+    Ok(new_jti)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::auth::{
+            repository::session::SessionRepo,
+            usecase::session::{create_session, rotate_session},
+            utils::jwt::REFRESH_EXP_MINS,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        SessionRepoImpl {}
+
+        #[async_trait]
+        impl SessionRepo for SessionRepoImpl {
+            async fn save_session(&self, user_id: i32, jti: &str, expire_mins: i32) -> Result<(), Arc<CustomError>>;
+            async fn rotate_session(&self, user_id: i32, jti: &str, new_jti: &str, expire_mins: i32) -> Result<(), Arc<CustomError>>;
+            async fn revoke_session(&self, user_id: i32, jti: &str) -> Result<(), Arc<CustomError>>;
+            async fn revoke_all_sessions(&self, user_id: i32, jti: &str) -> Result<u64, Arc<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_create_session() {
+        // Arrange
+        let mut mock_repo = MockSessionRepoImpl::new();
+        mock_repo
+            .expect_save_session()
+            .with(
+                predicate::eq(1),
+                predicate::function(|jti: &str| jti.len() == 32),
+                predicate::eq(REFRESH_EXP_MINS as i32),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        // Act
+        let result = create_session(&mock_repo, 1).await;
+
+        // Assert
+        let jti = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(jti.len(), 32);
+    }
+
+    #[tokio::test]
+    async fn check_create_session_fail() {
+        // Arrange
+        let mut mock_repo = MockSessionRepoImpl::new();
+        mock_repo.expect_save_session().returning(|_, _, _| {
+            Err(Arc::new(CustomError::Unexpected(anyhow::Error::msg(
+                "session error",
+            ))))
+        });
+
+        // Act
+        let result = create_session(&mock_repo, 1).await;
+
+        // Assert
+        assert!(result.is_err())
+    }
+
+    #[tokio::test]
+    async fn check_rotate_session() {
+        // Arrange
+        let mut mock_repo = MockSessionRepoImpl::new();
+        mock_repo
+            .expect_rotate_session()
+            .with(
+                predicate::eq(1),
+                predicate::eq("test_jti"),
+                predicate::function(|jti: &str| jti.len() == 32 && jti != "test_jti"),
+                predicate::eq(REFRESH_EXP_MINS as i32),
+            )
+            .returning(|_, _, _, _| Ok(()));
+
+        // Act
+        let result = rotate_session(&mock_repo, 1, "test_jti").await;
+
+        // Assert
+        let jti = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_ne!(jti, "test_jti");
+    }
+
+    #[tokio::test]
+    async fn check_rotate_session_reused() {
+        // Arrange
+        let mut mock_repo = MockSessionRepoImpl::new();
+        mock_repo.expect_rotate_session().returning(|_, _, _, _| {
+            Err(Arc::new(CustomError::Unauthorized("Session".to_string())))
+        });
+
+        // Act
+        let result = rotate_session(&mock_repo, 1, "test_jti").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -2,10 +2,13 @@ use axum::async_trait;
 use std::sync::Arc;
 
 use crate::{
-    domain::user::{
-        dto::request::NewUser,
-        repository::save::SaveUserRepo,
-        utils::password_hash::{hash_password, hash_password_fixed},
+    domain::{
+        auth::utils::verification::EmailVerifier,
+        user::{
+            dto::request::NewUser,
+            repository::save::SaveUserRepo,
+            utils::password_hash::{hash_password, hash_password_fixed},
+        },
     },
     global::errors::CustomError,
 };
@@ -15,6 +18,7 @@ where
     T: SaveUserRepo,
 {
     repository: T,
+    verifier: Arc<EmailVerifier>,
 }
 
 #[async_trait]
@@ -26,8 +30,11 @@ impl<T> SignupUserUsecaseImpl<T>
 where
     T: SaveUserRepo,
 {
-    pub(crate) fn new(repository: T) -> Self {
-        Self { repository }
+    pub(crate) fn new(repository: T, verifier: &Arc<EmailVerifier>) -> Self {
+        Self {
+            repository,
+            verifier: verifier.clone(),
+        }
     }
 }
 
@@ -37,7 +44,7 @@ where
     T: SaveUserRepo,
 {
     async fn signup_user(&self, new_user: NewUser) -> Result<i32, Arc<CustomError>> {
-        _signup_user(&self.repository, new_user).await
+        _signup_user(&self.repository, &self.verifier, new_user).await
     }
 }
 
@@ -51,7 +58,11 @@ fn _hash_password(password: &str) -> Result<String, argon2::password_hash::Error
     hash_password_fixed(password.as_bytes(), "fixedsaltfortest") // valid base64 string it's crazy
 }
 
-async fn _signup_user<T>(repository: &T, mut new_user: NewUser) -> Result<i32, Arc<CustomError>>
+async fn _signup_user<T>(
+    repository: &T,
+    verifier: &EmailVerifier,
+    mut new_user: NewUser,
+) -> Result<i32, Arc<CustomError>>
 where
     T: SaveUserRepo,
 {
@@ -66,7 +77,15 @@ where
 
     new_user.set_password(hashed_password);
 
-    repository.save_user(new_user.to_entity()).await
+    // 이메일 인증 전까지는 로그인 불가
+    let user_id = repository.save_user(new_user.to_entity()).await?;
+
+    // 메일 전송에 실패해도 가입은 유지, 로그인 시 다시 전송
+    if verifier.send(user_id, new_user.get_email()).await.is_err() {
+        tracing::warn!("Verification mail not sent: user {}", user_id);
+    }
+
+    Ok(user_id)
 }
 
 #[cfg(test)]
@@ -77,10 +96,13 @@ mod tests {
     use mockall::{mock, predicate};
 
     use crate::{
-        domain::user::{
-            dto::request::{LoginType, NewUser},
-            entity::User,
-            repository::save::SaveUserRepo,
+        domain::{
+            auth::utils::{mailer::Mailer, verification::EmailVerifier},
+            user::{
+                dto::request::{LoginType, NewUser},
+                entity::User,
+                repository::save::SaveUserRepo,
+            },
         },
         global::errors::CustomError,
     };
@@ -96,6 +118,30 @@ mod tests {
         }
     }
 
+    mock! {
+        MailerImpl {}
+
+        #[async_trait]
+        impl Mailer for MailerImpl {
+            async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    fn _get_verifier(ret: Result<(), Arc<CustomError>>) -> EmailVerifier {
+        let mut mailer = MockMailerImpl::new();
+        mailer
+            .expect_send()
+            .with(
+                predicate::eq("test1234@test.test"),
+                predicate::always(),
+                predicate::always(),
+            )
+            .times(1)
+            .returning(move |_, _, _| ret.clone());
+
+        EmailVerifier::new(Arc::new(mailer), "test_email", "http://test/verify")
+    }
+
     #[tokio::test]
     async fn check_create_user_success() {
         // Arrange
@@ -123,11 +169,41 @@ mod tests {
             .with(predicate::eq(user.clone()))
             .returning(|_| Ok(1));
 
+        let verifier = _get_verifier(Ok(()));
+
         // Act
-        let result = _signup_user(&mock_repo, new_user).await;
+        let result = _signup_user(&mock_repo, &verifier, new_user).await;
         assert!(result.clone().map_err(|e| println!("{:?}", e)).is_ok());
 
         // Assert
         assert_eq!(result.unwrap(), 1);
     }
+
+    #[tokio::test]
+    async fn check_create_user_mail_failed() {
+        // Arrange
+        let new_user = NewUser::new(
+            LoginType::Email,
+            "test1234@test.test".to_string(),
+            "test_password".to_string(),
+            "test_password".to_string(),
+            "nickname".to_string(),
+            "test1234@test.test".to_string(),
+            None,
+            None,
+        );
+
+        let mut mock_repo = MockSaveUserRepoImpl::new();
+        mock_repo.expect_save_user().returning(|_| Ok(1));
+        let verifier = _get_verifier(Err(Arc::new(CustomError::Unexpected(anyhow::Error::msg(
+            "smtp",
+        )))));
+
+        // Act
+        // 인증 메일은 로그인 시 다시 보낼 수 있으므로 가입은 성공
+        let result = _signup_user(&mock_repo, &verifier, new_user).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 1);
+    }
 }
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::{
+    domain::{
+        auth::utils::verification::EmailVerifier, user::repository::verify_email::VerifyEmailRepo,
+    },
+    global::errors::CustomError,
+};
+
+pub struct VerifyEmailUsecaseImpl<T>
+where
+    T: VerifyEmailRepo,
+{
+    repository: T,
+    verifier: Arc<EmailVerifier>,
+}
+
+#[async_trait]
+pub trait VerifyEmailUsecase: Send + Sync {
+    async fn verify_email(&self, token: &str) -> Result<(), Arc<CustomError>>;
+}
+
+impl<T> VerifyEmailUsecaseImpl<T>
+where
+    T: VerifyEmailRepo,
+{
+    pub fn new(repository: T, verifier: &Arc<EmailVerifier>) -> Self {
+        Self {
+            repository,
+            verifier: verifier.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<T> VerifyEmailUsecase for VerifyEmailUsecaseImpl<T>
+where
+    T: VerifyEmailRepo,
+{
+    async fn verify_email(&self, token: &str) -> Result<(), Arc<CustomError>> {
+        _verify_email(&self.repository, &self.verifier, token).await
+    }
+}
+
+async fn _verify_email<T>(
+    repository: &T,
+    verifier: &EmailVerifier,
+    token: &str,
+) -> Result<(), Arc<CustomError>>
+where
+    T: VerifyEmailRepo,
+{
+    let claims = verifier.verify(token)?;
+
+    repository.verify_email(claims.sub, &claims.email).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::{
+            auth::utils::{jwt::create_email_jwt, mailer::Mailer, verification::EmailVerifier},
+            user::repository::verify_email::VerifyEmailRepo,
+        },
+        global::errors::CustomError,
+    };
+
+    use super::_verify_email;
+
+    mock! {
+        VerifyEmailRepoImpl {}
+
+        #[async_trait]
+        impl VerifyEmailRepo for VerifyEmailRepoImpl {
+            async fn verify_email(&self, user_id: i32, email: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    mock! {
+        MailerImpl {}
+
+        #[async_trait]
+        impl Mailer for MailerImpl {
+            async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    fn _get_verifier() -> EmailVerifier {
+        EmailVerifier::new(Arc::new(MockMailerImpl::new()), "test_email", "http://test")
+    }
+
+    #[tokio::test]
+    async fn check_verify_email() {
+        // Arrange
+        let token = create_email_jwt(1, "verify@test.test", "test_email", 10).unwrap();
+        let mut mock_repo = MockVerifyEmailRepoImpl::new();
+        mock_repo
+            .expect_verify_email()
+            .with(predicate::eq(1), predicate::eq("verify@test.test"))
+            .returning(|_, _| Ok(()));
+
+        // Act
+        let result = _verify_email(&mock_repo, &_get_verifier(), &token).await;
+
+        // Assert
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok())
+    }
+
+    #[tokio::test]
+    async fn check_verify_email_invalid_token() {
+        // Arrange
+        let mut mock_repo = MockVerifyEmailRepoImpl::new();
+        mock_repo.expect_verify_email().never();
+
+        // Act
+        let result = _verify_email(&mock_repo, &_get_verifier(), "invalid_token").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -4,12 +4,30 @@ use jsonwebtoken::{
     decode, encode, errors::Result as JWT_Result, DecodingKey, EncodingKey, Header, Validation,
 };
 
+// refresh 토큰, 세션 유효 기간 (30일)
+pub const REFRESH_EXP_MINS: i64 = 43200;
+
+// 이메일 인증 토큰 유효 기간 (1일)
+pub const EMAIL_VERIFY_EXP_MINS: i64 = 1440;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TokenClaims {
     pub sub: i32,
     pub iat: usize,
     pub exp: usize,
     pub username: Option<String>,
+    // refresh 토큰의 세션 id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+}
+
+/// 이메일 인증 토큰, 발급 당시 이메일을 함께 서명
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailClaims {
+    pub sub: i32,
+    pub email: String,
+    pub iat: usize,
+    pub exp: usize,
 }
 
 pub fn create_jwt(
@@ -17,6 +35,32 @@ pub fn create_jwt(
     username: Option<String>,
     jwt_secret: &str,
     exp_time: i64,
+) -> JWT_Result<String> {
+    encode_jwt(user_id, username, None, jwt_secret, exp_time)
+}
+
+pub fn create_refresh_jwt(
+    user_id: i32,
+    username: Option<String>,
+    jti: &str,
+    jwt_secret: &str,
+    exp_time: i64,
+) -> JWT_Result<String> {
+    encode_jwt(
+        user_id,
+        username,
+        Some(jti.to_string()),
+        jwt_secret,
+        exp_time,
+    )
+}
+
+fn encode_jwt(
+    user_id: i32,
+    username: Option<String>,
+    jti: Option<String>,
+    jwt_secret: &str,
+    exp_time: i64,
 ) -> JWT_Result<String> {
     let now = chrono::Utc::now();
     let iat = now.timestamp() as usize;
@@ -26,6 +70,7 @@ pub fn create_jwt(
         iat,
         exp,
         username,
+        jti,
     };
 
     encode(
@@ -43,3 +88,33 @@ pub fn decode_jwt(token: &str, jwt_secret: &str) -> JWT_Result<TokenClaims> {
     )
     .map(|d| d.claims)
 }
+
+pub fn create_email_jwt(
+    user_id: i32,
+    email: &str,
+    jwt_secret: &str,
+    exp_time: i64,
+) -> JWT_Result<String> {
+    let now = chrono::Utc::now();
+    let claims = EmailClaims {
+        sub: user_id,
+        email: email.to_string(),
+        iat: now.timestamp() as usize,
+        exp: (now + chrono::Duration::minutes(exp_time)).timestamp() as usize,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(jwt_secret.as_ref()),
+    )
+}
+
+pub fn decode_email_jwt(token: &str, jwt_secret: &str) -> JWT_Result<EmailClaims> {
+    decode::<EmailClaims>(
+        token,
+        &DecodingKey::from_secret(jwt_secret.as_ref()),
+        &Validation::default(),
+    )
+    .map(|d| d.claims)
+}
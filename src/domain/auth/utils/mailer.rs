@@ -0,0 +1,505 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use tokio::{
+    fs::OpenOptions,
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+use tokio_native_tls::{native_tls, TlsConnector, TlsStream};
+
+use crate::{
+    config::mail::{MailBackend, MailConfig},
+    global::errors::CustomError,
+};
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), Arc<CustomError>>;
+}
+
+// 메일 서버가 응답하지 않으면 요청이 묶이지 않도록 연결부터 종료까지 제한
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct SmtpMailer {
+    host: String,
+    port: u16,
+    tls: bool,
+    from: String,
+    credentials: Option<(String, String)>,
+    timeout: Duration,
+}
+
+/// 메일 서버 없이 파일에 기록, 개발과 테스트용
+pub struct FileMailer {
+    path: String,
+    from: String,
+}
+
+impl SmtpMailer {
+    pub fn new(host: &str, port: u16, tls: bool, from: &str) -> Self {
+        Self {
+            host: host.to_string(),
+            port,
+            tls,
+            from: from.to_string(),
+            credentials: None,
+            timeout: SMTP_TIMEOUT,
+        }
+    }
+
+    pub fn credentials(mut self, username: &str, password: &str) -> Self {
+        self.credentials = Some((username.to_string(), password.to_string()));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn exchange(&self, to: &str, message: &str) -> Result<(), Arc<CustomError>> {
+        let stream = TcpStream::connect((self.host.as_str(), self.port))
+            .await
+            .map_err(|e| to_unexpected("SmtpConnect", e))?;
+
+        match self.tls {
+            true => {
+                let mut stream = BufReader::new(self.connect_tls(stream).await?);
+                expect_reply(&mut stream, '2').await?;
+                command(&mut stream, "EHLO localhost", '2').await?;
+                self.transfer(&mut stream, to, message).await
+            }
+            false => self.transfer_starttls(stream, to, message).await,
+        }
+    }
+
+    async fn connect_tls(
+        &self,
+        stream: TcpStream,
+    ) -> Result<TlsStream<TcpStream>, Arc<CustomError>> {
+        let connector =
+            native_tls::TlsConnector::new().map_err(|e| to_unexpected("SmtpConnect", e))?;
+        TlsConnector::from(connector)
+            .connect(&self.host, stream)
+            .await
+            .map_err(|e| to_unexpected("SmtpConnect", e))
+    }
+
+    // 평문 연결은 서버가 지원하면 STARTTLS로 암호화, 지원하지 않으면 인증 정보를 보내지 않음
+    async fn transfer_starttls(
+        &self,
+        stream: TcpStream,
+        to: &str,
+        message: &str,
+    ) -> Result<(), Arc<CustomError>> {
+        let mut stream = BufReader::new(stream);
+
+        expect_reply(&mut stream, '2').await?;
+        let reply = command(&mut stream, "EHLO localhost", '2').await?;
+        if !has_extension(&reply, "STARTTLS") {
+            if self.credentials.is_some() {
+                return Err(to_unexpected(
+                    "SmtpStartTls",
+                    anyhow::Error::msg("STARTTLS is not supported, credentials are not sent"),
+                ));
+            }
+            return self.transfer(&mut stream, to, message).await;
+        }
+
+        command(&mut stream, "STARTTLS", '2').await?;
+        let mut stream = BufReader::new(self.connect_tls(stream.into_inner()).await?);
+        command(&mut stream, "EHLO localhost", '2').await?;
+        self.transfer(&mut stream, to, message).await
+    }
+
+    async fn transfer<S>(
+        &self,
+        stream: &mut BufReader<S>,
+        to: &str,
+        message: &str,
+    ) -> Result<(), Arc<CustomError>>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send,
+    {
+        if let Some((username, password)) = &self.credentials {
+            let credential = STANDARD.encode(format!("\0{}\0{}", username, password));
+            command(stream, &format!("AUTH PLAIN {}", credential), '2').await?;
+        }
+        command(stream, &format!("MAIL FROM:<{}>", self.from), '2').await?;
+        command(stream, &format!("RCPT TO:<{}>", to), '2').await?;
+        command(stream, "DATA", '3').await?;
+        command(stream, &format!("{}\r\n.", message), '2').await?;
+        command(stream, "QUIT", '2').await?;
+        Ok(())
+    }
+}
+
+impl FileMailer {
+    pub fn new(path: &str, from: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            from: from.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), Arc<CustomError>> {
+        let message = to_message(&self.from, to, subject, body);
+        tokio::time::timeout(self.timeout, self.exchange(to, &message))
+            .await
+            .map_err(|e| to_unexpected("SmtpTimeout", e))?
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), Arc<CustomError>> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| to_unexpected("FileMailer", e))?;
+
+        let content = format!(
+            "From: {}\nTo: {}\nSubject: {}\n\n{}\n\n",
+            self.from, to, subject, body
+        );
+        file.write_all(content.as_bytes())
+            .await
+            .map_err(|e| to_unexpected("FileMailer", e))?;
+        // tokio 파일은 flush 전까지 기록이 끝나지 않을 수 있음
+        file.flush()
+            .await
+            .map_err(|e| to_unexpected("FileMailer", e))?;
+
+        tracing::info!("Mail to {} written to {}", to, self.path);
+        Ok(())
+    }
+}
+
+pub fn get_mailer(config: &MailConfig) -> Arc<dyn Mailer> {
+    let host = match config.get_backend() {
+        MailBackend::Smtp { host } => host,
+        MailBackend::File { path } => {
+            tracing::warn!("MAIL_BACKEND is file, mails are written to {}", path);
+            return Arc::new(FileMailer::new(path, config.get_from()));
+        }
+    };
+
+    let mailer = SmtpMailer::new(
+        host,
+        config.get_smtp_port(),
+        config.get_smtp_tls(),
+        config.get_from(),
+    )
+    .timeout(config.get_smtp_timeout());
+    match (config.get_smtp_username(), config.get_smtp_password()) {
+        (Some(username), Some(password)) => Arc::new(mailer.credentials(username, password)),
+        _ => Arc::new(mailer),
+    }
+}
+
+fn to_unexpected<E>(target: &str, e: E) -> Arc<CustomError>
+where
+    E: Into<anyhow::Error>,
+{
+    let e: anyhow::Error = e.into();
+    tracing::error!("Error({}): {:?}", target, e);
+    Arc::new(CustomError::Unexpected(e))
+}
+
+// 한글 제목, 본문은 base64로 인코딩, 본문은 76자마다 줄바꿈
+fn to_message(from: &str, to: &str, subject: &str, body: &str) -> String {
+    let body = STANDARD.encode(body);
+    let body = body
+        .as_bytes()
+        .chunks(76)
+        .map(|line| String::from_utf8_lossy(line))
+        .collect::<Vec<_>>()
+        .join("\r\n");
+
+    format!(
+        "From: <{}>\r\nTo: <{}>\r\nSubject: =?UTF-8?B?{}?=\r\nDate: {}\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=UTF-8\r\nContent-Transfer-Encoding: base64\r\n\r\n{}",
+        from,
+        to,
+        STANDARD.encode(subject),
+        chrono::Utc::now().to_rfc2822(),
+        body
+    )
+}
+
+async fn command<S>(
+    stream: &mut BufReader<S>,
+    line: &str,
+    expected: char,
+) -> Result<String, Arc<CustomError>>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send,
+{
+    stream
+        .get_mut()
+        .write_all(format!("{}\r\n", line).as_bytes())
+        .await
+        .map_err(|e| to_unexpected("SmtpCommand", e))?;
+
+    expect_reply(stream, expected).await
+}
+
+// 응답 코드의 첫 자리만 확인, "250-"처럼 이어지는 줄까지 모아서 반환
+async fn expect_reply<S>(
+    stream: &mut BufReader<S>,
+    expected: char,
+) -> Result<String, Arc<CustomError>>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send,
+{
+    let mut reply = String::new();
+    loop {
+        let mut line = String::new();
+        let read = stream
+            .read_line(&mut line)
+            .await
+            .map_err(|e| to_unexpected("SmtpReply", e))?;
+        if read == 0 {
+            return Err(to_unexpected(
+                "SmtpReply",
+                anyhow::Error::msg("connection closed"),
+            ));
+        }
+
+        reply.push_str(&line);
+        if line.get(3..4) == Some("-") {
+            continue;
+        }
+        if line.starts_with(expected) {
+            return Ok(reply);
+        }
+        return Err(to_unexpected(
+            "SmtpReply",
+            anyhow::Error::msg(format!("unexpected reply: {}", line.trim_end())),
+        ));
+    }
+}
+
+// EHLO 응답의 "250-STARTTLS"처럼 코드 뒤에 오는 확장 이름 확인
+fn has_extension(reply: &str, extension: &str) -> bool {
+    reply.lines().any(|line| {
+        line.get(4..)
+            .and_then(|name| name.split_whitespace().next())
+            .map(|name| name.eq_ignore_ascii_case(extension))
+            .unwrap_or(false)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+        task::JoinHandle,
+    };
+
+    use crate::{
+        domain::auth::utils::mailer::{FileMailer, Mailer, SmtpMailer},
+        global::errors::CustomError,
+    };
+
+    const EHLO_PLAIN: &str = "250-localhost\r\n250 AUTH PLAIN\r\n";
+    const EHLO_STARTTLS: &str = "250-localhost\r\n250-STARTTLS\r\n250 AUTH PLAIN\r\n";
+
+    // 받은 명령을 기록하는 SMTP 서버, EHLO는 ehlo_reply, 수신자는 rcpt_reply로 응답
+    async fn _mock_smtp(
+        ehlo_reply: &'static str,
+        rcpt_reply: &'static str,
+    ) -> (u16, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let mut received = vec![];
+            let mut is_data = false;
+
+            stream
+                .get_mut()
+                .write_all(b"220 localhost\r\n")
+                .await
+                .unwrap();
+            loop {
+                let mut line = String::new();
+                if stream.read_line(&mut line).await.unwrap() == 0 {
+                    break;
+                }
+                let line = line.trim_end().to_string();
+                received.push(line.clone());
+
+                let reply = if is_data {
+                    if line != "." {
+                        continue;
+                    }
+                    is_data = false;
+                    "250 queued\r\n"
+                } else if line.starts_with("EHLO") {
+                    ehlo_reply
+                } else if line == "STARTTLS" {
+                    // 이후는 TLS 협상이므로 기록 종료
+                    stream.get_mut().write_all(b"220 ready\r\n").await.unwrap();
+                    break;
+                } else if line.starts_with("AUTH") {
+                    "235 authenticated\r\n"
+                } else if line.starts_with("RCPT") {
+                    rcpt_reply
+                } else if line == "DATA" {
+                    is_data = true;
+                    "354 start mail input\r\n"
+                } else if line == "QUIT" {
+                    stream.get_mut().write_all(b"221 bye\r\n").await.unwrap();
+                    break;
+                } else {
+                    "250 ok\r\n"
+                };
+                stream.get_mut().write_all(reply.as_bytes()).await.unwrap();
+            }
+
+            received
+        });
+
+        (port, handle)
+    }
+
+    #[tokio::test]
+    async fn check_smtp_mailer() {
+        // Arrange
+        let (port, handle) = _mock_smtp(EHLO_PLAIN, "250 ok\r\n").await;
+        let mailer = SmtpMailer::new("127.0.0.1", port, false, "noreply@test.test");
+
+        // Act
+        let result = mailer
+            .send("smtp@test.test", "이메일 인증", "인증 링크")
+            .await;
+        assert!(result.is_ok(), "{:?}", result);
+
+        // Assert
+        let received = handle.await.unwrap();
+        assert!(received.contains(&"MAIL FROM:<noreply@test.test>".to_string()));
+        assert!(received.contains(&"RCPT TO:<smtp@test.test>".to_string()));
+        assert!(received.contains(&STANDARD.encode("인증 링크")));
+        assert_eq!(received.last().unwrap(), "QUIT");
+    }
+
+    #[tokio::test]
+    async fn check_smtp_mailer_rejected() {
+        // Arrange
+        let (port, _) = _mock_smtp(EHLO_PLAIN, "550 no such user\r\n").await;
+        let mailer = SmtpMailer::new("127.0.0.1", port, false, "noreply@test.test");
+
+        // Act
+        let result = mailer
+            .send("unknown@test.test", "이메일 인증", "인증 링크")
+            .await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unexpected(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_smtp_credentials_without_tls() {
+        // Arrange
+        let (port, handle) = _mock_smtp(EHLO_PLAIN, "250 ok\r\n").await;
+        let mailer = SmtpMailer::new("127.0.0.1", port, false, "noreply@test.test")
+            .credentials("smtp_user", "smtp_pw");
+
+        // Act
+        let result = mailer
+            .send("smtp@test.test", "이메일 인증", "인증 링크")
+            .await;
+
+        // Assert
+        // STARTTLS를 지원하지 않는 서버에는 인증 정보를 보내지 않음
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unexpected(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+        let received = handle.await.unwrap();
+        assert!(received.iter().all(|line| !line.starts_with("AUTH")));
+    }
+
+    #[tokio::test]
+    async fn check_smtp_starttls() {
+        // Arrange
+        let (port, handle) = _mock_smtp(EHLO_STARTTLS, "250 ok\r\n").await;
+        let mailer = SmtpMailer::new("127.0.0.1", port, false, "noreply@test.test")
+            .credentials("smtp_user", "smtp_pw");
+
+        // Act
+        // 테스트 서버는 TLS 협상을 하지 않으므로 실패
+        let result = mailer
+            .send("smtp@test.test", "이메일 인증", "인증 링크")
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        let received = handle.await.unwrap();
+        assert_eq!(received.last().unwrap(), "STARTTLS");
+        assert!(received.iter().all(|line| !line.starts_with("AUTH")));
+    }
+
+    #[tokio::test]
+    async fn check_smtp_timeout() {
+        // Arrange
+        // 연결만 받고 인사말을 보내지 않는 서버
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+        let mailer = SmtpMailer::new("127.0.0.1", port, false, "noreply@test.test")
+            .timeout(Duration::from_millis(200));
+
+        // Act
+        let result = mailer
+            .send("smtp@test.test", "이메일 인증", "인증 링크")
+            .await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unexpected(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_file_mailer() {
+        // Arrange
+        let path = std::env::temp_dir().join(format!("{}.log", uuid::Uuid::new_v4().simple()));
+        let mailer = FileMailer::new(path.to_str().unwrap(), "noreply@test.test");
+
+        // Act
+        let result = mailer
+            .send("file@test.test", "이메일 인증", "인증 링크")
+            .await;
+        assert!(result.is_ok(), "{:?}", result);
+
+        // Assert
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(content.contains("To: file@test.test"));
+        assert!(content.contains("인증 링크"));
+    }
+}
@@ -1 +1,4 @@
 pub mod jwt;
+pub mod mailer;
+pub mod oauth;
+pub mod verification;
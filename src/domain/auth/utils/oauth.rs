@@ -0,0 +1,836 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::async_trait;
+use http_body_util::{BodyExt, Empty};
+use hyper::{body::Bytes, header, Request};
+use hyper_util::rt::TokioIo;
+use serde::{de::DeserializeOwned, Deserialize};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpStream,
+};
+use tokio_native_tls::{native_tls, TlsConnector};
+use url::Url;
+
+use crate::{
+    config::oauth::OAuthConfig, domain::user::dto::request::LoginType, global::errors::CustomError,
+};
+
+/// 제공자가 검증한 사용자 식별자와 이메일
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuthUser {
+    subject: String,
+    email: String,
+}
+
+impl OAuthUser {
+    pub fn new(subject: &str, email: &str) -> Self {
+        Self {
+            subject: subject.to_string(),
+            email: email.to_string(),
+        }
+    }
+
+    pub fn get_subject(&self) -> &str {
+        &self.subject
+    }
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+}
+
+#[async_trait]
+pub trait OAuthProvider: Send + Sync {
+    /// 클라이언트가 받은 access token을 제공자에게 확인
+    async fn verify(&self, access_token: &str) -> Result<OAuthUser, Arc<CustomError>>;
+}
+
+pub struct GoogleProvider {
+    url: String,
+    token_url: String,
+    client_id: Option<String>,
+}
+
+pub struct KakaoProvider {
+    url: String,
+    token_url: String,
+    app_id: Option<String>,
+}
+
+pub struct NaverProvider {
+    url: String,
+    token_url: String,
+    client_id: Option<String>,
+}
+
+pub struct MetaProvider {
+    url: String,
+    token_url: String,
+    app_id: Option<String>,
+    app_secret: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoogleUser {
+    sub: String,
+    email: Option<String>,
+    #[serde(default)]
+    email_verified: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct KakaoUser {
+    id: i64,
+    kakao_account: Option<KakaoAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KakaoAccount {
+    email: Option<String>,
+    #[serde(default)]
+    is_email_verified: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct NaverUser {
+    resultcode: String,
+    response: Option<NaverProfile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NaverProfile {
+    id: String,
+    email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetaUser {
+    id: String,
+    email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoogleTokenInfo {
+    aud: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct KakaoTokenInfo {
+    app_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct NaverTokenInfo {
+    resultcode: String,
+    response: Option<NaverToken>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NaverToken {
+    client_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetaTokenInfo {
+    data: MetaToken,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetaToken {
+    app_id: Option<String>,
+    #[serde(default)]
+    is_valid: bool,
+}
+
+impl GoogleProvider {
+    pub fn new(url: &str, token_url: &str, client_id: Option<&str>) -> Self {
+        Self {
+            url: url.to_string(),
+            token_url: token_url.to_string(),
+            client_id: client_id.map(|id| id.to_string()),
+        }
+    }
+}
+
+impl KakaoProvider {
+    pub fn new(url: &str, token_url: &str, app_id: Option<&str>) -> Self {
+        Self {
+            url: url.to_string(),
+            token_url: token_url.to_string(),
+            app_id: app_id.map(|id| id.to_string()),
+        }
+    }
+}
+
+impl NaverProvider {
+    pub fn new(url: &str, token_url: &str, client_id: Option<&str>) -> Self {
+        Self {
+            url: url.to_string(),
+            token_url: token_url.to_string(),
+            client_id: client_id.map(|id| id.to_string()),
+        }
+    }
+}
+
+impl MetaProvider {
+    pub fn new(url: &str, token_url: &str, app_id: Option<&str>, app_secret: Option<&str>) -> Self {
+        Self {
+            url: url.to_string(),
+            token_url: token_url.to_string(),
+            app_id: app_id.map(|id| id.to_string()),
+            app_secret: app_secret.map(|secret| secret.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl OAuthProvider for GoogleProvider {
+    async fn verify(&self, access_token: &str) -> Result<OAuthUser, Arc<CustomError>> {
+        // 다른 앱에 발급된 토큰은 거부
+        let mut token_url = parse_url(&self.token_url)?;
+        token_url
+            .query_pairs_mut()
+            .append_pair("access_token", access_token);
+        let token: GoogleTokenInfo = get_json(&token_url, None).await?;
+        check_audience(self.client_id.as_deref(), &token.aud)?;
+
+        let url = parse_url(&self.url)?;
+        let user: GoogleUser = get_json(&url, Some(access_token)).await?;
+
+        // 인증되지 않은 이메일은 사용하지 않음
+        let email = user.email.filter(|_| user.email_verified);
+        to_oauth_user(&user.sub, email)
+    }
+}
+
+#[async_trait]
+impl OAuthProvider for KakaoProvider {
+    async fn verify(&self, access_token: &str) -> Result<OAuthUser, Arc<CustomError>> {
+        let token_url = parse_url(&self.token_url)?;
+        let token: KakaoTokenInfo = get_json(&token_url, Some(access_token)).await?;
+        check_audience(self.app_id.as_deref(), &token.app_id.to_string())?;
+
+        let url = parse_url(&self.url)?;
+        let user: KakaoUser = get_json(&url, Some(access_token)).await?;
+
+        let email = user
+            .kakao_account
+            .filter(|account| account.is_email_verified)
+            .and_then(|account| account.email);
+        to_oauth_user(&user.id.to_string(), email)
+    }
+}
+
+#[async_trait]
+impl OAuthProvider for NaverProvider {
+    async fn verify(&self, access_token: &str) -> Result<OAuthUser, Arc<CustomError>> {
+        // info=true로 조회하면 토큰을 발급받은 클라이언트 id를 포함
+        let token_url = parse_url(&self.token_url)?;
+        let token: NaverTokenInfo = get_json(&token_url, Some(access_token)).await?;
+        let client_id = match (token.resultcode.as_str(), token.response) {
+            (
+                "00",
+                Some(NaverToken {
+                    client_id: Some(id),
+                }),
+            ) => id,
+            _ => return Err(Arc::new(CustomError::Unauthorized("OAuth".to_string()))),
+        };
+        check_audience(self.client_id.as_deref(), &client_id)?;
+
+        let url = parse_url(&self.url)?;
+        let user: NaverUser = get_json(&url, Some(access_token)).await?;
+
+        // 00: 성공
+        let profile = match (user.resultcode.as_str(), user.response) {
+            ("00", Some(profile)) => profile,
+            _ => return Err(Arc::new(CustomError::Unauthorized("OAuth".to_string()))),
+        };
+        to_oauth_user(&profile.id, profile.email)
+    }
+}
+
+#[async_trait]
+impl OAuthProvider for MetaProvider {
+    async fn verify(&self, access_token: &str) -> Result<OAuthUser, Arc<CustomError>> {
+        // debug_token은 앱 토큰(app_id|app_secret)으로 조회
+        let (app_id, app_secret) = match (&self.app_id, &self.app_secret) {
+            (Some(app_id), Some(app_secret)) => (app_id, app_secret),
+            _ => return Err(to_not_configured()),
+        };
+        let mut token_url = parse_url(&self.token_url)?;
+        token_url
+            .query_pairs_mut()
+            .append_pair("input_token", access_token)
+            .append_pair("access_token", &format!("{}|{}", app_id, app_secret));
+        let token: MetaTokenInfo = get_json(&token_url, None).await?;
+        match (token.data.is_valid, token.data.app_id) {
+            (true, Some(id)) => check_audience(Some(app_id), &id)?,
+            _ => return Err(Arc::new(CustomError::Unauthorized("OAuth".to_string()))),
+        }
+
+        let mut url = parse_url(&self.url)?;
+        url.query_pairs_mut()
+            .append_pair("fields", "id,email")
+            .append_pair("access_token", access_token);
+        let user: MetaUser = get_json(&url, None).await?;
+
+        to_oauth_user(&user.id, user.email)
+    }
+}
+
+/// 로그인 방식별 제공자, 이메일 로그인은 제공자 없음
+pub struct OAuthProviders {
+    google: GoogleProvider,
+    kakao: KakaoProvider,
+    naver: NaverProvider,
+    meta: MetaProvider,
+}
+
+impl OAuthProviders {
+    pub fn new(config: &OAuthConfig) -> Self {
+        Self {
+            google: GoogleProvider::new(
+                config.get_google_url(),
+                config.get_google_token_url(),
+                config.get_google_client_id(),
+            ),
+            kakao: KakaoProvider::new(
+                config.get_kakao_url(),
+                config.get_kakao_token_url(),
+                config.get_kakao_app_id(),
+            ),
+            naver: NaverProvider::new(
+                config.get_naver_url(),
+                config.get_naver_token_url(),
+                config.get_naver_client_id(),
+            ),
+            meta: MetaProvider::new(
+                config.get_meta_url(),
+                config.get_meta_token_url(),
+                config.get_meta_app_id(),
+                config.get_meta_app_secret(),
+            ),
+        }
+    }
+
+    pub fn get(&self, login_type: &LoginType) -> Option<&dyn OAuthProvider> {
+        match login_type {
+            LoginType::Email => None,
+            LoginType::Google => Some(&self.google),
+            LoginType::Kakao => Some(&self.kakao),
+            LoginType::Naver => Some(&self.naver),
+            LoginType::Meta => Some(&self.meta),
+        }
+    }
+}
+
+fn to_unexpected<E>(target: &str, e: E) -> Arc<CustomError>
+where
+    E: Into<anyhow::Error>,
+{
+    let e: anyhow::Error = e.into();
+    tracing::error!("Error({}): {:?}", target, e);
+    Arc::new(CustomError::Unexpected(e))
+}
+
+fn to_not_configured() -> Arc<CustomError> {
+    tracing::error!("Error(OAuthConfig): client id is not configured");
+    Arc::new(CustomError::Unauthorized("OAuth".to_string()))
+}
+
+// 토큰을 발급받은 앱이 설정된 클라이언트 id와 같은지 확인
+fn check_audience(expected: Option<&str>, actual: &str) -> Result<(), Arc<CustomError>> {
+    match expected {
+        None => Err(to_not_configured()),
+        Some(expected) if expected == actual => Ok(()),
+        Some(_) => {
+            tracing::debug!("OAuth token issued to another app: {}", actual);
+            Err(Arc::new(CustomError::Unauthorized("OAuth".to_string())))
+        }
+    }
+}
+
+fn to_oauth_user(subject: &str, email: Option<String>) -> Result<OAuthUser, Arc<CustomError>> {
+    match email {
+        Some(email) => Ok(OAuthUser::new(subject, &email)),
+        None => Err(Arc::new(CustomError::ValidationError("Email".to_string()))),
+    }
+}
+
+fn parse_url(url: &str) -> Result<Url, Arc<CustomError>> {
+    Url::parse(url).map_err(|e| to_unexpected("OAuthUrl", e))
+}
+
+// 제공자가 응답하지 않으면 로그인 요청이 묶이지 않도록 제한
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 제공자 API GET 요청, https는 TLS 연결
+async fn get_json<T>(url: &Url, bearer: Option<&str>) -> Result<T, Arc<CustomError>>
+where
+    T: DeserializeOwned,
+{
+    get_json_within(url, bearer, REQUEST_TIMEOUT).await
+}
+
+// 연결, TLS, 요청과 응답 전체에 시간 제한 적용
+async fn get_json_within<T>(
+    url: &Url,
+    bearer: Option<&str>,
+    limit: Duration,
+) -> Result<T, Arc<CustomError>>
+where
+    T: DeserializeOwned,
+{
+    let host = url
+        .host_str()
+        .ok_or_else(|| to_unexpected("OAuthUrl", anyhow::Error::msg("host not found")))?;
+    let port = url.port_or_known_default().unwrap_or(80);
+    let authority = match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_string(),
+    };
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+
+    let mut builder = Request::get(path)
+        .header(header::HOST, authority)
+        .header(header::ACCEPT, "application/json");
+    if let Some(token) = bearer {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let req = builder
+        .body(Empty::<Bytes>::new())
+        .map_err(|e| to_unexpected("OAuthRequest", e))?;
+
+    let exchange = async {
+        let stream = TcpStream::connect((host, port))
+            .await
+            .map_err(|e| to_unexpected("OAuthConnect", e))?;
+
+        match url.scheme() {
+            "https" => {
+                let connector = native_tls::TlsConnector::new()
+                    .map_err(|e| to_unexpected("OAuthConnect", e))?;
+                let stream = TlsConnector::from(connector)
+                    .connect(host, stream)
+                    .await
+                    .map_err(|e| to_unexpected("OAuthConnect", e))?;
+                send_request(stream, req).await
+            }
+            _ => send_request(stream, req).await,
+        }
+    };
+    let body = tokio::time::timeout(limit, exchange)
+        .await
+        .map_err(|e| to_unexpected("OAuthTimeout", e))??;
+
+    serde_json::from_slice(&body).map_err(|e| to_unexpected("OAuthResponse", e))
+}
+
+async fn send_request<S>(stream: S, req: Request<Empty<Bytes>>) -> Result<Bytes, Arc<CustomError>>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+        .await
+        .map_err(|e| to_unexpected("OAuthConnect", e))?;
+    tokio::spawn(async move {
+        if let Err(e) = conn.await {
+            tracing::error!("Error(OAuthConnect): {:?}", e);
+        }
+    });
+
+    let res = sender
+        .send_request(req)
+        .await
+        .map_err(|e| to_unexpected("OAuthRequest", e))?;
+
+    // 만료, 위조된 토큰
+    if !res.status().is_success() {
+        tracing::debug!("OAuth provider rejected token: {}", res.status());
+        return Err(Arc::new(CustomError::Unauthorized("OAuth".to_string())));
+    }
+
+    let body = res
+        .into_body()
+        .collect()
+        .await
+        .map_err(|e| to_unexpected("OAuthResponse", e))?
+        .to_bytes();
+
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use axum::{
+        extract::Query,
+        http::{HeaderMap, StatusCode},
+        response::IntoResponse,
+        routing::get,
+        Json, Router,
+    };
+    use serde_json::{json, Value};
+    use url::Url;
+
+    use crate::{
+        domain::{
+            auth::utils::oauth::{
+                GoogleProvider, KakaoProvider, MetaProvider, NaverProvider, OAuthProvider,
+                OAuthUser,
+            },
+            user::dto::request::LoginType,
+        },
+        global::errors::CustomError,
+    };
+
+    use super::{get_json_within, OAuthProviders};
+
+    fn _is_valid(headers: &HeaderMap) -> bool {
+        headers
+            .get("authorization")
+            .map(|v| v == "Bearer valid_token")
+            .unwrap_or(false)
+    }
+
+    // 제공자 API를 흉내내는 로컬 서버
+    async fn _mock_server() -> String {
+        let app = Router::new()
+            .route(
+                "/google",
+                get(|headers: HeaderMap| async move {
+                    match _is_valid(&headers) {
+                        true => Json(json!({
+                            "sub": "google_subject",
+                            "email": "google@test.test",
+                            "email_verified": true
+                        }))
+                        .into_response(),
+                        false => StatusCode::UNAUTHORIZED.into_response(),
+                    }
+                }),
+            )
+            .route(
+                "/kakao",
+                get(|headers: HeaderMap| async move {
+                    match _is_valid(&headers) {
+                        true => Json(json!({
+                            "id": 1234567890_i64,
+                            "kakao_account": {
+                                "email": "kakao@test.test",
+                                "is_email_verified": true
+                            }
+                        }))
+                        .into_response(),
+                        false => StatusCode::UNAUTHORIZED.into_response(),
+                    }
+                }),
+            )
+            .route(
+                "/google/token",
+                get(|Query(params): Query<HashMap<String, String>>| async move {
+                    match params.get("access_token").map(|t| t.as_str()) {
+                        Some("valid_token") => Json(json!({
+                            "aud": "google_client",
+                            "sub": "google_subject"
+                        }))
+                        .into_response(),
+                        _ => StatusCode::BAD_REQUEST.into_response(),
+                    }
+                }),
+            )
+            .route(
+                "/kakao/token",
+                get(|headers: HeaderMap| async move {
+                    match _is_valid(&headers) {
+                        true => Json(json!({"id": 1234567890_i64, "app_id": 1001})).into_response(),
+                        false => StatusCode::UNAUTHORIZED.into_response(),
+                    }
+                }),
+            )
+            .route(
+                "/naver/token",
+                get(|headers: HeaderMap| async move {
+                    match _is_valid(&headers) {
+                        true => Json(json!({
+                            "resultcode": "00",
+                            "message": "success",
+                            "response": {"token": "valid_token", "client_id": "naver_client"}
+                        }))
+                        .into_response(),
+                        false => StatusCode::UNAUTHORIZED.into_response(),
+                    }
+                }),
+            )
+            .route(
+                "/meta/token",
+                get(|Query(params): Query<HashMap<String, String>>| async move {
+                    // 앱 토큰의 secret만 확인, 발급 앱 비교는 제공자 쪽에서 수행
+                    let is_app = params
+                        .get("access_token")
+                        .map(|t| t.ends_with("|meta_secret"))
+                        .unwrap_or(false);
+                    match (is_app, params.get("input_token").map(|t| t.as_str())) {
+                        (true, Some("valid_token")) => Json(json!({
+                            "data": {"app_id": "meta_app", "is_valid": true}
+                        }))
+                        .into_response(),
+                        _ => StatusCode::BAD_REQUEST.into_response(),
+                    }
+                }),
+            )
+            .route(
+                "/kakao/no-email",
+                get(|| async { Json(json!({"id": 1234567890_i64})) }),
+            )
+            .route(
+                "/naver",
+                get(|headers: HeaderMap| async move {
+                    match _is_valid(&headers) {
+                        true => Json(json!({
+                            "resultcode": "00",
+                            "message": "success",
+                            "response": {"id": "naver_subject", "email": "naver@test.test"}
+                        }))
+                        .into_response(),
+                        false => StatusCode::UNAUTHORIZED.into_response(),
+                    }
+                }),
+            )
+            .route(
+                "/meta",
+                get(|Query(params): Query<HashMap<String, String>>| async move {
+                    match params.get("access_token").map(|t| t.as_str()) {
+                        Some("valid_token") => Json(json!({
+                            "id": "meta_subject",
+                            "email": "meta@test.test"
+                        }))
+                        .into_response(),
+                        _ => StatusCode::BAD_REQUEST.into_response(),
+                    }
+                }),
+            );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        format!("http://{}", addr)
+    }
+
+    fn _google(server: &str, client_id: Option<&str>) -> GoogleProvider {
+        GoogleProvider::new(
+            &format!("{}/google", server),
+            &format!("{}/google/token", server),
+            client_id,
+        )
+    }
+
+    fn _kakao(server: &str, path: &str, app_id: Option<&str>) -> KakaoProvider {
+        KakaoProvider::new(
+            &format!("{}{}", server, path),
+            &format!("{}/kakao/token", server),
+            app_id,
+        )
+    }
+
+    fn _naver(server: &str, client_id: Option<&str>) -> NaverProvider {
+        NaverProvider::new(
+            &format!("{}/naver", server),
+            &format!("{}/naver/token", server),
+            client_id,
+        )
+    }
+
+    fn _meta(server: &str, app_id: Option<&str>) -> MetaProvider {
+        MetaProvider::new(
+            &format!("{}/meta", server),
+            &format!("{}/meta/token", server),
+            app_id,
+            Some("meta_secret"),
+        )
+    }
+
+    #[tokio::test]
+    async fn check_google_verify() {
+        // Arrange
+        let server = _mock_server().await;
+        let provider = _google(&server, Some("google_client"));
+
+        // Act
+        let result = provider.verify("valid_token").await;
+
+        // Assert
+        let user = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(user, OAuthUser::new("google_subject", "google@test.test"));
+    }
+
+    #[tokio::test]
+    async fn check_google_verify_invalid_token() {
+        // Arrange
+        let server = _mock_server().await;
+        let provider = _google(&server, Some("google_client"));
+
+        // Act
+        let result = provider.verify("invalid_token").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_kakao_verify() {
+        // Arrange
+        let server = _mock_server().await;
+        let provider = _kakao(&server, "/kakao", Some("1001"));
+
+        // Act
+        let result = provider.verify("valid_token").await;
+
+        // Assert
+        let user = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(user, OAuthUser::new("1234567890", "kakao@test.test"));
+    }
+
+    #[tokio::test]
+    async fn check_kakao_verify_without_email() {
+        // Arrange
+        let server = _mock_server().await;
+        let provider = _kakao(&server, "/kakao/no-email", Some("1001"));
+
+        // Act
+        let result = provider.verify("valid_token").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_naver_verify() {
+        // Arrange
+        let server = _mock_server().await;
+        let provider = _naver(&server, Some("naver_client"));
+
+        // Act
+        let result = provider.verify("valid_token").await;
+
+        // Assert
+        let user = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(user, OAuthUser::new("naver_subject", "naver@test.test"));
+    }
+
+    #[tokio::test]
+    async fn check_meta_verify() {
+        // Arrange
+        let server = _mock_server().await;
+        let provider = _meta(&server, Some("meta_app"));
+
+        // Act
+        let result = provider.verify("valid_token").await;
+
+        // Assert
+        let user = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(user, OAuthUser::new("meta_subject", "meta@test.test"));
+    }
+
+    #[tokio::test]
+    async fn check_meta_verify_invalid_token() {
+        // Arrange
+        let server = _mock_server().await;
+        let provider = _meta(&server, Some("meta_app"));
+
+        // Act
+        let result = provider.verify("invalid_token").await;
+
+        // Assert
+        assert!(result.is_err())
+    }
+
+    #[tokio::test]
+    async fn check_foreign_audience() {
+        // Arrange
+        let server = _mock_server().await;
+        let providers: Vec<Box<dyn OAuthProvider>> = vec![
+            Box::new(_google(&server, Some("other_client"))),
+            Box::new(_kakao(&server, "/kakao", Some("2002"))),
+            Box::new(_naver(&server, Some("other_client"))),
+            Box::new(_meta(&server, Some("other_app"))),
+        ];
+
+        for provider in providers {
+            // Act
+            let result = provider.verify("valid_token").await;
+
+            // Assert
+            // 다른 앱에 발급된 토큰
+            let err_type = match *result.err().unwrap() {
+                CustomError::Unauthorized(_) => true,
+                _ => false,
+            };
+            assert!(err_type)
+        }
+    }
+
+    #[tokio::test]
+    async fn check_client_id_not_configured() {
+        // Arrange
+        let server = _mock_server().await;
+        let provider = _google(&server, None);
+
+        // Act
+        let result = provider.verify("valid_token").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_provider_timeout() {
+        // Arrange
+        // 연결만 받고 응답하지 않는 서버
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}/google", listener.local_addr().unwrap())).unwrap();
+        tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        // Act
+        let result: Result<Value, _> =
+            get_json_within(&url, Some("valid_token"), Duration::from_millis(200)).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unexpected(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[test]
+    fn check_email_has_no_provider() {
+        // Arrange
+        let providers = OAuthProviders::new(&crate::config::oauth::get_oauth_config());
+
+        // Act, Assert
+        assert!(providers.get(&LoginType::Email).is_none());
+        assert!(providers.get(&LoginType::Kakao).is_some());
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::global::errors::CustomError;
+
+use super::{
+    jwt::{create_email_jwt, decode_email_jwt, EmailClaims, EMAIL_VERIFY_EXP_MINS},
+    mailer::Mailer,
+};
+
+// 같은 사용자에게 인증 메일을 다시 보내기까지 기다리는 시간
+const RESEND_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// 인증 토큰을 서명해 메일로 보내고, 돌아온 토큰을 확인
+pub struct EmailVerifier {
+    mailer: Arc<dyn Mailer>,
+    jwt_secret: String,
+    verify_url: String,
+    // 사용자별 마지막 발송 시각
+    sent_at: Mutex<HashMap<i32, Instant>>,
+}
+
+impl EmailVerifier {
+    pub fn new(mailer: Arc<dyn Mailer>, jwt_secret: &str, verify_url: &str) -> Self {
+        Self {
+            mailer,
+            jwt_secret: jwt_secret.to_string(),
+            verify_url: verify_url.to_string(),
+            sent_at: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 최근에 보낸 사용자면 다시 보내지 않음
+    pub async fn send(&self, user_id: i32, email: &str) -> Result<(), Arc<CustomError>> {
+        if !self.mark_sent(user_id) {
+            tracing::debug!("Verification mail recently sent: user {}", user_id);
+            return Ok(());
+        }
+
+        let result = self.send_mail(user_id, email).await;
+        // 실패하면 바로 다시 보낼 수 있도록 기록 제거
+        if result.is_err() {
+            self.sent_at.lock().unwrap().remove(&user_id);
+        }
+        result
+    }
+
+    // 발송 가능하면 시각을 기록하고 true, 지난 기록은 정리
+    fn mark_sent(&self, user_id: i32) -> bool {
+        let now = Instant::now();
+        let mut sent_at = self.sent_at.lock().unwrap();
+        sent_at.retain(|_, at| now.duration_since(*at) < RESEND_INTERVAL);
+
+        if sent_at.contains_key(&user_id) {
+            return false;
+        }
+        sent_at.insert(user_id, now);
+        true
+    }
+
+    async fn send_mail(&self, user_id: i32, email: &str) -> Result<(), Arc<CustomError>> {
+        let token = create_email_jwt(user_id, email, &self.jwt_secret, EMAIL_VERIFY_EXP_MINS)
+            .map_err(|e| {
+                let err_msg = format!("Error(EmailVerify-token): {:?}", &e);
+                tracing::error!("{}", err_msg);
+
+                Arc::new(CustomError::Unexpected(e.into()))
+            })?;
+
+        let body = format!(
+            "아래 링크에서 이메일 인증을 완료해 주세요.\n\n{}?token={}\n\n링크는 24시간 동안 유효합니다.",
+            self.verify_url, token
+        );
+        self.mailer.send(email, "이메일 인증", &body).await
+    }
+
+    pub fn verify(&self, token: &str) -> Result<EmailClaims, Arc<CustomError>> {
+        decode_email_jwt(token, &self.jwt_secret).map_err(|e| {
+            tracing::debug!("Invalid email token: {:?}", e);
+            Arc::new(CustomError::Unauthorized("EmailToken".to_string()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::auth::utils::{jwt::create_email_jwt, mailer::Mailer},
+        global::errors::CustomError,
+    };
+
+    use super::EmailVerifier;
+
+    mock! {
+        MailerImpl {}
+
+        #[async_trait]
+        impl Mailer for MailerImpl {
+            async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_send_and_verify() {
+        // Arrange
+        let sent = Arc::new(Mutex::new(String::new()));
+        let body = sent.clone();
+        let mut mailer = MockMailerImpl::new();
+        mailer
+            .expect_send()
+            .with(
+                predicate::eq("verify@test.test"),
+                predicate::always(),
+                predicate::always(),
+            )
+            .returning(move |_, _, b| {
+                *body.lock().unwrap() = b.to_string();
+                Ok(())
+            });
+        let verifier = EmailVerifier::new(Arc::new(mailer), "test_email", "http://test/verify");
+
+        // Act
+        let result = verifier.send(1, "verify@test.test").await;
+        assert!(result.is_ok(), "{:?}", result);
+
+        // Assert
+        // 메일의 링크에 담긴 토큰으로 인증
+        let sent = sent.lock().unwrap().clone();
+        let token = sent
+            .split("http://test/verify?token=")
+            .nth(1)
+            .and_then(|s| s.split_whitespace().next())
+            .unwrap();
+        let claims = verifier.verify(token).unwrap();
+        assert_eq!(claims.sub, 1);
+        assert_eq!(claims.email, "verify@test.test");
+    }
+
+    #[tokio::test]
+    async fn check_resend_throttled() {
+        // Arrange
+        let mut mailer = MockMailerImpl::new();
+        mailer.expect_send().times(1).returning(|_, _, _| Ok(()));
+        let verifier = EmailVerifier::new(Arc::new(mailer), "test_email", "http://test/verify");
+
+        // Act
+        let first = verifier.send(1, "verify@test.test").await;
+        let second = verifier.send(1, "verify@test.test").await;
+
+        // Assert
+        // 두 번째 요청은 메일을 보내지 않음
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_resend_after_failure() {
+        // Arrange
+        let mut mailer = MockMailerImpl::new();
+        let mut seq = mockall::Sequence::new();
+        mailer
+            .expect_send()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| {
+                Err(Arc::new(CustomError::Unexpected(anyhow::Error::msg(
+                    "smtp down",
+                ))))
+            });
+        mailer
+            .expect_send()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _, _| Ok(()));
+        let verifier = EmailVerifier::new(Arc::new(mailer), "test_email", "http://test/verify");
+
+        // Act
+        let first = verifier.send(1, "verify@test.test").await;
+        let second = verifier.send(1, "verify@test.test").await;
+
+        // Assert
+        assert!(first.is_err());
+        assert!(second.is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_verify_expired_token() {
+        // Arrange
+        let verifier =
+            EmailVerifier::new(Arc::new(MockMailerImpl::new()), "test_email", "http://test");
+        let token = create_email_jwt(1, "verify@test.test", "test_email", -10).unwrap();
+
+        // Act
+        let result = verifier.verify(&token);
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_verify_forged_token() {
+        // Arrange
+        let verifier =
+            EmailVerifier::new(Arc::new(MockMailerImpl::new()), "test_email", "http://test");
+        // 다른 키로 서명된 토큰
+        let token = create_email_jwt(1, "verify@test.test", "test_access", 10).unwrap();
+
+        // Act
+        let result = verifier.verify(&token);
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::book::entity::{Book, BookUpdate};
+use crate::{
+    domain::book::entity::{Book, BookRoleType, BookUpdate, Invitee},
+    global::errors::CustomError,
+};
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Serialize)]
 pub struct NewBook {
@@ -52,3 +55,103 @@ impl EditBook {
         BookUpdate::new(user_id, self.book_id.unwrap(), self.name.to_string())
     }
 }
+
+/// 아이디, 이메일 중 하나로 초대
+#[derive(Deserialize, Debug, Clone, PartialEq, Serialize)]
+pub struct InviteMember {
+    username: Option<String>,
+    email: Option<String>,
+    role: String,
+}
+
+impl InviteMember {
+    pub fn new(username: Option<String>, email: Option<String>, role: String) -> Self {
+        Self {
+            username,
+            email,
+            role,
+        }
+    }
+
+    pub fn to_invitee(&self) -> Result<Invitee, Box<CustomError>> {
+        match (&self.username, &self.email) {
+            (Some(username), None) => Ok(Invitee::Username(username.to_string())),
+            (None, Some(email)) => Ok(Invitee::Email(email.to_string())),
+            _ => Err(Box::new(CustomError::ValidationError(
+                "Invitee".to_string(),
+            ))),
+        }
+    }
+
+    pub fn to_role(&self) -> Result<BookRoleType, Box<CustomError>> {
+        self.role.parse()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Serialize)]
+pub struct EditMember {
+    role: String,
+}
+
+impl EditMember {
+    pub fn new(role: String) -> Self {
+        Self { role }
+    }
+
+    pub fn to_role(&self) -> Result<BookRoleType, Box<CustomError>> {
+        self.role.parse()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Serialize)]
+pub struct TransferOwner {
+    user_id: i32,
+}
+
+impl TransferOwner {
+    pub fn new(user_id: i32) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> i32 {
+        self.user_id
+    }
+}
+
+// 초대 코드 유효 기간(시간), 사용 횟수 기본값과 상한
+const DEFAULT_INVITE_HOURS: i32 = 72;
+const MAX_INVITE_HOURS: i32 = 24 * 30;
+const MAX_INVITE_USES: i32 = 100;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Serialize)]
+pub struct NewBookInvite {
+    role: String,
+    max_uses: Option<i32>,
+    expire_hours: Option<i32>,
+}
+
+impl NewBookInvite {
+    pub fn new(role: String, max_uses: Option<i32>, expire_hours: Option<i32>) -> Self {
+        Self {
+            role,
+            max_uses,
+            expire_hours,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        (1..=MAX_INVITE_USES).contains(&self.get_max_uses())
+            && (1..=MAX_INVITE_HOURS).contains(&self.get_expire_hours())
+    }
+
+    pub fn to_role(&self) -> Result<BookRoleType, Box<CustomError>> {
+        self.role.parse()
+    }
+
+    pub fn get_max_uses(&self) -> i32 {
+        self.max_uses.unwrap_or(1)
+    }
+    pub fn get_expire_hours(&self) -> i32 {
+        self.expire_hours.unwrap_or(DEFAULT_INVITE_HOURS)
+    }
+}
@@ -1,5 +1,19 @@
+use std::{fmt, str::FromStr};
+
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    domain::{
+        asset::entity::Asset,
+        category::entity::{BaseCategory, SubCategory},
+        connect::entity::Connect,
+        diary::entity::Diary,
+        record::entity::{Record, RecordLine},
+    },
+    global::errors::CustomError,
+};
+
 #[derive(Deserialize, sqlx::FromRow, Serialize, Clone, Debug, PartialEq)]
 pub struct Book {
     id: Option<i32>,
@@ -102,3 +116,290 @@ impl BookRole {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BookRoleType {
+    Owner,
+    Editor,
+    Viewer,
+}
+
+impl FromStr for BookRoleType {
+    type Err = Box<CustomError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "owner" => Ok(BookRoleType::Owner),
+            "editor" => Ok(BookRoleType::Editor),
+            "viewer" => Ok(BookRoleType::Viewer),
+            _ => Err(Box::new(CustomError::ValidationError(
+                "BookRole".to_string(),
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for BookRoleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let role = match self {
+            BookRoleType::Owner => "owner",
+            BookRoleType::Editor => "editor",
+            BookRoleType::Viewer => "viewer",
+        };
+        write!(f, "{}", role)
+    }
+}
+
+/// 초대할 사용자: 아이디 또는 이메일
+#[derive(Debug, Clone, PartialEq)]
+pub enum Invitee {
+    Username(String),
+    Email(String),
+}
+
+impl Invitee {
+    pub fn get_username(&self) -> Option<&str> {
+        match self {
+            Invitee::Username(username) => Some(username),
+            Invitee::Email(_) => None,
+        }
+    }
+    pub fn get_email(&self) -> Option<&str> {
+        match self {
+            Invitee::Username(_) => None,
+            Invitee::Email(email) => Some(email),
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct BookMember {
+    user_id: i32,
+    username: String,
+    nickname: Option<String>,
+    role: String,
+}
+
+impl BookMember {
+    pub fn new(user_id: i32, username: String, role: String) -> Self {
+        Self {
+            user_id,
+            username,
+            nickname: None,
+            role,
+        }
+    }
+
+    pub fn get_user_id(&self) -> i32 {
+        self.user_id
+    }
+    pub fn get_role(&self) -> &str {
+        &self.role
+    }
+}
+
+#[derive(Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct BookInvite {
+    id: Option<i32>,
+    book_id: i32,
+    code: String,
+    role: String,
+    max_uses: i32,
+    use_count: i32,
+    is_revoked: bool,
+    expired_at: Option<NaiveDateTime>,
+    created_at: Option<NaiveDateTime>,
+}
+
+impl BookInvite {
+    pub fn new(book_id: i32, code: String, role: BookRoleType, max_uses: i32) -> Self {
+        Self {
+            id: None,
+            book_id,
+            code,
+            role: role.to_string(),
+            max_uses,
+            use_count: 0,
+            is_revoked: false,
+            expired_at: None,
+            created_at: None,
+        }
+    }
+
+    pub fn id(mut self, id: i32) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn expired_at(mut self, expired_at: NaiveDateTime) -> Self {
+        self.expired_at = Some(expired_at);
+        self
+    }
+
+    pub fn get_id(&self) -> Option<i32> {
+        self.id
+    }
+    pub fn get_book_id(&self) -> i32 {
+        self.book_id
+    }
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn get_role(&self) -> &str {
+        &self.role
+    }
+    pub fn get_max_uses(&self) -> i32 {
+        self.max_uses
+    }
+    pub fn get_use_count(&self) -> i32 {
+        self.use_count
+    }
+}
+
+// 백업 파일 구조가 바뀌면 올림
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// 백업용 기록: 이체 방향, 커넥트, 분할 내역 포함
+#[derive(Deserialize, sqlx::FromRow, Serialize, Clone, Debug, PartialEq)]
+pub struct ArchiveRecord {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    record: Record,
+    #[serde(default)]
+    is_transfer_in: bool,
+    #[sqlx(skip)]
+    #[serde(default)]
+    connect_ids: Vec<i32>,
+    #[sqlx(skip)]
+    #[serde(default)]
+    lines: Vec<RecordLine>,
+}
+
+impl ArchiveRecord {
+    pub fn new(record: Record) -> Self {
+        Self {
+            record,
+            is_transfer_in: false,
+            connect_ids: vec![],
+            lines: vec![],
+        }
+    }
+
+    pub fn connect_ids(mut self, connect_ids: Vec<i32>) -> Self {
+        self.connect_ids = connect_ids;
+        self
+    }
+
+    pub fn lines(mut self, lines: Vec<RecordLine>) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    pub fn get_record(&self) -> &Record {
+        &self.record
+    }
+    pub fn get_is_transfer_in(&self) -> bool {
+        self.is_transfer_in
+    }
+    pub fn get_connect_ids(&self) -> &Vec<i32> {
+        &self.connect_ids
+    }
+    pub fn get_lines(&self) -> &Vec<RecordLine> {
+        &self.lines
+    }
+}
+
+/// 가계부 전체 백업: 공용 카테고리는 id만, 가계부 카테고리는 내용까지 포함
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct BookArchive {
+    version: u32,
+    exported_at: NaiveDateTime,
+    book: Book,
+    #[serde(default)]
+    base_categories: Vec<BaseCategory>,
+    #[serde(default)]
+    sub_categories: Vec<SubCategory>,
+    #[serde(default)]
+    assets: Vec<Asset>,
+    #[serde(default)]
+    connects: Vec<Connect>,
+    #[serde(default)]
+    records: Vec<ArchiveRecord>,
+    #[serde(default)]
+    diaries: Vec<Diary>,
+}
+
+impl BookArchive {
+    pub fn new(book: Book, exported_at: NaiveDateTime) -> Self {
+        Self {
+            version: ARCHIVE_VERSION,
+            exported_at,
+            book,
+            base_categories: vec![],
+            sub_categories: vec![],
+            assets: vec![],
+            connects: vec![],
+            records: vec![],
+            diaries: vec![],
+        }
+    }
+
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn categories(
+        mut self,
+        base_categories: Vec<BaseCategory>,
+        sub_categories: Vec<SubCategory>,
+    ) -> Self {
+        self.base_categories = base_categories;
+        self.sub_categories = sub_categories;
+        self
+    }
+
+    pub fn assets(mut self, assets: Vec<Asset>) -> Self {
+        self.assets = assets;
+        self
+    }
+
+    pub fn connects(mut self, connects: Vec<Connect>) -> Self {
+        self.connects = connects;
+        self
+    }
+
+    pub fn records(mut self, records: Vec<ArchiveRecord>) -> Self {
+        self.records = records;
+        self
+    }
+
+    pub fn diaries(mut self, diaries: Vec<Diary>) -> Self {
+        self.diaries = diaries;
+        self
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+    pub fn get_book(&self) -> &Book {
+        &self.book
+    }
+    pub fn get_base_categories(&self) -> &Vec<BaseCategory> {
+        &self.base_categories
+    }
+    pub fn get_sub_categories(&self) -> &Vec<SubCategory> {
+        &self.sub_categories
+    }
+    pub fn get_assets(&self) -> &Vec<Asset> {
+        &self.assets
+    }
+    pub fn get_connects(&self) -> &Vec<Connect> {
+        &self.connects
+    }
+    pub fn get_records(&self) -> &Vec<ArchiveRecord> {
+        &self.records
+    }
+    pub fn get_diaries(&self) -> &Vec<Diary> {
+        &self.diaries
+    }
+}
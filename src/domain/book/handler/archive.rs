@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::Path,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde_json::json;
+
+use crate::{
+    domain::book::{entity::BookArchive, usecase::archive::BookArchiveUsecase},
+    global::errors::CustomError,
+};
+
+pub async fn export_book<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: BookArchiveUsecase,
+{
+    tracing::debug!("CALL: Export Book");
+
+    match usecase.export_book(user_id, book_id).await {
+        Ok(archive) => {
+            let content_disposition = format!("attachment; filename=\"book-{}.json\"", book_id);
+            (
+                StatusCode::OK,
+                [(header::CONTENT_DISPOSITION, content_disposition)],
+                Json(json!(archive)),
+            )
+                .into_response()
+        }
+        Err(err) => {
+            tracing::error!("Error: {:?}", err);
+            err.into_response()
+        }
+    }
+}
+
+pub async fn import_book<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Json(archive): Json<BookArchive>,
+) -> impl IntoResponse
+where
+    T: BookArchiveUsecase,
+{
+    tracing::debug!("CALL: Import Book");
+
+    // type_id check 1 ~ 3
+    let type_id = archive.get_book().get_type_id();
+    if !(0 < type_id && type_id <= 3) {
+        return CustomError::ValidationError("BookType".to_string()).into_response();
+    }
+
+    match usecase.import_book(user_id, archive).await {
+        Ok(id) => {
+            tracing::info!("Restored: {}", id);
+            (
+                StatusCode::CREATED,
+                Json(json!({"message": "가계부 복원 완료", "book_id": id})),
+            )
+                .into_response()
+        }
+        Err(err) => {
+            tracing::error!("Error: {:?}", err);
+            err.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        async_trait,
+        body::Body,
+        http::{Method, Request},
+        routing::{get, post},
+        Extension, Router,
+    };
+    use chrono::NaiveDateTime;
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::{export_book, import_book};
+    use crate::{
+        domain::book::{
+            entity::{Book, BookArchive},
+            usecase::archive::BookArchiveUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        BookArchiveUsecaseImpl {}
+
+        #[async_trait]
+        impl BookArchiveUsecase for BookArchiveUsecaseImpl {
+            async fn export_book(&self, user_id: i32, book_id: i32) -> Result<BookArchive, Box<CustomError>>;
+            async fn import_book(&self, user_id: i32, archive: BookArchive) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _archive(type_id: i16) -> BookArchive {
+        let exported_at =
+            NaiveDateTime::parse_from_str("2024-09-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        BookArchive::new(
+            Book::new("백업 가계부".to_string(), type_id).id(1),
+            exported_at,
+        )
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockBookArchiveUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/book/:book_id/archive",
+                get(export_book::<MockBookArchiveUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/book/archive",
+                post(import_book::<MockBookArchiveUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    #[tokio::test]
+    async fn check_export_book() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let mut mock_usecase = MockBookArchiveUsecaseImpl::new();
+        mock_usecase
+            .expect_export_book()
+            .with(predicate::eq(user_id), predicate::eq(book_id))
+            .returning(|_, _| Ok(_archive(1)));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri(format!("/api/v1/book/{}/archive", book_id))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers()["content-disposition"],
+            "attachment; filename=\"book-1.json\""
+        );
+
+        let body_bytes = response
+            .into_body()
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+        let body_json: Value = serde_json::from_slice(&body_bytes).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["version"], 1);
+        assert_eq!(body_json["book"]["name"], "백업 가계부");
+    }
+
+    #[tokio::test]
+    async fn check_import_book() {
+        // Arrange
+        let user_id = 1;
+        let archive = _archive(1);
+        let mut mock_usecase = MockBookArchiveUsecaseImpl::new();
+        mock_usecase
+            .expect_import_book()
+            .with(predicate::eq(user_id), predicate::eq(archive.clone()))
+            .returning(|_, _| Ok(10));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/book/archive")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&archive).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let body_bytes = response
+            .into_body()
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+        let body_json: Value = serde_json::from_slice(&body_bytes).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["book_id"], 10);
+    }
+
+    #[tokio::test]
+    async fn check_import_book_type() {
+        // Arrange
+        let user_id = 1;
+        let mut mock_usecase = MockBookArchiveUsecaseImpl::new();
+        mock_usecase.expect_import_book().never();
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/book/archive")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&_archive(9)).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::book::{dto::request::NewBookInvite, usecase::invite::BookInviteUsecase},
+    global::errors::CustomError,
+};
+
+pub async fn create_invite<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    Json(new_invite): Json<NewBookInvite>,
+) -> impl IntoResponse
+where
+    T: BookInviteUsecase,
+{
+    tracing::debug!("CALL: Create Book Invite");
+
+    // 사용 횟수, 유효 기간 범위 확인
+    if !new_invite.is_valid() {
+        return CustomError::ValidationError("BookInvite".to_string()).into_response();
+    }
+
+    match usecase.create_invite(user_id, book_id, new_invite).await {
+        Ok(invite) => (StatusCode::CREATED, Json(json!(invite))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn read_invites<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: BookInviteUsecase,
+{
+    match usecase.read_invites(user_id, book_id).await {
+        Ok(result) => (StatusCode::OK, Json(json!(result))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn revoke_invite<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path((book_id, invite_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T: BookInviteUsecase,
+{
+    match usecase.revoke_invite(user_id, book_id, invite_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn redeem_invite<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(code): Path<String>,
+) -> impl IntoResponse
+where
+    T: BookInviteUsecase,
+{
+    tracing::debug!("CALL: Redeem Book Invite");
+
+    match usecase.redeem_invite(user_id, code).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "가계부 참여 완료", "book_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        async_trait,
+        body::Body,
+        extract::Request,
+        routing::{delete, get, post},
+        Extension, Router,
+    };
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::{create_invite, read_invites, redeem_invite, revoke_invite};
+    use crate::{
+        domain::book::{
+            dto::request::NewBookInvite,
+            entity::{BookInvite, BookRoleType},
+            usecase::invite::BookInviteUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        BookInviteUsecaseImpl {}
+
+        #[async_trait]
+        impl BookInviteUsecase for BookInviteUsecaseImpl {
+            async fn create_invite(&self, user_id: i32, book_id: i32, new_invite: NewBookInvite) -> Result<BookInvite, Box<CustomError>>;
+            async fn read_invites(&self, user_id: i32, book_id: i32) -> Result<Vec<BookInvite>, Box<CustomError>>;
+            async fn revoke_invite(&self, user_id: i32, book_id: i32, invite_id: i32) -> Result<(), Box<CustomError>>;
+            async fn redeem_invite(&self, user_id: i32, code: String) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _get_router(user_id: i32, mock_usecase: MockBookInviteUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/book/:book_id/invite",
+                get(read_invites::<MockBookInviteUsecaseImpl>)
+                    .post(create_invite::<MockBookInviteUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/book/:book_id/invite/:invite_id",
+                delete(revoke_invite::<MockBookInviteUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/book/invite/:code",
+                post(redeem_invite::<MockBookInviteUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _invite() -> BookInvite {
+        BookInvite::new(1, "invitecode01".to_string(), BookRoleType::Viewer, 3).id(1)
+    }
+
+    #[tokio::test]
+    async fn check_create_invite() {
+        // Arrange
+        let new_invite = NewBookInvite::new("viewer".to_string(), Some(3), Some(24));
+        let mut mock_usecase = MockBookInviteUsecaseImpl::new();
+        mock_usecase
+            .expect_create_invite()
+            .with(
+                predicate::eq(1),
+                predicate::eq(1),
+                predicate::eq(new_invite.clone()),
+            )
+            .returning(|_, _, _| Ok(_invite()));
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/book/1/invite")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&new_invite).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body["code"], "invitecode01");
+        assert_eq!(body["max_uses"], 3);
+    }
+
+    #[tokio::test]
+    async fn check_create_invite_invalid() {
+        // Arrange
+        let mut mock_usecase = MockBookInviteUsecaseImpl::new();
+        mock_usecase.expect_create_invite().never();
+
+        let new_invite = NewBookInvite::new("viewer".to_string(), Some(0), None);
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/book/1/invite")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&new_invite).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn check_read_invites() {
+        // Arrange
+        let mut mock_usecase = MockBookInviteUsecaseImpl::new();
+        mock_usecase
+            .expect_read_invites()
+            .with(predicate::eq(1), predicate::eq(1))
+            .returning(|_, _| Ok(vec![_invite()]));
+
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/book/1/invite")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body.as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_revoke_invite() {
+        // Arrange
+        let mut mock_usecase = MockBookInviteUsecaseImpl::new();
+        mock_usecase
+            .expect_revoke_invite()
+            .with(predicate::eq(1), predicate::eq(1), predicate::eq(2))
+            .returning(|_, _, _| Ok(()));
+
+        let req = Request::builder()
+            .method("DELETE")
+            .uri("/api/v1/book/1/invite/2")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn check_redeem_invite() {
+        // Arrange
+        let mut mock_usecase = MockBookInviteUsecaseImpl::new();
+        mock_usecase
+            .expect_redeem_invite()
+            .with(predicate::eq(3), predicate::eq("invitecode01".to_string()))
+            .returning(|_, _| Ok(1));
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/book/invite/invitecode01")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(3, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body["book_id"], 1);
+    }
+}
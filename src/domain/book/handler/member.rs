@@ -0,0 +1,386 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::book::{
+    dto::request::{EditMember, InviteMember, TransferOwner},
+    usecase::member::BookMemberUsecase,
+};
+
+pub async fn read_members<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: BookMemberUsecase,
+{
+    match usecase.read_members(user_id, book_id).await {
+        Ok(result) => (StatusCode::OK, Json(json!(result))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn invite_member<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    Json(invite): Json<InviteMember>,
+) -> impl IntoResponse
+where
+    T: BookMemberUsecase,
+{
+    tracing::debug!("CALL: Invite Book Member");
+
+    match usecase.invite_member(user_id, book_id, invite).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "구성원 초대 완료", "user_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn update_member<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path((book_id, member_id)): Path<(i32, i32)>,
+    Json(edit): Json<EditMember>,
+) -> impl IntoResponse
+where
+    T: BookMemberUsecase,
+{
+    match usecase
+        .update_member(user_id, book_id, member_id, edit)
+        .await
+    {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn remove_member<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path((book_id, member_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T: BookMemberUsecase,
+{
+    match usecase.remove_member(user_id, book_id, member_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn leave_book<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: BookMemberUsecase,
+{
+    match usecase.leave_book(user_id, book_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn transfer_owner<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    Json(transfer): Json<TransferOwner>,
+) -> impl IntoResponse
+where
+    T: BookMemberUsecase,
+{
+    tracing::debug!("CALL: Transfer Book Owner");
+
+    match usecase.transfer_owner(user_id, book_id, transfer).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        async_trait,
+        body::Body,
+        extract::Request,
+        routing::{get, patch},
+        Extension, Router,
+    };
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::{
+        invite_member, leave_book, read_members, remove_member, transfer_owner, update_member,
+    };
+    use crate::{
+        domain::book::{
+            dto::request::{EditMember, InviteMember, TransferOwner},
+            entity::BookMember,
+            usecase::member::BookMemberUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        BookMemberUsecaseImpl {}
+
+        #[async_trait]
+        impl BookMemberUsecase for BookMemberUsecaseImpl {
+            async fn read_members(&self, user_id: i32, book_id: i32) -> Result<Vec<BookMember>, Box<CustomError>>;
+            async fn invite_member(&self, user_id: i32, book_id: i32, invite: InviteMember) -> Result<i32, Box<CustomError>>;
+            async fn update_member(&self, user_id: i32, book_id: i32, member_id: i32, edit: EditMember) -> Result<(), Box<CustomError>>;
+            async fn remove_member(&self, user_id: i32, book_id: i32, member_id: i32) -> Result<(), Box<CustomError>>;
+            async fn leave_book(&self, user_id: i32, book_id: i32) -> Result<(), Box<CustomError>>;
+            async fn transfer_owner(&self, user_id: i32, book_id: i32, transfer: TransferOwner) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _get_router(user_id: i32, mock_usecase: MockBookMemberUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/book/:book_id/member",
+                get(read_members::<MockBookMemberUsecaseImpl>)
+                    .post(invite_member::<MockBookMemberUsecaseImpl>)
+                    .delete(leave_book::<MockBookMemberUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/book/:book_id/member/:user_id",
+                patch(update_member::<MockBookMemberUsecaseImpl>)
+                    .delete(remove_member::<MockBookMemberUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/book/:book_id/owner",
+                patch(transfer_owner::<MockBookMemberUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    #[tokio::test]
+    async fn check_read_members() {
+        // Arrange
+        let mut mock_usecase = MockBookMemberUsecaseImpl::new();
+        mock_usecase
+            .expect_read_members()
+            .with(predicate::eq(1), predicate::eq(1))
+            .returning(|_, _| {
+                Ok(vec![
+                    BookMember::new(1, "test_user".to_string(), "owner".to_string()),
+                    BookMember::new(2, "viewer_user".to_string(), "viewer".to_string()),
+                ])
+            });
+
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/book/1/member")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body.as_array().unwrap().len(), 2);
+        assert_eq!(body[1]["username"], "viewer_user");
+        assert_eq!(body[1]["role"], "viewer");
+    }
+
+    #[tokio::test]
+    async fn check_invite_member() {
+        // Arrange
+        let invite =
+            InviteMember::new(Some("no_role_user".to_string()), None, "editor".to_string());
+        let mut mock_usecase = MockBookMemberUsecaseImpl::new();
+        mock_usecase
+            .expect_invite_member()
+            .with(
+                predicate::eq(1),
+                predicate::eq(1),
+                predicate::eq(invite.clone()),
+            )
+            .returning(|_, _, _| Ok(3));
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/book/1/member")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&invite).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body["user_id"], 3);
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_unauthorized() {
+        // Arrange
+        let invite =
+            InviteMember::new(Some("no_role_user".to_string()), None, "editor".to_string());
+        let mut mock_usecase = MockBookMemberUsecaseImpl::new();
+        mock_usecase
+            .expect_invite_member()
+            .returning(|_, _, _| Err(Box::new(CustomError::Unauthorized("BookRole".to_string()))));
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/book/1/member")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&invite).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = _get_router(2, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn check_update_member() {
+        // Arrange
+        let edit = EditMember::new("viewer".to_string());
+        let mut mock_usecase = MockBookMemberUsecaseImpl::new();
+        mock_usecase
+            .expect_update_member()
+            .with(
+                predicate::eq(1),
+                predicate::eq(1),
+                predicate::eq(3),
+                predicate::eq(edit.clone()),
+            )
+            .returning(|_, _, _, _| Ok(()));
+
+        let req = Request::builder()
+            .method("PATCH")
+            .uri("/api/v1/book/1/member/3")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&edit).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn check_remove_member() {
+        // Arrange
+        let mut mock_usecase = MockBookMemberUsecaseImpl::new();
+        mock_usecase
+            .expect_remove_member()
+            .with(predicate::eq(1), predicate::eq(1), predicate::eq(3))
+            .returning(|_, _, _| Err(Box::new(CustomError::NotFound("BookMember".to_string()))));
+
+        let req = Request::builder()
+            .method("DELETE")
+            .uri("/api/v1/book/1/member/3")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn check_leave_book() {
+        // Arrange
+        let mut mock_usecase = MockBookMemberUsecaseImpl::new();
+        mock_usecase
+            .expect_leave_book()
+            .with(predicate::eq(2), predicate::eq(1))
+            .returning(|_, _| Ok(()));
+
+        let req = Request::builder()
+            .method("DELETE")
+            .uri("/api/v1/book/1/member")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(2, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn check_transfer_owner() {
+        // Arrange
+        let transfer = TransferOwner::new(3);
+        let mut mock_usecase = MockBookMemberUsecaseImpl::new();
+        mock_usecase
+            .expect_transfer_owner()
+            .with(
+                predicate::eq(1),
+                predicate::eq(1),
+                predicate::eq(transfer.clone()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        let req = Request::builder()
+            .method("PATCH")
+            .uri("/api/v1/book/1/owner")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&transfer).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn check_leave_book_last_owner() {
+        // Arrange
+        let mut mock_usecase = MockBookMemberUsecaseImpl::new();
+        mock_usecase.expect_leave_book().returning(|_, _| {
+            Err(Box::new(CustomError::ValidationError(
+                "LastOwner".to_string(),
+            )))
+        });
+
+        let req = Request::builder()
+            .method("DELETE")
+            .uri("/api/v1/book/1/member")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+}
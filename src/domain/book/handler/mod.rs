@@ -1,31 +1,43 @@
 use std::sync::Arc;
 
 use axum::{
+    extract::DefaultBodyLimit,
     routing::{delete, get, patch, post},
     Extension, Router,
 };
 
 use sqlx::PgPool;
 
+mod archive;
 mod create;
 mod delete;
+mod invite;
+mod member;
 mod read;
 mod read_type;
 mod update;
 
+use archive::{export_book, import_book};
 use create::create_book;
 use delete::delete_book;
+use invite::{create_invite, read_invites, redeem_invite, revoke_invite};
+use member::{
+    invite_member, leave_book, read_members, remove_member, transfer_owner, update_member,
+};
 use read::{read_book, read_books};
 use read_type::read_book_types;
 use update::update_book;
 
 use super::{
     repository::{
-        delete::DeleteBookRepoImpl, get_book::GetBookRepoImpl, get_book_type::GetBookTypeRepoImpl,
+        archive::BookArchiveRepoImpl, delete::DeleteBookRepoImpl, get_book::GetBookRepoImpl,
+        get_book_type::GetBookTypeRepoImpl, invite::BookInviteRepoImpl, member::BookMemberRepoImpl,
         save::SaveBookRepoImpl, update::UpdateBookRepoImpl,
     },
     usecase::{
-        create::CreateBookUsecaseImpl, delete::DeleteBookUsecaseImpl, read::ReadBookUsecaseImpl,
+        archive::BookArchiveUsecaseImpl, create::CreateBookUsecaseImpl,
+        delete::DeleteBookUsecaseImpl, invite::BookInviteUsecaseImpl,
+        member::BookMemberUsecaseImpl, read::ReadBookUsecaseImpl,
         read_type::ReadBookTypeUsecaseImpl, update::UpdateBookUsecaseImpl,
     },
 };
@@ -42,6 +54,26 @@ pub fn create_router(pool: &Arc<PgPool>) -> Router {
     )
 }
 
+// 백업 파일은 기본 요청 크기 제한(2MB)보다 클 수 있음
+const MAX_ARCHIVE_SIZE: usize = 32 * 1024 * 1024;
+
+pub fn archive_router(pool: &Arc<PgPool>) -> Router {
+    let repository = BookArchiveRepoImpl::new(pool.clone());
+    let usecase = BookArchiveUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:book_id/archive",
+            get(export_book::<BookArchiveUsecaseImpl<BookArchiveRepoImpl>>),
+        )
+        .route(
+            "/archive",
+            post(import_book::<BookArchiveUsecaseImpl<BookArchiveRepoImpl>>)
+                .layer(DefaultBodyLimit::max(MAX_ARCHIVE_SIZE)),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
 pub fn read_router(pool: &Arc<PgPool>) -> Router {
     let repository = GetBookRepoImpl::new(pool.clone());
 
@@ -92,3 +124,47 @@ pub fn delete_router(pool: &Arc<PgPool>) -> Router {
         )
         .layer(Extension(Arc::new(usecase)))
 }
+
+pub fn member_router(pool: &Arc<PgPool>) -> Router {
+    let repository = BookMemberRepoImpl::new(pool.clone());
+    let usecase = BookMemberUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:book_id/member",
+            get(read_members::<BookMemberUsecaseImpl<BookMemberRepoImpl>>)
+                .post(invite_member::<BookMemberUsecaseImpl<BookMemberRepoImpl>>)
+                .delete(leave_book::<BookMemberUsecaseImpl<BookMemberRepoImpl>>),
+        )
+        .route(
+            "/:book_id/member/:user_id",
+            patch(update_member::<BookMemberUsecaseImpl<BookMemberRepoImpl>>)
+                .delete(remove_member::<BookMemberUsecaseImpl<BookMemberRepoImpl>>),
+        )
+        .route(
+            "/:book_id/owner",
+            patch(transfer_owner::<BookMemberUsecaseImpl<BookMemberRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn invite_router(pool: &Arc<PgPool>) -> Router {
+    let repository = BookInviteRepoImpl::new(pool.clone());
+    let usecase = BookInviteUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:book_id/invite",
+            get(read_invites::<BookInviteUsecaseImpl<BookInviteRepoImpl>>)
+                .post(create_invite::<BookInviteUsecaseImpl<BookInviteRepoImpl>>),
+        )
+        .route(
+            "/:book_id/invite/:invite_id",
+            delete(revoke_invite::<BookInviteUsecaseImpl<BookInviteRepoImpl>>),
+        )
+        .route(
+            "/invite/:code",
+            post(redeem_invite::<BookInviteUsecaseImpl<BookInviteRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
@@ -0,0 +1,882 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::Arc,
+};
+
+use axum::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{
+        asset::entity::Asset,
+        book::entity::{ArchiveRecord, Book, BookArchive},
+        category::entity::{BaseCategory, SubCategory},
+        connect::entity::Connect,
+        diary::entity::Diary,
+        record::entity::RecordLine,
+    },
+    global::errors::CustomError,
+};
+
+pub struct BookArchiveRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait BookArchiveRepo: Send + Sync {
+    async fn get_archive(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<BookArchive, Box<CustomError>>;
+    async fn restore_archive(
+        &self,
+        user_id: i32,
+        archive: BookArchive,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl BookArchiveRepoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BookArchiveRepo for BookArchiveRepoImpl {
+    async fn get_archive(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<BookArchive, Box<CustomError>> {
+        get_archive(&self.pool, user_id, book_id).await
+    }
+    async fn restore_archive(
+        &self,
+        user_id: i32,
+        archive: BookArchive,
+    ) -> Result<i32, Box<CustomError>> {
+        restore_archive(&self.pool, user_id, archive).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+fn invalid_archive() -> Box<CustomError> {
+    Box::new(CustomError::ValidationError("BookArchive".to_string()))
+}
+
+/// 가계부와 딸린 데이터를 한 시점 기준으로 조회
+pub async fn get_archive(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+) -> Result<BookArchive, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("GetBookArchive", e))?;
+
+    // 조회 도중 바뀐 내용이 섞이지 않도록
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| to_custom_error("GetBookArchive", e))?;
+
+    let book = sqlx::query_as::<_, Book>(
+        "
+        SELECT b.id, b.name, b.type_id
+        FROM tb_book AS b
+        JOIN tb_user_book_role AS br ON br.book_id = b.id
+        WHERE b.id = $1 AND br.user_id = $2
+    ",
+    )
+    .bind(book_id)
+    .bind(user_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetBookArchive", e))?
+    .ok_or(Box::new(CustomError::NotFound("Book".to_string())))?;
+
+    let base_categories = sqlx::query_as::<_, BaseCategory>(
+        "
+        SELECT id, type_id, book_id, is_record, is_income, name, color
+        FROM tb_base_category
+        WHERE book_id = $1
+        ORDER BY id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetArchiveBaseCategory", e))?;
+
+    let sub_categories = sqlx::query_as::<_, SubCategory>(
+        "
+        SELECT sc.id, sc.base_id, sc.name
+        FROM tb_sub_category AS sc
+        JOIN tb_base_category AS bc ON bc.id = sc.base_id
+        WHERE bc.book_id = $1
+        ORDER BY sc.id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetArchiveSubCategory", e))?;
+
+    let assets = sqlx::query_as::<_, Asset>(
+        "
+        SELECT id, book_id, sub_category_id, code, memo, balance, created_at, updated_at
+        FROM tb_asset
+        WHERE book_id = $1
+        ORDER BY id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetArchiveAsset", e))?;
+
+    // 커넥트는 전체 공용이라 기록에서 사용 중인 것만
+    let connects = sqlx::query_as::<_, Connect>(
+        "
+        SELECT DISTINCT c.id, c.name
+        FROM tb_connect AS c
+        JOIN tb_record_connect AS rc ON rc.connect_id = c.id
+        JOIN tb_record AS r ON r.id = rc.record_id
+        WHERE r.book_id = $1
+        ORDER BY c.id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetArchiveConnect", e))?;
+
+    let records = sqlx::query_as::<_, ArchiveRecord>(
+        "
+        SELECT
+            id, book_id, sub_category_id, amount, memo, target_dt,
+            created_at, updated_at, asset_id, transfer_id, is_transfer_in
+        FROM tb_record
+        WHERE book_id = $1
+        ORDER BY id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetArchiveRecord", e))?;
+
+    let record_connects: Vec<(i64, i32)> = sqlx::query_as(
+        "
+        SELECT rc.record_id, rc.connect_id
+        FROM tb_record_connect AS rc
+        JOIN tb_record AS r ON r.id = rc.record_id
+        WHERE r.book_id = $1
+        ORDER BY rc.record_id, rc.connect_id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetArchiveRecordConnect", e))?;
+
+    let record_lines: Vec<(i64, i32, i32)> = sqlx::query_as(
+        "
+        SELECT rl.record_id, rl.sub_category_id, rl.amount
+        FROM tb_record_line AS rl
+        JOIN tb_record AS r ON r.id = rl.record_id
+        WHERE r.book_id = $1
+        ORDER BY rl.id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetArchiveRecordLine", e))?;
+
+    let diaries = sqlx::query_as::<_, Diary>(
+        "
+        SELECT id, book_id, title, content, target_dt, created_at, updated_at
+        FROM tb_diary
+        WHERE book_id = $1
+        ORDER BY id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetArchiveDiary", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("GetBookArchive", e))?;
+
+    let mut connect_ids: HashMap<i64, Vec<i32>> = HashMap::new();
+    for (record_id, connect_id) in record_connects {
+        connect_ids.entry(record_id).or_default().push(connect_id);
+    }
+    let mut lines: HashMap<i64, Vec<RecordLine>> = HashMap::new();
+    for (record_id, sub_category_id, amount) in record_lines {
+        lines
+            .entry(record_id)
+            .or_default()
+            .push(RecordLine::new(sub_category_id, amount));
+    }
+    let records = records
+        .into_iter()
+        .map(|record| {
+            let record_id = record.get_record().get_id();
+            record
+                .connect_ids(connect_ids.remove(&record_id).unwrap_or_default())
+                .lines(lines.remove(&record_id).unwrap_or_default())
+        })
+        .collect();
+
+    Ok(BookArchive::new(book, Utc::now().naive_utc())
+        .categories(base_categories, sub_categories)
+        .assets(assets)
+        .connects(connects)
+        .records(records)
+        .diaries(diaries))
+}
+
+// 새로 저장할 행의 id를 미리 받아 두어 기존 id와 짝지음
+async fn allocate_ids(
+    conn: &mut sqlx::PgConnection,
+    table: &str,
+    count: usize,
+) -> Result<Vec<i64>, Box<CustomError>> {
+    sqlx::query_scalar(
+        "SELECT nextval(pg_get_serial_sequence($1, 'id')) FROM generate_series(1, $2)",
+    )
+    .bind(table)
+    .bind(count as i64)
+    .fetch_all(conn)
+    .await
+    .map_err(|e| to_custom_error("AllocateArchiveId", e))
+}
+
+fn to_id_map<K, V>(
+    old_ids: impl Iterator<Item = K>,
+    new_ids: impl Iterator<Item = V>,
+) -> Result<HashMap<K, V>, Box<CustomError>>
+where
+    K: Hash + Eq,
+{
+    let mut id_map = HashMap::new();
+    for (old_id, new_id) in old_ids.zip(new_ids) {
+        // 백업 파일에 id가 없거나 겹치는 경우
+        if id_map.insert(old_id, new_id).is_some() {
+            return Err(invalid_archive());
+        }
+    }
+    Ok(id_map)
+}
+
+fn map_id<K, V>(id_map: &HashMap<K, V>, id: &K) -> Result<V, Box<CustomError>>
+where
+    K: Hash + Eq,
+    V: Copy,
+{
+    id_map.get(id).copied().ok_or_else(invalid_archive)
+}
+
+/// 백업을 호출한 사용자 소유의 새 가계부로 복원, 모든 id는 새로 발급
+pub async fn restore_archive(
+    pool: &PgPool,
+    user_id: i32,
+    archive: BookArchive,
+) -> Result<i32, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("RestoreBookArchive", e))?;
+
+    let book = archive.get_book();
+    let is_duplicated: bool = sqlx::query_scalar(
+        "
+        SELECT EXISTS (
+            SELECT 1
+            FROM tb_book AS b
+            JOIN tb_user_book_role AS br ON br.book_id = b.id
+            WHERE br.user_id = $1 AND b.name = $2
+        )
+    ",
+    )
+    .bind(user_id)
+    .bind(book.get_name())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreBook", e))?;
+
+    if is_duplicated {
+        return Err(Box::new(CustomError::Duplicated("Book".to_string())));
+    }
+
+    let book_id: i32 =
+        sqlx::query_scalar("INSERT INTO tb_book (name, type_id) VALUES ($1, $2) RETURNING id")
+            .bind(book.get_name())
+            .bind(book.get_type_id())
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| to_custom_error("RestoreBook", e))?;
+
+    sqlx::query("INSERT INTO tb_user_book_role (user_id, book_id, role) VALUES ($1, $2, 'owner')")
+        .bind(user_id)
+        .bind(book_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| to_custom_error("RestoreBookRole", e))?;
+
+    // 대분류
+    let base_categories = archive.get_base_categories();
+    let new_ids = allocate_ids(&mut tx, "tb_base_category", base_categories.len()).await?;
+    let base_map = to_id_map(
+        base_categories.iter().map(|c| c.get_id()),
+        new_ids.iter().map(|id| *id as i16),
+    )?;
+
+    sqlx::query(
+        "
+        INSERT INTO tb_base_category (id, type_id, book_id, is_record, is_income, name, color)
+            SELECT c.id, b.type_id, b.id, c.is_record, c.is_income, c.name, c.color
+            FROM UNNEST($2::smallint[], $3::bool[], $4::bool[], $5::varchar[], $6::varchar[])
+                AS c(id, is_record, is_income, name, color)
+            JOIN tb_book AS b ON b.id = $1
+    ",
+    )
+    .bind(book_id)
+    .bind(
+        base_categories
+            .iter()
+            .map(|c| map_id(&base_map, &c.get_id()))
+            .collect::<Result<Vec<i16>, _>>()?,
+    )
+    .bind(
+        base_categories
+            .iter()
+            .map(|c| c.get_is_record())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        base_categories
+            .iter()
+            .map(|c| c.get_is_income())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        base_categories
+            .iter()
+            .map(|c| c.get_name())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        base_categories
+            .iter()
+            .map(|c| c.get_color())
+            .collect::<Vec<_>>(),
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreBaseCategory", e))?;
+
+    // 소분류 (가계부 대분류에만 추가 가능)
+    let sub_categories = archive.get_sub_categories();
+    let new_ids = allocate_ids(&mut tx, "tb_sub_category", sub_categories.len()).await?;
+    let sub_map = to_id_map(
+        sub_categories.iter().map(|c| c.get_id()),
+        new_ids.iter().map(|id| *id as i32),
+    )?;
+
+    sqlx::query(
+        "
+        INSERT INTO tb_sub_category (id, base_id, name)
+            SELECT * FROM UNNEST($1::int[], $2::smallint[], $3::varchar[])
+    ",
+    )
+    .bind(
+        sub_categories
+            .iter()
+            .map(|c| map_id(&sub_map, &c.get_id()))
+            .collect::<Result<Vec<i32>, _>>()?,
+    )
+    .bind(
+        sub_categories
+            .iter()
+            .map(|c| map_id(&base_map, &c.get_base_id()))
+            .collect::<Result<Vec<i16>, _>>()?,
+    )
+    .bind(
+        sub_categories
+            .iter()
+            .map(|c| c.get_name())
+            .collect::<Vec<_>>(),
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreSubCategory", e))?;
+
+    // 백업에 없는 소분류는 같은 유형의 공용 카테고리여야 함
+    let mut global_ids = HashSet::new();
+    let mut to_sub_id = |id: i32| -> i32 {
+        match sub_map.get(&id) {
+            Some(new_id) => *new_id,
+            None => {
+                global_ids.insert(id);
+                id
+            }
+        }
+    };
+
+    // 자산
+    let assets = archive.get_assets();
+    let new_ids = allocate_ids(&mut tx, "tb_asset", assets.len()).await?;
+    let asset_map = to_id_map(
+        assets.iter().map(|a| a.get_id()),
+        new_ids.iter().map(|id| *id as i32),
+    )?;
+    let asset_ids = assets
+        .iter()
+        .map(|a| map_id(&asset_map, &a.get_id()))
+        .collect::<Result<Vec<i32>, _>>()?;
+    let asset_sub_ids: Vec<i32> = assets
+        .iter()
+        .map(|a| to_sub_id(a.get_sub_category_id()))
+        .collect();
+
+    // 기록
+    let records = archive.get_records();
+    let new_ids = allocate_ids(&mut tx, "tb_record", records.len()).await?;
+    let record_map = to_id_map(
+        records.iter().map(|r| r.get_record().get_id()),
+        new_ids.into_iter(),
+    )?;
+
+    let mut record_ids = vec![];
+    let mut record_sub_ids = vec![];
+    let mut record_asset_ids = vec![];
+    let mut transfer_ids = vec![];
+    let mut line_record_ids = vec![];
+    let mut line_sub_ids = vec![];
+    let mut line_amounts = vec![];
+    for archive_record in records {
+        let record = archive_record.get_record();
+        let record_id = map_id(&record_map, &record.get_id())?;
+
+        record_ids.push(record_id);
+        record_sub_ids.push(to_sub_id(record.get_sub_category_id()));
+        record_asset_ids.push(match record.get_asset_id() {
+            Some(asset_id) => Some(map_id(&asset_map, asset_id)?),
+            None => None,
+        });
+        transfer_ids.push(match record.get_transfer_id() {
+            Some(transfer_id) => Some(map_id(&record_map, transfer_id)?),
+            None => None,
+        });
+
+        for line in archive_record.get_lines() {
+            line_record_ids.push(record_id);
+            line_sub_ids.push(to_sub_id(line.get_sub_category_id()));
+            line_amounts.push(line.get_amount());
+        }
+    }
+
+    let global_ids: Vec<i32> = global_ids.into_iter().collect();
+    let global_count: i64 = sqlx::query_scalar(
+        "
+        SELECT COUNT(*)
+        FROM tb_sub_category AS sc
+        JOIN tb_base_category AS bc ON bc.id = sc.base_id
+        WHERE sc.id = ANY($1) AND bc.book_id IS NULL AND bc.type_id = $2
+    ",
+    )
+    .bind(&global_ids)
+    .bind(book.get_type_id())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreCategoryCheck", e))?;
+
+    if global_count != global_ids.len() as i64 {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    }
+
+    sqlx::query(
+        "
+        INSERT INTO tb_asset (id, book_id, sub_category_id, code, memo, balance, created_at)
+            SELECT a.id, $1, a.sub_category_id, a.code, a.memo, a.balance, COALESCE(a.created_at, NOW())
+            FROM UNNEST($2::int[], $3::int[], $4::varchar[], $5::varchar[], $6::bigint[], $7::timestamp[])
+                AS a(id, sub_category_id, code, memo, balance, created_at)
+    ",
+    )
+    .bind(book_id)
+    .bind(asset_ids)
+    .bind(asset_sub_ids)
+    .bind(assets.iter().map(|a| a.get_code().clone()).collect::<Vec<_>>())
+    .bind(assets.iter().map(|a| a.get_memo().clone()).collect::<Vec<_>>())
+    .bind(assets.iter().map(|a| a.get_balance()).collect::<Vec<_>>())
+    .bind(assets.iter().map(|a| *a.get_created_at()).collect::<Vec<_>>())
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreAsset", e))?;
+
+    // 이체 기록은 서로를 가리키므로 한 문장으로 저장
+    sqlx::query(
+        "
+        INSERT INTO tb_record (
+            id, book_id, sub_category_id, amount, memo, asset_id,
+            transfer_id, is_transfer_in, target_dt, created_at
+        )
+            SELECT
+                r.id, $1, r.sub_category_id, r.amount, r.memo, r.asset_id,
+                r.transfer_id, r.is_transfer_in, r.target_dt, COALESCE(r.created_at, NOW())
+            FROM UNNEST(
+                $2::bigint[], $3::int[], $4::int[], $5::varchar[], $6::int[],
+                $7::bigint[], $8::bool[], $9::timestamp[], $10::timestamp[]
+            ) AS r(
+                id, sub_category_id, amount, memo, asset_id,
+                transfer_id, is_transfer_in, target_dt, created_at
+            )
+    ",
+    )
+    .bind(book_id)
+    .bind(record_ids)
+    .bind(record_sub_ids)
+    .bind(
+        records
+            .iter()
+            .map(|r| r.get_record().get_amount())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        records
+            .iter()
+            .map(|r| r.get_record().get_memo().clone())
+            .collect::<Vec<_>>(),
+    )
+    .bind(record_asset_ids)
+    .bind(transfer_ids)
+    .bind(
+        records
+            .iter()
+            .map(|r| r.get_is_transfer_in())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        records
+            .iter()
+            .map(|r| r.get_record().get_target_dt())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        records
+            .iter()
+            .map(|r| r.get_record().get_created_at())
+            .collect::<Vec<Option<NaiveDateTime>>>(),
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreRecord", e))?;
+
+    // 시작 잔액은 백업에 없으므로 잔액에서 기록 합계를 빼서 계산
+    sqlx::query(
+        "
+        UPDATE tb_asset AS a
+        SET opening_balance = a.balance - COALESCE((
+            SELECT SUM(CASE WHEN r.is_transfer_in OR (r.transfer_id IS NULL AND bc.is_income)
+                THEN r.amount ELSE -r.amount END)
+            FROM tb_record AS r
+            JOIN tb_sub_category AS sc ON sc.id = r.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            WHERE r.asset_id = a.id
+        ), 0)
+        WHERE a.book_id = $1
+    ",
+    )
+    .bind(book_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreAsset", e))?;
+
+    sqlx::query(
+        "
+        INSERT INTO tb_record_line (record_id, sub_category_id, amount)
+            SELECT * FROM UNNEST($1::bigint[], $2::int[], $3::int[])
+    ",
+    )
+    .bind(line_record_ids)
+    .bind(line_sub_ids)
+    .bind(line_amounts)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreRecordLine", e))?;
+
+    // 커넥트는 이름으로 찾고 없으면 추가
+    let connects = archive.get_connects();
+    let connect_names: Vec<&str> = connects.iter().map(|c| c.get_name()).collect();
+    sqlx::query(
+        "
+        INSERT INTO tb_connect (name)
+            SELECT UNNEST($1::varchar[])
+            ON CONFLICT (name) DO NOTHING
+    ",
+    )
+    .bind(&connect_names)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreConnect", e))?;
+
+    let name_map: HashMap<String, i32> =
+        sqlx::query_as::<_, (String, i32)>("SELECT name, id FROM tb_connect WHERE name = ANY($1)")
+            .bind(&connect_names)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| to_custom_error("RestoreConnect", e))?
+            .into_iter()
+            .collect();
+    let connect_map = to_id_map(
+        connects.iter().map(|c| c.get_id()),
+        connects.iter().map(|c| name_map.get(c.get_name()).copied()),
+    )?;
+
+    let mut connect_record_ids = vec![];
+    let mut connect_ids = vec![];
+    for archive_record in records {
+        let record_id = map_id(&record_map, &archive_record.get_record().get_id())?;
+        for connect_id in archive_record.get_connect_ids() {
+            connect_record_ids.push(record_id);
+            connect_ids.push(map_id(&connect_map, connect_id)?.ok_or_else(invalid_archive)?);
+        }
+    }
+
+    sqlx::query(
+        "
+        INSERT INTO tb_record_connect (record_id, connect_id)
+            SELECT * FROM UNNEST($1::bigint[], $2::int[])
+            ON CONFLICT DO NOTHING
+    ",
+    )
+    .bind(connect_record_ids)
+    .bind(connect_ids)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreRecordConnect", e))?;
+
+    // 일기
+    let diaries = archive.get_diaries();
+    sqlx::query(
+        "
+        INSERT INTO tb_diary (book_id, title, content, target_dt, created_at)
+            SELECT $1, d.title, d.content, d.target_dt, COALESCE(d.created_at, NOW())
+            FROM UNNEST($2::varchar[], $3::varchar[], $4::timestamp[], $5::timestamp[])
+                AS d(title, content, target_dt, created_at)
+    ",
+    )
+    .bind(book_id)
+    .bind(
+        diaries
+            .iter()
+            .map(|d| d.get_title().clone())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        diaries
+            .iter()
+            .map(|d| d.get_content().clone())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        diaries
+            .iter()
+            .map(|d| d.get_target_dt())
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        diaries
+            .iter()
+            .map(|d| *d.get_created_at())
+            .collect::<Vec<_>>(),
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RestoreDiary", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("RestoreBookArchive", e))?;
+
+    Ok(book_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::{
+            book::{
+                entity::{ArchiveRecord, Book, BookArchive},
+                repository::archive::{get_archive, restore_archive},
+            },
+            record::entity::Record,
+        },
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false);
+    }
+
+    #[tokio::test]
+    async fn check_get_archive_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = 1;
+        let book_id = 1;
+
+        // Act
+        let result = get_archive(&pool, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        // ref) init.sql, 테스트 카테고리(11), 월급통장(1), 첫 일기(1)
+        assert_eq!(result.get_book().get_name(), "테스트 가계부");
+        assert!(result
+            .get_base_categories()
+            .iter()
+            .any(|c| c.get_id() == 11));
+        assert!(result.get_assets().iter().any(|a| a.get_id() == 1));
+        assert!(result.get_diaries().iter().any(|d| d.get_id() == 1));
+        assert!(!result.get_records().is_empty());
+    }
+
+    #[tokio::test]
+    async fn check_get_archive_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let book_id = 1;
+
+        // Act
+        let result = get_archive(&pool, user_id, book_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_restore_archive_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let archive = get_archive(&pool, 1, 1).await.unwrap();
+        let record_count = archive.get_records().len();
+
+        // Act
+        // ref) init.sql, 3번 사용자는 같은 이름의 가계부가 없음
+        let result = restore_archive(&pool, 3, archive.clone()).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let book_id = result.unwrap();
+
+        // Assert
+        let restored = get_archive(&pool, 3, book_id).await.unwrap();
+        assert_eq!(
+            restored.get_book().get_name(),
+            archive.get_book().get_name()
+        );
+        assert_eq!(restored.get_records().len(), record_count);
+        assert_eq!(restored.get_assets().len(), archive.get_assets().len());
+        assert_eq!(restored.get_diaries().len(), archive.get_diaries().len());
+        assert_eq!(
+            restored.get_base_categories()[0].get_name(),
+            archive.get_base_categories()[0].get_name()
+        );
+        // 새 id 발급
+        assert!(restored.get_assets().iter().all(|a| archive
+            .get_assets()
+            .iter()
+            .all(|b| a.get_id() != b.get_id())));
+    }
+
+    #[tokio::test]
+    async fn check_restore_archive_rollback() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let target_dt =
+            NaiveDateTime::parse_from_str("2024-09-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let record = Record::new(1, -32, 1000, target_dt, None)
+            .id(Some(1))
+            .build();
+        let archive = BookArchive::new(Book::new("복원 실패".to_string(), 1), target_dt)
+            .records(vec![ArchiveRecord::new(record)]);
+
+        // Act
+        let result = restore_archive(&pool, 1, archive).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM tb_book WHERE name = '복원 실패'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn check_restore_archive_invalid_reference() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let target_dt =
+            NaiveDateTime::parse_from_str("2024-09-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        // 백업에 없는 자산 참조
+        let record = Record::new(1, 17, 1000, target_dt, Some(-32))
+            .id(Some(1))
+            .build();
+        let archive = BookArchive::new(Book::new("잘못된 백업".to_string(), 1), target_dt)
+            .records(vec![ArchiveRecord::new(record)]);
+
+        // Act
+        let result = restore_archive(&pool, 1, archive).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use axum::async_trait;
 use sqlx::PgPool;
 
-use crate::global::{constants::DeleteResult, errors::CustomError};
+use crate::global::errors::CustomError;
 
 pub struct DeleteBookRepoImpl {
     pool: Arc<PgPool>,
@@ -27,8 +27,16 @@ impl DeleteBookRepo for DeleteBookRepoImpl {
     }
 }
 
+#[derive(Debug, sqlx::FromRow)]
+struct DeleteBookResult {
+    is_exist: bool,
+    is_authorized: bool,
+    is_shared: bool,
+}
+
+/// 다른 구성원이 남아 있으면 삭제하지 않음
 async fn delete_book(pool: &PgPool, user_id: i32, book_id: i32) -> Result<(), Box<CustomError>> {
-    let result = sqlx::query_as::<_, DeleteResult>(
+    let result = sqlx::query_as::<_, DeleteBookResult>(
         "
         WITH BookExists AS (
             SELECT id
@@ -43,21 +51,30 @@ async fn delete_book(pool: &PgPool, user_id: i32, book_id: i32) -> Result<(), Bo
                 WHERE br.user_id = $1 AND role = 'owner'
             ) AS is_authorized
         ),
+        SharedCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM tb_user_book_role
+                WHERE book_id = $2 AND user_id != $1
+            ) AS is_shared
+        ),
         DeleteRole AS (
             DELETE FROM tb_user_book_role
             WHERE user_id = $1 AND book_id = $2
                 AND (SELECT is_authorized FROM AuthorityCheck) = true
+                AND (SELECT is_shared FROM SharedCheck) = false
         ),
         DeleteBook AS (
             DELETE FROM tb_book 
             WHERE id = $2
                 AND (SELECT is_authorized FROM AuthorityCheck) = true
+                AND (SELECT is_shared FROM SharedCheck) = false
             RETURNING id
         )
         SELECT 
             EXISTS (SELECT 1 FROM BookExists) AS is_exist,
             (SELECT is_authorized FROM AuthorityCheck) AS is_authorized,
-            (SELECT COUNT(*) FROM DeleteBook) AS delete_count;
+            (SELECT is_shared FROM SharedCheck) AS is_shared;
         ",
     )
     .bind(user_id)
@@ -77,10 +94,15 @@ async fn delete_book(pool: &PgPool, user_id: i32, book_id: i32) -> Result<(), Bo
         Box::new(err)
     })?;
 
-    if !result.get_exist() {
+    if !result.is_exist {
         return Err(Box::new(CustomError::NotFound("Book".to_string())));
-    } else if !result.get_authorized() {
+    } else if !result.is_authorized {
         return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())));
+    } else if result.is_shared {
+        // 구성원을 내보내거나 소유권을 넘긴 뒤 삭제
+        return Err(Box::new(CustomError::ValidationError(
+            "BookMember".to_string(),
+        )));
     }
 
     Ok(())
@@ -165,4 +187,25 @@ mod tests {
         };
         assert!(err_type)
     }
+
+    #[tokio::test]
+    async fn check_delete_book_shared() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        // ref) init.sql, 1번 가계부에는 viewer인 2번 사용자가 있음
+        let user_id = 1;
+        let book_id = 1;
+
+        // Act
+        let result = delete_book(&pool, user_id, book_id).await;
+
+        // Assert
+        assert!(result.as_ref().is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+        assert!(get_book(&pool, user_id, book_id).await.is_ok())
+    }
 }
@@ -0,0 +1,490 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::{
+    domain::book::entity::BookInvite,
+    global::{constants::UpdateResult, errors::CustomError},
+};
+
+pub struct BookInviteRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait BookInviteRepo: Send + Sync {
+    async fn save_invite(
+        &self,
+        user_id: i32,
+        invite: BookInvite,
+        expire_hours: i32,
+    ) -> Result<BookInvite, Box<CustomError>>;
+    async fn get_invites(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<BookInvite>, Box<CustomError>>;
+    async fn revoke_invite(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        invite_id: i32,
+    ) -> Result<(), Box<CustomError>>;
+    async fn redeem_invite(&self, user_id: i32, code: String) -> Result<i32, Box<CustomError>>;
+}
+
+impl BookInviteRepoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BookInviteRepo for BookInviteRepoImpl {
+    async fn save_invite(
+        &self,
+        user_id: i32,
+        invite: BookInvite,
+        expire_hours: i32,
+    ) -> Result<BookInvite, Box<CustomError>> {
+        save_invite(&self.pool, user_id, invite, expire_hours).await
+    }
+    async fn get_invites(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<BookInvite>, Box<CustomError>> {
+        get_invites(&self.pool, user_id, book_id).await
+    }
+    async fn revoke_invite(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        invite_id: i32,
+    ) -> Result<(), Box<CustomError>> {
+        revoke_invite(&self.pool, user_id, book_id, invite_id).await
+    }
+    async fn redeem_invite(&self, user_id: i32, code: String) -> Result<i32, Box<CustomError>> {
+        redeem_invite(&self.pool, user_id, &code).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct SaveInviteResult {
+    is_exist: bool,
+    is_authorized: bool,
+    id: Option<i32>,
+    expired_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct RedeemTarget {
+    book_id: i32,
+    role: String,
+    max_uses: i32,
+    use_count: i32,
+    is_expired: bool,
+}
+
+/// 소유자만 생성 가능, 만료 시각은 DB 시간 기준
+pub async fn save_invite(
+    pool: &PgPool,
+    user_id: i32,
+    invite: BookInvite,
+    expire_hours: i32,
+) -> Result<BookInvite, Box<CustomError>> {
+    let result = sqlx::query_as::<_, SaveInviteResult>(
+        "
+        WITH BookExists AS (
+            SELECT id
+            FROM tb_book
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM BookExists AS be
+                JOIN tb_user_book_role AS br ON br.book_id = be.id
+                WHERE br.user_id = $1 AND br.role = 'owner'
+            ) AS is_authorized
+        ),
+        InsertInvite AS (
+            INSERT INTO tb_book_invite (book_id, code, role, max_uses, expired_at, created_by)
+                SELECT $2, $3, $4, $5, NOW() + make_interval(hours => $6), $1
+                WHERE (SELECT is_authorized FROM AuthorityCheck) = true
+            RETURNING id, expired_at
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM BookExists) AS is_exist,
+            (SELECT is_authorized FROM AuthorityCheck) AS is_authorized,
+            (SELECT id FROM InsertInvite) AS id,
+            (SELECT expired_at FROM InsertInvite) AS expired_at;
+    ",
+    )
+    .bind(user_id)
+    .bind(invite.get_book_id())
+    .bind(invite.get_code())
+    .bind(invite.get_role())
+    .bind(invite.get_max_uses())
+    .bind(expire_hours)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| to_custom_error("SaveBookInvite", e))?;
+
+    if !result.is_exist {
+        return Err(Box::new(CustomError::NotFound("Book".to_string())));
+    } else if !result.is_authorized {
+        return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())));
+    }
+
+    match (result.id, result.expired_at) {
+        (Some(id), Some(expired_at)) => Ok(invite.id(id).expired_at(expired_at)),
+        _ => Err(Box::new(CustomError::NotFound("BookInvite".to_string()))),
+    }
+}
+
+/// 소유자가 아니면 빈 목록
+pub async fn get_invites(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<BookInvite>, Box<CustomError>> {
+    let rows = sqlx::query_as::<_, BookInvite>(
+        "
+        SELECT
+            bi.id, bi.book_id, bi.code, bi.role, bi.max_uses, bi.use_count,
+            bi.is_revoked, bi.expired_at, bi.created_at
+        FROM tb_book_invite AS bi
+        JOIN tb_user_book_role AS br ON br.book_id = bi.book_id
+        WHERE br.user_id = $1 AND br.role = 'owner' AND bi.book_id = $2
+        ORDER BY bi.id DESC
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| to_custom_error("GetBookInvites", e))?;
+
+    Ok(rows)
+}
+
+pub async fn revoke_invite(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    invite_id: i32,
+) -> Result<(), Box<CustomError>> {
+    let result = sqlx::query_as::<_, UpdateResult>(
+        "
+        WITH BookExists AS (
+            SELECT id
+            FROM tb_book
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM BookExists AS be
+                JOIN tb_user_book_role AS br ON br.book_id = be.id
+                WHERE br.user_id = $1 AND br.role = 'owner'
+            ) AS is_authorized
+        ),
+        RevokeInvite AS (
+            UPDATE tb_book_invite
+            SET is_revoked = true
+            WHERE id = $3 AND book_id = $2
+                AND (SELECT is_authorized FROM AuthorityCheck) = true
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM BookExists) AS is_exist,
+            (SELECT is_authorized FROM AuthorityCheck) AS is_authorized,
+            false AS is_duplicated,
+            (SELECT COUNT(*) FROM RevokeInvite) AS update_count;
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .bind(invite_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| to_custom_error("RevokeBookInvite", e))?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Book".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())));
+    } else if result.get_count() == 0 {
+        return Err(Box::new(CustomError::NotFound("BookInvite".to_string())));
+    }
+
+    Ok(())
+}
+
+/// 초대 코드로 가계부 참여, 참여한 가계부 id 반환
+pub async fn redeem_invite(
+    pool: &PgPool,
+    user_id: i32,
+    code: &str,
+) -> Result<i32, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("RedeemBookInvite", e))?;
+
+    // 동시에 같은 코드를 사용하면 행 잠금으로 순서대로 처리
+    let target = sqlx::query_as::<_, RedeemTarget>(
+        "
+        SELECT book_id, role, max_uses, use_count, expired_at <= NOW() AS is_expired
+        FROM tb_book_invite
+        WHERE code = $1 AND is_revoked = false
+        FOR UPDATE
+    ",
+    )
+    .bind(code)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RedeemBookInvite", e))?;
+
+    let target = match target {
+        Some(target) => target,
+        None => return Err(Box::new(CustomError::NotFound("BookInvite".to_string()))),
+    };
+
+    if target.is_expired {
+        return Err(Box::new(CustomError::ValidationError(
+            "InviteExpired".to_string(),
+        )));
+    } else if target.use_count >= target.max_uses {
+        return Err(Box::new(CustomError::ValidationError(
+            "InviteLimit".to_string(),
+        )));
+    }
+
+    // 이미 구성원이면 사용 횟수를 올리지 않음
+    let inserted = sqlx::query(
+        "
+        INSERT INTO tb_user_book_role (user_id, book_id, role)
+        VALUES ($1, $2, $3)
+        ON CONFLICT DO NOTHING
+    ",
+    )
+    .bind(user_id)
+    .bind(target.book_id)
+    .bind(&target.role)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RedeemBookInvite", e))?;
+
+    if inserted.rows_affected() == 0 {
+        return Err(Box::new(CustomError::Duplicated("BookMember".to_string())));
+    }
+
+    sqlx::query(
+        "
+        UPDATE tb_book_invite
+        SET use_count = use_count + 1
+        WHERE code = $1
+    ",
+    )
+    .bind(code)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("RedeemBookInvite", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("RedeemBookInvite", e))?;
+
+    Ok(target.book_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::book::{
+            entity::{Book, BookInvite, BookRoleType},
+            repository::{
+                invite::{get_invites, redeem_invite, revoke_invite, save_invite},
+                member::get_members,
+                save::save_book,
+            },
+        },
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    // 다른 테스트의 권한에 영향을 주지 않도록 새 가계부 사용
+    async fn _new_invite(pool: &PgPool, name: &str, code: &str, max_uses: i32) -> BookInvite {
+        let book_id = save_book(pool, Book::new(name.to_string(), 2), 1)
+            .await
+            .unwrap();
+        let invite = BookInvite::new(book_id, code.to_string(), BookRoleType::Editor, max_uses);
+        save_invite(pool, 1, invite, 24).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_save_invite_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // Act
+        let invite = _new_invite(&pool, "초대 코드 가계부", "savecode0001", 3).await;
+
+        // Assert
+        assert!(invite.get_id().is_some());
+        let invites = get_invites(&pool, 1, invite.get_book_id()).await.unwrap();
+        assert_eq!(invites.len(), 1);
+        assert_eq!(invites[0].get_code(), "savecode0001");
+        assert_eq!(invites[0].get_role(), "editor");
+    }
+
+    #[tokio::test]
+    async fn check_save_invite_not_owner() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        // ref) init.sql, 2번 사용자는 1번 가계부의 viewer
+        let invite = BookInvite::new(1, "viewercode01".to_string(), BookRoleType::Viewer, 1);
+
+        // Act
+        let result = save_invite(&pool, 2, invite, 24).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+        assert!(get_invites(&pool, 2, 1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn check_redeem_invite_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let invite = _new_invite(&pool, "코드 참여 가계부", "redeemcode01", 1).await;
+
+        // Act
+        let result = redeem_invite(&pool, 3, "redeemcode01").await;
+
+        // Assert
+        assert_eq!(
+            result.map_err(|e| println!("{:?}", e)).unwrap(),
+            invite.get_book_id()
+        );
+        let members = get_members(&pool, 3, invite.get_book_id()).await.unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[1].get_role(), "editor");
+
+        // 사용 횟수 초과
+        let result = redeem_invite(&pool, 2, "redeemcode01").await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_redeem_invite_duplicated() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let invite = _new_invite(&pool, "중복 참여 가계부", "dupcode00001", 5).await;
+
+        // Act
+        let result = redeem_invite(&pool, 1, "dupcode00001").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Duplicated(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+        let invites = get_invites(&pool, 1, invite.get_book_id()).await.unwrap();
+        assert_eq!(invites[0].get_use_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn check_redeem_invite_concurrent() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let invite = _new_invite(&pool, "동시 참여 가계부", "racecode0001", 1).await;
+
+        // Act
+        let (first, second) = tokio::join!(
+            redeem_invite(&pool, 2, "racecode0001"),
+            redeem_invite(&pool, 3, "racecode0001")
+        );
+
+        // Assert
+        assert!(first.is_ok() != second.is_ok());
+        let invites = get_invites(&pool, 1, invite.get_book_id()).await.unwrap();
+        assert_eq!(invites[0].get_use_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_redeem_invite_expired() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = save_book(&pool, Book::new("만료 코드 가계부".to_string(), 2), 1)
+            .await
+            .unwrap();
+        let invite = BookInvite::new(book_id, "expiredcode1".to_string(), BookRoleType::Viewer, 1);
+        save_invite(&pool, 1, invite, 0).await.unwrap();
+
+        // Act
+        let result = redeem_invite(&pool, 3, "expiredcode1").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_revoke_invite() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let invite = _new_invite(&pool, "코드 취소 가계부", "revokecode01", 1).await;
+
+        // Act
+        let result = revoke_invite(&pool, 1, invite.get_book_id(), invite.get_id().unwrap()).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let result = redeem_invite(&pool, 3, "revokecode01").await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,768 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::{
+    domain::book::entity::{BookMember, BookRoleType, Invitee},
+    global::errors::CustomError,
+};
+
+pub struct BookMemberRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait BookMemberRepo: Send + Sync {
+    async fn get_members(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<BookMember>, Box<CustomError>>;
+    async fn invite_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        invitee: Invitee,
+        role: BookRoleType,
+    ) -> Result<i32, Box<CustomError>>;
+    async fn update_member_role(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+        role: BookRoleType,
+    ) -> Result<(), Box<CustomError>>;
+    async fn remove_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+    ) -> Result<(), Box<CustomError>>;
+    async fn leave_book(&self, user_id: i32, book_id: i32) -> Result<(), Box<CustomError>>;
+    async fn transfer_owner(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl BookMemberRepoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BookMemberRepo for BookMemberRepoImpl {
+    async fn get_members(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<BookMember>, Box<CustomError>> {
+        get_members(&self.pool, user_id, book_id).await
+    }
+    async fn invite_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        invitee: Invitee,
+        role: BookRoleType,
+    ) -> Result<i32, Box<CustomError>> {
+        invite_member(&self.pool, user_id, book_id, &invitee, role).await
+    }
+    async fn update_member_role(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+        role: BookRoleType,
+    ) -> Result<(), Box<CustomError>> {
+        update_member_role(&self.pool, user_id, book_id, member_id, role).await
+    }
+    async fn remove_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+    ) -> Result<(), Box<CustomError>> {
+        remove_member(&self.pool, user_id, book_id, member_id).await
+    }
+    async fn leave_book(&self, user_id: i32, book_id: i32) -> Result<(), Box<CustomError>> {
+        leave_book(&self.pool, user_id, book_id).await
+    }
+    async fn transfer_owner(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+    ) -> Result<(), Box<CustomError>> {
+        transfer_owner(&self.pool, user_id, book_id, member_id).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct MemberState {
+    user_role: Option<String>,
+    member_role: Option<String>,
+    owner_count: i64,
+}
+
+impl MemberState {
+    fn is_owner(&self) -> bool {
+        self.user_role.as_deref() == Some("owner")
+    }
+
+    // 대상 구성원이 남은 유일한 소유자인지
+    fn is_last_owner(&self) -> bool {
+        self.member_role.as_deref() == Some("owner") && self.owner_count == 1
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct InviteResult {
+    is_exist: bool,
+    is_authorized: bool,
+    invitee_count: i64,
+    is_member: bool,
+    user_id: Option<i32>,
+}
+
+/// 구성원이면 누구나 조회 가능
+pub async fn get_members(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<BookMember>, Box<CustomError>> {
+    let members = sqlx::query_as::<_, BookMember>(
+        "
+        SELECT u.id AS user_id, u.username, u.nickname, br.role
+        FROM tb_user_book_role AS br
+        JOIN tb_user AS u ON u.id = br.user_id
+        WHERE br.book_id = $2
+            AND EXISTS (
+                SELECT 1
+                FROM tb_user_book_role
+                WHERE user_id = $1 AND book_id = $2
+            )
+        ORDER BY br.role = 'owner' DESC, u.id
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| to_custom_error("GetBookMember", e))?;
+
+    if members.is_empty() {
+        return Err(Box::new(CustomError::NotFound("Book".to_string())));
+    }
+
+    Ok(members)
+}
+
+/// 소유자만 초대 가능, 이미 구성원이면 Duplicated
+pub async fn invite_member(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    invitee: &Invitee,
+    role: BookRoleType,
+) -> Result<i32, Box<CustomError>> {
+    let result = sqlx::query_as::<_, InviteResult>(
+        "
+        WITH BookExists AS (
+            SELECT id
+            FROM tb_book
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM BookExists AS be
+                JOIN tb_user_book_role AS br ON br.book_id = be.id
+                WHERE br.user_id = $1 AND br.role = 'owner'
+            ) AS is_authorized
+        ),
+        Invitee AS (
+            SELECT id
+            FROM tb_user
+            WHERE (username = $3 OR (email = $4 AND email_verified_at IS NOT NULL))
+                AND is_active = true
+        ),
+        MemberCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM Invitee AS i
+                JOIN tb_user_book_role AS br ON br.user_id = i.id
+                WHERE br.book_id = $2
+            ) AS is_member
+        ),
+        InsertRole AS (
+            INSERT INTO tb_user_book_role (user_id, book_id, role)
+                SELECT id, $2, $5
+                FROM Invitee
+                WHERE (SELECT is_authorized FROM AuthorityCheck) = true
+                    AND (SELECT is_member FROM MemberCheck) = false
+                    AND (SELECT COUNT(*) FROM Invitee) = 1
+            ON CONFLICT DO NOTHING
+            RETURNING user_id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM BookExists) AS is_exist,
+            (SELECT is_authorized FROM AuthorityCheck) AS is_authorized,
+            (SELECT COUNT(*) FROM Invitee) AS invitee_count,
+            (SELECT is_member FROM MemberCheck) AS is_member,
+            (SELECT user_id FROM InsertRole) AS user_id;
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .bind(invitee.get_username())
+    .bind(invitee.get_email())
+    .bind(role.to_string())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| to_custom_error("InviteBookMember", e))?;
+
+    if !result.is_exist {
+        return Err(Box::new(CustomError::NotFound("Book".to_string())));
+    } else if !result.is_authorized {
+        return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())));
+    } else if result.invitee_count == 0 {
+        return Err(Box::new(CustomError::NotFound("User".to_string())));
+    } else if result.invitee_count > 1 {
+        // 같은 이메일을 쓰는 계정이 여럿이면 아이디로 초대해야 함
+        return Err(Box::new(CustomError::ValidationError(
+            "Invitee".to_string(),
+        )));
+    }
+
+    match result.user_id {
+        Some(id) if !result.is_member => Ok(id),
+        // 동시에 초대된 경우 ON CONFLICT로 건너뜀
+        _ => Err(Box::new(CustomError::Duplicated("BookMember".to_string()))),
+    }
+}
+
+/// 가계부 행을 잠가 동시에 들어온 구성원 변경을 순서대로 처리
+async fn lock_member_state(
+    tx: &mut Transaction<'_, Postgres>,
+    user_id: i32,
+    book_id: i32,
+    member_id: i32,
+) -> Result<MemberState, Box<CustomError>> {
+    let book = sqlx::query(
+        "
+        SELECT id
+        FROM tb_book
+        WHERE id = $1
+        FOR UPDATE
+    ",
+    )
+    .bind(book_id)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| to_custom_error("LockBook", e))?;
+
+    if book.is_none() {
+        return Err(Box::new(CustomError::NotFound("Book".to_string())));
+    }
+
+    sqlx::query_as::<_, MemberState>(
+        "
+        SELECT
+            (SELECT role FROM tb_user_book_role WHERE book_id = $1 AND user_id = $2) AS user_role,
+            (SELECT role FROM tb_user_book_role WHERE book_id = $1 AND user_id = $3) AS member_role,
+            (SELECT COUNT(*) FROM tb_user_book_role WHERE book_id = $1 AND role = 'owner') AS owner_count
+    ",
+    )
+    .bind(book_id)
+    .bind(user_id)
+    .bind(member_id)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| to_custom_error("GetMemberState", e))
+}
+
+/// 마지막 소유자는 다른 권한으로 바꿀 수 없음
+pub async fn update_member_role(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    member_id: i32,
+    role: BookRoleType,
+) -> Result<(), Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("UpdateBookMember", e))?;
+
+    let state = lock_member_state(&mut tx, user_id, book_id, member_id).await?;
+
+    if !state.is_owner() {
+        return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())));
+    } else if state.member_role.is_none() {
+        return Err(Box::new(CustomError::NotFound("BookMember".to_string())));
+    } else if state.is_last_owner() && role != BookRoleType::Owner {
+        return Err(Box::new(CustomError::ValidationError(
+            "LastOwner".to_string(),
+        )));
+    }
+
+    sqlx::query(
+        "
+        UPDATE tb_user_book_role
+        SET role = $3
+        WHERE book_id = $1 AND user_id = $2
+    ",
+    )
+    .bind(book_id)
+    .bind(member_id)
+    .bind(role.to_string())
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("UpdateBookMember", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("UpdateBookMember", e))?;
+
+    Ok(())
+}
+
+pub async fn remove_member(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    member_id: i32,
+) -> Result<(), Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("RemoveBookMember", e))?;
+
+    let state = lock_member_state(&mut tx, user_id, book_id, member_id).await?;
+
+    if !state.is_owner() {
+        return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())));
+    } else if state.member_role.is_none() {
+        return Err(Box::new(CustomError::NotFound("BookMember".to_string())));
+    } else if state.is_last_owner() {
+        return Err(Box::new(CustomError::ValidationError(
+            "LastOwner".to_string(),
+        )));
+    }
+
+    delete_role(&mut tx, book_id, member_id).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("RemoveBookMember", e))?;
+
+    Ok(())
+}
+
+/// 구성원 본인이 가계부에서 나감, 마지막 소유자는 소유권을 넘기거나 가계부를 삭제해야 함
+pub async fn leave_book(pool: &PgPool, user_id: i32, book_id: i32) -> Result<(), Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("LeaveBook", e))?;
+
+    let state = lock_member_state(&mut tx, user_id, book_id, user_id).await?;
+
+    if state.member_role.is_none() {
+        return Err(Box::new(CustomError::NotFound("BookMember".to_string())));
+    } else if state.is_last_owner() {
+        return Err(Box::new(CustomError::ValidationError(
+            "LastOwner".to_string(),
+        )));
+    }
+
+    delete_role(&mut tx, book_id, user_id).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("LeaveBook", e))?;
+
+    Ok(())
+}
+
+/// 대상 구성원을 소유자로, 요청한 소유자는 편집자로 변경
+pub async fn transfer_owner(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    member_id: i32,
+) -> Result<(), Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("TransferBookOwner", e))?;
+
+    let state = lock_member_state(&mut tx, user_id, book_id, member_id).await?;
+
+    if !state.is_owner() {
+        return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())));
+    } else if state.member_role.is_none() {
+        return Err(Box::new(CustomError::NotFound("BookMember".to_string())));
+    } else if user_id == member_id {
+        return Err(Box::new(CustomError::ValidationError(
+            "BookMember".to_string(),
+        )));
+    }
+
+    sqlx::query(
+        "
+        UPDATE tb_user_book_role
+        SET role = CASE WHEN user_id = $3 THEN 'owner' ELSE 'editor' END
+        WHERE book_id = $1 AND user_id IN ($2, $3)
+    ",
+    )
+    .bind(book_id)
+    .bind(user_id)
+    .bind(member_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("TransferBookOwner", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("TransferBookOwner", e))?;
+
+    Ok(())
+}
+
+async fn delete_role(
+    tx: &mut Transaction<'_, Postgres>,
+    book_id: i32,
+    member_id: i32,
+) -> Result<(), Box<CustomError>> {
+    sqlx::query(
+        "
+        DELETE FROM tb_user_book_role
+        WHERE book_id = $1 AND user_id = $2
+    ",
+    )
+    .bind(book_id)
+    .bind(member_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| to_custom_error("DeleteBookRole", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::book::{
+            entity::{Book, BookRoleType, Invitee},
+            repository::{
+                member::{
+                    get_members, invite_member, leave_book, remove_member, transfer_owner,
+                    update_member_role,
+                },
+                save::save_book,
+            },
+        },
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    // 다른 테스트의 권한에 영향을 주지 않도록 새 가계부 사용
+    async fn _new_book(pool: &PgPool, name: &str) -> i32 {
+        save_book(pool, Book::new(name.to_string(), 2), 1)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _new_book(&pool, "초대 가계부").await;
+        // ref) init.sql
+        let invitee = Invitee::Username("no_role_user".to_string());
+
+        // Act
+        let result = invite_member(&pool, 1, book_id, &invitee, BookRoleType::Editor).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap(), 3);
+        let members = get_members(&pool, 3, book_id).await.unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].get_role(), "owner");
+        assert_eq!(members[1].get_user_id(), 3);
+        assert_eq!(members[1].get_role(), "editor");
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_by_email() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _new_book(&pool, "이메일 초대 가계부").await;
+        // ref) init.sql
+        let invitee = Invitee::Email("test22@test.test".to_string());
+
+        // Act
+        let result = invite_member(&pool, 1, book_id, &invitee, BookRoleType::Viewer).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_unverified_email() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _new_book(&pool, "미인증 초대 가계부").await;
+        sqlx::query(
+            "INSERT INTO tb_user (login_type, username, password, email)
+            VALUES ('email', 'unverified_user', 'test_password', 'unverified@test.test')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let invitee = Invitee::Email("unverified@test.test".to_string());
+
+        // Act
+        let result = invite_member(&pool, 1, book_id, &invitee, BookRoleType::Viewer).await;
+
+        // Assert
+        // 인증되지 않은 이메일로는 초대할 수 없음
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_not_owner() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        // ref) init.sql, 2번 사용자는 1번 가계부의 viewer
+        let invitee = Invitee::Username("no_role_user".to_string());
+
+        // Act
+        let result = invite_member(&pool, 2, 1, &invitee, BookRoleType::Viewer).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_duplicated() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        // ref) init.sql
+        let invitee = Invitee::Username("viewer_user".to_string());
+
+        // Act
+        let result = invite_member(&pool, 1, 1, &invitee, BookRoleType::Editor).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Duplicated(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let invitee = Invitee::Username("unknown_user".to_string());
+
+        // Act
+        let result = invite_member(&pool, 1, 1, &invitee, BookRoleType::Editor).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_update_member_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _new_book(&pool, "권한 변경 가계부").await;
+        let invitee = Invitee::Username("no_role_user".to_string());
+        invite_member(&pool, 1, book_id, &invitee, BookRoleType::Viewer)
+            .await
+            .unwrap();
+
+        // Act
+        let result = update_member_role(&pool, 1, book_id, 3, BookRoleType::Editor).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let members = get_members(&pool, 1, book_id).await.unwrap();
+        assert_eq!(members[1].get_role(), "editor");
+
+        // 구성원이 아닌 사용자
+        let result = update_member_role(&pool, 1, book_id, 2, BookRoleType::Editor).await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_remove_member() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _new_book(&pool, "내보내기 가계부").await;
+        let invitee = Invitee::Username("no_role_user".to_string());
+        invite_member(&pool, 1, book_id, &invitee, BookRoleType::Editor)
+            .await
+            .unwrap();
+
+        // 소유자가 아니면 내보낼 수 없음
+        let result = remove_member(&pool, 3, book_id, 1).await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+
+        // Act
+        let result = remove_member(&pool, 1, book_id, 3).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let result = get_members(&pool, 3, book_id).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn check_leave_book() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _new_book(&pool, "나가기 가계부").await;
+        let invitee = Invitee::Username("no_role_user".to_string());
+        invite_member(&pool, 1, book_id, &invitee, BookRoleType::Viewer)
+            .await
+            .unwrap();
+
+        // Act
+        let result = leave_book(&pool, 3, book_id).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let members = get_members(&pool, 1, book_id).await.unwrap();
+        assert_eq!(members.len(), 1);
+
+        let result = leave_book(&pool, 3, book_id).await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    fn _is_last_owner(err: Box<CustomError>) -> bool {
+        match *err {
+            CustomError::ValidationError(target) => target == "LastOwner",
+            _ => false,
+        }
+    }
+
+    #[tokio::test]
+    async fn check_last_owner_protected() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _new_book(&pool, "소유자 보호 가계부").await;
+        let invitee = Invitee::Username("no_role_user".to_string());
+        invite_member(&pool, 1, book_id, &invitee, BookRoleType::Editor)
+            .await
+            .unwrap();
+
+        // Act, Assert
+        let result = update_member_role(&pool, 1, book_id, 1, BookRoleType::Editor).await;
+        assert!(_is_last_owner(result.err().unwrap()));
+
+        let result = remove_member(&pool, 1, book_id, 1).await;
+        assert!(_is_last_owner(result.err().unwrap()));
+
+        let result = leave_book(&pool, 1, book_id).await;
+        assert!(_is_last_owner(result.err().unwrap()));
+
+        // 다른 소유자가 있으면 나갈 수 있음
+        update_member_role(&pool, 1, book_id, 3, BookRoleType::Owner)
+            .await
+            .unwrap();
+        let result = leave_book(&pool, 1, book_id).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_transfer_owner() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _new_book(&pool, "소유권 이전 가계부").await;
+        let invitee = Invitee::Username("no_role_user".to_string());
+        invite_member(&pool, 1, book_id, &invitee, BookRoleType::Viewer)
+            .await
+            .unwrap();
+
+        // Act
+        let result = transfer_owner(&pool, 1, book_id, 3).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let members = get_members(&pool, 1, book_id).await.unwrap();
+        assert_eq!(members[0].get_user_id(), 3);
+        assert_eq!(members[0].get_role(), "owner");
+        assert_eq!(members[1].get_user_id(), 1);
+        assert_eq!(members[1].get_role(), "editor");
+
+        // 이전 소유자는 더 이상 권한이 없음
+        let result = transfer_owner(&pool, 1, book_id, 3).await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+        assert!(leave_book(&pool, 1, book_id).await.is_ok());
+    }
+}
@@ -1,4 +1,7 @@
+pub(super) mod archive;
 pub(super) mod delete;
+pub(super) mod invite;
+pub(super) mod member;
 pub(super) mod get_book;
 pub(super) mod get_book_type;
 pub(super) mod save;
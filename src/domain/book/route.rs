@@ -4,13 +4,19 @@ use axum::Router;
 
 use sqlx::PgPool;
 
-use super::handler::{create_router, delete_router, read_router, read_type_router, update_router};
+use super::handler::{
+    archive_router, create_router, delete_router, invite_router, member_router, read_router,
+    read_type_router, update_router,
+};
 
 pub fn get_router(pool: &Arc<PgPool>) -> Router {
     Router::new()
         .merge(create_router(&pool))
+        .merge(archive_router(&pool))
         .merge(read_router(&pool))
         .nest("/type", read_type_router(&pool))
         .merge(update_router(&pool))
         .merge(delete_router(&pool))
+        .merge(member_router(&pool))
+        .merge(invite_router(&pool))
 }
@@ -0,0 +1,146 @@
+use axum::async_trait;
+
+use crate::{
+    domain::book::{
+        entity::{BookArchive, ARCHIVE_VERSION},
+        repository::archive::BookArchiveRepo,
+    },
+    global::errors::CustomError,
+};
+
+pub struct BookArchiveUsecaseImpl<T>
+where
+    T: BookArchiveRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait BookArchiveUsecase: Send + Sync {
+    async fn export_book(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<BookArchive, Box<CustomError>>;
+    async fn import_book(
+        &self,
+        user_id: i32,
+        archive: BookArchive,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl<T> BookArchiveUsecaseImpl<T>
+where
+    T: BookArchiveRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> BookArchiveUsecase for BookArchiveUsecaseImpl<T>
+where
+    T: BookArchiveRepo,
+{
+    async fn export_book(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<BookArchive, Box<CustomError>> {
+        self.repository.get_archive(user_id, book_id).await
+    }
+    async fn import_book(
+        &self,
+        user_id: i32,
+        archive: BookArchive,
+    ) -> Result<i32, Box<CustomError>> {
+        import_book(&self.repository, user_id, archive).await
+    }
+}
+
+async fn import_book<T>(
+    repository: &T,
+    user_id: i32,
+    archive: BookArchive,
+) -> Result<i32, Box<CustomError>>
+where
+    T: BookArchiveRepo,
+{
+    // 다른 버전의 백업은 구조가 다를 수 있음
+    if archive.get_version() != ARCHIVE_VERSION {
+        return Err(Box::new(CustomError::ValidationError(
+            "ArchiveVersion".to_string(),
+        )));
+    }
+
+    repository.restore_archive(user_id, archive).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::NaiveDateTime;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::book::{
+            entity::{Book, BookArchive},
+            repository::archive::BookArchiveRepo,
+            usecase::archive::import_book,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        BookArchiveRepoImpl {}
+
+        #[async_trait]
+        impl BookArchiveRepo for BookArchiveRepoImpl {
+            async fn get_archive(&self, user_id: i32, book_id: i32) -> Result<BookArchive, Box<CustomError>>;
+            async fn restore_archive(&self, user_id: i32, archive: BookArchive) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _archive() -> BookArchive {
+        let exported_at =
+            NaiveDateTime::parse_from_str("2024-09-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        BookArchive::new(Book::new("백업 가계부".to_string(), 1), exported_at)
+    }
+
+    #[tokio::test]
+    async fn check_import_book_success() {
+        // Arrange
+        let archive = _archive();
+        let mut mock_repo = MockBookArchiveRepoImpl::new();
+        mock_repo
+            .expect_restore_archive()
+            .with(predicate::eq(1), predicate::eq(archive.clone()))
+            .returning(|_, _| Ok(10));
+
+        // Act
+        let result = import_book(&mock_repo, 1, archive).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 10);
+    }
+
+    #[tokio::test]
+    async fn check_import_book_version() {
+        // Arrange
+        let archive = _archive().version(0);
+        let mut mock_repo = MockBookArchiveRepoImpl::new();
+        mock_repo.expect_restore_archive().never();
+
+        // Act
+        let result = import_book(&mock_repo, 1, archive).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,185 @@
+use axum::async_trait;
+
+use crate::{
+    domain::book::{
+        dto::request::NewBookInvite,
+        entity::{BookInvite, BookRoleType},
+        repository::invite::BookInviteRepo,
+    },
+    global::errors::CustomError,
+};
+
+pub struct BookInviteUsecaseImpl<T>
+where
+    T: BookInviteRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait BookInviteUsecase: Send + Sync {
+    async fn create_invite(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        new_invite: NewBookInvite,
+    ) -> Result<BookInvite, Box<CustomError>>;
+    async fn read_invites(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<BookInvite>, Box<CustomError>>;
+    async fn revoke_invite(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        invite_id: i32,
+    ) -> Result<(), Box<CustomError>>;
+    async fn redeem_invite(&self, user_id: i32, code: String) -> Result<i32, Box<CustomError>>;
+}
+
+impl<T> BookInviteUsecaseImpl<T>
+where
+    T: BookInviteRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> BookInviteUsecase for BookInviteUsecaseImpl<T>
+where
+    T: BookInviteRepo,
+{
+    async fn create_invite(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        new_invite: NewBookInvite,
+    ) -> Result<BookInvite, Box<CustomError>> {
+        create_invite(&self.repository, user_id, book_id, new_invite).await
+    }
+    async fn read_invites(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<BookInvite>, Box<CustomError>> {
+        self.repository.get_invites(user_id, book_id).await
+    }
+    async fn revoke_invite(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        invite_id: i32,
+    ) -> Result<(), Box<CustomError>> {
+        self.repository
+            .revoke_invite(user_id, book_id, invite_id)
+            .await
+    }
+    async fn redeem_invite(&self, user_id: i32, code: String) -> Result<i32, Box<CustomError>> {
+        self.repository.redeem_invite(user_id, code).await
+    }
+}
+
+async fn create_invite<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    new_invite: NewBookInvite,
+) -> Result<BookInvite, Box<CustomError>>
+where
+    T: BookInviteRepo,
+{
+    // 공유되는 코드라 소유자 권한은 부여하지 않음
+    let role = new_invite.to_role()?;
+    if role == BookRoleType::Owner {
+        return Err(Box::new(CustomError::ValidationError(
+            "BookRole".to_string(),
+        )));
+    }
+
+    let code = uuid::Uuid::new_v4().simple().to_string();
+    let invite = BookInvite::new(book_id, code, role, new_invite.get_max_uses());
+
+    repository
+        .save_invite(user_id, invite, new_invite.get_expire_hours())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::NaiveDateTime;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::book::{
+            dto::request::NewBookInvite, entity::BookInvite, repository::invite::BookInviteRepo,
+            usecase::invite::create_invite,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        BookInviteRepoImpl {}
+
+        #[async_trait]
+        impl BookInviteRepo for BookInviteRepoImpl {
+            async fn save_invite(&self, user_id: i32, invite: BookInvite, expire_hours: i32) -> Result<BookInvite, Box<CustomError>>;
+            async fn get_invites(&self, user_id: i32, book_id: i32) -> Result<Vec<BookInvite>, Box<CustomError>>;
+            async fn revoke_invite(&self, user_id: i32, book_id: i32, invite_id: i32) -> Result<(), Box<CustomError>>;
+            async fn redeem_invite(&self, user_id: i32, code: String) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_create_invite_success() {
+        // Arrange
+        let new_invite = NewBookInvite::new("viewer".to_string(), Some(5), None);
+        let mut mock_repo = MockBookInviteRepoImpl::new();
+        mock_repo
+            .expect_save_invite()
+            .with(
+                predicate::eq(1),
+                predicate::function(|invite: &BookInvite| {
+                    invite.get_book_id() == 1
+                        && invite.get_code().len() == 32
+                        && invite.get_role() == "viewer"
+                        && invite.get_max_uses() == 5
+                }),
+                predicate::eq(72),
+            )
+            .returning(|_, invite, _| {
+                let expired_at =
+                    NaiveDateTime::parse_from_str("2024-09-04 00:00:00", "%Y-%m-%d %H:%M:%S")
+                        .unwrap();
+                Ok(invite.id(1).expired_at(expired_at))
+            });
+
+        // Act
+        let result = create_invite(&mock_repo, 1, 1, new_invite).await;
+
+        // Assert
+        let invite = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(invite.get_id(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn check_create_invite_owner_role() {
+        // Arrange
+        let new_invite = NewBookInvite::new("owner".to_string(), None, None);
+        let mut mock_repo = MockBookInviteRepoImpl::new();
+        mock_repo.expect_save_invite().never();
+
+        // Act
+        let result = create_invite(&mock_repo, 1, 1, new_invite).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,220 @@
+use axum::async_trait;
+
+use crate::{
+    domain::book::{
+        dto::request::{EditMember, InviteMember, TransferOwner},
+        entity::BookMember,
+        repository::member::BookMemberRepo,
+    },
+    global::errors::CustomError,
+};
+
+pub struct BookMemberUsecaseImpl<T>
+where
+    T: BookMemberRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait BookMemberUsecase: Send + Sync {
+    async fn read_members(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<BookMember>, Box<CustomError>>;
+    async fn invite_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        invite: InviteMember,
+    ) -> Result<i32, Box<CustomError>>;
+    async fn update_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+        edit: EditMember,
+    ) -> Result<(), Box<CustomError>>;
+    async fn remove_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+    ) -> Result<(), Box<CustomError>>;
+    async fn leave_book(&self, user_id: i32, book_id: i32) -> Result<(), Box<CustomError>>;
+    async fn transfer_owner(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        transfer: TransferOwner,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> BookMemberUsecaseImpl<T>
+where
+    T: BookMemberRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> BookMemberUsecase for BookMemberUsecaseImpl<T>
+where
+    T: BookMemberRepo,
+{
+    async fn read_members(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<BookMember>, Box<CustomError>> {
+        self.repository.get_members(user_id, book_id).await
+    }
+    async fn invite_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        invite: InviteMember,
+    ) -> Result<i32, Box<CustomError>> {
+        invite_member(&self.repository, user_id, book_id, invite).await
+    }
+    async fn update_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+        edit: EditMember,
+    ) -> Result<(), Box<CustomError>> {
+        self.repository
+            .update_member_role(user_id, book_id, member_id, edit.to_role()?)
+            .await
+    }
+    async fn remove_member(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        member_id: i32,
+    ) -> Result<(), Box<CustomError>> {
+        self.repository
+            .remove_member(user_id, book_id, member_id)
+            .await
+    }
+    async fn leave_book(&self, user_id: i32, book_id: i32) -> Result<(), Box<CustomError>> {
+        self.repository.leave_book(user_id, book_id).await
+    }
+    async fn transfer_owner(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        transfer: TransferOwner,
+    ) -> Result<(), Box<CustomError>> {
+        self.repository
+            .transfer_owner(user_id, book_id, transfer.get_user_id())
+            .await
+    }
+}
+
+async fn invite_member<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    invite: InviteMember,
+) -> Result<i32, Box<CustomError>>
+where
+    T: BookMemberRepo,
+{
+    let invitee = invite.to_invitee()?;
+    let role = invite.to_role()?;
+
+    repository
+        .invite_member(user_id, book_id, invitee, role)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::book::{
+            dto::request::InviteMember,
+            entity::{BookMember, BookRoleType, Invitee},
+            repository::member::BookMemberRepo,
+            usecase::member::invite_member,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        BookMemberRepoImpl {}
+
+        #[async_trait]
+        impl BookMemberRepo for BookMemberRepoImpl {
+            async fn get_members(&self, user_id: i32, book_id: i32) -> Result<Vec<BookMember>, Box<CustomError>>;
+            async fn invite_member(&self, user_id: i32, book_id: i32, invitee: Invitee, role: BookRoleType) -> Result<i32, Box<CustomError>>;
+            async fn update_member_role(&self, user_id: i32, book_id: i32, member_id: i32, role: BookRoleType) -> Result<(), Box<CustomError>>;
+            async fn remove_member(&self, user_id: i32, book_id: i32, member_id: i32) -> Result<(), Box<CustomError>>;
+            async fn leave_book(&self, user_id: i32, book_id: i32) -> Result<(), Box<CustomError>>;
+            async fn transfer_owner(&self, user_id: i32, book_id: i32, member_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_success() {
+        // Arrange
+        let invite = InviteMember::new(
+            None,
+            Some("test@test.test".to_string()),
+            "Editor".to_string(),
+        );
+        let mut mock_repo = MockBookMemberRepoImpl::new();
+        mock_repo
+            .expect_invite_member()
+            .with(
+                predicate::eq(1),
+                predicate::eq(1),
+                predicate::eq(Invitee::Email("test@test.test".to_string())),
+                predicate::eq(BookRoleType::Editor),
+            )
+            .returning(|_, _, _, _| Ok(3));
+
+        // Act
+        let result = invite_member(&mock_repo, 1, 1, invite).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn check_invite_member_invalid() {
+        // Arrange
+        let mut mock_repo = MockBookMemberRepoImpl::new();
+        mock_repo.expect_invite_member().never();
+
+        let invites = [
+            // 아이디, 이메일 중 하나만
+            InviteMember::new(
+                Some("test_user".to_string()),
+                Some("test@test.test".to_string()),
+                "viewer".to_string(),
+            ),
+            InviteMember::new(None, None, "viewer".to_string()),
+            InviteMember::new(Some("test_user".to_string()), None, "admin".to_string()),
+        ];
+
+        for invite in invites {
+            // Act
+            let result = invite_member(&mock_repo, 1, 1, invite).await;
+
+            // Assert
+            let err_type = match *result.err().unwrap() {
+                CustomError::ValidationError(_) => true,
+                _ => false,
+            };
+            assert!(err_type)
+        }
+    }
+}
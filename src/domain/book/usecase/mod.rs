@@ -1,5 +1,8 @@
+pub(super) mod archive;
 pub(super) mod create;
 pub(super) mod delete;
+pub(super) mod invite;
+pub(super) mod member;
 pub(super) mod read;
 pub(super) mod read_type;
 pub(super) mod update;
@@ -0,0 +1,92 @@
+use chrono::{Datelike, Months, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded::Serializer;
+
+use crate::domain::budget::entity::Budget;
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct NewBudget {
+    book_id: i32,
+    base_id: Option<i16>,
+    sub_category_id: Option<i32>,
+    amount: i32,
+}
+
+impl NewBudget {
+    pub fn new(
+        book_id: i32,
+        base_id: Option<i16>,
+        sub_category_id: Option<i32>,
+        amount: i32,
+    ) -> Self {
+        Self {
+            book_id,
+            base_id,
+            sub_category_id,
+            amount,
+        }
+    }
+
+    // 대분류, 소분류 중 하나만 지정
+    pub fn is_valid(&self) -> bool {
+        self.base_id.is_some() != self.sub_category_id.is_some() && self.amount > 0
+    }
+
+    pub fn to_entity(&self) -> Budget {
+        Budget::new(self.book_id, self.amount)
+            .base_id(self.base_id)
+            .sub_category_id(self.sub_category_id)
+            .build()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct EditBudget {
+    amount: i32,
+}
+
+impl EditBudget {
+    pub fn new(amount: i32) -> Self {
+        Self { amount }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.amount > 0
+    }
+
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Clone, Default)]
+pub struct ProgressParams {
+    year: Option<i32>,
+    month: Option<u32>,
+}
+
+impl ProgressParams {
+    pub fn new(year: Option<i32>, month: Option<u32>) -> Self {
+        Self { year, month }
+    }
+
+    // 지정하지 않은 값은 오늘 기준, 해당 월 [1일, 다음 달 1일)
+    pub fn get_range(&self, today: NaiveDate) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let year = self.year.unwrap_or(today.year());
+        let month = self.month.unwrap_or(today.month());
+        let start_dt = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let end_dt = start_dt.checked_add_months(Months::new(1))?;
+        Some((start_dt.and_hms_opt(0, 0, 0)?, end_dt.and_hms_opt(0, 0, 0)?))
+    }
+
+    pub fn encode_param(&self) -> String {
+        let mut binding = Serializer::new(String::new());
+        if let Some(year) = self.year {
+            binding.append_pair("year", &year.to_string());
+        }
+        if let Some(month) = self.month {
+            binding.append_pair("month", &month.to_string());
+        }
+        binding.finish()
+    }
+}
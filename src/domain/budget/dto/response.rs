@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+use crate::domain::budget::entity::BudgetUsage;
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BudgetProgress {
+    budget_id: i32,
+    base_id: Option<i16>,
+    sub_category_id: Option<i32>,
+    name: String,
+    amount: i64,
+    used: i64,
+    remaining: i64,
+    percent: f64,
+}
+
+impl BudgetProgress {
+    pub fn new(usage: BudgetUsage) -> Self {
+        let amount = usage.get_amount();
+        let used = usage.get_used();
+        // 소수점 첫째 자리까지
+        let percent = if amount > 0 {
+            (used as f64 * 1000.0 / amount as f64).round() / 10.0
+        } else {
+            0.0
+        };
+
+        Self {
+            budget_id: usage.get_budget_id(),
+            base_id: usage.get_base_id(),
+            sub_category_id: usage.get_sub_category_id(),
+            name: usage.get_name().to_string(),
+            amount,
+            used,
+            remaining: amount - used,
+            percent,
+        }
+    }
+
+    pub fn get_used(&self) -> i64 {
+        self.used
+    }
+    pub fn get_remaining(&self) -> i64 {
+        self.remaining
+    }
+    pub fn get_percent(&self) -> f64 {
+        self.percent
+    }
+}
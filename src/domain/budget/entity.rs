@@ -0,0 +1,97 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct Budget {
+    id: Option<i32>,
+    book_id: i32,
+    base_id: Option<i16>,
+    sub_category_id: Option<i32>,
+    amount: i32,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+impl Budget {
+    pub fn new(book_id: i32, amount: i32) -> Self {
+        Self {
+            id: None,
+            book_id,
+            base_id: None,
+            sub_category_id: None,
+            amount,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn base_id(mut self, base_id: Option<i16>) -> Self {
+        self.base_id = base_id;
+        self
+    }
+
+    pub fn sub_category_id(mut self, sub_category_id: Option<i32>) -> Self {
+        self.sub_category_id = sub_category_id;
+        self
+    }
+
+    pub fn build(&self) -> Self {
+        self.clone()
+    }
+
+    pub fn get_book_id(&self) -> i32 {
+        self.book_id
+    }
+    pub fn get_base_id(&self) -> Option<i16> {
+        self.base_id
+    }
+    pub fn get_sub_category_id(&self) -> Option<i32> {
+        self.sub_category_id
+    }
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+}
+
+/// 예산별 해당 기간 지출 합계
+#[derive(Debug, sqlx::FromRow, Clone, PartialEq)]
+pub struct BudgetUsage {
+    budget_id: i32,
+    base_id: Option<i16>,
+    sub_category_id: Option<i32>,
+    name: String,
+    amount: i64,
+    used: i64,
+}
+
+impl BudgetUsage {
+    pub fn new(budget_id: i32, name: String, amount: i64, used: i64) -> Self {
+        Self {
+            budget_id,
+            base_id: None,
+            sub_category_id: None,
+            name,
+            amount,
+            used,
+        }
+    }
+
+    pub fn get_budget_id(&self) -> i32 {
+        self.budget_id
+    }
+    pub fn get_base_id(&self) -> Option<i16> {
+        self.base_id
+    }
+    pub fn get_sub_category_id(&self) -> Option<i32> {
+        self.sub_category_id
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_amount(&self) -> i64 {
+        self.amount
+    }
+    pub fn get_used(&self) -> i64 {
+        self.used
+    }
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use axum::{response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::budget::{dto::request::NewBudget, usecase::create::CreateBudgetUsecase},
+    global::errors::CustomError,
+};
+
+pub async fn create_budget<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Json(new_budget): Json<NewBudget>,
+) -> impl IntoResponse
+where
+    T: CreateBudgetUsecase,
+{
+    if !new_budget.is_valid() {
+        return CustomError::ValidationError("Budget".to_string()).into_response();
+    }
+
+    match usecase.create_budget(user_id, new_budget).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "성공", "budget_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, extract::Request, routing::post, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::create_budget;
+    use crate::{
+        domain::budget::{dto::request::NewBudget, usecase::create::CreateBudgetUsecase},
+        global::errors::CustomError,
+    };
+
+    mock! {
+        CreateBudgetUsecaseImpl {}
+
+        #[async_trait]
+        impl CreateBudgetUsecase for CreateBudgetUsecaseImpl {
+            async fn create_budget(&self, user_id: i32, new_budget: NewBudget) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockCreateBudgetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/budget",
+                post(create_budget::<MockCreateBudgetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_req(new_budget: &NewBudget) -> Request<String> {
+        Request::builder()
+            .method("POST")
+            .uri("/api/v1/budget")
+            .header("content-type", "application/json")
+            .body(to_string(&new_budget).unwrap())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_create_budget_body() {
+        // Arrange
+        let user_id = 1;
+        let new_budget = NewBudget::new(1, None, Some(17), 400000);
+
+        let mut mock_usecase = MockCreateBudgetUsecaseImpl::new();
+        mock_usecase
+            .expect_create_budget()
+            .with(predicate::eq(user_id), predicate::eq(new_budget.clone()))
+            .returning(|_, _| Ok(1));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_budget);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 201);
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json["budget_id"], 1);
+    }
+
+    #[tokio::test]
+    async fn check_invalid_target() {
+        // Arrange
+        let user_id = 1;
+        let new_budget = NewBudget::new(1, Some(9), Some(17), 400000);
+        let mock_usecase = MockCreateBudgetUsecaseImpl::new();
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_budget);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400)
+    }
+
+    #[tokio::test]
+    async fn check_not_owner() {
+        // Arrange
+        let user_id = 2;
+        let new_budget = NewBudget::new(1, Some(9), None, 400000);
+
+        let mut mock_usecase = MockCreateBudgetUsecaseImpl::new();
+        mock_usecase
+            .expect_create_budget()
+            .with(predicate::eq(user_id), predicate::eq(new_budget.clone()))
+            .returning(|_, _| {
+                Err(Box::new(CustomError::Unauthorized(
+                    "BudgetRole".to_string(),
+                )))
+            });
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_budget);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+}
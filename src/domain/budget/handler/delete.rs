@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::budget::usecase::delete::DeleteBudgetUsecase;
+
+pub async fn delete_budget<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(budget_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: DeleteBudgetUsecase,
+{
+    match usecase.delete_budget(user_id, budget_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::delete, Extension, Router};
+    use mockall::{mock, predicate};
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::budget::{handler::delete::delete_budget, usecase::delete::DeleteBudgetUsecase},
+        global::errors::CustomError,
+    };
+
+    mock! {
+        DeleteBudgetUsecaseImpl {}
+
+        #[async_trait]
+        impl DeleteBudgetUsecase for DeleteBudgetUsecaseImpl {
+            async fn delete_budget(&self, user_id: i32, budget_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockDeleteBudgetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/budget/:budget_id",
+                delete(delete_budget::<MockDeleteBudgetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(budget_id: i32) -> Request {
+        Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/v1/budget/{}", budget_id))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_delete_budget_status() {
+        // Arrange
+        let user_id = 1;
+        let budget_id = 1;
+        let mut mock_usecase = MockDeleteBudgetUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_budget()
+            .with(predicate::eq(user_id), predicate::eq(budget_id))
+            .returning(|_, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(budget_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_budget_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+        let mut mock_usecase = MockDeleteBudgetUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_budget()
+            .with(predicate::eq(user_id), predicate::eq(no_id))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Budget".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(no_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404)
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{delete, get, patch, post},
+    Extension, Router,
+};
+
+use sqlx::PgPool;
+
+mod create;
+mod delete;
+mod read;
+mod update;
+
+use create::create_budget;
+use delete::delete_budget;
+use read::{read_budgets, read_progress};
+use update::update_budget;
+
+use super::{
+    repository::{
+        delete::DeleteBudgetRepoImpl, get_budget::GetBudgetRepoImpl, save::SaveBudgetRepoImpl,
+        update::UpdateBudgetRepoImpl,
+    },
+    usecase::{
+        create::CreateBudgetUsecaseImpl, delete::DeleteBudgetUsecaseImpl,
+        read::ReadBudgetUsecaseImpl, update::UpdateBudgetUsecaseImpl,
+    },
+};
+
+pub fn create_router(pool: &Arc<PgPool>) -> Router {
+    let repository = SaveBudgetRepoImpl::new(pool);
+    let usecase = CreateBudgetUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/",
+            post(create_budget::<CreateBudgetUsecaseImpl<SaveBudgetRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn read_router(pool: &Arc<PgPool>) -> Router {
+    let repository = GetBudgetRepoImpl::new(pool);
+    let usecase = ReadBudgetUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/list/:book_id",
+            get(read_budgets::<ReadBudgetUsecaseImpl<GetBudgetRepoImpl>>),
+        )
+        .route(
+            "/progress/:book_id",
+            get(read_progress::<ReadBudgetUsecaseImpl<GetBudgetRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn update_router(pool: &Arc<PgPool>) -> Router {
+    let repository = UpdateBudgetRepoImpl::new(pool);
+    let usecase = UpdateBudgetUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:budget_id",
+            patch(update_budget::<UpdateBudgetUsecaseImpl<UpdateBudgetRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn delete_router(pool: &Arc<PgPool>) -> Router {
+    let repository = DeleteBudgetRepoImpl::new(pool);
+    let usecase = DeleteBudgetUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:budget_id",
+            delete(delete_budget::<DeleteBudgetUsecaseImpl<DeleteBudgetRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    response::IntoResponse,
+    Extension, Json,
+};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::budget::{dto::request::ProgressParams, usecase::read::ReadBudgetUsecase};
+
+pub async fn read_budgets<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: ReadBudgetUsecase,
+{
+    match usecase.read_budgets(user_id, book_id).await {
+        Ok(budgets) => (StatusCode::OK, Json(json!(budgets))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+pub async fn read_progress<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    params: Query<ProgressParams>,
+) -> impl IntoResponse
+where
+    T: ReadBudgetUsecase,
+{
+    match usecase.read_progress(user_id, book_id, params.0).await {
+        Ok(progress) => (StatusCode::OK, Json(json!(progress))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::get, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::budget::{
+            dto::{request::ProgressParams, response::BudgetProgress},
+            entity::{Budget, BudgetUsage},
+            usecase::read::ReadBudgetUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    use super::read_progress;
+
+    mock! {
+        ReadBudgetUsecaseImpl {}
+
+        #[async_trait]
+        impl ReadBudgetUsecase for ReadBudgetUsecaseImpl {
+            async fn read_budgets(&self, user_id: i32, book_id: i32) -> Result<Vec<Budget>, Box<CustomError>>;
+            async fn read_progress(&self, user_id: i32, book_id: i32, params: ProgressParams) -> Result<Vec<BudgetProgress>, Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockReadBudgetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/budget/progress/:book_id",
+                get(read_progress::<MockReadBudgetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_req(book_id: i32, params: &ProgressParams) -> Request {
+        Request::builder()
+            .method("GET")
+            .uri(format!(
+                "/api/v1/budget/progress/{}?{}",
+                book_id,
+                params.encode_param()
+            ))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_progress_body() {
+        // Arrange
+        let user_id = 3;
+        let book_id = 2;
+        let params = ProgressParams::new(Some(2024), Some(9));
+
+        let mut mock_usecase = MockReadBudgetUsecaseImpl::new();
+        mock_usecase
+            .expect_read_progress()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(params.clone()),
+            )
+            .returning(|_, _, _| {
+                Ok(vec![BudgetProgress::new(BudgetUsage::new(
+                    1,
+                    "변동지출".to_string(),
+                    100000,
+                    15000,
+                ))])
+            });
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(book_id, &params);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json[0]["remaining"], 85000);
+        assert_eq!(body_json[0]["percent"], 15.0);
+    }
+
+    #[tokio::test]
+    async fn check_default_month() {
+        // Arrange
+        let user_id = 3;
+        let book_id = 2;
+        let params = ProgressParams::default();
+
+        let mut mock_usecase = MockReadBudgetUsecaseImpl::new();
+        mock_usecase
+            .expect_read_progress()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(params.clone()),
+            )
+            .returning(|_, _, _| Ok(vec![]));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(book_id, &params);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::budget::{dto::request::EditBudget, usecase::update::UpdateBudgetUsecase},
+    global::errors::CustomError,
+};
+
+pub async fn update_budget<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(budget_id): Path<i32>,
+    Json(edit_budget): Json<EditBudget>,
+) -> impl IntoResponse
+where
+    T: UpdateBudgetUsecase,
+{
+    if !edit_budget.is_valid() {
+        return CustomError::ValidationError("Budget".to_string()).into_response();
+    }
+
+    match usecase.update_budget(user_id, budget_id, edit_budget).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, extract::Request, routing::patch, Extension, Router};
+    use mockall::{mock, predicate};
+    use serde_json::to_string;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::budget::{dto::request::EditBudget, usecase::update::UpdateBudgetUsecase},
+        global::errors::CustomError,
+    };
+
+    use super::update_budget;
+
+    mock! {
+        UpdateBudgetUsecaseImpl {}
+
+        #[async_trait]
+        impl UpdateBudgetUsecase for UpdateBudgetUsecaseImpl {
+            async fn update_budget(&self, user_id: i32, budget_id: i32, edit_budget: EditBudget) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockUpdateBudgetUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/budget/:budget_id",
+                patch(update_budget::<MockUpdateBudgetUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_req(budget_id: i32, edit_budget: &EditBudget) -> Request<String> {
+        Request::builder()
+            .method("PATCH")
+            .uri(format!("/api/v1/budget/{}", budget_id))
+            .header("content-type", "application/json")
+            .body(to_string(edit_budget).unwrap())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_update_budget_status() {
+        // Arrange
+        let user_id = 3;
+        let budget_id = 1;
+        let edit_budget = EditBudget::new(200000);
+
+        let mut mock_usecase = MockUpdateBudgetUsecaseImpl::new();
+        mock_usecase
+            .expect_update_budget()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(budget_id),
+                predicate::eq(edit_budget.clone()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(budget_id, &edit_budget);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_invalid_amount() {
+        // Arrange
+        let user_id = 3;
+        let budget_id = 1;
+        let edit_budget = EditBudget::new(-1);
+        let mock_usecase = MockUpdateBudgetUsecaseImpl::new();
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(budget_id, &edit_budget);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400)
+    }
+}
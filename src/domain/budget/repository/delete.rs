@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::global::{constants::DeleteResult, errors::CustomError};
+
+pub struct DeleteBudgetRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait DeleteBudgetRepo: Send + Sync {
+    async fn delete_budget(&self, user_id: i32, budget_id: i32) -> Result<(), Box<CustomError>>;
+}
+
+impl DeleteBudgetRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl DeleteBudgetRepo for DeleteBudgetRepoImpl {
+    async fn delete_budget(&self, user_id: i32, budget_id: i32) -> Result<(), Box<CustomError>> {
+        delete_budget(&self.pool, user_id, budget_id).await
+    }
+}
+
+async fn delete_budget(
+    pool: &PgPool,
+    user_id: i32,
+    budget_id: i32,
+) -> Result<(), Box<CustomError>> {
+    let result = sqlx::query_as::<_, DeleteResult>(
+        r"
+        WITH BudgetExists AS (
+            SELECT book_id
+            FROM tb_budget
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT bg.book_id
+            FROM BudgetExists AS bg
+            JOIN tb_user_book_role AS br ON bg.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role = 'owner'
+        ),
+        DeleteBudget AS (
+            DELETE FROM tb_budget
+            WHERE id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM BudgetExists) AS is_exist,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT COUNT(*) FROM DeleteBudget) AS delete_count
+        ",
+    )
+    .bind(user_id)
+    .bind(budget_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(DeleteBudget {}): {:?}", budget_id, &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Budget".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized(
+            "BudgetRole".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::database::create_connection_pool,
+        domain::budget::{entity::Budget, repository::save::save_budget},
+        global::errors::CustomError,
+    };
+
+    use super::delete_budget;
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_delete_budget_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 1;
+        let budget = Budget::new(1, 100000).sub_category_id(Some(24)); // 친목비
+        let new_id = save_budget(&pool, user_id, budget).await.unwrap();
+
+        // Act
+        let result = delete_budget(&pool, user_id, new_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tb_budget WHERE id = $1")
+            .bind(new_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn check_not_owner() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 1; // 2번 가계부 역할 없음
+        let budget_id = 2;
+
+        // Act
+        let result = delete_budget(&pool, user_id, budget_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
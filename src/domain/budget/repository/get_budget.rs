@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::{
+    domain::budget::entity::{Budget, BudgetUsage},
+    global::errors::CustomError,
+};
+
+pub struct GetBudgetRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait GetBudgetRepo: Send + Sync {
+    async fn get_list(&self, user_id: i32, book_id: i32) -> Result<Vec<Budget>, Box<CustomError>>;
+    async fn get_usage(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<BudgetUsage>, Box<CustomError>>;
+}
+
+impl GetBudgetRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl GetBudgetRepo for GetBudgetRepoImpl {
+    async fn get_list(&self, user_id: i32, book_id: i32) -> Result<Vec<Budget>, Box<CustomError>> {
+        get_list(&self.pool, user_id, book_id).await
+    }
+    async fn get_usage(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<BudgetUsage>, Box<CustomError>> {
+        get_usage(&self.pool, user_id, book_id, start_dt, end_dt).await
+    }
+}
+
+async fn get_list(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<Budget>, Box<CustomError>> {
+    let rows = sqlx::query_as::<_, Budget>(
+        "
+        SELECT bg.*
+        FROM tb_budget AS bg
+        JOIN tb_user_book_role AS br ON bg.book_id = br.book_id
+        WHERE br.user_id = $1 AND bg.book_id = $2
+        ORDER BY bg.id
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetBudgets): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(rows)
+}
+
+async fn get_usage(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    start_dt: NaiveDateTime,
+    end_dt: NaiveDateTime,
+) -> Result<Vec<BudgetUsage>, Box<CustomError>> {
+    // 이체는 지출에서 제외, 분할 기록은 분할 내역의 소분류로 집계, 대분류 예산은 하위 소분류 지출 합계
+    let rows = sqlx::query_as::<_, BudgetUsage>(
+        "
+        WITH AuthorityCheck AS (
+            SELECT book_id
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2
+        ),
+        SubSpent AS (
+            SELECT sc.id AS sub_id, sc.base_id, SUM(COALESCE(rl.amount, r.amount)) AS amount
+            FROM tb_record AS r
+            LEFT JOIN tb_record_line AS rl ON rl.record_id = r.id
+            JOIN tb_sub_category AS sc ON sc.id = COALESCE(rl.sub_category_id, r.sub_category_id)
+            WHERE r.book_id = $2
+                AND r.transfer_id IS NULL
+                AND r.target_dt >= $3 AND r.target_dt < $4
+            GROUP BY sc.id, sc.base_id
+        )
+        SELECT
+            bg.id AS budget_id,
+            bg.base_id,
+            bg.sub_category_id,
+            COALESCE(bc.name, sc.name) AS name,
+            bg.amount::BIGINT AS amount,
+            COALESCE((
+                SELECT SUM(ss.amount)
+                FROM SubSpent AS ss
+                WHERE ss.sub_id = bg.sub_category_id OR ss.base_id = bg.base_id
+            ), 0)::BIGINT AS used
+        FROM tb_budget AS bg
+        JOIN AuthorityCheck AS ac ON ac.book_id = bg.book_id
+        LEFT JOIN tb_base_category AS bc ON bc.id = bg.base_id
+        LEFT JOIN tb_sub_category AS sc ON sc.id = bg.sub_category_id
+        ORDER BY bg.id
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .bind(start_dt)
+    .bind(end_dt)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetBudgetUsage): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::budget::repository::get_budget::{get_list, get_usage},
+    };
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_get_list_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 1;
+        let book_id = 2;
+
+        // Act
+        let result = get_list(&pool, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn check_get_usage_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let book_id = 2;
+        let start_dt = _parse("2024-09-01 00:00:00");
+        let end_dt = _parse("2024-10-01 00:00:00");
+
+        // Act
+        let result = get_usage(&pool, user_id, book_id, start_dt, end_dt).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        // 변동지출: 9월 생필품비 15000, 식비: 9월 기록 없음
+        let base = result.iter().find(|u| u.get_budget_id() == 1).unwrap();
+        let sub = result.iter().find(|u| u.get_budget_id() == 2).unwrap();
+        assert_eq!(base.get_used(), 15000);
+        assert_eq!(base.get_name(), "변동지출");
+        assert_eq!(sub.get_used(), 0);
+        assert_eq!(sub.get_name(), "식비");
+    }
+
+    #[tokio::test]
+    async fn check_get_usage_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 1;
+        let book_id = 2;
+
+        // Act
+        let result = get_usage(
+            &pool,
+            user_id,
+            book_id,
+            _parse("2024-09-01 00:00:00"),
+            _parse("2024-10-01 00:00:00"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 0);
+    }
+}
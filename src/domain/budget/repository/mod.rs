@@ -0,0 +1,4 @@
+pub(super) mod delete;
+pub(super) mod get_budget;
+pub(super) mod save;
+pub(super) mod update;
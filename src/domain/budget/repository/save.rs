@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::{Error, PgPool};
+
+use crate::{domain::budget::entity::Budget, global::errors::CustomError};
+
+pub struct SaveBudgetRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait SaveBudgetRepo: Send + Sync {
+    async fn save_budget(&self, user_id: i32, budget: Budget) -> Result<i32, Box<CustomError>>;
+}
+
+impl SaveBudgetRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl SaveBudgetRepo for SaveBudgetRepoImpl {
+    async fn save_budget(&self, user_id: i32, budget: Budget) -> Result<i32, Box<CustomError>> {
+        save_budget(&self.pool, user_id, budget).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct InsertBudget {
+    is_authorized: bool,
+    budget_id: Option<i32>,
+    is_category_exist: bool,
+    is_duplicated: bool,
+}
+
+impl InsertBudget {
+    fn get_authorized(&self) -> bool {
+        self.is_authorized
+    }
+    fn get_budget_id(&self) -> Option<i32> {
+        self.budget_id
+    }
+    fn get_category_exist(&self) -> bool {
+        self.is_category_exist
+    }
+    fn get_duplicated(&self) -> bool {
+        self.is_duplicated
+    }
+}
+
+pub async fn save_budget(
+    pool: &PgPool,
+    user_id: i32,
+    budget: Budget,
+) -> Result<i32, Box<CustomError>> {
+    // 예산은 가계부 소유자만 설정 가능
+    let result = sqlx::query_as::<_, InsertBudget>(
+        r#"
+        WITH AuthorityCheck AS (
+            SELECT book_id
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2 AND role = 'owner'
+        ),
+        CategoryCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM tb_base_category AS bc
+                LEFT JOIN tb_sub_category AS sc ON sc.base_id = bc.id
+                LEFT JOIN AuthorityCheck AS ac ON bc.book_id = ac.book_id
+                WHERE (bc.id = $3 OR sc.id = $4)
+                    AND bc.is_record = true
+                    AND bc.is_income = false
+                    AND (ac.book_id IS NOT NULL OR bc.book_id IS NULL)
+            ) AS is_category_exist
+        ),
+        DuplicateCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM tb_budget
+                WHERE book_id = $2 AND (base_id = $3 OR sub_category_id = $4)
+            ) AS is_duplicated
+        ),
+        InsertBudget AS (
+            INSERT INTO tb_budget (book_id, base_id, sub_category_id, amount, created_at)
+                SELECT book_id, $3, $4, $5, NOW()
+                    FROM AuthorityCheck
+                    WHERE book_id IS NOT NULL
+                        AND (SELECT is_category_exist FROM CategoryCheck) = true
+                        AND (SELECT is_duplicated FROM DuplicateCheck) = false
+            RETURNING id
+        )
+        SELECT
+            (SELECT id FROM InsertBudget) AS budget_id,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT is_category_exist FROM CategoryCheck) AS is_category_exist,
+            (SELECT is_duplicated FROM DuplicateCheck) AS is_duplicated;
+    "#,
+    )
+    .bind(user_id)
+    .bind(budget.get_book_id())
+    .bind(budget.get_base_id())
+    .bind(budget.get_sub_category_id())
+    .bind(budget.get_amount())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Save(Budget): {:?}", e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized(
+            "BudgetRole".to_string(),
+        )));
+    } else if !result.get_category_exist() {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    } else if result.get_duplicated() {
+        return Err(Box::new(CustomError::Duplicated("Budget".to_string())));
+    }
+
+    Ok(result.get_budget_id().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::database::create_connection_pool,
+        domain::budget::{entity::Budget, repository::save::save_budget},
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_save_budget_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 1;
+        let budget = Budget::new(1, 300000).sub_category_id(Some(19)); // 취미
+
+        // Act
+        let result = save_budget(&pool, user_id, budget).await;
+        let inserted_id = result.map_err(|e| println!("{:?}", e)).unwrap();
+
+        // Assert
+        let row = sqlx::query_as::<_, Budget>("SELECT * FROM tb_budget WHERE id = $1")
+            .bind(inserted_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(row.get_sub_category_id(), Some(19));
+        assert_eq!(row.get_base_id(), None);
+        assert_eq!(row.get_amount(), 300000);
+    }
+
+    #[tokio::test]
+    async fn check_duplicated() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let budget = Budget::new(2, 300000).base_id(Some(9)); // 이미 설정된 변동지출
+
+        // Act
+        let result = save_budget(&pool, user_id, budget).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Duplicated(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_income_category() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let income_category_id = 9; // 급여, 지출 카테고리가 아님
+        let budget = Budget::new(1, 300000).sub_category_id(Some(income_category_id));
+
+        // Act
+        let result = save_budget(&pool, user_id, budget).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_not_owner() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2; // viewer
+        let budget = Budget::new(1, 300000).base_id(Some(8));
+
+        // Act
+        let result = save_budget(&pool, user_id, budget).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::global::{constants::UpdateResult, errors::CustomError};
+
+pub struct UpdateBudgetRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait UpdateBudgetRepo: Send + Sync {
+    async fn update_budget(
+        &self,
+        user_id: i32,
+        budget_id: i32,
+        amount: i32,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl UpdateBudgetRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl UpdateBudgetRepo for UpdateBudgetRepoImpl {
+    async fn update_budget(
+        &self,
+        user_id: i32,
+        budget_id: i32,
+        amount: i32,
+    ) -> Result<(), Box<CustomError>> {
+        update_budget(&self.pool, user_id, budget_id, amount).await
+    }
+}
+
+async fn update_budget(
+    pool: &PgPool,
+    user_id: i32,
+    budget_id: i32,
+    amount: i32,
+) -> Result<(), Box<CustomError>> {
+    let result = sqlx::query_as::<_, UpdateResult>(
+        r"
+        WITH BudgetExists AS (
+            SELECT book_id
+            FROM tb_budget
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT bg.book_id
+            FROM BudgetExists AS bg
+            JOIN tb_user_book_role AS br ON bg.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role = 'owner'
+        ),
+        UpdateBudget AS (
+            UPDATE tb_budget SET amount = $3, updated_at = NOW()
+            WHERE id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM BudgetExists) AS is_exist,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            false AS is_duplicated,
+            (SELECT COUNT(*) FROM UpdateBudget) AS update_count;
+        ",
+    )
+    .bind(user_id)
+    .bind(budget_id)
+    .bind(amount)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Update(Budget {}): {:?}", budget_id, e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Budget".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized(
+            "BudgetRole".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::database::create_connection_pool,
+        domain::budget::{
+            entity::Budget,
+            repository::{save::save_budget, update::update_budget},
+        },
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_update_budget_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 1;
+        let budget = Budget::new(1, 100000).sub_category_id(Some(23)); // 여행
+        let new_id = save_budget(&pool, user_id, budget).await.unwrap();
+
+        // Act
+        let result = update_budget(&pool, user_id, new_id, 250000).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = sqlx::query_as::<_, Budget>("SELECT * FROM tb_budget WHERE id = $1")
+            .bind(new_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(row.get_amount(), 250000);
+    }
+
+    #[tokio::test]
+    async fn check_budget_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+
+        // Act
+        let result = update_budget(&pool, user_id, no_id, 250000).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_not_owner() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 1; // 2번 가계부 역할 없음
+        let budget_id = 1;
+
+        // Act
+        let result = update_budget(&pool, user_id, budget_id, 250000).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
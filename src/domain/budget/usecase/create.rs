@@ -0,0 +1,114 @@
+use axum::async_trait;
+
+use crate::{
+    domain::budget::{dto::request::NewBudget, repository::save::SaveBudgetRepo},
+    global::errors::CustomError,
+};
+
+pub struct CreateBudgetUsecaseImpl<T>
+where
+    T: SaveBudgetRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait CreateBudgetUsecase: Send + Sync {
+    async fn create_budget(
+        &self,
+        user_id: i32,
+        new_budget: NewBudget,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl<T> CreateBudgetUsecaseImpl<T>
+where
+    T: SaveBudgetRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> CreateBudgetUsecase for CreateBudgetUsecaseImpl<T>
+where
+    T: SaveBudgetRepo,
+{
+    async fn create_budget(
+        &self,
+        user_id: i32,
+        new_budget: NewBudget,
+    ) -> Result<i32, Box<CustomError>> {
+        create_budget(&self.repository, user_id, new_budget).await
+    }
+}
+
+async fn create_budget<T>(
+    repository: &T,
+    user_id: i32,
+    new_budget: NewBudget,
+) -> Result<i32, Box<CustomError>>
+where
+    T: SaveBudgetRepo,
+{
+    repository
+        .save_budget(user_id, new_budget.to_entity())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::domain::budget::{
+        dto::request::NewBudget, entity::Budget, repository::save::SaveBudgetRepo,
+        usecase::create::create_budget,
+    };
+    use crate::global::errors::CustomError;
+
+    mock! {
+        SaveBudgetRepoImpl {}
+
+        #[async_trait]
+        impl SaveBudgetRepo for SaveBudgetRepoImpl {
+            async fn save_budget(&self, user_id: i32, budget: Budget) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_create_budget_success() {
+        // Arrange
+        let user_id = 1;
+        let new_budget = NewBudget::new(1, None, Some(17), 400000);
+
+        let mut mock_repo = MockSaveBudgetRepoImpl::new();
+        mock_repo
+            .expect_save_budget()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(new_budget.to_entity()),
+            )
+            .returning(|_, _| Ok(1));
+
+        // Act
+        let result = create_budget(&mock_repo, user_id, new_budget).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_invalid_target() {
+        // Arrange, Act
+        let both = NewBudget::new(1, Some(9), Some(17), 400000);
+        let none = NewBudget::new(1, None, None, 400000);
+        let zero = NewBudget::new(1, Some(9), None, 0);
+
+        // Assert
+        assert!(!both.is_valid());
+        assert!(!none.is_valid());
+        assert!(!zero.is_valid());
+    }
+}
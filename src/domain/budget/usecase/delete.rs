@@ -0,0 +1,84 @@
+use axum::async_trait;
+
+use crate::{domain::budget::repository::delete::DeleteBudgetRepo, global::errors::CustomError};
+
+pub struct DeleteBudgetUsecaseImpl<T>
+where
+    T: DeleteBudgetRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait DeleteBudgetUsecase: Send + Sync {
+    async fn delete_budget(&self, user_id: i32, budget_id: i32) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> DeleteBudgetUsecaseImpl<T>
+where
+    T: DeleteBudgetRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> DeleteBudgetUsecase for DeleteBudgetUsecaseImpl<T>
+where
+    T: DeleteBudgetRepo,
+{
+    async fn delete_budget(&self, user_id: i32, budget_id: i32) -> Result<(), Box<CustomError>> {
+        delete_budget(&self.repository, user_id, budget_id).await
+    }
+}
+
+async fn delete_budget<T>(
+    repository: &T,
+    user_id: i32,
+    budget_id: i32,
+) -> Result<(), Box<CustomError>>
+where
+    T: DeleteBudgetRepo,
+{
+    repository.delete_budget(user_id, budget_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::budget::{repository::delete::DeleteBudgetRepo, usecase::delete::delete_budget},
+        global::errors::CustomError,
+    };
+
+    mock! {
+        DeleteBudgetRepoImpl {}
+
+        #[async_trait]
+        impl DeleteBudgetRepo for DeleteBudgetRepoImpl {
+            async fn delete_budget(&self, user_id: i32, budget_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_delete_budget_success() {
+        // Arrange
+        let user_id = 3;
+        let budget_id = 1;
+
+        let mut mock_repo = MockDeleteBudgetRepoImpl::new();
+        mock_repo
+            .expect_delete_budget()
+            .with(predicate::eq(user_id), predicate::eq(budget_id))
+            .returning(|_, _| Ok(()));
+
+        // Act
+        let result = delete_budget(&mock_repo, user_id, budget_id).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,180 @@
+use axum::async_trait;
+use chrono::Local;
+
+use crate::{
+    domain::budget::{
+        dto::{request::ProgressParams, response::BudgetProgress},
+        entity::Budget,
+        repository::get_budget::GetBudgetRepo,
+    },
+    global::errors::CustomError,
+};
+
+pub struct ReadBudgetUsecaseImpl<T>
+where
+    T: GetBudgetRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait ReadBudgetUsecase: Send + Sync {
+    async fn read_budgets(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Budget>, Box<CustomError>>;
+    async fn read_progress(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: ProgressParams,
+    ) -> Result<Vec<BudgetProgress>, Box<CustomError>>;
+}
+
+impl<T> ReadBudgetUsecaseImpl<T>
+where
+    T: GetBudgetRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> ReadBudgetUsecase for ReadBudgetUsecaseImpl<T>
+where
+    T: GetBudgetRepo,
+{
+    async fn read_budgets(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Budget>, Box<CustomError>> {
+        read_budgets(&self.repository, user_id, book_id).await
+    }
+
+    async fn read_progress(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: ProgressParams,
+    ) -> Result<Vec<BudgetProgress>, Box<CustomError>> {
+        read_progress(&self.repository, user_id, book_id, params).await
+    }
+}
+
+async fn read_budgets<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<Budget>, Box<CustomError>>
+where
+    T: GetBudgetRepo,
+{
+    repository.get_list(user_id, book_id).await
+}
+
+async fn read_progress<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    params: ProgressParams,
+) -> Result<Vec<BudgetProgress>, Box<CustomError>>
+where
+    T: GetBudgetRepo,
+{
+    let (start_dt, end_dt) = params.get_range(Local::now().date_naive()).ok_or(Box::new(
+        CustomError::ValidationError("BudgetMonth".to_string()),
+    ))?;
+
+    let rows = repository
+        .get_usage(user_id, book_id, start_dt, end_dt)
+        .await?;
+
+    Ok(rows.into_iter().map(BudgetProgress::new).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::NaiveDateTime;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::budget::{
+            dto::request::ProgressParams,
+            entity::{Budget, BudgetUsage},
+            repository::get_budget::GetBudgetRepo,
+            usecase::read::read_progress,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        GetBudgetRepoImpl {}
+
+        #[async_trait]
+        impl GetBudgetRepo for GetBudgetRepoImpl {
+            async fn get_list(&self, user_id: i32, book_id: i32) -> Result<Vec<Budget>, Box<CustomError>>;
+            async fn get_usage(&self, user_id: i32, book_id: i32, start_dt: NaiveDateTime, end_dt: NaiveDateTime) -> Result<Vec<BudgetUsage>, Box<CustomError>>;
+        }
+    }
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_progress_success() {
+        // Arrange
+        let user_id = 3;
+        let book_id = 2;
+        let params = ProgressParams::new(Some(2024), Some(9));
+
+        let mut mock_repo = MockGetBudgetRepoImpl::new();
+        mock_repo
+            .expect_get_usage()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(_parse("2024-09-01 00:00:00")),
+                predicate::eq(_parse("2024-10-01 00:00:00")),
+            )
+            .returning(|_, _, _, _| {
+                Ok(vec![
+                    BudgetUsage::new(1, "변동지출".to_string(), 100000, 15000),
+                    BudgetUsage::new(2, "식비".to_string(), 50000, 62500),
+                ])
+            });
+
+        // Act
+        let result = read_progress(&mock_repo, user_id, book_id, params).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result[0].get_remaining(), 85000);
+        assert_eq!(result[0].get_percent(), 15.0);
+        assert_eq!(result[1].get_remaining(), -12500);
+        assert_eq!(result[1].get_percent(), 125.0);
+    }
+
+    #[tokio::test]
+    async fn check_invalid_month() {
+        // Arrange
+        let params = ProgressParams::new(Some(2024), Some(13));
+        let mock_repo = MockGetBudgetRepoImpl::new();
+
+        // Act
+        let result = read_progress(&mock_repo, 3, 2, params).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,108 @@
+use axum::async_trait;
+
+use crate::{
+    domain::budget::{dto::request::EditBudget, repository::update::UpdateBudgetRepo},
+    global::errors::CustomError,
+};
+
+pub struct UpdateBudgetUsecaseImpl<T>
+where
+    T: UpdateBudgetRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait UpdateBudgetUsecase: Send + Sync {
+    async fn update_budget(
+        &self,
+        user_id: i32,
+        budget_id: i32,
+        edit_budget: EditBudget,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> UpdateBudgetUsecaseImpl<T>
+where
+    T: UpdateBudgetRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> UpdateBudgetUsecase for UpdateBudgetUsecaseImpl<T>
+where
+    T: UpdateBudgetRepo,
+{
+    async fn update_budget(
+        &self,
+        user_id: i32,
+        budget_id: i32,
+        edit_budget: EditBudget,
+    ) -> Result<(), Box<CustomError>> {
+        update_budget(&self.repository, user_id, budget_id, edit_budget).await
+    }
+}
+
+async fn update_budget<T>(
+    repository: &T,
+    user_id: i32,
+    budget_id: i32,
+    edit_budget: EditBudget,
+) -> Result<(), Box<CustomError>>
+where
+    T: UpdateBudgetRepo,
+{
+    repository
+        .update_budget(user_id, budget_id, edit_budget.get_amount())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::budget::{
+            dto::request::EditBudget, repository::update::UpdateBudgetRepo,
+            usecase::update::update_budget,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        UpdateBudgetRepoImpl {}
+
+        #[async_trait]
+        impl UpdateBudgetRepo for UpdateBudgetRepoImpl {
+            async fn update_budget(&self, user_id: i32, budget_id: i32, amount: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_update_budget_success() {
+        // Arrange
+        let user_id = 3;
+        let budget_id = 1;
+        let edit_budget = EditBudget::new(200000);
+
+        let mut mock_repo = MockUpdateBudgetRepoImpl::new();
+        mock_repo
+            .expect_update_budget()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(budget_id),
+                predicate::eq(200000),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        // Act
+        let result = update_budget(&mock_repo, user_id, budget_id, edit_budget).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
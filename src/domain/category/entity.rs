@@ -1,8 +1,8 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::global::constants::FieldUpdate;
 
-#[derive(Debug, sqlx::FromRow, PartialEq, Clone, Serialize)]
+#[derive(Debug, sqlx::FromRow, PartialEq, Clone, Serialize, Deserialize)]
 pub struct BaseCategory {
     id: Option<i16>,
     type_id: i16,
@@ -39,7 +39,7 @@ impl BaseCategory {
     }
 
     pub fn get_id(&self) -> i16 {
-        self.id.unwrap()
+        self.id.unwrap_or(-1)
     }
     pub fn get_type_id(&self) -> i16 {
         self.type_id
@@ -61,7 +61,7 @@ impl BaseCategory {
     }
 }
 
-#[derive(Debug, sqlx::FromRow, PartialEq, Serialize)]
+#[derive(Debug, sqlx::FromRow, PartialEq, Clone, Serialize, Deserialize)]
 pub struct SubCategory {
     id: Option<i32>,
     base_id: i16,
@@ -83,7 +83,7 @@ impl SubCategory {
     }
 
     pub fn get_id(&self) -> i32 {
-        self.id.unwrap()
+        self.id.unwrap_or(-1)
     }
 
     pub fn get_base_id(&self) -> i16 {
@@ -1,4 +1,4 @@
-mod entity;
+pub(crate) mod entity;
 mod handler;
 mod repository;
 mod usecase;
@@ -1,6 +1,6 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, sqlx::FromRow, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, PartialEq, Clone)]
 pub struct Connect {
     id: Option<i32>,
     name: String,
@@ -17,7 +17,7 @@ impl Connect {
     }
 
     pub fn get_id(&self) -> i32 {
-        self.id.unwrap()
+        self.id.unwrap_or(-1)
     }
 
     pub fn get_name(&self) -> &str {
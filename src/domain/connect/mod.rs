@@ -1,7 +1,7 @@
 mod dto {
     pub(super) mod request;
 }
-mod entity;
+pub(crate) mod entity;
 mod handler;
 mod repository;
 mod usecase;
@@ -0,0 +1,108 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded::Serializer;
+
+use crate::{
+    domain::diary::entity::{Diary, UpdateDiary},
+    global::constants::FieldUpdate,
+};
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct NewDiary {
+    book_id: i32,
+    title: Option<String>,
+    content: Option<String>,
+    target_dt: NaiveDateTime,
+}
+
+impl NewDiary {
+    pub fn new(
+        book_id: i32,
+        title: Option<String>,
+        content: Option<String>,
+        target_dt: NaiveDateTime,
+    ) -> Self {
+        Self {
+            book_id,
+            title,
+            content,
+            target_dt,
+        }
+    }
+
+    pub fn to_entity(&self) -> Diary {
+        Diary::new(self.book_id, self.target_dt)
+            .title(self.title.clone())
+            .content(self.content.clone())
+            .build()
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct DiaryParams {
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+}
+
+impl DiaryParams {
+    pub fn new(start_dt: NaiveDate, end_dt: NaiveDate) -> Self {
+        Self { start_dt, end_dt }
+    }
+
+    pub fn get_start_dt(&self) -> &NaiveDate {
+        &self.start_dt
+    }
+    pub fn get_end_dt(&self) -> &NaiveDate {
+        &self.end_dt
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.start_dt <= self.end_dt
+    }
+
+    pub fn encode_param(&self) -> String {
+        Serializer::new(String::new())
+            .append_pair("start_dt", &self.start_dt.format("%Y-%m-%d").to_string())
+            .append_pair("end_dt", &self.end_dt.format("%Y-%m-%d").to_string())
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct EditDiary {
+    title: Option<String>,
+    content: Option<String>,
+    target_dt: Option<NaiveDateTime>,
+}
+
+impl EditDiary {
+    pub fn new(
+        title: Option<String>,
+        content: Option<String>,
+        target_dt: Option<NaiveDateTime>,
+    ) -> Self {
+        Self {
+            title,
+            content,
+            target_dt,
+        }
+    }
+
+    pub fn to_update(self) -> UpdateDiary {
+        let title = match &self.title {
+            Some(v) if v == "NULL" => FieldUpdate::SetNone,
+            Some(v) => FieldUpdate::Set(v.to_string()),
+            None => FieldUpdate::NoChange,
+        };
+        let content = match &self.content {
+            Some(v) if v == "NULL" => FieldUpdate::SetNone,
+            Some(v) => FieldUpdate::Set(v.to_string()),
+            None => FieldUpdate::NoChange,
+        };
+        let target_dt = match self.target_dt {
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        UpdateDiary::new(title, content, target_dt)
+    }
+}
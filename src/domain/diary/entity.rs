@@ -0,0 +1,109 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::global::constants::FieldUpdate;
+
+#[derive(Deserialize, Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct Diary {
+    id: Option<i32>,
+    book_id: i32,
+    title: Option<String>,
+    content: Option<String>,
+    target_dt: NaiveDateTime,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+impl Diary {
+    pub fn new(book_id: i32, target_dt: NaiveDateTime) -> Self {
+        Self {
+            id: None,
+            book_id,
+            title: None,
+            content: None,
+            target_dt,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn id(mut self, id: Option<i32>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn title(mut self, title: Option<String>) -> Self {
+        self.title = title;
+        self
+    }
+
+    pub fn content(mut self, content: Option<String>) -> Self {
+        self.content = content;
+        self
+    }
+
+    pub fn build(&self) -> Self {
+        Self {
+            id: self.id,
+            book_id: self.book_id,
+            title: self.title.clone(),
+            content: self.content.clone(),
+            target_dt: self.target_dt,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    pub fn get_id(&self) -> i32 {
+        self.id.unwrap_or(-1)
+    }
+    pub fn get_book_id(&self) -> i32 {
+        self.book_id
+    }
+    pub fn get_title(&self) -> &Option<String> {
+        &self.title
+    }
+    pub fn get_content(&self) -> &Option<String> {
+        &self.content
+    }
+    pub fn get_target_dt(&self) -> NaiveDateTime {
+        self.target_dt
+    }
+    pub fn get_created_at(&self) -> &Option<NaiveDateTime> {
+        &self.created_at
+    }
+    pub fn get_updated_at(&self) -> &Option<NaiveDateTime> {
+        &self.updated_at
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct UpdateDiary {
+    title: FieldUpdate<String>,
+    content: FieldUpdate<String>,
+    target_dt: FieldUpdate<NaiveDateTime>,
+}
+
+impl UpdateDiary {
+    pub fn new(
+        title: FieldUpdate<String>,
+        content: FieldUpdate<String>,
+        target_dt: FieldUpdate<NaiveDateTime>,
+    ) -> Self {
+        Self {
+            title,
+            content,
+            target_dt,
+        }
+    }
+
+    pub fn get_title(&self) -> &FieldUpdate<String> {
+        &self.title
+    }
+    pub fn get_content(&self) -> &FieldUpdate<String> {
+        &self.content
+    }
+    pub fn get_target_dt(&self) -> &FieldUpdate<NaiveDateTime> {
+        &self.target_dt
+    }
+}
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use axum::{response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::diary::{dto::request::NewDiary, usecase::create::CreateDiaryUsecase};
+
+pub async fn create_diary<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Json(new_diary): Json<NewDiary>,
+) -> impl IntoResponse
+where
+    T: CreateDiaryUsecase,
+{
+    match usecase.create_diary(user_id, new_diary).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "성공", "diary_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, extract::Request, routing::post, Extension, Router};
+    use chrono::NaiveDateTime;
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::create_diary;
+    use crate::{
+        domain::diary::{dto::request::NewDiary, usecase::create::CreateDiaryUsecase},
+        global::errors::CustomError,
+    };
+
+    mock! {
+        CreateDiaryUsecaseImpl {}
+
+        #[async_trait]
+        impl CreateDiaryUsecase for CreateDiaryUsecaseImpl {
+            async fn create_diary(&self, user_id: i32, new_diary: NewDiary) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn test_target_dt() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2024-09-23 21:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockCreateDiaryUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/diary",
+                post(create_diary::<MockCreateDiaryUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_req(new_diary: &NewDiary) -> Request<String> {
+        Request::builder()
+            .method("POST")
+            .uri("/api/v1/diary")
+            .header("content-type", "application/json")
+            .body(to_string(&new_diary).unwrap())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_create_diary_status() {
+        // Arrange
+        let user_id = 1;
+        let new_diary = NewDiary::new(
+            1,
+            Some("첫 일기".to_string()),
+            Some("감자탕 먹은 날".to_string()),
+            test_target_dt(),
+        );
+
+        let mut mock_usecase = MockCreateDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_create_diary()
+            .with(predicate::eq(user_id), predicate::eq(new_diary.clone()))
+            .returning(|_, _| Ok(1));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_diary);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 201);
+    }
+
+    #[tokio::test]
+    async fn check_create_diary_body() {
+        // Arrange
+        let user_id = 1;
+        let new_diary = NewDiary::new(
+            1,
+            Some("첫 일기".to_string()),
+            Some("감자탕 먹은 날".to_string()),
+            test_target_dt(),
+        );
+
+        let mut mock_usecase = MockCreateDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_create_diary()
+            .with(predicate::eq(user_id), predicate::eq(new_diary.clone()))
+            .returning(|_, _| Ok(1));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_diary);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json["diary_id"], 1);
+    }
+
+    #[tokio::test]
+    async fn check_no_role() {
+        // Arrange
+        let user_id = 1;
+        let new_diary = NewDiary::new(1, None, None, test_target_dt());
+
+        let mut mock_usecase = MockCreateDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_create_diary()
+            .with(predicate::eq(user_id), predicate::eq(new_diary.clone()))
+            .returning(|_, _| Err(Box::new(CustomError::Unauthorized("DiaryRole".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_diary);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+}
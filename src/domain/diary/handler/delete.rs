@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::diary::usecase::delete::DeleteDiaryUsecase;
+
+pub async fn delete_diary<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(diary_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: DeleteDiaryUsecase,
+{
+    match usecase.delete_diary(user_id, diary_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::delete, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::diary::{handler::delete::delete_diary, usecase::delete::DeleteDiaryUsecase},
+        global::errors::CustomError,
+    };
+
+    mock! {
+        DeleteDiaryUsecaseImpl {}
+
+        #[async_trait]
+        impl DeleteDiaryUsecase for DeleteDiaryUsecaseImpl {
+            async fn delete_diary(&self, user_id: i32, diary_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockDeleteDiaryUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/diary/:diary_id",
+                delete(delete_diary::<MockDeleteDiaryUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(diary_id: i32) -> Request {
+        Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/v1/diary/{}", diary_id))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_delete_diary_status() {
+        // Arrange
+        let user_id = 1;
+        let diary_id = 1;
+        let mut mock_usecase = MockDeleteDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_diary()
+            .with(predicate::eq(user_id), predicate::eq(diary_id))
+            .returning(|_, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(diary_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_delete_diary_body() {
+        // Arrange
+        let user_id = 1;
+        let diary_id = 1;
+        let mut mock_usecase = MockDeleteDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_diary()
+            .with(predicate::eq(user_id), predicate::eq(diary_id))
+            .returning(|_, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(diary_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["message"], "성공");
+    }
+
+    #[tokio::test]
+    async fn check_id_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+        let mut mock_usecase = MockDeleteDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_diary()
+            .with(predicate::eq(user_id), predicate::eq(no_id))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Diary".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(no_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404);
+    }
+}
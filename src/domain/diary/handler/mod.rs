@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{delete, get, patch, post},
+    Extension, Router,
+};
+
+use sqlx::PgPool;
+
+mod create;
+mod delete;
+mod read;
+mod update;
+
+use create::create_diary;
+use delete::delete_diary;
+use read::{read_diaries, read_diary};
+use update::update_diary;
+
+use super::{
+    repository::{
+        delete::DeleteDiaryRepoImpl, get_diary::GetDiaryRepoImpl, save::SaveDiaryRepoImpl,
+        update::UpdateDiaryRepoImpl,
+    },
+    usecase::{
+        create::CreateDiaryUsecaseImpl, delete::DeleteDiaryUsecaseImpl, read::ReadDiaryUsecaseImpl,
+        update::UpdateDiaryUsecaseImpl,
+    },
+};
+
+pub fn create_router(pool: &Arc<PgPool>) -> Router {
+    let repository = SaveDiaryRepoImpl::new(pool);
+    let usecase = CreateDiaryUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/",
+            post(create_diary::<CreateDiaryUsecaseImpl<SaveDiaryRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn read_router(pool: &Arc<PgPool>) -> Router {
+    let repository = GetDiaryRepoImpl::new(pool);
+    let usecase = ReadDiaryUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/list/:book_id",
+            get(read_diaries::<ReadDiaryUsecaseImpl<GetDiaryRepoImpl>>),
+        )
+        .route(
+            "/:diary_id",
+            get(read_diary::<ReadDiaryUsecaseImpl<GetDiaryRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn update_router(pool: &Arc<PgPool>) -> Router {
+    let repository = UpdateDiaryRepoImpl::new(pool);
+    let usecase = UpdateDiaryUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:diary_id",
+            patch(update_diary::<UpdateDiaryUsecaseImpl<UpdateDiaryRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn delete_router(pool: &Arc<PgPool>) -> Router {
+    let repository = DeleteDiaryRepoImpl::new(pool);
+    let usecase = DeleteDiaryUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:diary_id",
+            delete(delete_diary::<DeleteDiaryUsecaseImpl<DeleteDiaryRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    response::IntoResponse,
+    Extension, Json,
+};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::diary::{dto::request::DiaryParams, usecase::read::ReadDiaryUsecase},
+    global::errors::CustomError,
+};
+
+pub async fn read_diaries<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    params: Query<DiaryParams>,
+) -> impl IntoResponse
+where
+    T: ReadDiaryUsecase,
+{
+    let params = params.0;
+    if !params.is_valid() {
+        return CustomError::ValidationError("DiaryPeriod".to_string()).into_response();
+    }
+
+    match usecase.read_diaries(user_id, book_id, params).await {
+        Ok(diaries) => (StatusCode::OK, Json(json!(diaries))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+pub async fn read_diary<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(diary_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: ReadDiaryUsecase,
+{
+    match usecase.read_diary(user_id, diary_id).await {
+        Ok(diary) => (StatusCode::OK, Json(json!(diary))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::get, Extension, Router};
+    use chrono::{NaiveDate, NaiveDateTime};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::{read_diaries, read_diary};
+    use crate::{
+        domain::diary::{
+            dto::request::DiaryParams, entity::Diary, usecase::read::ReadDiaryUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        ReadDiaryUsecaseImpl {}
+
+        #[async_trait]
+        impl ReadDiaryUsecase for ReadDiaryUsecaseImpl {
+            async fn read_diaries(&self, user_id: i32, book_id: i32, params: DiaryParams) -> Result<Vec<Diary>, Box<CustomError>>;
+            async fn read_diary(&self, user_id: i32, diary_id: i32) -> Result<Diary, Box<CustomError>>;
+        }
+    }
+
+    fn test_diary(id: i32) -> Diary {
+        Diary::new(
+            1,
+            NaiveDateTime::parse_from_str("2024-09-23 21:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+        )
+        .id(Some(id))
+        .title(Some("첫 일기".to_string()))
+        .build()
+    }
+
+    fn test_params(start_dt: &str, end_dt: &str) -> DiaryParams {
+        DiaryParams::new(
+            NaiveDate::parse_from_str(start_dt, "%Y-%m-%d").unwrap(),
+            NaiveDate::parse_from_str(end_dt, "%Y-%m-%d").unwrap(),
+        )
+    }
+
+    fn _create_list_app(user_id: i32, mock_usecase: MockReadDiaryUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/diary/list/:book_id",
+                get(read_diaries::<MockReadDiaryUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_list_req(book_id: i32, params: &DiaryParams) -> Request {
+        Request::builder()
+            .method("GET")
+            .uri(format!(
+                "/api/v1/diary/list/{}?{}",
+                book_id,
+                params.encode_param()
+            ))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockReadDiaryUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/diary/:diary_id",
+                get(read_diary::<MockReadDiaryUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_req(diary_id: i32) -> Request {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/api/v1/diary/{}", diary_id))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_diaries_body() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let params = test_params("2024-09-01", "2024-09-30");
+
+        let mut mock_usecase = MockReadDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_read_diaries()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(params.clone()),
+            )
+            .returning(|_, _, _| Ok(vec![test_diary(1), test_diary(2)]));
+
+        let app = _create_list_app(user_id, mock_usecase);
+        let req = _create_list_req(book_id, &params);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json[1]["id"], 2);
+        assert_eq!(body_json[0]["title"], "첫 일기");
+    }
+
+    #[tokio::test]
+    async fn check_invalid_period() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let params = test_params("2024-09-30", "2024-09-01");
+
+        let mut mock_usecase = MockReadDiaryUsecaseImpl::new();
+        mock_usecase.expect_read_diaries().never();
+
+        let app = _create_list_app(user_id, mock_usecase);
+        let req = _create_list_req(book_id, &params);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn check_read_diary_body() {
+        // Arrange
+        let user_id = 1;
+        let diary_id = 1;
+        let mut mock_usecase = MockReadDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_read_diary()
+            .with(predicate::eq(user_id), predicate::eq(diary_id))
+            .returning(|_, i| Ok(test_diary(i)));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(diary_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["id"], diary_id);
+    }
+
+    #[tokio::test]
+    async fn check_read_diary_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+        let mut mock_usecase = MockReadDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_read_diary()
+            .with(predicate::eq(user_id), predicate::eq(no_id))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Diary".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(no_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404);
+    }
+}
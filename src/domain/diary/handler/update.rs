@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::diary::{dto::request::EditDiary, usecase::update::UpdateDiaryUsecase};
+
+pub async fn update_diary<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(diary_id): Path<i32>,
+    Json(edit_diary): Json<EditDiary>,
+) -> impl IntoResponse
+where
+    T: UpdateDiaryUsecase,
+{
+    match usecase.update_diary(user_id, diary_id, edit_diary).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::patch, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::diary::{dto::request::EditDiary, usecase::update::UpdateDiaryUsecase},
+        global::errors::CustomError,
+    };
+
+    use super::update_diary;
+
+    mock! {
+        UpdateDiaryUsecaseImpl {}
+
+        #[async_trait]
+        impl UpdateDiaryUsecase for UpdateDiaryUsecaseImpl {
+            async fn update_diary(&self, user_id: i32, diary_id: i32, edit_diary: EditDiary) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockUpdateDiaryUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/diary/:diary_id",
+                patch(update_diary::<MockUpdateDiaryUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(diary_id: i32, edit_diary: &EditDiary) -> Request {
+        Request::builder()
+            .method("PATCH")
+            .uri(format!("/api/v1/diary/{}", diary_id))
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(edit_diary).unwrap()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_update_diary_status() {
+        // Arrange
+        let user_id = 1;
+        let diary_id = 1;
+        let edit_diary = EditDiary::new(
+            Some("NULL".to_string()),
+            Some("일기 내용 수정".to_string()),
+            None,
+        );
+
+        let mut mock_usecase = MockUpdateDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_update_diary()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(diary_id),
+                predicate::eq(edit_diary.clone()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(diary_id, &edit_diary);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_update_diary_body() {
+        // Arrange
+        let user_id = 1;
+        let diary_id = 1;
+        let edit_diary = EditDiary::new(
+            Some("NULL".to_string()),
+            Some("일기 내용 수정".to_string()),
+            None,
+        );
+
+        let mut mock_usecase = MockUpdateDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_update_diary()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(diary_id),
+                predicate::eq(edit_diary.clone()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(diary_id, &edit_diary);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json["message"], "성공")
+    }
+
+    #[tokio::test]
+    async fn check_update_diary_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+        let edit_diary = EditDiary::new(None, Some("일기 내용 수정".to_string()), None);
+
+        let mut mock_usecase = MockUpdateDiaryUsecaseImpl::new();
+        mock_usecase
+            .expect_update_diary()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(no_id),
+                predicate::eq(edit_diary.clone()),
+            )
+            .returning(|_, _, _| Err(Box::new(CustomError::NotFound("Diary".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(no_id, &edit_diary);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404)
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::global::{constants::DeleteResult, errors::CustomError};
+
+pub struct DeleteDiaryRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait DeleteDiaryRepo: Send + Sync {
+    async fn delete_diary(&self, user_id: i32, diary_id: i32) -> Result<(), Box<CustomError>>;
+}
+
+impl DeleteDiaryRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl DeleteDiaryRepo for DeleteDiaryRepoImpl {
+    async fn delete_diary(&self, user_id: i32, diary_id: i32) -> Result<(), Box<CustomError>> {
+        delete_diary(&self.pool, user_id, diary_id).await
+    }
+}
+
+async fn delete_diary(pool: &PgPool, user_id: i32, diary_id: i32) -> Result<(), Box<CustomError>> {
+    let result = sqlx::query_as::<_, DeleteResult>(
+        r"
+        WITH DiaryExists AS (
+            SELECT book_id
+            FROM tb_diary
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT d.book_id
+            FROM DiaryExists AS d
+            JOIN tb_user_book_role AS br ON d.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role != 'viewer'
+        ),
+        DeleteDiary AS (
+            DELETE FROM tb_diary
+            WHERE id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM DiaryExists) AS is_exist,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT COUNT(*) FROM DeleteDiary) AS delete_count
+        ",
+    )
+    .bind(user_id)
+    .bind(diary_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(DeleteDiary {}): {:?}", diary_id, &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Diary".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("DiaryRole".to_string())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::diary::{
+            entity::Diary,
+            repository::{get_diary::get_by_id, save::save_diary},
+        },
+        global::errors::CustomError,
+    };
+
+    use super::delete_diary;
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_delete_diary_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let diary = Diary::new(
+            1,
+            NaiveDateTime::parse_from_str("2024-09-08 21:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+        );
+
+        let new_id = save_diary(&pool, user_id, diary).await.unwrap();
+
+        // Act
+        let result = delete_diary(&pool, user_id, new_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, new_id).await;
+        assert!(row.is_err())
+    }
+
+    #[tokio::test]
+    async fn check_diary_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+
+        // Act
+        let result = delete_diary(&pool, user_id, no_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let viewer_id = 2;
+        let diary_id = 1;
+
+        // Act
+        let result = delete_diary(&pool, viewer_id, diary_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::{domain::diary::entity::Diary, global::errors::CustomError};
+
+pub struct GetDiaryRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait GetDiaryRepo: Send + Sync {
+    async fn get_list(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDate,
+        end_dt: NaiveDate,
+    ) -> Result<Vec<Diary>, Box<CustomError>>;
+    async fn get_by_id(&self, user_id: i32, diary_id: i32) -> Result<Diary, Box<CustomError>>;
+}
+
+impl GetDiaryRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl GetDiaryRepo for GetDiaryRepoImpl {
+    async fn get_list(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDate,
+        end_dt: NaiveDate,
+    ) -> Result<Vec<Diary>, Box<CustomError>> {
+        get_list(&self.pool, user_id, book_id, start_dt, end_dt).await
+    }
+    async fn get_by_id(&self, user_id: i32, diary_id: i32) -> Result<Diary, Box<CustomError>> {
+        get_by_id(&self.pool, user_id, diary_id).await
+    }
+}
+
+async fn get_list(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+) -> Result<Vec<Diary>, Box<CustomError>> {
+    // end_dt 당일까지 포함
+    let rows = sqlx::query_as::<_, Diary>(
+        "
+        SELECT d.*
+        FROM tb_diary AS d
+        JOIN tb_user_book_role AS br ON d.book_id = br.book_id
+        WHERE br.user_id = $1 AND d.book_id = $2
+            AND d.target_dt >= $3 AND d.target_dt < $4::DATE + 1
+        ORDER BY d.target_dt, d.id
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .bind(start_dt)
+    .bind(end_dt)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetDiaries): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn get_by_id(
+    pool: &PgPool,
+    user_id: i32,
+    diary_id: i32,
+) -> Result<Diary, Box<CustomError>> {
+    let row = sqlx::query_as::<_, Diary>(
+        "
+        SELECT d.* FROM tb_diary AS d
+        JOIN tb_user_book_role AS br ON d.book_id = br.book_id
+        WHERE br.user_id = $1 AND d.id = $2
+    ",
+    )
+    .bind(user_id)
+    .bind(diary_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetDiary {}): {:?}", diary_id, &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            sqlx::Error::RowNotFound => CustomError::NotFound("Diary".to_string()),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::diary::repository::get_diary::{get_by_id, get_list},
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_get_list_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2; // viewer
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-23", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-09-23", "%Y-%m-%d").unwrap();
+
+        // Act
+        let result = get_list(&pool, user_id, book_id, start_dt, end_dt).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].get_id(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_get_list_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-09-30", "%Y-%m-%d").unwrap();
+
+        // Act
+        let result = get_list(&pool, user_id, book_id, start_dt, end_dt).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn check_get_by_id_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 1;
+        let diary_id = 1;
+
+        // Act
+        let result = get_by_id(&pool, user_id, diary_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().get_book_id(), 1)
+    }
+
+    #[tokio::test]
+    async fn check_get_by_id_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+
+        // Act
+        let result = get_by_id(&pool, user_id, no_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::{Error, PgPool};
+
+use crate::{domain::diary::entity::Diary, global::errors::CustomError};
+
+pub struct SaveDiaryRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait SaveDiaryRepo: Send + Sync {
+    async fn save_diary(&self, user_id: i32, diary: Diary) -> Result<i32, Box<CustomError>>;
+}
+
+impl SaveDiaryRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl SaveDiaryRepo for SaveDiaryRepoImpl {
+    async fn save_diary(&self, user_id: i32, diary: Diary) -> Result<i32, Box<CustomError>> {
+        save_diary(&self.pool, user_id, diary).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct InsertDiary {
+    is_authorized: bool,
+    diary_id: Option<i32>,
+}
+
+impl InsertDiary {
+    fn get_authorized(&self) -> bool {
+        self.is_authorized
+    }
+    fn get_diary_id(&self) -> Option<i32> {
+        self.diary_id
+    }
+}
+
+pub async fn save_diary(
+    pool: &PgPool,
+    user_id: i32,
+    diary: Diary,
+) -> Result<i32, Box<CustomError>> {
+    let result = sqlx::query_as::<_, InsertDiary>(
+        r#"
+        WITH AuthorityCheck AS (
+            SELECT book_id
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2 AND role != 'viewer'
+        ),
+        InsertDiary AS (
+            INSERT INTO tb_diary (book_id, title, content, target_dt, created_at)
+                SELECT book_id, $3, $4, $5, NOW()
+                    FROM AuthorityCheck
+                    WHERE book_id IS NOT NULL
+            RETURNING id
+        )
+        SELECT
+            (SELECT id FROM InsertDiary) AS diary_id,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized;
+    "#,
+    )
+    .bind(user_id)
+    .bind(diary.get_book_id())
+    .bind(diary.get_title())
+    .bind(diary.get_content())
+    .bind(diary.get_target_dt())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Save(Diary): {:?}", e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("DiaryRole".to_string())));
+    }
+
+    Ok(result.get_diary_id().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::diary::{entity::Diary, repository::save::save_diary},
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_save_diary_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let diary = Diary::new(
+            1,
+            NaiveDateTime::parse_from_str("2024-09-08 21:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+        )
+        .title(Some("장보기".to_string()))
+        .content(Some("생필품 잔뜩 산 날".to_string()));
+
+        // Act
+        let result = save_diary(&pool, user_id, diary).await;
+        let inserted_id = result.map_err(|e| println!("{:?}", e)).unwrap();
+
+        // Assert
+        let row = sqlx::query_as::<_, Diary>("SELECT * FROM tb_diary WHERE id = $1")
+            .bind(inserted_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(inserted_id, row.get_id());
+        assert_eq!(&Some("장보기".to_string()), row.get_title());
+    }
+
+    #[tokio::test]
+    async fn check_book_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let diary = Diary::new(
+            -32, // 존재하지 않는 가계부
+            NaiveDateTime::parse_from_str("2024-09-08 21:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+        );
+
+        // Act
+        let result = save_diary(&pool, user_id, diary).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2;
+        let diary = Diary::new(
+            1, // 읽기전용 가계부
+            NaiveDateTime::parse_from_str("2024-09-08 21:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+        );
+
+        // Act
+        let result = save_diary(&pool, user_id, diary).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
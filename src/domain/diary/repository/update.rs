@@ -0,0 +1,316 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::{
+    domain::diary::entity::UpdateDiary,
+    global::{
+        constants::{FieldUpdate, UpdateResult},
+        errors::CustomError,
+    },
+};
+
+pub struct UpdateDiaryRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait UpdateDiaryRepo: Send + Sync {
+    async fn update_diary(
+        &self,
+        user_id: i32,
+        diary_id: i32,
+        edit_diary: UpdateDiary,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl UpdateDiaryRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl UpdateDiaryRepo for UpdateDiaryRepoImpl {
+    async fn update_diary(
+        &self,
+        user_id: i32,
+        diary_id: i32,
+        edit_diary: UpdateDiary,
+    ) -> Result<(), Box<CustomError>> {
+        update_diary(&self.pool, user_id, diary_id, edit_diary).await
+    }
+}
+
+fn make_query(index: &mut i32, field_name: &str) -> String {
+    *index += 1;
+    format!("{} = ${}, ", field_name, index)
+}
+
+async fn update_diary(
+    pool: &PgPool,
+    user_id: i32,
+    diary_id: i32,
+    edit_diary: UpdateDiary,
+) -> Result<(), Box<CustomError>> {
+    let mut query = r"
+        WITH DiaryExists AS (
+            SELECT book_id
+            FROM tb_diary
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT d.book_id
+            FROM DiaryExists AS d
+            JOIN tb_user_book_role AS br ON d.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role != 'viewer'
+        ),
+        UpdateDiary AS (
+            UPDATE tb_diary SET "
+        .to_string();
+
+    let mut index = 2;
+
+    match edit_diary.get_title() {
+        FieldUpdate::Set(_) | FieldUpdate::SetNone => {
+            query.push_str(&make_query(&mut index, "title"));
+        }
+        _ => {}
+    }
+    match edit_diary.get_content() {
+        FieldUpdate::Set(_) | FieldUpdate::SetNone => {
+            query.push_str(&make_query(&mut index, "content"));
+        }
+        _ => {}
+    }
+    if let FieldUpdate::Set(_) = edit_diary.get_target_dt() {
+        query.push_str(&make_query(&mut index, "target_dt"));
+    };
+
+    if index == 2 {
+        return Err(Box::new(CustomError::NoFieldUpdate("Diary".to_string())));
+    }
+
+    query.push_str(
+        "
+            updated_at = NOW()
+            WHERE id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM DiaryExists) AS is_exist,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            false AS is_duplicated,
+            (SELECT COUNT(*) FROM UpdateDiary) AS update_count;
+        ",
+    );
+
+    let mut query_builder = sqlx::query_as::<_, UpdateResult>(&query)
+        .bind(user_id)
+        .bind(diary_id);
+
+    match edit_diary.get_title() {
+        FieldUpdate::Set(v) => {
+            query_builder = query_builder.bind(v);
+        }
+        FieldUpdate::SetNone => {
+            query_builder = query_builder.bind(None::<String>);
+        }
+        _ => {}
+    }
+    match edit_diary.get_content() {
+        FieldUpdate::Set(v) => {
+            query_builder = query_builder.bind(v);
+        }
+        FieldUpdate::SetNone => {
+            query_builder = query_builder.bind(None::<String>);
+        }
+        _ => {}
+    }
+    if let FieldUpdate::Set(v) = edit_diary.get_target_dt() {
+        query_builder = query_builder.bind(v);
+    }
+
+    let result = query_builder.fetch_one(pool).await.map_err(|e| {
+        let err_msg = format!("Update(Diary {}): {}", diary_id, e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Diary".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("DiaryRole".to_string())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDateTime, Utc};
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::diary::{
+            entity::{Diary, UpdateDiary},
+            repository::{get_diary::get_by_id, save::save_diary, update::update_diary},
+        },
+        global::{constants::FieldUpdate, errors::CustomError},
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    async fn _save_sample(pool: &PgPool, user_id: i32) -> i32 {
+        let diary = Diary::new(
+            1,
+            NaiveDateTime::parse_from_str("2024-09-08 21:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+        )
+        .title(Some("수정용 일기".to_string()));
+        save_diary(pool, user_id, diary).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_update_diary_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let change_dt =
+            NaiveDateTime::parse_from_str("2024-09-09 08:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_diary = UpdateDiary::new(
+            FieldUpdate::SetNone,
+            FieldUpdate::Set("아침에 다시 씀".to_string()),
+            FieldUpdate::Set(change_dt),
+        );
+
+        // Act
+        let result = update_diary(&pool, user_id, new_id, edit_diary).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+
+        assert_eq!(row.get_title(), &None);
+        assert_eq!(row.get_content(), &Some("아침에 다시 씀".to_string()));
+        assert_eq!(row.get_target_dt(), change_dt);
+    }
+
+    #[tokio::test]
+    async fn check_no_field_to_update() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_diary = UpdateDiary::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+
+        // Act
+        let result = update_diary(&pool, user_id, new_id, edit_diary).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NoFieldUpdate(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_diary_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+        let edit_diary = UpdateDiary::new(
+            FieldUpdate::Set("제목".to_string()),
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+
+        // Act
+        let result = update_diary(&pool, user_id, no_id, edit_diary).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let viewer_id = 2;
+        let diary_id = 1;
+        let edit_diary = UpdateDiary::new(
+            FieldUpdate::Set("제목".to_string()),
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+
+        // Act
+        let result = update_diary(&pool, viewer_id, diary_id, edit_diary).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_updated_at_changed() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_diary = UpdateDiary::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::Set("내용 추가".to_string()),
+            FieldUpdate::NoChange,
+        );
+        let last_time = Utc::now().naive_utc();
+
+        // Act
+        let result = update_diary(&pool, user_id, new_id, edit_diary).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let updated_diary = get_by_id(&pool, user_id, new_id).await.unwrap();
+        assert!(last_time < updated_diary.get_updated_at().unwrap())
+    }
+}
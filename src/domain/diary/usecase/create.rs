@@ -0,0 +1,104 @@
+use axum::async_trait;
+
+use crate::{
+    domain::diary::{dto::request::NewDiary, repository::save::SaveDiaryRepo},
+    global::errors::CustomError,
+};
+
+pub struct CreateDiaryUsecaseImpl<T>
+where
+    T: SaveDiaryRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait CreateDiaryUsecase: Send + Sync {
+    async fn create_diary(
+        &self,
+        user_id: i32,
+        new_diary: NewDiary,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl<T> CreateDiaryUsecaseImpl<T>
+where
+    T: SaveDiaryRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> CreateDiaryUsecase for CreateDiaryUsecaseImpl<T>
+where
+    T: SaveDiaryRepo,
+{
+    async fn create_diary(
+        &self,
+        user_id: i32,
+        new_diary: NewDiary,
+    ) -> Result<i32, Box<CustomError>> {
+        create_diary(&self.repository, user_id, new_diary).await
+    }
+}
+
+async fn create_diary<T>(
+    repository: &T,
+    user_id: i32,
+    new_diary: NewDiary,
+) -> Result<i32, Box<CustomError>>
+where
+    T: SaveDiaryRepo,
+{
+    repository.save_diary(user_id, new_diary.to_entity()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::NaiveDateTime;
+    use mockall::{mock, predicate};
+
+    use crate::domain::diary::{
+        dto::request::NewDiary, entity::Diary, repository::save::SaveDiaryRepo,
+        usecase::create::create_diary,
+    };
+    use crate::global::errors::CustomError;
+
+    mock! {
+        SaveDiaryRepoImpl {}
+
+        #[async_trait]
+        impl SaveDiaryRepo for SaveDiaryRepoImpl {
+            async fn save_diary(&self, user_id: i32, diary: Diary) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_create_diary_success() {
+        // Arrange
+        let user_id = 1;
+        let new_diary = NewDiary::new(
+            1,
+            Some("장보기".to_string()),
+            Some("생필품 잔뜩 산 날".to_string()),
+            NaiveDateTime::parse_from_str("2024-09-08 21:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+        );
+
+        let mut mock_repo = MockSaveDiaryRepoImpl::new();
+        mock_repo
+            .expect_save_diary()
+            .with(predicate::eq(user_id), predicate::eq(new_diary.to_entity()))
+            .returning(|_, _| Ok(1));
+
+        // Act
+        let result = create_diary(&mock_repo, user_id, new_diary).await;
+        assert!(result.is_ok());
+        let inserted_id = result.unwrap();
+
+        // Assert
+        assert_eq!(inserted_id, 1);
+    }
+}
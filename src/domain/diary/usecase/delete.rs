@@ -0,0 +1,83 @@
+use axum::async_trait;
+
+use crate::{domain::diary::repository::delete::DeleteDiaryRepo, global::errors::CustomError};
+
+pub struct DeleteDiaryUsecaseImpl<T>
+where
+    T: DeleteDiaryRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait DeleteDiaryUsecase: Send + Sync {
+    async fn delete_diary(&self, user_id: i32, diary_id: i32) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> DeleteDiaryUsecaseImpl<T>
+where
+    T: DeleteDiaryRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> DeleteDiaryUsecase for DeleteDiaryUsecaseImpl<T>
+where
+    T: DeleteDiaryRepo,
+{
+    async fn delete_diary(&self, user_id: i32, diary_id: i32) -> Result<(), Box<CustomError>> {
+        delete_diary(&self.repository, user_id, diary_id).await
+    }
+}
+
+async fn delete_diary<T>(
+    repository: &T,
+    user_id: i32,
+    diary_id: i32,
+) -> Result<(), Box<CustomError>>
+where
+    T: DeleteDiaryRepo,
+{
+    repository.delete_diary(user_id, diary_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{domain::diary::repository::delete::DeleteDiaryRepo, global::errors::CustomError};
+
+    use super::delete_diary;
+
+    mock! {
+        DeleteDiaryRepoImpl {}
+
+        #[async_trait]
+        impl DeleteDiaryRepo for DeleteDiaryRepoImpl{
+            async fn delete_diary(&self, user_id: i32, diary_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_delete_diary_success() {
+        // Arrange
+        let user_id = 1;
+        let diary_id = 1;
+
+        let mut mock_repo = MockDeleteDiaryRepoImpl::new();
+        mock_repo
+            .expect_delete_diary()
+            .with(predicate::eq(user_id), predicate::eq(diary_id))
+            .returning(|_, _| Ok(()));
+
+        // Act
+        let result = delete_diary(&mock_repo, user_id, diary_id).await;
+
+        // Assert
+        assert!(result.is_ok())
+    }
+}
@@ -0,0 +1,175 @@
+use axum::async_trait;
+
+use crate::{
+    domain::diary::{
+        dto::request::DiaryParams, entity::Diary, repository::get_diary::GetDiaryRepo,
+    },
+    global::errors::CustomError,
+};
+
+pub struct ReadDiaryUsecaseImpl<T>
+where
+    T: GetDiaryRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait ReadDiaryUsecase: Send + Sync {
+    async fn read_diaries(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: DiaryParams,
+    ) -> Result<Vec<Diary>, Box<CustomError>>;
+    async fn read_diary(&self, user_id: i32, diary_id: i32) -> Result<Diary, Box<CustomError>>;
+}
+
+impl<T> ReadDiaryUsecaseImpl<T>
+where
+    T: GetDiaryRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> ReadDiaryUsecase for ReadDiaryUsecaseImpl<T>
+where
+    T: GetDiaryRepo,
+{
+    async fn read_diaries(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: DiaryParams,
+    ) -> Result<Vec<Diary>, Box<CustomError>> {
+        read_diaries(&self.repository, user_id, book_id, params).await
+    }
+
+    async fn read_diary(&self, user_id: i32, diary_id: i32) -> Result<Diary, Box<CustomError>> {
+        read_diary(&self.repository, user_id, diary_id).await
+    }
+}
+
+async fn read_diaries<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    params: DiaryParams,
+) -> Result<Vec<Diary>, Box<CustomError>>
+where
+    T: GetDiaryRepo,
+{
+    repository
+        .get_list(
+            user_id,
+            book_id,
+            *params.get_start_dt(),
+            *params.get_end_dt(),
+        )
+        .await
+}
+
+async fn read_diary<T>(
+    repository: &T,
+    user_id: i32,
+    diary_id: i32,
+) -> Result<Diary, Box<CustomError>>
+where
+    T: GetDiaryRepo,
+{
+    repository.get_by_id(user_id, diary_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::diary::{
+            dto::request::DiaryParams,
+            entity::Diary,
+            repository::get_diary::GetDiaryRepo,
+            usecase::read::{read_diaries, read_diary},
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        GetDiaryRepoImpl {}
+
+        #[async_trait]
+        impl GetDiaryRepo for GetDiaryRepoImpl {
+            async fn get_list(&self, user_id: i32, book_id: i32, start_dt: NaiveDate, end_dt: NaiveDate) -> Result<Vec<Diary>, Box<CustomError>>;
+            async fn get_by_id(&self, user_id: i32, diary_id: i32) -> Result<Diary, Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_read_diaries_success() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-09-30", "%Y-%m-%d").unwrap();
+        let params = DiaryParams::new(start_dt, end_dt);
+
+        let mut mock_repo = MockGetDiaryRepoImpl::new();
+        mock_repo
+            .expect_get_list()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(start_dt),
+                predicate::eq(end_dt),
+            )
+            .returning(|_, b, _, _| {
+                Ok(vec![Diary::new(
+                    b,
+                    NaiveDateTime::parse_from_str("2024-09-23 21:00:00", "%Y-%m-%d %H:%M:%S")
+                        .unwrap(),
+                )
+                .id(Some(1))
+                .build()])
+            });
+
+        // Act
+        let result = read_diaries(&mock_repo, user_id, book_id, params).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_read_diary_success() {
+        // Arrange
+        let user_id = 1;
+        let diary_id = 1;
+
+        let mut mock_repo = MockGetDiaryRepoImpl::new();
+        mock_repo
+            .expect_get_by_id()
+            .with(predicate::eq(user_id), predicate::eq(diary_id))
+            .returning(|_, i| {
+                Ok(Diary::new(
+                    1,
+                    NaiveDateTime::parse_from_str("2024-09-23 21:00:00", "%Y-%m-%d %H:%M:%S")
+                        .unwrap(),
+                )
+                .id(Some(i))
+                .build())
+            });
+
+        // Act
+        let result = read_diary(&mock_repo, user_id, diary_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().get_id(), diary_id);
+    }
+}
@@ -0,0 +1,113 @@
+use axum::async_trait;
+
+use crate::{
+    domain::diary::{dto::request::EditDiary, repository::update::UpdateDiaryRepo},
+    global::errors::CustomError,
+};
+
+pub struct UpdateDiaryUsecaseImpl<T>
+where
+    T: UpdateDiaryRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait UpdateDiaryUsecase: Send + Sync {
+    async fn update_diary(
+        &self,
+        user_id: i32,
+        diary_id: i32,
+        edit_diary: EditDiary,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> UpdateDiaryUsecaseImpl<T>
+where
+    T: UpdateDiaryRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> UpdateDiaryUsecase for UpdateDiaryUsecaseImpl<T>
+where
+    T: UpdateDiaryRepo,
+{
+    async fn update_diary(
+        &self,
+        user_id: i32,
+        diary_id: i32,
+        edit_diary: EditDiary,
+    ) -> Result<(), Box<CustomError>> {
+        update_diary(&self.repository, user_id, diary_id, edit_diary).await
+    }
+}
+
+async fn update_diary<T>(
+    repository: &T,
+    user_id: i32,
+    diary_id: i32,
+    edit_diary: EditDiary,
+) -> Result<(), Box<CustomError>>
+where
+    T: UpdateDiaryRepo,
+{
+    repository
+        .update_diary(user_id, diary_id, edit_diary.to_update())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::diary::{
+            dto::request::EditDiary, entity::UpdateDiary, repository::update::UpdateDiaryRepo,
+        },
+        global::errors::CustomError,
+    };
+
+    use super::update_diary;
+
+    mock! {
+        UpdateDiaryRepoImpl {}
+
+        #[async_trait]
+        impl UpdateDiaryRepo for UpdateDiaryRepoImpl {
+            async fn update_diary(&self, user_id: i32, diary_id: i32, edit_diary: UpdateDiary) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_update_diary_success() {
+        // Arrange
+        let edit_diary = EditDiary::new(
+            Some("NULL".to_string()),
+            Some("일기 내용 수정".to_string()),
+            None,
+        );
+        let user_id = 1;
+        let diary_id = 1;
+
+        let mut mock_repo = MockUpdateDiaryRepoImpl::new();
+        mock_repo
+            .expect_update_diary()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(diary_id),
+                predicate::eq(edit_diary.clone().to_update()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        // Act
+        let result = update_diary(&mock_repo, user_id, diary_id, edit_diary).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+}
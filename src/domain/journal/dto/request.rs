@@ -0,0 +1,136 @@
+use chrono::{Days, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::journal::entity::{JournalEntry, JournalLine},
+    global::errors::CustomError,
+};
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct NewJournalLine {
+    account_id: i32,
+    debit: Option<i64>,
+    credit: Option<i64>,
+}
+
+impl NewJournalLine {
+    pub fn new(account_id: i32, debit: Option<i64>, credit: Option<i64>) -> Self {
+        Self {
+            account_id,
+            debit,
+            credit,
+        }
+    }
+}
+
+/// 분개: 줄마다 차변, 대변 중 한쪽만 입력, 차변 합계와 대변 합계가 같아야 함
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct NewJournalEntry {
+    memo: Option<String>,
+    target_dt: NaiveDateTime,
+    lines: Vec<NewJournalLine>,
+}
+
+impl NewJournalEntry {
+    pub fn new(memo: Option<String>, target_dt: NaiveDateTime, lines: Vec<NewJournalLine>) -> Self {
+        Self {
+            memo,
+            target_dt,
+            lines,
+        }
+    }
+
+    pub fn to_entity(&self) -> Result<JournalEntry, Box<CustomError>> {
+        let invalid = || Box::new(CustomError::ValidationError("JournalEntry".to_string()));
+
+        if self.lines.len() < 2 {
+            return Err(invalid());
+        }
+        if let Some(memo) = &self.memo {
+            if memo.chars().count() > 32 {
+                return Err(invalid());
+            }
+        }
+
+        let lines = self
+            .lines
+            .iter()
+            .map(
+                |line| match (line.debit.unwrap_or(0), line.credit.unwrap_or(0)) {
+                    (debit, 0) if debit > 0 => Ok(JournalLine::new(line.account_id, debit, 0)),
+                    (0, credit) if credit > 0 => Ok(JournalLine::new(line.account_id, 0, credit)),
+                    _ => Err(invalid()),
+                },
+            )
+            .collect::<Result<Vec<JournalLine>, Box<CustomError>>>()?;
+
+        let debit = lines
+            .iter()
+            .try_fold(0i64, |sum, line| sum.checked_add(line.get_debit()));
+        let credit = lines
+            .iter()
+            .try_fold(0i64, |sum, line| sum.checked_add(line.get_credit()));
+        match (debit, credit) {
+            (Some(debit), Some(credit)) if debit == credit => {}
+            _ => return Err(invalid()),
+        }
+
+        Ok(JournalEntry::new(self.memo.clone(), self.target_dt, lines))
+    }
+}
+
+/// 조회 기간, 종료일 포함
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct JournalPeriod {
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+}
+
+impl JournalPeriod {
+    pub fn new(start_dt: NaiveDate, end_dt: NaiveDate) -> Self {
+        Self { start_dt, end_dt }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.start_dt <= self.end_dt
+    }
+
+    pub fn get_start_dt(&self) -> NaiveDate {
+        self.start_dt
+    }
+    pub fn get_end_dt(&self) -> NaiveDate {
+        self.end_dt
+    }
+
+    // [시작일 0시, 종료일 다음 날 0시)
+    pub fn get_range(&self) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let end_dt = self.end_dt.checked_add_days(Days::new(1))?;
+        Some((
+            self.start_dt.and_hms_opt(0, 0, 0)?,
+            end_dt.and_hms_opt(0, 0, 0)?,
+        ))
+    }
+}
+
+/// 재무상태표 기준일, 기준일까지의 누적 잔액
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct BaseDate {
+    base_dt: NaiveDate,
+}
+
+impl BaseDate {
+    pub fn new(base_dt: NaiveDate) -> Self {
+        Self { base_dt }
+    }
+
+    pub fn get_base_dt(&self) -> NaiveDate {
+        self.base_dt
+    }
+
+    // 기준일 다음 날 0시
+    pub fn get_end(&self) -> Option<NaiveDateTime> {
+        self.base_dt
+            .checked_add_days(Days::new(1))?
+            .and_hms_opt(0, 0, 0)
+    }
+}
@@ -0,0 +1,219 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct EntryLineInfo {
+    account_id: i32,
+    code: String,
+    name: String,
+    debit: i64,
+    credit: i64,
+}
+
+impl EntryLineInfo {
+    pub fn new(account_id: i32, code: &str, name: &str, debit: i64, credit: i64) -> Self {
+        Self {
+            account_id,
+            code: code.to_string(),
+            name: name.to_string(),
+            debit,
+            credit,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct EntryInfo {
+    id: i64,
+    memo: Option<String>,
+    target_dt: NaiveDateTime,
+    lines: Vec<EntryLineInfo>,
+}
+
+impl EntryInfo {
+    pub fn new(
+        id: i64,
+        memo: Option<&str>,
+        target_dt: NaiveDateTime,
+        lines: Vec<EntryLineInfo>,
+    ) -> Self {
+        Self {
+            id,
+            memo: memo.map(|m| m.to_string()),
+            target_dt,
+            lines,
+        }
+    }
+
+    pub fn get_id(&self) -> i64 {
+        self.id
+    }
+    pub fn get_lines(&self) -> &[EntryLineInfo] {
+        &self.lines
+    }
+}
+
+/// 계정별 차변, 대변 합계와 정상 잔액
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct AccountBalance {
+    account_id: i32,
+    code: String,
+    name: String,
+    class: String,
+    debit: i64,
+    credit: i64,
+    balance: i64,
+}
+
+impl AccountBalance {
+    pub fn new(
+        account_id: i32,
+        code: &str,
+        name: &str,
+        class: &str,
+        debit: i64,
+        credit: i64,
+        balance: i64,
+    ) -> Self {
+        Self {
+            account_id,
+            code: code.to_string(),
+            name: name.to_string(),
+            class: class.to_string(),
+            debit,
+            credit,
+            balance,
+        }
+    }
+
+    pub fn get_balance(&self) -> i64 {
+        self.balance
+    }
+}
+
+/// 시산표
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct TrialBalance {
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+    accounts: Vec<AccountBalance>,
+    total_debit: i64,
+    total_credit: i64,
+}
+
+impl TrialBalance {
+    pub fn new(start_dt: NaiveDate, end_dt: NaiveDate, accounts: Vec<AccountBalance>) -> Self {
+        let total_debit = accounts.iter().map(|a| a.debit).sum();
+        let total_credit = accounts.iter().map(|a| a.credit).sum();
+        Self {
+            start_dt,
+            end_dt,
+            accounts,
+            total_debit,
+            total_credit,
+        }
+    }
+
+    pub fn get_accounts(&self) -> &[AccountBalance] {
+        &self.accounts
+    }
+    pub fn get_total_debit(&self) -> i64 {
+        self.total_debit
+    }
+    pub fn get_total_credit(&self) -> i64 {
+        self.total_credit
+    }
+}
+
+/// 손익계산서, 당기순이익 = 수익 - 비용
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct IncomeStatement {
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+    revenues: Vec<AccountBalance>,
+    expenses: Vec<AccountBalance>,
+    total_revenue: i64,
+    total_expense: i64,
+    net_income: i64,
+}
+
+impl IncomeStatement {
+    pub fn new(
+        start_dt: NaiveDate,
+        end_dt: NaiveDate,
+        revenues: Vec<AccountBalance>,
+        expenses: Vec<AccountBalance>,
+    ) -> Self {
+        let total_revenue = revenues.iter().map(|a| a.balance).sum();
+        let total_expense = expenses.iter().map(|a| a.balance).sum();
+        Self {
+            start_dt,
+            end_dt,
+            revenues,
+            expenses,
+            total_revenue,
+            total_expense,
+            net_income: total_revenue - total_expense,
+        }
+    }
+
+    pub fn get_total_revenue(&self) -> i64 {
+        self.total_revenue
+    }
+    pub fn get_total_expense(&self) -> i64 {
+        self.total_expense
+    }
+    pub fn get_net_income(&self) -> i64 {
+        self.net_income
+    }
+}
+
+/// 재무상태표, 자본 합계에 기준일까지의 누적 순이익 포함
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BalanceSheet {
+    base_dt: NaiveDate,
+    assets: Vec<AccountBalance>,
+    liabilities: Vec<AccountBalance>,
+    equities: Vec<AccountBalance>,
+    net_income: i64,
+    total_asset: i64,
+    total_liability: i64,
+    total_equity: i64,
+}
+
+impl BalanceSheet {
+    pub fn new(
+        base_dt: NaiveDate,
+        assets: Vec<AccountBalance>,
+        liabilities: Vec<AccountBalance>,
+        equities: Vec<AccountBalance>,
+        net_income: i64,
+    ) -> Self {
+        let total_asset = assets.iter().map(|a| a.balance).sum();
+        let total_liability = liabilities.iter().map(|a| a.balance).sum();
+        let total_equity = equities.iter().map(|a| a.balance).sum::<i64>() + net_income;
+        Self {
+            base_dt,
+            assets,
+            liabilities,
+            equities,
+            net_income,
+            total_asset,
+            total_liability,
+            total_equity,
+        }
+    }
+
+    pub fn get_net_income(&self) -> i64 {
+        self.net_income
+    }
+    pub fn get_total_asset(&self) -> i64 {
+        self.total_asset
+    }
+    pub fn get_total_liability(&self) -> i64 {
+        self.total_liability
+    }
+    pub fn get_total_equity(&self) -> i64 {
+        self.total_equity
+    }
+}
@@ -0,0 +1,236 @@
+use std::{fmt, str::FromStr};
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+use crate::global::errors::CustomError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccountClass {
+    Asset,
+    Liability,
+    Equity,
+    Revenue,
+    Expense,
+}
+
+impl AccountClass {
+    // 자산, 비용은 차변, 부채, 자본, 수익은 대변이 증가
+    pub fn is_debit_normal(&self) -> bool {
+        matches!(self, AccountClass::Asset | AccountClass::Expense)
+    }
+}
+
+impl FromStr for AccountClass {
+    type Err = Box<CustomError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "asset" => Ok(AccountClass::Asset),
+            "liability" => Ok(AccountClass::Liability),
+            "equity" => Ok(AccountClass::Equity),
+            "revenue" => Ok(AccountClass::Revenue),
+            "expense" => Ok(AccountClass::Expense),
+            _ => Err(Box::new(CustomError::ValidationError(
+                "AccountClass".to_string(),
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for AccountClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let class = match self {
+            AccountClass::Asset => "asset",
+            AccountClass::Liability => "liability",
+            AccountClass::Equity => "equity",
+            AccountClass::Revenue => "revenue",
+            AccountClass::Expense => "expense",
+        };
+        write!(f, "{}", class)
+    }
+}
+
+/// 계정과목
+#[derive(Debug, Serialize, sqlx::FromRow, Clone, PartialEq)]
+pub struct Account {
+    id: i32,
+    code: String,
+    name: String,
+    class: String,
+}
+
+impl Account {
+    pub fn new(id: i32, code: &str, name: &str, class: AccountClass) -> Self {
+        Self {
+            id,
+            code: code.to_string(),
+            name: name.to_string(),
+            class: class.to_string(),
+        }
+    }
+}
+
+/// 분개 한 줄, 차변과 대변 중 한쪽만 금액이 있음
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalLine {
+    account_id: i32,
+    debit: i64,
+    credit: i64,
+}
+
+impl JournalLine {
+    pub fn new(account_id: i32, debit: i64, credit: i64) -> Self {
+        Self {
+            account_id,
+            debit,
+            credit,
+        }
+    }
+
+    pub fn get_account_id(&self) -> i32 {
+        self.account_id
+    }
+    pub fn get_debit(&self) -> i64 {
+        self.debit
+    }
+    pub fn get_credit(&self) -> i64 {
+        self.credit
+    }
+}
+
+/// 차변 합계와 대변 합계가 같은 분개
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalEntry {
+    memo: Option<String>,
+    target_dt: NaiveDateTime,
+    lines: Vec<JournalLine>,
+}
+
+impl JournalEntry {
+    pub fn new(memo: Option<String>, target_dt: NaiveDateTime, lines: Vec<JournalLine>) -> Self {
+        Self {
+            memo,
+            target_dt,
+            lines,
+        }
+    }
+
+    pub fn get_memo(&self) -> Option<&str> {
+        self.memo.as_deref()
+    }
+    pub fn get_target_dt(&self) -> NaiveDateTime {
+        self.target_dt
+    }
+    pub fn get_lines(&self) -> &[JournalLine] {
+        &self.lines
+    }
+    pub fn get_account_ids(&self) -> Vec<i32> {
+        let mut account_ids: Vec<i32> = self.lines.iter().map(|l| l.account_id).collect();
+        account_ids.sort();
+        account_ids.dedup();
+        account_ids
+    }
+}
+
+/// 분개 조회 시 분개 줄 한 행
+#[derive(Debug, sqlx::FromRow, Clone, PartialEq)]
+pub struct EntryLineRow {
+    entry_id: i64,
+    memo: Option<String>,
+    target_dt: NaiveDateTime,
+    account_id: i32,
+    code: String,
+    name: String,
+    debit: i64,
+    credit: i64,
+}
+
+impl EntryLineRow {
+    pub fn new(
+        entry_id: i64,
+        memo: Option<&str>,
+        target_dt: NaiveDateTime,
+        account: &Account,
+        debit: i64,
+        credit: i64,
+    ) -> Self {
+        Self {
+            entry_id,
+            memo: memo.map(|m| m.to_string()),
+            target_dt,
+            account_id: account.id,
+            code: account.code.clone(),
+            name: account.name.clone(),
+            debit,
+            credit,
+        }
+    }
+
+    pub fn get_entry_id(&self) -> i64 {
+        self.entry_id
+    }
+    pub fn get_memo(&self) -> Option<&str> {
+        self.memo.as_deref()
+    }
+    pub fn get_target_dt(&self) -> NaiveDateTime {
+        self.target_dt
+    }
+    pub fn get_account_id(&self) -> i32 {
+        self.account_id
+    }
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_debit(&self) -> i64 {
+        self.debit
+    }
+    pub fn get_credit(&self) -> i64 {
+        self.credit
+    }
+}
+
+/// 기간 내 계정별 차변, 대변 합계
+#[derive(Debug, sqlx::FromRow, Clone, PartialEq)]
+pub struct AccountSum {
+    account_id: i32,
+    code: String,
+    name: String,
+    class: String,
+    debit: i64,
+    credit: i64,
+}
+
+impl AccountSum {
+    pub fn new(account: &Account, debit: i64, credit: i64) -> Self {
+        Self {
+            account_id: account.id,
+            code: account.code.clone(),
+            name: account.name.clone(),
+            class: account.class.clone(),
+            debit,
+            credit,
+        }
+    }
+
+    pub fn get_account_id(&self) -> i32 {
+        self.account_id
+    }
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_class(&self) -> &str {
+        &self.class
+    }
+    pub fn get_debit(&self) -> i64 {
+        self.debit
+    }
+    pub fn get_credit(&self) -> i64 {
+        self.credit
+    }
+}
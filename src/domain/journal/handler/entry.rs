@@ -0,0 +1,281 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    response::IntoResponse,
+    Extension, Json,
+};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::journal::{
+        dto::request::{JournalPeriod, NewJournalEntry},
+        usecase::entry::JournalEntryUsecase,
+    },
+    global::errors::CustomError,
+};
+
+pub async fn read_accounts<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: JournalEntryUsecase,
+{
+    match usecase.read_accounts(user_id, book_id).await {
+        Ok(accounts) => (StatusCode::OK, Json(json!({"accounts": accounts}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn read_entries<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    period: Query<JournalPeriod>,
+) -> impl IntoResponse
+where
+    T: JournalEntryUsecase,
+{
+    if !period.is_valid() {
+        return CustomError::ValidationError("JournalPeriod".to_string()).into_response();
+    }
+
+    match usecase.read_entries(user_id, book_id, period.0).await {
+        Ok(entries) => (StatusCode::OK, Json(json!({"entries": entries}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn create_entry<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    Json(new_entry): Json<NewJournalEntry>,
+) -> impl IntoResponse
+where
+    T: JournalEntryUsecase,
+{
+    tracing::debug!("CALL: Create Journal Entry");
+
+    match usecase.create_entry(user_id, book_id, new_entry).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "성공", "entry_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn delete_entry<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path((book_id, entry_id)): Path<(i32, i64)>,
+) -> impl IntoResponse
+where
+    T: JournalEntryUsecase,
+{
+    tracing::debug!("CALL: Delete Journal Entry");
+
+    match usecase.delete_entry(user_id, book_id, entry_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        async_trait,
+        body::Body,
+        extract::Request,
+        routing::{delete, get},
+        Extension, Router,
+    };
+    use chrono::NaiveDateTime;
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::{create_entry, delete_entry, read_accounts, read_entries};
+    use crate::{
+        domain::journal::{
+            dto::{
+                request::{JournalPeriod, NewJournalEntry, NewJournalLine},
+                response::EntryInfo,
+            },
+            entity::{Account, AccountClass},
+            usecase::entry::JournalEntryUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        JournalEntryUsecaseImpl {}
+
+        #[async_trait]
+        impl JournalEntryUsecase for JournalEntryUsecaseImpl {
+            async fn read_accounts(&self, user_id: i32, book_id: i32) -> Result<Vec<Account>, Box<CustomError>>;
+            async fn read_entries(&self, user_id: i32, book_id: i32, period: JournalPeriod) -> Result<Vec<EntryInfo>, Box<CustomError>>;
+            async fn create_entry(&self, user_id: i32, book_id: i32, new_entry: NewJournalEntry) -> Result<i64, Box<CustomError>>;
+            async fn delete_entry(&self, user_id: i32, book_id: i32, entry_id: i64) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _get_router(user_id: i32, mock_usecase: MockJournalEntryUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/journal/:book_id/account",
+                get(read_accounts::<MockJournalEntryUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/journal/:book_id/entry",
+                get(read_entries::<MockJournalEntryUsecaseImpl>)
+                    .post(create_entry::<MockJournalEntryUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/journal/:book_id/entry/:entry_id",
+                delete(delete_entry::<MockJournalEntryUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    #[tokio::test]
+    async fn check_read_accounts() {
+        // Arrange
+        let mut mock_usecase = MockJournalEntryUsecaseImpl::new();
+        mock_usecase
+            .expect_read_accounts()
+            .with(predicate::eq(1), predicate::eq(5))
+            .returning(|_, _| Ok(vec![Account::new(1, "101", "현금", AccountClass::Asset)]));
+
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/journal/5/account")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body["accounts"][0]["class"], "asset");
+    }
+
+    #[tokio::test]
+    async fn check_read_entries_invalid_period() {
+        // Arrange
+        let mut mock_usecase = MockJournalEntryUsecaseImpl::new();
+        mock_usecase.expect_read_entries().never();
+
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/journal/5/entry?start_dt=2024-09-30&end_dt=2024-09-01")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn check_create_entry() {
+        // Arrange
+        let new_entry = NewJournalEntry::new(
+            Some("현금 매출".to_string()),
+            NaiveDateTime::parse_from_str("2024-09-02 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            vec![
+                NewJournalLine::new(1, Some(1000), None),
+                NewJournalLine::new(8, None, Some(1000)),
+            ],
+        );
+        let mut mock_usecase = MockJournalEntryUsecaseImpl::new();
+        mock_usecase
+            .expect_create_entry()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(new_entry.clone()),
+            )
+            .returning(|_, _, _| Ok(7));
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/journal/5/entry")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&new_entry).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body["entry_id"], 7);
+    }
+
+    #[tokio::test]
+    async fn check_create_entry_unbalanced() {
+        // Arrange
+        let mut mock_usecase = MockJournalEntryUsecaseImpl::new();
+        mock_usecase.expect_create_entry().returning(|_, _, _| {
+            Err(Box::new(CustomError::ValidationError(
+                "JournalEntry".to_string(),
+            )))
+        });
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/journal/5/entry")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                r#"{"target_dt":"2024-09-02T10:00:00","lines":[{"account_id":1,"debit":1000},{"account_id":8,"credit":900}]}"#,
+            ))
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn check_delete_entry() {
+        // Arrange
+        let mut mock_usecase = MockJournalEntryUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_entry()
+            .with(predicate::eq(1), predicate::eq(5), predicate::eq(7))
+            .returning(|_, _, _| Ok(()));
+
+        let req = Request::builder()
+            .method("DELETE")
+            .uri("/api/v1/journal/5/entry/7")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+    }
+}
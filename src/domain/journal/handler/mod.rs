@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{delete, get},
+    Extension, Router,
+};
+
+use sqlx::PgPool;
+
+mod entry;
+mod report;
+
+use entry::{create_entry, delete_entry, read_accounts, read_entries};
+use report::{read_balance_sheet, read_income_statement, read_trial_balance};
+
+use super::{
+    repository::{entry::JournalEntryRepoImpl, report::JournalReportRepoImpl},
+    usecase::{entry::JournalEntryUsecaseImpl, report::JournalReportUsecaseImpl},
+};
+
+pub fn entry_router(pool: &Arc<PgPool>) -> Router {
+    let repository = JournalEntryRepoImpl::new(pool);
+    let usecase = JournalEntryUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:book_id/account",
+            get(read_accounts::<JournalEntryUsecaseImpl<JournalEntryRepoImpl>>),
+        )
+        .route(
+            "/:book_id/entry",
+            get(read_entries::<JournalEntryUsecaseImpl<JournalEntryRepoImpl>>)
+                .post(create_entry::<JournalEntryUsecaseImpl<JournalEntryRepoImpl>>),
+        )
+        .route(
+            "/:book_id/entry/:entry_id",
+            delete(delete_entry::<JournalEntryUsecaseImpl<JournalEntryRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn report_router(pool: &Arc<PgPool>) -> Router {
+    let repository = JournalReportRepoImpl::new(pool);
+    let usecase = JournalReportUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:book_id/trial-balance",
+            get(read_trial_balance::<JournalReportUsecaseImpl<JournalReportRepoImpl>>),
+        )
+        .route(
+            "/:book_id/income-statement",
+            get(read_income_statement::<JournalReportUsecaseImpl<JournalReportRepoImpl>>),
+        )
+        .route(
+            "/:book_id/balance-sheet",
+            get(read_balance_sheet::<JournalReportUsecaseImpl<JournalReportRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    response::IntoResponse,
+    Extension, Json,
+};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::journal::{
+        dto::request::{BaseDate, JournalPeriod},
+        usecase::report::JournalReportUsecase,
+    },
+    global::errors::CustomError,
+};
+
+pub async fn read_trial_balance<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    period: Query<JournalPeriod>,
+) -> impl IntoResponse
+where
+    T: JournalReportUsecase,
+{
+    if !period.is_valid() {
+        return CustomError::ValidationError("JournalPeriod".to_string()).into_response();
+    }
+
+    match usecase.read_trial_balance(user_id, book_id, period.0).await {
+        Ok(trial_balance) => (StatusCode::OK, Json(json!(trial_balance))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn read_income_statement<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    period: Query<JournalPeriod>,
+) -> impl IntoResponse
+where
+    T: JournalReportUsecase,
+{
+    if !period.is_valid() {
+        return CustomError::ValidationError("JournalPeriod".to_string()).into_response();
+    }
+
+    match usecase
+        .read_income_statement(user_id, book_id, period.0)
+        .await
+    {
+        Ok(statement) => (StatusCode::OK, Json(json!(statement))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn read_balance_sheet<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    base_date: Query<BaseDate>,
+) -> impl IntoResponse
+where
+    T: JournalReportUsecase,
+{
+    match usecase
+        .read_balance_sheet(user_id, book_id, base_date.0)
+        .await
+    {
+        Ok(sheet) => (StatusCode::OK, Json(json!(sheet))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::get, Extension, Router};
+    use chrono::NaiveDate;
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::{read_balance_sheet, read_income_statement, read_trial_balance};
+    use crate::{
+        domain::journal::{
+            dto::{
+                request::{BaseDate, JournalPeriod},
+                response::{AccountBalance, BalanceSheet, IncomeStatement, TrialBalance},
+            },
+            usecase::report::JournalReportUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        JournalReportUsecaseImpl {}
+
+        #[async_trait]
+        impl JournalReportUsecase for JournalReportUsecaseImpl {
+            async fn read_trial_balance(&self, user_id: i32, book_id: i32, period: JournalPeriod) -> Result<TrialBalance, Box<CustomError>>;
+            async fn read_income_statement(&self, user_id: i32, book_id: i32, period: JournalPeriod) -> Result<IncomeStatement, Box<CustomError>>;
+            async fn read_balance_sheet(&self, user_id: i32, book_id: i32, base_date: BaseDate) -> Result<BalanceSheet, Box<CustomError>>;
+        }
+    }
+
+    fn _get_router(user_id: i32, mock_usecase: MockJournalReportUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/journal/:book_id/trial-balance",
+                get(read_trial_balance::<MockJournalReportUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/journal/:book_id/income-statement",
+                get(read_income_statement::<MockJournalReportUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/journal/:book_id/balance-sheet",
+                get(read_balance_sheet::<MockJournalReportUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 9, day).unwrap()
+    }
+
+    fn _cash(balance: i64) -> AccountBalance {
+        AccountBalance::new(1, "101", "현금", "asset", balance, 0, balance)
+    }
+
+    async fn _get_body(router: Router, uri: &str) -> Value {
+        let req = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_trial_balance() {
+        // Arrange
+        let mut mock_usecase = MockJournalReportUsecaseImpl::new();
+        mock_usecase
+            .expect_read_trial_balance()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(JournalPeriod::new(_date(1), _date(30))),
+            )
+            .returning(|_, _, _| Ok(TrialBalance::new(_date(1), _date(30), vec![_cash(1000)])));
+
+        // Act
+        let body = _get_body(
+            _get_router(1, mock_usecase),
+            "/api/v1/journal/5/trial-balance?start_dt=2024-09-01&end_dt=2024-09-30",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(body["total_debit"], 1000);
+        assert_eq!(body["accounts"][0]["code"], "101");
+    }
+
+    #[tokio::test]
+    async fn check_read_income_statement() {
+        // Arrange
+        let mut mock_usecase = MockJournalReportUsecaseImpl::new();
+        mock_usecase
+            .expect_read_income_statement()
+            .returning(|_, _, _| {
+                Ok(IncomeStatement::new(
+                    _date(1),
+                    _date(30),
+                    vec![AccountBalance::new(
+                        8,
+                        "401",
+                        "매출금",
+                        "revenue",
+                        0,
+                        5000,
+                        5000,
+                    )],
+                    vec![],
+                ))
+            });
+
+        // Act
+        let body = _get_body(
+            _get_router(1, mock_usecase),
+            "/api/v1/journal/5/income-statement?start_dt=2024-09-01&end_dt=2024-09-30",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(body["net_income"], 5000);
+    }
+
+    #[tokio::test]
+    async fn check_read_balance_sheet() {
+        // Arrange
+        let mut mock_usecase = MockJournalReportUsecaseImpl::new();
+        mock_usecase
+            .expect_read_balance_sheet()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(BaseDate::new(_date(30))),
+            )
+            .returning(|_, _, _| {
+                Ok(BalanceSheet::new(
+                    _date(30),
+                    vec![_cash(5000)],
+                    vec![],
+                    vec![],
+                    5000,
+                ))
+            });
+
+        // Act
+        let body = _get_body(
+            _get_router(1, mock_usecase),
+            "/api/v1/journal/5/balance-sheet?base_dt=2024-09-30",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(body["total_asset"], 5000);
+        assert_eq!(body["total_equity"], 5000);
+    }
+
+    #[tokio::test]
+    async fn check_read_trial_balance_invalid_period() {
+        // Arrange
+        let mut mock_usecase = MockJournalReportUsecaseImpl::new();
+        mock_usecase.expect_read_trial_balance().never();
+
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/journal/5/trial-balance?start_dt=2024-09-30&end_dt=2024-09-01")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -0,0 +1,8 @@
+mod dto;
+mod entity;
+mod handler;
+mod repository;
+mod usecase;
+mod utils;
+
+pub mod route;
@@ -0,0 +1,527 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::{
+    domain::journal::entity::{Account, EntryLineRow, JournalEntry},
+    global::{constants::DeleteResult, errors::CustomError},
+};
+
+pub struct JournalEntryRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait JournalEntryRepo: Send + Sync {
+    async fn get_accounts(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Account>, Box<CustomError>>;
+    async fn get_entry_lines(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<EntryLineRow>, Box<CustomError>>;
+    async fn save_entry(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        entry: JournalEntry,
+    ) -> Result<i64, Box<CustomError>>;
+    async fn delete_entry(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        entry_id: i64,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl JournalEntryRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl JournalEntryRepo for JournalEntryRepoImpl {
+    async fn get_accounts(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Account>, Box<CustomError>> {
+        get_accounts(&self.pool, user_id, book_id).await
+    }
+    async fn get_entry_lines(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<EntryLineRow>, Box<CustomError>> {
+        get_entry_lines(&self.pool, user_id, book_id, start_dt, end_dt).await
+    }
+    async fn save_entry(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        entry: JournalEntry,
+    ) -> Result<i64, Box<CustomError>> {
+        save_entry(&self.pool, user_id, book_id, entry).await
+    }
+    async fn delete_entry(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        entry_id: i64,
+    ) -> Result<(), Box<CustomError>> {
+        delete_entry(&self.pool, user_id, book_id, entry_id).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CompanyBook {
+    is_company: bool,
+    role: Option<String>,
+}
+
+/// 기업 가계부인지, 요청한 사용자의 권한 확인
+pub async fn check_company_book(
+    tx: &mut Transaction<'_, Postgres>,
+    user_id: i32,
+    book_id: i32,
+    is_write: bool,
+) -> Result<(), Box<CustomError>> {
+    let book = sqlx::query_as::<_, CompanyBook>(
+        "
+        SELECT bt.name = '기업' AS is_company, br.role
+        FROM tb_book AS b
+        JOIN tb_book_type AS bt ON bt.id = b.type_id
+        LEFT JOIN tb_user_book_role AS br ON br.book_id = b.id AND br.user_id = $1
+        WHERE b.id = $2
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| to_custom_error("CheckCompanyBook", e))?;
+
+    let book = match book {
+        Some(book) => book,
+        None => return Err(Box::new(CustomError::NotFound("Book".to_string()))),
+    };
+
+    match book.role.as_deref() {
+        None => return Err(Box::new(CustomError::Unauthorized("BookRole".to_string()))),
+        Some("viewer") if is_write => {
+            return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())))
+        }
+        _ => {}
+    }
+    if !book.is_company {
+        return Err(Box::new(CustomError::ValidationError(
+            "BookType".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+/// 기본 계정과목과 가계부 계정과목
+pub async fn get_accounts(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<Account>, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("GetAccounts", e))?;
+
+    check_company_book(&mut tx, user_id, book_id, false).await?;
+
+    let accounts = sqlx::query_as::<_, Account>(
+        "
+        SELECT id, code, name, class
+        FROM tb_account
+        WHERE book_id IS NULL OR book_id = $1
+        ORDER BY code, id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetAccounts", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("GetAccounts", e))?;
+
+    Ok(accounts)
+}
+
+/// 기간 내 분개 줄, 분개 일시, 분개 순으로 정렬
+pub async fn get_entry_lines(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    start_dt: NaiveDateTime,
+    end_dt: NaiveDateTime,
+) -> Result<Vec<EntryLineRow>, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("GetEntryLines", e))?;
+
+    check_company_book(&mut tx, user_id, book_id, false).await?;
+
+    let rows = sqlx::query_as::<_, EntryLineRow>(
+        "
+        SELECT
+            e.id AS entry_id, e.memo, e.target_dt,
+            l.account_id, a.code, a.name, l.debit, l.credit
+        FROM tb_journal_entry AS e
+        JOIN tb_journal_line AS l ON l.entry_id = e.id
+        JOIN tb_account AS a ON a.id = l.account_id
+        WHERE e.book_id = $1 AND e.target_dt >= $2 AND e.target_dt < $3
+        ORDER BY e.target_dt, e.id, l.id
+    ",
+    )
+    .bind(book_id)
+    .bind(start_dt)
+    .bind(end_dt)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetEntryLines", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("GetEntryLines", e))?;
+
+    Ok(rows)
+}
+
+/// 분개와 분개 줄 저장, 다른 가계부의 계정과목은 사용할 수 없음
+pub async fn save_entry(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    entry: JournalEntry,
+) -> Result<i64, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("SaveJournalEntry", e))?;
+
+    check_company_book(&mut tx, user_id, book_id, true).await?;
+
+    let account_ids = entry.get_account_ids();
+    let account_count = sqlx::query_scalar::<_, i64>(
+        "
+        SELECT COUNT(*)
+        FROM tb_account
+        WHERE id = ANY($1) AND (book_id IS NULL OR book_id = $2)
+    ",
+    )
+    .bind(&account_ids)
+    .bind(book_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("SaveJournalEntry", e))?;
+
+    if account_count != account_ids.len() as i64 {
+        return Err(Box::new(CustomError::NotFound("Account".to_string())));
+    }
+
+    let entry_id = sqlx::query_scalar::<_, i64>(
+        "
+        INSERT INTO tb_journal_entry (book_id, memo, target_dt, created_by)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+    ",
+    )
+    .bind(book_id)
+    .bind(entry.get_memo())
+    .bind(entry.get_target_dt())
+    .bind(user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("SaveJournalEntry", e))?;
+
+    let lines = entry.get_lines();
+    let line_accounts: Vec<i32> = lines.iter().map(|l| l.get_account_id()).collect();
+    let debits: Vec<i64> = lines.iter().map(|l| l.get_debit()).collect();
+    let credits: Vec<i64> = lines.iter().map(|l| l.get_credit()).collect();
+    sqlx::query(
+        "
+        INSERT INTO tb_journal_line (entry_id, account_id, debit, credit)
+        SELECT $1, account_id, debit, credit
+        FROM UNNEST($2::INT[], $3::BIGINT[], $4::BIGINT[]) WITH ORDINALITY
+            AS l(account_id, debit, credit, seq)
+        ORDER BY seq
+    ",
+    )
+    .bind(entry_id)
+    .bind(&line_accounts)
+    .bind(&debits)
+    .bind(&credits)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("SaveJournalEntry", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("SaveJournalEntry", e))?;
+
+    Ok(entry_id)
+}
+
+pub async fn delete_entry(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    entry_id: i64,
+) -> Result<(), Box<CustomError>> {
+    let result = sqlx::query_as::<_, DeleteResult>(
+        "
+        WITH EntryExists AS (
+            SELECT id
+            FROM tb_journal_entry
+            WHERE id = $3 AND book_id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT 1
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2 AND role != 'viewer'
+        ),
+        DeleteEntry AS (
+            DELETE FROM tb_journal_entry
+            WHERE id IN (SELECT id FROM EntryExists)
+                AND EXISTS (SELECT 1 FROM AuthorityCheck)
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM EntryExists) AS is_exist,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT COUNT(*) FROM DeleteEntry) AS delete_count
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .bind(entry_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| to_custom_error("DeleteJournalEntry", e))?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("JournalEntry".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::journal::{
+            entity::{JournalEntry, JournalLine},
+            repository::entry::{delete_entry, get_accounts, get_entry_lines, save_entry},
+        },
+        global::errors::CustomError,
+    };
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    // 1번(소유자), 2번(뷰어) 사용자가 있는 기업 가계부
+    async fn _company_book(pool: &PgPool, name: &str) -> i32 {
+        let book_id: i32 =
+            sqlx::query_scalar("INSERT INTO tb_book (name, type_id) VALUES ($1, 3) RETURNING id")
+                .bind(name)
+                .fetch_one(pool)
+                .await
+                .unwrap();
+        sqlx::query(
+            "INSERT INTO tb_user_book_role (user_id, book_id, role) VALUES (1, $1, 'owner'), (2, $1, 'viewer')",
+        )
+        .bind(book_id)
+        .execute(pool)
+        .await
+        .unwrap();
+        book_id
+    }
+
+    // ref) init.sql, 1번 현금, 8번 매출금
+    fn _sales_entry(amount: i64, target_dt: &str) -> JournalEntry {
+        JournalEntry::new(
+            Some("현금 매출".to_string()),
+            _parse(target_dt),
+            vec![
+                JournalLine::new(1, amount, 0),
+                JournalLine::new(8, 0, amount),
+            ],
+        )
+    }
+
+    #[tokio::test]
+    async fn check_get_accounts() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _company_book(&pool, "계정과목 조회").await;
+
+        // Act
+        let result = get_accounts(&pool, 2, book_id).await;
+
+        // Assert
+        let accounts = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(accounts.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn check_save_entry() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _company_book(&pool, "분개 저장").await;
+
+        // Act
+        let result = save_entry(
+            &pool,
+            1,
+            book_id,
+            _sales_entry(30000, "2024-09-02 10:00:00"),
+        )
+        .await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let rows = get_entry_lines(
+            &pool,
+            1,
+            book_id,
+            _parse("2024-09-01 00:00:00"),
+            _parse("2024-10-01 00:00:00"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get_debit(), 30000);
+        assert_eq!(rows[1].get_credit(), 30000);
+    }
+
+    #[tokio::test]
+    async fn check_save_entry_unknown_account() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _company_book(&pool, "없는 계정과목").await;
+        let entry = JournalEntry::new(
+            None,
+            _parse("2024-09-02 10:00:00"),
+            vec![JournalLine::new(1, 1000, 0), JournalLine::new(-1, 0, 1000)],
+        );
+
+        // Act
+        let result = save_entry(&pool, 1, book_id, entry).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_save_entry_viewer() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _company_book(&pool, "뷰어 분개").await;
+
+        // Act
+        let result = save_entry(&pool, 2, book_id, _sales_entry(1000, "2024-09-02 10:00:00")).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_save_entry_not_company() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // Act
+        // ref) init.sql, 1번 가계부는 개인 가계부
+        let result = save_entry(&pool, 1, 1, _sales_entry(1000, "2024-09-02 10:00:00")).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_delete_entry() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _company_book(&pool, "분개 삭제").await;
+        let entry_id = save_entry(&pool, 1, book_id, _sales_entry(1000, "2024-09-02 10:00:00"))
+            .await
+            .unwrap();
+
+        // Act
+        let viewer_result = delete_entry(&pool, 2, book_id, entry_id).await;
+        let result = delete_entry(&pool, 1, book_id, entry_id).await;
+
+        // Assert
+        let err_type = match *viewer_result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        let result = delete_entry(&pool, 1, book_id, entry_id).await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
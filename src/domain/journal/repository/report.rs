@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::{
+    domain::journal::{entity::AccountSum, repository::entry::check_company_book},
+    global::errors::CustomError,
+};
+
+pub struct JournalReportRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait JournalReportRepo: Send + Sync {
+    async fn get_account_sums(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: Option<NaiveDateTime>,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<AccountSum>, Box<CustomError>>;
+}
+
+impl JournalReportRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl JournalReportRepo for JournalReportRepoImpl {
+    async fn get_account_sums(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: Option<NaiveDateTime>,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<AccountSum>, Box<CustomError>> {
+        get_account_sums(&self.pool, user_id, book_id, start_dt, end_dt).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+/// 계정별 차변, 대변 합계, start_dt가 없으면 처음부터 누적
+pub async fn get_account_sums(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    start_dt: Option<NaiveDateTime>,
+    end_dt: NaiveDateTime,
+) -> Result<Vec<AccountSum>, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("GetAccountSums", e))?;
+
+    check_company_book(&mut tx, user_id, book_id, false).await?;
+
+    let sums = sqlx::query_as::<_, AccountSum>(
+        "
+        SELECT
+            a.id AS account_id, a.code, a.name, a.class,
+            SUM(l.debit)::BIGINT AS debit,
+            SUM(l.credit)::BIGINT AS credit
+        FROM tb_journal_entry AS e
+        JOIN tb_journal_line AS l ON l.entry_id = e.id
+        JOIN tb_account AS a ON a.id = l.account_id
+        WHERE e.book_id = $1
+            AND ($2::TIMESTAMP IS NULL OR e.target_dt >= $2)
+            AND e.target_dt < $3
+        GROUP BY a.id
+        ORDER BY a.code, a.id
+    ",
+    )
+    .bind(book_id)
+    .bind(start_dt)
+    .bind(end_dt)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetAccountSums", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("GetAccountSums", e))?;
+
+    Ok(sums)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::journal::{
+            entity::{JournalEntry, JournalLine},
+            repository::{entry::save_entry, report::get_account_sums},
+        },
+        global::errors::CustomError,
+    };
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    async fn _company_book(pool: &PgPool, name: &str) -> i32 {
+        let book_id: i32 =
+            sqlx::query_scalar("INSERT INTO tb_book (name, type_id) VALUES ($1, 3) RETURNING id")
+                .bind(name)
+                .fetch_one(pool)
+                .await
+                .unwrap();
+        sqlx::query(
+            "INSERT INTO tb_user_book_role (user_id, book_id, role) VALUES (1, $1, 'owner')",
+        )
+        .bind(book_id)
+        .execute(pool)
+        .await
+        .unwrap();
+        book_id
+    }
+
+    #[tokio::test]
+    async fn check_get_account_sums() {
+        // Arrange
+        // ref) init.sql, 1번 현금, 7번 자본금, 8번 매출금
+        let pool = create_connection_pool().await;
+        let book_id = _company_book(&pool, "계정 합계").await;
+        let entries = [
+            (7, 1000000, "2024-08-01 09:00:00"),
+            (8, 30000, "2024-09-02 10:00:00"),
+            (8, 20000, "2024-09-03 10:00:00"),
+        ];
+        for (credit_id, amount, target_dt) in entries {
+            let entry = JournalEntry::new(
+                None,
+                _parse(target_dt),
+                vec![
+                    JournalLine::new(1, amount, 0),
+                    JournalLine::new(credit_id, 0, amount),
+                ],
+            );
+            save_entry(&pool, 1, book_id, entry).await.unwrap();
+        }
+        let end_dt = _parse("2024-10-01 00:00:00");
+
+        // Act
+        let period = get_account_sums(
+            &pool,
+            1,
+            book_id,
+            Some(_parse("2024-09-01 00:00:00")),
+            end_dt,
+        )
+        .await;
+        let total = get_account_sums(&pool, 1, book_id, None, end_dt).await;
+
+        // Assert
+        let period = period.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(period.len(), 2);
+        assert_eq!(period[0].get_debit(), 50000);
+        assert_eq!(period[1].get_credit(), 50000);
+
+        let total = total.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(total.len(), 3);
+        assert_eq!(total[0].get_debit(), 1050000);
+    }
+
+    #[tokio::test]
+    async fn check_get_account_sums_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _company_book(&pool, "권한 없는 합계").await;
+
+        // Act
+        let result = get_account_sums(&pool, 3, book_id, None, _parse("2024-10-01 00:00:00")).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,12 @@
+use std::sync::Arc;
+
+use axum::Router;
+use sqlx::PgPool;
+
+use super::handler::{entry_router, report_router};
+
+pub fn get_router(pool: &Arc<PgPool>) -> Router {
+    Router::new()
+        .merge(entry_router(pool))
+        .merge(report_router(pool))
+}
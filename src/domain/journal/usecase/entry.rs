@@ -0,0 +1,298 @@
+use axum::async_trait;
+
+use crate::{
+    domain::journal::{
+        dto::{
+            request::{JournalPeriod, NewJournalEntry},
+            response::EntryInfo,
+        },
+        entity::Account,
+        repository::entry::JournalEntryRepo,
+        utils::statement::to_entries,
+    },
+    global::errors::CustomError,
+};
+
+pub struct JournalEntryUsecaseImpl<T>
+where
+    T: JournalEntryRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait JournalEntryUsecase: Send + Sync {
+    async fn read_accounts(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Account>, Box<CustomError>>;
+    async fn read_entries(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: JournalPeriod,
+    ) -> Result<Vec<EntryInfo>, Box<CustomError>>;
+    async fn create_entry(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        new_entry: NewJournalEntry,
+    ) -> Result<i64, Box<CustomError>>;
+    async fn delete_entry(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        entry_id: i64,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> JournalEntryUsecaseImpl<T>
+where
+    T: JournalEntryRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> JournalEntryUsecase for JournalEntryUsecaseImpl<T>
+where
+    T: JournalEntryRepo,
+{
+    async fn read_accounts(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Account>, Box<CustomError>> {
+        self.repository.get_accounts(user_id, book_id).await
+    }
+    async fn read_entries(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: JournalPeriod,
+    ) -> Result<Vec<EntryInfo>, Box<CustomError>> {
+        read_entries(&self.repository, user_id, book_id, period).await
+    }
+    async fn create_entry(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        new_entry: NewJournalEntry,
+    ) -> Result<i64, Box<CustomError>> {
+        create_entry(&self.repository, user_id, book_id, new_entry).await
+    }
+    async fn delete_entry(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        entry_id: i64,
+    ) -> Result<(), Box<CustomError>> {
+        self.repository
+            .delete_entry(user_id, book_id, entry_id)
+            .await
+    }
+}
+
+async fn read_entries<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    period: JournalPeriod,
+) -> Result<Vec<EntryInfo>, Box<CustomError>>
+where
+    T: JournalEntryRepo,
+{
+    let (start_dt, end_dt) = period
+        .get_range()
+        .ok_or(Box::new(CustomError::ValidationError(
+            "JournalPeriod".to_string(),
+        )))?;
+
+    let rows = repository
+        .get_entry_lines(user_id, book_id, start_dt, end_dt)
+        .await?;
+
+    Ok(to_entries(&rows))
+}
+
+async fn create_entry<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    new_entry: NewJournalEntry,
+) -> Result<i64, Box<CustomError>>
+where
+    T: JournalEntryRepo,
+{
+    let entry = new_entry.to_entity()?;
+
+    repository.save_entry(user_id, book_id, entry).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::journal::{
+            dto::request::{JournalPeriod, NewJournalEntry, NewJournalLine},
+            entity::{Account, AccountClass, EntryLineRow, JournalEntry, JournalLine},
+            repository::entry::JournalEntryRepo,
+            usecase::entry::{create_entry, read_entries},
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        JournalEntryRepoImpl {}
+
+        #[async_trait]
+        impl JournalEntryRepo for JournalEntryRepoImpl {
+            async fn get_accounts(&self, user_id: i32, book_id: i32) -> Result<Vec<Account>, Box<CustomError>>;
+            async fn get_entry_lines(&self, user_id: i32, book_id: i32, start_dt: NaiveDateTime, end_dt: NaiveDateTime) -> Result<Vec<EntryLineRow>, Box<CustomError>>;
+            async fn save_entry(&self, user_id: i32, book_id: i32, entry: JournalEntry) -> Result<i64, Box<CustomError>>;
+            async fn delete_entry(&self, user_id: i32, book_id: i32, entry_id: i64) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_entries() {
+        // Arrange
+        let mut mock_repo = MockJournalEntryRepoImpl::new();
+        mock_repo
+            .expect_get_entry_lines()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(_parse("2024-09-01 00:00:00")),
+                predicate::eq(_parse("2024-10-01 00:00:00")),
+            )
+            .returning(|_, _, _, _| {
+                let dt = _parse("2024-09-02 10:00:00");
+                let cash = Account::new(1, "101", "현금", AccountClass::Asset);
+                let sales = Account::new(8, "401", "매출금", AccountClass::Revenue);
+                Ok(vec![
+                    EntryLineRow::new(3, None, dt, &cash, 1000, 0),
+                    EntryLineRow::new(3, None, dt, &sales, 0, 1000),
+                ])
+            });
+        let period = JournalPeriod::new(
+            NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 30).unwrap(),
+        );
+
+        // Act
+        let result = read_entries(&mock_repo, 1, 5, period).await;
+
+        // Assert
+        let entries = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].get_id(), 3);
+    }
+
+    #[tokio::test]
+    async fn check_create_entry() {
+        // Arrange
+        let dt = _parse("2024-09-02 10:00:00");
+        let new_entry = NewJournalEntry::new(
+            None,
+            dt,
+            vec![
+                NewJournalLine::new(1, Some(1000), None),
+                NewJournalLine::new(8, None, Some(600)),
+                NewJournalLine::new(3, Some(0), Some(400)),
+            ],
+        );
+        let mut mock_repo = MockJournalEntryRepoImpl::new();
+        mock_repo
+            .expect_save_entry()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(JournalEntry::new(
+                    None,
+                    dt,
+                    vec![
+                        JournalLine::new(1, 1000, 0),
+                        JournalLine::new(8, 0, 600),
+                        JournalLine::new(3, 0, 400),
+                    ],
+                )),
+            )
+            .returning(|_, _, _| Ok(7));
+
+        // Act
+        let result = create_entry(&mock_repo, 1, 5, new_entry).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn check_create_entry_invalid() {
+        // Arrange
+        let mut mock_repo = MockJournalEntryRepoImpl::new();
+        mock_repo.expect_save_entry().never();
+
+        let dt = _parse("2024-09-02 10:00:00");
+        let new_entries = [
+            // 차변 합계와 대변 합계가 다름
+            NewJournalEntry::new(
+                None,
+                dt,
+                vec![
+                    NewJournalLine::new(1, Some(1000), None),
+                    NewJournalLine::new(8, None, Some(900)),
+                ],
+            ),
+            // 한 줄에 차변, 대변 모두 입력
+            NewJournalEntry::new(
+                None,
+                dt,
+                vec![
+                    NewJournalLine::new(1, Some(1000), Some(1000)),
+                    NewJournalLine::new(8, Some(1000), Some(1000)),
+                ],
+            ),
+            NewJournalEntry::new(
+                None,
+                dt,
+                vec![
+                    NewJournalLine::new(1, Some(-1000), None),
+                    NewJournalLine::new(8, None, Some(-1000)),
+                ],
+            ),
+            NewJournalEntry::new(None, dt, vec![NewJournalLine::new(1, Some(1000), None)]),
+            NewJournalEntry::new(
+                None,
+                dt,
+                vec![
+                    NewJournalLine::new(1, Some(i64::MAX), None),
+                    NewJournalLine::new(1, Some(i64::MAX), None),
+                    NewJournalLine::new(8, None, Some(i64::MAX)),
+                ],
+            ),
+        ];
+
+        for new_entry in new_entries {
+            // Act
+            let result = create_entry(&mock_repo, 1, 5, new_entry).await;
+
+            // Assert
+            let err_type = match *result.err().unwrap() {
+                CustomError::ValidationError(_) => true,
+                _ => false,
+            };
+            assert!(err_type)
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use axum::async_trait;
+
+use crate::{
+    domain::journal::{
+        dto::{
+            request::{BaseDate, JournalPeriod},
+            response::{BalanceSheet, IncomeStatement, TrialBalance},
+        },
+        repository::report::JournalReportRepo,
+        utils::statement::{to_balance_sheet, to_balances, to_income_statement},
+    },
+    global::errors::CustomError,
+};
+
+pub struct JournalReportUsecaseImpl<T>
+where
+    T: JournalReportRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait JournalReportUsecase: Send + Sync {
+    async fn read_trial_balance(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: JournalPeriod,
+    ) -> Result<TrialBalance, Box<CustomError>>;
+    async fn read_income_statement(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: JournalPeriod,
+    ) -> Result<IncomeStatement, Box<CustomError>>;
+    async fn read_balance_sheet(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        base_date: BaseDate,
+    ) -> Result<BalanceSheet, Box<CustomError>>;
+}
+
+impl<T> JournalReportUsecaseImpl<T>
+where
+    T: JournalReportRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> JournalReportUsecase for JournalReportUsecaseImpl<T>
+where
+    T: JournalReportRepo,
+{
+    async fn read_trial_balance(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: JournalPeriod,
+    ) -> Result<TrialBalance, Box<CustomError>> {
+        read_trial_balance(&self.repository, user_id, book_id, period).await
+    }
+    async fn read_income_statement(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: JournalPeriod,
+    ) -> Result<IncomeStatement, Box<CustomError>> {
+        read_income_statement(&self.repository, user_id, book_id, period).await
+    }
+    async fn read_balance_sheet(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        base_date: BaseDate,
+    ) -> Result<BalanceSheet, Box<CustomError>> {
+        read_balance_sheet(&self.repository, user_id, book_id, base_date).await
+    }
+}
+
+fn invalid_period() -> Box<CustomError> {
+    Box::new(CustomError::ValidationError("JournalPeriod".to_string()))
+}
+
+async fn read_trial_balance<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    period: JournalPeriod,
+) -> Result<TrialBalance, Box<CustomError>>
+where
+    T: JournalReportRepo,
+{
+    let (start_dt, end_dt) = period.get_range().ok_or_else(invalid_period)?;
+
+    let sums = repository
+        .get_account_sums(user_id, book_id, Some(start_dt), end_dt)
+        .await?;
+    let accounts = to_balances(&sums)?
+        .into_iter()
+        .map(|(_, balance)| balance)
+        .collect();
+
+    Ok(TrialBalance::new(
+        period.get_start_dt(),
+        period.get_end_dt(),
+        accounts,
+    ))
+}
+
+async fn read_income_statement<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    period: JournalPeriod,
+) -> Result<IncomeStatement, Box<CustomError>>
+where
+    T: JournalReportRepo,
+{
+    let (start_dt, end_dt) = period.get_range().ok_or_else(invalid_period)?;
+
+    let sums = repository
+        .get_account_sums(user_id, book_id, Some(start_dt), end_dt)
+        .await?;
+    let balances = to_balances(&sums)?;
+
+    Ok(to_income_statement(
+        period.get_start_dt(),
+        period.get_end_dt(),
+        &balances,
+    ))
+}
+
+async fn read_balance_sheet<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    base_date: BaseDate,
+) -> Result<BalanceSheet, Box<CustomError>>
+where
+    T: JournalReportRepo,
+{
+    let end_dt = base_date.get_end().ok_or_else(invalid_period)?;
+
+    let sums = repository
+        .get_account_sums(user_id, book_id, None, end_dt)
+        .await?;
+    let balances = to_balances(&sums)?;
+
+    Ok(to_balance_sheet(base_date.get_base_dt(), &balances))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::journal::{
+            dto::request::{BaseDate, JournalPeriod},
+            entity::{Account, AccountClass, AccountSum},
+            repository::report::JournalReportRepo,
+            usecase::report::{read_balance_sheet, read_income_statement, read_trial_balance},
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        JournalReportRepoImpl {}
+
+        #[async_trait]
+        impl JournalReportRepo for JournalReportRepoImpl {
+            async fn get_account_sums(&self, user_id: i32, book_id: i32, start_dt: Option<NaiveDateTime>, end_dt: NaiveDateTime) -> Result<Vec<AccountSum>, Box<CustomError>>;
+        }
+    }
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn _period() -> JournalPeriod {
+        JournalPeriod::new(
+            NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 30).unwrap(),
+        )
+    }
+
+    // 현금 매출 5만원, 현금 판매관리비 2만원
+    fn _sums() -> Vec<AccountSum> {
+        vec![
+            AccountSum::new(
+                &Account::new(1, "101", "현금", AccountClass::Asset),
+                50000,
+                20000,
+            ),
+            AccountSum::new(
+                &Account::new(8, "401", "매출금", AccountClass::Revenue),
+                0,
+                50000,
+            ),
+            AccountSum::new(
+                &Account::new(10, "502", "판매관리비", AccountClass::Expense),
+                20000,
+                0,
+            ),
+        ]
+    }
+
+    fn _mock_repo(start_dt: Option<NaiveDateTime>) -> MockJournalReportRepoImpl {
+        let mut mock_repo = MockJournalReportRepoImpl::new();
+        mock_repo
+            .expect_get_account_sums()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(start_dt),
+                predicate::eq(_parse("2024-10-01 00:00:00")),
+            )
+            .returning(|_, _, _, _| Ok(_sums()));
+        mock_repo
+    }
+
+    #[tokio::test]
+    async fn check_read_trial_balance() {
+        // Arrange
+        let mock_repo = _mock_repo(Some(_parse("2024-09-01 00:00:00")));
+
+        // Act
+        let result = read_trial_balance(&mock_repo, 1, 5, _period()).await;
+
+        // Assert
+        let trial_balance = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(trial_balance.get_accounts().len(), 3);
+        assert_eq!(trial_balance.get_accounts()[0].get_balance(), 30000);
+        assert_eq!(trial_balance.get_total_debit(), 70000);
+        assert_eq!(trial_balance.get_total_credit(), 70000);
+    }
+
+    #[tokio::test]
+    async fn check_read_income_statement() {
+        // Arrange
+        let mock_repo = _mock_repo(Some(_parse("2024-09-01 00:00:00")));
+
+        // Act
+        let result = read_income_statement(&mock_repo, 1, 5, _period()).await;
+
+        // Assert
+        let statement = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(statement.get_net_income(), 30000);
+    }
+
+    #[tokio::test]
+    async fn check_read_balance_sheet() {
+        // Arrange
+        let mock_repo = _mock_repo(None);
+        let base_date = BaseDate::new(NaiveDate::from_ymd_opt(2024, 9, 30).unwrap());
+
+        // Act
+        let result = read_balance_sheet(&mock_repo, 1, 5, base_date).await;
+
+        // Assert
+        let sheet = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(sheet.get_total_asset(), 30000);
+        assert_eq!(sheet.get_total_equity(), 30000);
+    }
+}
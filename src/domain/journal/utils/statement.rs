@@ -0,0 +1,219 @@
+use chrono::NaiveDate;
+
+use crate::{
+    domain::journal::{
+        dto::response::{AccountBalance, BalanceSheet, EntryInfo, EntryLineInfo, IncomeStatement},
+        entity::{AccountClass, AccountSum, EntryLineRow},
+    },
+    global::errors::CustomError,
+};
+
+/// 분개 줄 행을 분개별로 묶음, rows는 entry_id 순으로 모여 있어야 함
+pub fn to_entries(rows: &[EntryLineRow]) -> Vec<EntryInfo> {
+    rows.chunk_by(|a, b| a.get_entry_id() == b.get_entry_id())
+        .map(|chunk| {
+            let first = &chunk[0];
+            let lines = chunk
+                .iter()
+                .map(|row| {
+                    EntryLineInfo::new(
+                        row.get_account_id(),
+                        row.get_code(),
+                        row.get_name(),
+                        row.get_debit(),
+                        row.get_credit(),
+                    )
+                })
+                .collect();
+            EntryInfo::new(
+                first.get_entry_id(),
+                first.get_memo(),
+                first.get_target_dt(),
+                lines,
+            )
+        })
+        .collect()
+}
+
+/// 계정별 합계에 정상 잔액 계산, 자산, 비용은 차변 - 대변, 나머지는 대변 - 차변
+pub fn to_balances(
+    sums: &[AccountSum],
+) -> Result<Vec<(AccountClass, AccountBalance)>, Box<CustomError>> {
+    sums.iter()
+        .map(|sum| {
+            let class: AccountClass = sum.get_class().parse()?;
+            let balance = match class.is_debit_normal() {
+                true => sum.get_debit() - sum.get_credit(),
+                false => sum.get_credit() - sum.get_debit(),
+            };
+            Ok((
+                class,
+                AccountBalance::new(
+                    sum.get_account_id(),
+                    sum.get_code(),
+                    sum.get_name(),
+                    sum.get_class(),
+                    sum.get_debit(),
+                    sum.get_credit(),
+                    balance,
+                ),
+            ))
+        })
+        .collect()
+}
+
+fn filter_class(
+    balances: &[(AccountClass, AccountBalance)],
+    target: AccountClass,
+) -> Vec<AccountBalance> {
+    balances
+        .iter()
+        .filter(|(class, _)| *class == target)
+        .map(|(_, balance)| balance.clone())
+        .collect()
+}
+
+pub fn to_income_statement(
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+    balances: &[(AccountClass, AccountBalance)],
+) -> IncomeStatement {
+    IncomeStatement::new(
+        start_dt,
+        end_dt,
+        filter_class(balances, AccountClass::Revenue),
+        filter_class(balances, AccountClass::Expense),
+    )
+}
+
+/// 수익, 비용 계정은 누적 순이익으로 자본에 반영
+pub fn to_balance_sheet(
+    base_dt: NaiveDate,
+    balances: &[(AccountClass, AccountBalance)],
+) -> BalanceSheet {
+    let net_income = balances
+        .iter()
+        .map(|(class, balance)| match class {
+            AccountClass::Revenue => balance.get_balance(),
+            AccountClass::Expense => -balance.get_balance(),
+            _ => 0,
+        })
+        .sum();
+
+    BalanceSheet::new(
+        base_dt,
+        filter_class(balances, AccountClass::Asset),
+        filter_class(balances, AccountClass::Liability),
+        filter_class(balances, AccountClass::Equity),
+        net_income,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use crate::domain::journal::{
+        entity::{Account, AccountClass, AccountSum, EntryLineRow},
+        utils::statement::{to_balance_sheet, to_balances, to_entries, to_income_statement},
+    };
+
+    fn _date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 9, day).unwrap()
+    }
+
+    // 자본금 100만원 출자, 현금 매출 30만원, 외상 매입 10만원
+    fn _sums() -> Vec<AccountSum> {
+        vec![
+            AccountSum::new(
+                &Account::new(1, "101", "현금", AccountClass::Asset),
+                1300000,
+                0,
+            ),
+            AccountSum::new(
+                &Account::new(5, "201", "외상매입금", AccountClass::Liability),
+                0,
+                100000,
+            ),
+            AccountSum::new(
+                &Account::new(7, "301", "자본금", AccountClass::Equity),
+                0,
+                1000000,
+            ),
+            AccountSum::new(
+                &Account::new(8, "401", "매출금", AccountClass::Revenue),
+                0,
+                300000,
+            ),
+            AccountSum::new(
+                &Account::new(9, "501", "매입금", AccountClass::Expense),
+                100000,
+                0,
+            ),
+        ]
+    }
+
+    #[test]
+    fn check_to_entries() {
+        // Arrange
+        let dt = NaiveDateTime::parse_from_str("2024-09-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let cash = Account::new(1, "101", "현금", AccountClass::Asset);
+        let sales = Account::new(8, "401", "매출금", AccountClass::Revenue);
+        let rows = vec![
+            EntryLineRow::new(1, Some("매출"), dt, &cash, 1000, 0),
+            EntryLineRow::new(1, Some("매출"), dt, &sales, 0, 1000),
+            EntryLineRow::new(2, None, dt, &cash, 500, 0),
+            EntryLineRow::new(2, None, dt, &sales, 0, 500),
+        ];
+
+        // Act
+        let entries = to_entries(&rows);
+
+        // Assert
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].get_id(), 1);
+        assert_eq!(entries[1].get_lines().len(), 2);
+    }
+
+    #[test]
+    fn check_to_balances() {
+        // Arrange, Act
+        let balances = to_balances(&_sums()).unwrap();
+
+        // Assert
+        let balances: Vec<i64> = balances.iter().map(|(_, b)| b.get_balance()).collect();
+        assert_eq!(balances, vec![1300000, 100000, 1000000, 300000, 100000]);
+    }
+
+    #[test]
+    fn check_to_income_statement() {
+        // Arrange
+        let balances = to_balances(&_sums()).unwrap();
+
+        // Act
+        let statement = to_income_statement(_date(1), _date(30), &balances);
+
+        // Assert
+        assert_eq!(statement.get_total_revenue(), 300000);
+        assert_eq!(statement.get_total_expense(), 100000);
+        assert_eq!(statement.get_net_income(), 200000);
+    }
+
+    #[test]
+    fn check_to_balance_sheet() {
+        // Arrange
+        let balances = to_balances(&_sums()).unwrap();
+
+        // Act
+        let sheet = to_balance_sheet(_date(30), &balances);
+
+        // Assert
+        // 자산 = 부채 + 자본(순이익 포함)
+        assert_eq!(sheet.get_net_income(), 200000);
+        assert_eq!(sheet.get_total_asset(), 1300000);
+        assert_eq!(
+            sheet.get_total_asset(),
+            sheet.get_total_liability() + sheet.get_total_equity()
+        );
+    }
+}
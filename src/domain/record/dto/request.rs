@@ -1,10 +1,13 @@
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, Weekday};
 use serde::{Deserialize, Serialize};
 use url::form_urlencoded::Serializer;
 
 use crate::{
-    domain::record::entity::{Record, Search, UpdateRecord},
-    global::constants::FieldUpdate,
+    domain::record::entity::{
+        ExportFormat, Record, RecordCursor, RecordLine, RecordSort, Search, Transfer, UpdateRecord,
+        MAX_PAGE_LIMIT,
+    },
+    global::{constants::FieldUpdate, errors::CustomError},
 };
 
 #[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
@@ -16,6 +19,15 @@ pub struct NewRecord {
     target_dt: NaiveDateTime,
     asset_id: Option<i32>,
     connect_ids: Option<Vec<i32>>,
+    lines: Option<Vec<RecordLine>>,
+}
+
+// 분할 내역은 금액이 양수이고 합계가 기록 금액과 같아야 함
+fn is_valid_lines(amount: i32, lines: &[RecordLine]) -> bool {
+    let sum = lines
+        .iter()
+        .try_fold(0i32, |acc, line| acc.checked_add(line.get_amount()));
+    lines.iter().all(|line| line.get_amount() > 0) && sum == Some(amount)
 }
 
 impl NewRecord {
@@ -36,6 +48,19 @@ impl NewRecord {
             target_dt,
             asset_id,
             connect_ids,
+            lines: None,
+        }
+    }
+
+    pub fn lines(mut self, lines: Option<Vec<RecordLine>>) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    pub fn is_valid_lines(&self) -> bool {
+        match &self.lines {
+            Some(lines) if !lines.is_empty() => is_valid_lines(self.amount, lines),
+            _ => true,
         }
     }
 
@@ -54,33 +79,220 @@ impl NewRecord {
     pub fn get_connect_ids(&self) -> Option<Vec<i32>> {
         self.connect_ids.clone()
     }
+
+    pub fn get_lines(&self) -> Vec<RecordLine> {
+        self.lines.clone().unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct NewTransfer {
+    book_id: i32,
+    sub_category_id: i32,
+    amount: i32,
+    memo: Option<String>,
+    target_dt: NaiveDateTime,
+    from_asset_id: i32,
+    to_asset_id: i32,
+}
+
+impl NewTransfer {
+    pub fn new(
+        book_id: i32,
+        sub_category_id: i32,
+        amount: i32,
+        memo: Option<String>,
+        target_dt: NaiveDateTime,
+        from_asset_id: i32,
+        to_asset_id: i32,
+    ) -> Self {
+        Self {
+            book_id,
+            sub_category_id,
+            amount,
+            memo,
+            target_dt,
+            from_asset_id,
+            to_asset_id,
+        }
+    }
+
+    pub fn is_same_asset(&self) -> bool {
+        self.from_asset_id == self.to_asset_id
+    }
+
+    pub fn to_entity(&self) -> Transfer {
+        Transfer::new(
+            self.book_id,
+            self.sub_category_id,
+            self.amount,
+            self.target_dt,
+            self.from_asset_id,
+            self.to_asset_id,
+        )
+        .memo(self.memo.clone())
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq, Clone)]
 pub struct SearchParams {
     start_dt: NaiveDate,
-    period: String, // M, D
+    #[serde(default)]
+    period: String, // D, W, M, Q, Y
+    end_dt: Option<NaiveDate>,
+    week_start: Option<String>, // mon ~ sun, default mon
     base_id: Option<i16>,
     sub_id: Option<i32>,
+    memo: Option<String>,
+    min_amount: Option<i32>,
+    max_amount: Option<i32>,
+    asset_id: Option<i32>,
+    connect_id: Option<i32>,
+    sort: Option<String>,  // target_dt, amount, created_at
+    order: Option<String>, // asc, desc
+    cursor: Option<String>,
+    limit: Option<i64>,
 }
 
 impl SearchParams {
     pub fn new(
         start_dt: NaiveDate,
-        period: String, // M, D
+        period: String, // D, W, M, Q, Y
         base_id: Option<i16>,
         sub_id: Option<i32>,
     ) -> Self {
         Self {
             start_dt,
             period,
+            end_dt: None,
+            week_start: None,
             base_id,
             sub_id,
+            memo: None,
+            min_amount: None,
+            max_amount: None,
+            asset_id: None,
+            connect_id: None,
+            sort: None,
+            order: None,
+            cursor: None,
+            limit: None,
         }
     }
 
-    pub fn get_period(&self) -> &str {
-        &self.period
+    pub fn end_dt(mut self, end_dt: Option<NaiveDate>) -> Self {
+        self.end_dt = end_dt;
+        self
+    }
+
+    pub fn week_start(mut self, week_start: Option<String>) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn memo(mut self, memo: Option<String>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    pub fn amount_range(mut self, min_amount: Option<i32>, max_amount: Option<i32>) -> Self {
+        self.min_amount = min_amount;
+        self.max_amount = max_amount;
+        self
+    }
+
+    pub fn asset_id(mut self, asset_id: Option<i32>) -> Self {
+        self.asset_id = asset_id;
+        self
+    }
+
+    pub fn connect_id(mut self, connect_id: Option<i32>) -> Self {
+        self.connect_id = connect_id;
+        self
+    }
+
+    pub fn sort(mut self, sort: Option<String>, order: Option<String>) -> Self {
+        self.sort = sort;
+        self.order = order;
+        self
+    }
+
+    pub fn cursor(mut self, cursor: Option<String>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    pub fn limit(mut self, limit: Option<i64>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    // 조회 범위 [start, end)
+    fn get_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        if let Some(end_dt) = self.end_dt {
+            if self.start_dt > end_dt {
+                return None;
+            }
+            return Some((self.start_dt, end_dt.checked_add_days(Days::new(1))?));
+        }
+
+        let start_dt = self.start_dt;
+        match self.period.to_lowercase().as_str() {
+            "d" => Some((start_dt, start_dt.checked_add_days(Days::new(1))?)),
+            "w" => {
+                let week_start = match &self.week_start {
+                    Some(week_start) => week_start.parse::<Weekday>().ok()?,
+                    None => Weekday::Mon,
+                };
+                let offset = (7 + start_dt.weekday().num_days_from_monday()
+                    - week_start.num_days_from_monday())
+                    % 7;
+                let start_dt = start_dt.checked_sub_days(Days::new(offset as u64))?;
+                Some((start_dt, start_dt.checked_add_days(Days::new(7))?))
+            }
+            "m" => Some((start_dt, start_dt.checked_add_months(Months::new(1))?)),
+            "q" => {
+                let month = start_dt.month0() / 3 * 3 + 1;
+                let start_dt = NaiveDate::from_ymd_opt(start_dt.year(), month, 1)?;
+                Some((start_dt, start_dt.checked_add_months(Months::new(3))?))
+            }
+            "y" => {
+                let start_dt = NaiveDate::from_ymd_opt(start_dt.year(), 1, 1)?;
+                Some((start_dt, start_dt.checked_add_months(Months::new(12))?))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_valid_period(&self) -> bool {
+        self.get_range().is_some()
+    }
+
+    fn get_sort(&self) -> Option<RecordSort> {
+        match &self.sort {
+            Some(sort) => RecordSort::parse(sort),
+            None => Some(RecordSort::TargetDt),
+        }
+    }
+
+    pub fn is_valid_page(&self) -> bool {
+        let Some(sort) = self.get_sort() else {
+            return false;
+        };
+        let is_valid_order = match &self.order {
+            Some(order) => matches!(order.to_lowercase().as_str(), "asc" | "desc"),
+            None => true,
+        };
+        let is_valid_cursor = match &self.cursor {
+            Some(cursor) => RecordCursor::decode(sort, cursor).is_some(),
+            None => true,
+        };
+        let is_valid_limit = match self.limit {
+            Some(limit) => (1..=MAX_PAGE_LIMIT).contains(&limit),
+            None => true,
+        };
+
+        is_valid_order && is_valid_cursor && is_valid_limit
     }
 
     pub fn encode_param(&self) -> String {
@@ -89,6 +301,14 @@ impl SearchParams {
             .append_pair("start_dt", &self.start_dt.format("%Y-%m-%d").to_string())
             .append_pair("period", &self.period);
 
+        if let Some(end_dt) = self.end_dt {
+            binding.append_pair("end_dt", &end_dt.format("%Y-%m-%d").to_string());
+        }
+
+        if let Some(week_start) = &self.week_start {
+            binding.append_pair("week_start", week_start);
+        }
+
         if let Some(base_id) = self.base_id {
             binding.append_pair("base_id", &base_id.to_string());
         }
@@ -97,17 +317,123 @@ impl SearchParams {
             binding.append_pair("sub_id", &sub_id.to_string());
         }
 
+        if let Some(memo) = &self.memo {
+            binding.append_pair("memo", memo);
+        }
+
+        if let Some(min_amount) = self.min_amount {
+            binding.append_pair("min_amount", &min_amount.to_string());
+        }
+
+        if let Some(max_amount) = self.max_amount {
+            binding.append_pair("max_amount", &max_amount.to_string());
+        }
+
+        if let Some(asset_id) = self.asset_id {
+            binding.append_pair("asset_id", &asset_id.to_string());
+        }
+
+        if let Some(connect_id) = self.connect_id {
+            binding.append_pair("connect_id", &connect_id.to_string());
+        }
+
+        if let Some(sort) = &self.sort {
+            binding.append_pair("sort", sort);
+        }
+
+        if let Some(order) = &self.order {
+            binding.append_pair("order", order);
+        }
+
+        if let Some(cursor) = &self.cursor {
+            binding.append_pair("cursor", cursor);
+        }
+
+        if let Some(limit) = self.limit {
+            binding.append_pair("limit", &limit.to_string());
+        }
+
         binding.finish()
     }
 
-    pub fn to_query(&self) -> Search {
-        let end_dt = match self.period.to_lowercase().as_str() {
-            "m" => self.start_dt.checked_add_months(chrono::Months::new(1)),
-            "d" => self.start_dt.checked_add_days(chrono::Days::new(1)),
-            _ => None,
-        };
+    pub fn to_query(&self) -> Result<Search, Box<CustomError>> {
+        let (start_dt, end_dt) = self
+            .get_range()
+            .ok_or(Box::new(CustomError::ValidationError(
+                "RecordPeriod".to_string(),
+            )))?;
+
+        if let (Some(min_amount), Some(max_amount)) = (self.min_amount, self.max_amount) {
+            if min_amount > max_amount {
+                return Err(Box::new(CustomError::ValidationError(
+                    "RecordAmount".to_string(),
+                )));
+            }
+        }
+        let memo = self
+            .memo
+            .as_ref()
+            .map(|memo| memo.trim().to_string())
+            .filter(|memo| !memo.is_empty());
+
+        let sort = self.get_sort().unwrap_or(RecordSort::TargetDt);
+        let is_asc = self
+            .order
+            .as_ref()
+            .is_some_and(|order| order.to_lowercase() == "asc");
+        let cursor = self
+            .cursor
+            .as_ref()
+            .and_then(|cursor| RecordCursor::decode(sort, cursor));
+
+        let search = Search::new(start_dt, end_dt, self.base_id, self.sub_id)
+            .memo(memo)
+            .amount_range(self.min_amount, self.max_amount)
+            .asset_id(self.asset_id)
+            .connect_id(self.connect_id)
+            .sort(sort, is_asc)
+            .cursor(cursor);
+
+        match self.limit {
+            Some(limit) => Ok(search.limit(limit)),
+            None => Ok(search),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct CalendarParams {
+    year: i32,
+    month: u32,
+}
+
+impl CalendarParams {
+    pub fn new(year: i32, month: u32) -> Self {
+        Self { year, month }
+    }
+
+    // 해당 월 [1일, 다음 달 1일)
+    pub fn get_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let start_dt = NaiveDate::from_ymd_opt(self.year, self.month, 1)?;
+        Some((start_dt, start_dt.checked_add_months(Months::new(1))?))
+    }
+
+    pub fn encode_param(&self) -> String {
+        Serializer::new(String::new())
+            .append_pair("year", &self.year.to_string())
+            .append_pair("month", &self.month.to_string())
+            .finish()
+    }
+}
 
-        Search::new(self.start_dt, end_dt.unwrap(), self.base_id, self.sub_id)
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct ExportParams {
+    format: String, // csv, xlsx
+}
+
+impl ExportParams {
+    pub fn to_format(&self) -> Result<ExportFormat, Box<CustomError>> {
+        self.format.parse()
     }
 }
 
@@ -118,6 +444,7 @@ pub struct EditRecord {
     memo: Option<String>,
     target_dt: Option<NaiveDateTime>,
     asset_id: Option<i32>,
+    lines: Option<Vec<RecordLine>>, // 빈 목록이면 분할 해제
 }
 
 impl EditRecord {
@@ -134,6 +461,21 @@ impl EditRecord {
             memo,
             target_dt,
             asset_id,
+            lines: None,
+        }
+    }
+
+    pub fn lines(mut self, lines: Option<Vec<RecordLine>>) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    // 금액을 함께 변경하지 않으면 합계는 저장소에서 기존 금액과 비교
+    pub fn is_valid_lines(&self) -> bool {
+        match (&self.lines, self.amount) {
+            (Some(lines), Some(amount)) if !lines.is_empty() => is_valid_lines(amount, lines),
+            (Some(lines), None) => lines.iter().all(|line| line.get_amount() > 0),
+            _ => true,
         }
     }
 
@@ -159,6 +501,76 @@ impl EditRecord {
             Some(v) => FieldUpdate::Set(v),
             None => FieldUpdate::NoChange,
         };
-        UpdateRecord::new(sub_category_id, amount, memo, target_dt, asset_id)
+        let lines = match self.lines {
+            Some(v) if v.is_empty() => FieldUpdate::SetNone,
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        UpdateRecord::new(sub_category_id, amount, memo, target_dt, asset_id).lines(lines)
+    }
+}
+
+// CSV 열 이름 매핑
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct ColumnMapping {
+    target_dt: String,
+    amount: String,
+    memo: Option<String>,
+    category: String,
+}
+
+impl ColumnMapping {
+    pub fn new(target_dt: String, amount: String, memo: Option<String>, category: String) -> Self {
+        Self {
+            target_dt,
+            amount,
+            memo,
+            category,
+        }
+    }
+
+    pub fn get_target_dt(&self) -> &str {
+        &self.target_dt
+    }
+    pub fn get_amount(&self) -> &str {
+        &self.amount
+    }
+    pub fn get_memo(&self) -> Option<&str> {
+        self.memo.as_deref()
+    }
+    pub fn get_category(&self) -> &str {
+        &self.category
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRecords {
+    content: String,
+    mapping: ColumnMapping,
+    dry_run: bool,
+}
+
+impl ImportRecords {
+    pub fn new(content: String, mapping: ColumnMapping) -> Self {
+        Self {
+            content,
+            mapping,
+            dry_run: false,
+        }
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn get_mapping(&self) -> &ColumnMapping {
+        &self.mapping
+    }
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
     }
 }
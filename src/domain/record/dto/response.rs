@@ -0,0 +1,262 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+use tokio::sync::mpsc::Receiver;
+
+use crate::{
+    domain::record::entity::{CategorySum, ExportFormat, Record, RecordLine},
+    global::errors::CustomError,
+};
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct RecordPage {
+    records: Vec<Record>,
+    next_cursor: Option<String>,
+}
+
+impl RecordPage {
+    pub fn new(records: Vec<Record>, next_cursor: Option<String>) -> Self {
+        Self {
+            records,
+            next_cursor,
+        }
+    }
+
+    pub fn get_records(&self) -> &Vec<Record> {
+        &self.records
+    }
+    pub fn get_next_cursor(&self) -> &Option<String> {
+        &self.next_cursor
+    }
+}
+
+// 단건 조회: 기록과 분할 내역
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct RecordDetail {
+    #[serde(flatten)]
+    record: Record,
+    lines: Vec<RecordLine>,
+}
+
+impl RecordDetail {
+    pub fn new(record: Record, lines: Vec<RecordLine>) -> Self {
+        Self { record, lines }
+    }
+
+    pub fn get_record(&self) -> &Record {
+        &self.record
+    }
+    pub fn get_lines(&self) -> &Vec<RecordLine> {
+        &self.lines
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct SubSummary {
+    sub_id: i32,
+    name: String,
+    amount: i64,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BaseSummary {
+    base_id: i16,
+    name: String,
+    color: String,
+    amount: i64,
+    sub_categories: Vec<SubSummary>,
+}
+
+impl BaseSummary {
+    pub fn get_base_id(&self) -> i16 {
+        self.base_id
+    }
+    pub fn get_amount(&self) -> i64 {
+        self.amount
+    }
+    pub fn get_sub_categories(&self) -> &Vec<SubSummary> {
+        &self.sub_categories
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct RecordSummary {
+    income: Vec<BaseSummary>,
+    expense: Vec<BaseSummary>,
+}
+
+impl RecordSummary {
+    // 대분류 순서로 정렬된 소분류 합계를 대분류 단위로 묶음
+    pub fn new(rows: Vec<CategorySum>) -> Self {
+        let mut income: Vec<BaseSummary> = vec![];
+        let mut expense: Vec<BaseSummary> = vec![];
+
+        for row in rows {
+            let summaries = if row.get_income() {
+                &mut income
+            } else {
+                &mut expense
+            };
+            let sub = SubSummary {
+                sub_id: row.get_sub_id(),
+                name: row.get_sub_name().to_string(),
+                amount: row.get_amount(),
+            };
+
+            match summaries.last_mut() {
+                Some(base) if base.base_id == row.get_base_id() => base.sub_categories.push(sub),
+                _ => summaries.push(BaseSummary {
+                    base_id: row.get_base_id(),
+                    name: row.get_base_name().to_string(),
+                    color: row.get_color().to_string(),
+                    amount: row.get_base_amount(),
+                    sub_categories: vec![sub],
+                }),
+            }
+        }
+
+        Self { income, expense }
+    }
+
+    pub fn get_income(&self) -> &Vec<BaseSummary> {
+        &self.income
+    }
+    pub fn get_expense(&self) -> &Vec<BaseSummary> {
+        &self.expense
+    }
+}
+
+// CSV 가져오기: 검증된 행
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ImportedRow {
+    row: u64,
+    target_dt: NaiveDateTime,
+    amount: i32,
+    memo: Option<String>,
+    category: String,
+    sub_category_id: i32,
+}
+
+impl ImportedRow {
+    pub fn new(
+        row: u64,
+        target_dt: NaiveDateTime,
+        amount: i32,
+        memo: Option<String>,
+        category: String,
+        sub_category_id: i32,
+    ) -> Self {
+        Self {
+            row,
+            target_dt,
+            amount,
+            memo,
+            category,
+            sub_category_id,
+        }
+    }
+
+    pub fn to_entity(&self, book_id: i32) -> Record {
+        Record::new(
+            book_id,
+            self.sub_category_id,
+            self.amount,
+            self.target_dt,
+            None,
+        )
+        .memo(self.memo.clone())
+        .build()
+    }
+
+    pub fn get_row(&self) -> u64 {
+        self.row
+    }
+    pub fn get_sub_category_id(&self) -> i32 {
+        self.sub_category_id
+    }
+}
+
+// CSV 가져오기: 행 단위 오류 (row는 헤더를 1행으로 세고 빈 줄은 제외한 행 번호)
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ImportError {
+    row: u64,
+    field: String,
+    reason: String,
+}
+
+impl ImportError {
+    pub fn new(row: u64, field: &str, reason: &str) -> Self {
+        Self {
+            row,
+            field: field.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn get_row(&self) -> u64 {
+        self.row
+    }
+    pub fn get_field(&self) -> &str {
+        &self.field
+    }
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ImportResult {
+    dry_run: bool,
+    imported: u64,
+    rows: Vec<ImportedRow>,
+    errors: Vec<ImportError>,
+}
+
+impl ImportResult {
+    pub fn new(dry_run: bool, rows: Vec<ImportedRow>, errors: Vec<ImportError>) -> Self {
+        Self {
+            dry_run,
+            imported: 0,
+            rows,
+            errors,
+        }
+    }
+
+    pub fn imported(mut self, imported: u64) -> Self {
+        self.imported = imported;
+        self
+    }
+
+    pub fn get_imported(&self) -> u64 {
+        self.imported
+    }
+    pub fn get_rows(&self) -> &Vec<ImportedRow> {
+        &self.rows
+    }
+    pub fn get_errors(&self) -> &Vec<ImportError> {
+        &self.errors
+    }
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+/// 내보내기 파일: 형식과 파일 내용 조각
+#[derive(Debug)]
+pub struct ExportFile {
+    format: ExportFormat,
+    chunks: Receiver<Result<Vec<u8>, Box<CustomError>>>,
+}
+
+impl ExportFile {
+    pub fn new(format: ExportFormat, chunks: Receiver<Result<Vec<u8>, Box<CustomError>>>) -> Self {
+        Self { format, chunks }
+    }
+
+    pub fn get_format(&self) -> &ExportFormat {
+        &self.format
+    }
+    pub fn into_chunks(self) -> Receiver<Result<Vec<u8>, Box<CustomError>>> {
+        self.chunks
+    }
+}
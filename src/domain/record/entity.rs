@@ -1,7 +1,9 @@
+use std::str::FromStr;
+
 use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 
-use crate::global::constants::FieldUpdate;
+use crate::global::{constants::FieldUpdate, errors::CustomError};
 
 #[derive(Deserialize, Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
 pub struct Record {
@@ -14,6 +16,7 @@ pub struct Record {
     created_at: Option<NaiveDateTime>,
     updated_at: Option<NaiveDateTime>,
     asset_id: Option<i32>,
+    transfer_id: Option<i64>,
 }
 
 impl Record {
@@ -34,6 +37,7 @@ impl Record {
             created_at: None,
             updated_at: None,
             asset_id,
+            transfer_id: None,
         }
     }
 
@@ -63,6 +67,7 @@ impl Record {
             created_at: self.created_at,
             updated_at: self.updated_at,
             asset_id: self.asset_id,
+            transfer_id: self.transfer_id,
         }
     }
 
@@ -98,6 +103,441 @@ impl Record {
     pub fn get_asset_id(&self) -> &Option<i32> {
         &self.asset_id
     }
+    pub fn get_transfer_id(&self) -> &Option<i64> {
+        &self.transfer_id
+    }
+}
+
+/// 분할 기록의 소분류별 금액
+#[derive(Deserialize, Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct RecordLine {
+    sub_category_id: i32,
+    amount: i32,
+}
+
+impl RecordLine {
+    pub fn new(sub_category_id: i32, amount: i32) -> Self {
+        Self {
+            sub_category_id,
+            amount,
+        }
+    }
+
+    pub fn get_sub_category_id(&self) -> i32 {
+        self.sub_category_id
+    }
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transfer {
+    book_id: i32,
+    sub_category_id: i32,
+    amount: i32,
+    memo: Option<String>,
+    target_dt: NaiveDateTime,
+    from_asset_id: i32,
+    to_asset_id: i32,
+}
+
+impl Transfer {
+    pub fn new(
+        book_id: i32,
+        sub_category_id: i32,
+        amount: i32,
+        target_dt: NaiveDateTime,
+        from_asset_id: i32,
+        to_asset_id: i32,
+    ) -> Self {
+        Self {
+            book_id,
+            sub_category_id,
+            amount,
+            memo: None,
+            target_dt,
+            from_asset_id,
+            to_asset_id,
+        }
+    }
+
+    pub fn memo(mut self, memo: Option<String>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    pub fn get_book_id(&self) -> i32 {
+        self.book_id
+    }
+    pub fn get_sub_category_id(&self) -> i32 {
+        self.sub_category_id
+    }
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+    pub fn get_memo(&self) -> &Option<String> {
+        &self.memo
+    }
+    pub fn get_target_dt(&self) -> NaiveDateTime {
+        self.target_dt
+    }
+    pub fn get_from_asset_id(&self) -> i32 {
+        self.from_asset_id
+    }
+    pub fn get_to_asset_id(&self) -> i32 {
+        self.to_asset_id
+    }
+}
+
+#[derive(Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct RecordTotal {
+    income: i64,
+    expense: i64,
+}
+
+impl RecordTotal {
+    pub fn new(income: i64, expense: i64) -> Self {
+        Self { income, expense }
+    }
+
+    pub fn get_income(&self) -> i64 {
+        self.income
+    }
+    pub fn get_expense(&self) -> i64 {
+        self.expense
+    }
+}
+
+#[derive(Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct DailyTotal {
+    target_date: NaiveDate,
+    income: i64,
+    expense: i64,
+    count: i64,
+}
+
+impl DailyTotal {
+    pub fn new(target_date: NaiveDate, income: i64, expense: i64, count: i64) -> Self {
+        Self {
+            target_date,
+            income,
+            expense,
+            count,
+        }
+    }
+
+    pub fn get_target_date(&self) -> NaiveDate {
+        self.target_date
+    }
+    pub fn get_income(&self) -> i64 {
+        self.income
+    }
+    pub fn get_expense(&self) -> i64 {
+        self.expense
+    }
+    pub fn get_count(&self) -> i64 {
+        self.count
+    }
+}
+
+// 소분류별 합계, base_amount는 대분류 합계
+#[derive(Debug, sqlx::FromRow, Clone, PartialEq)]
+pub struct CategorySum {
+    base_id: i16,
+    base_name: String,
+    color: String,
+    is_income: bool,
+    base_amount: i64,
+    sub_id: i32,
+    sub_name: String,
+    amount: i64,
+}
+
+impl CategorySum {
+    pub fn new(base_id: i16, sub_id: i32, is_income: bool, amount: i64) -> Self {
+        Self {
+            base_id,
+            base_name: String::new(),
+            color: String::new(),
+            is_income,
+            base_amount: amount,
+            sub_id,
+            sub_name: String::new(),
+            amount,
+        }
+    }
+
+    pub fn base(mut self, base_name: String, color: String, base_amount: i64) -> Self {
+        self.base_name = base_name;
+        self.color = color;
+        self.base_amount = base_amount;
+        self
+    }
+
+    pub fn sub_name(mut self, sub_name: String) -> Self {
+        self.sub_name = sub_name;
+        self
+    }
+
+    pub fn get_base_id(&self) -> i16 {
+        self.base_id
+    }
+    pub fn get_base_name(&self) -> &str {
+        &self.base_name
+    }
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+    pub fn get_income(&self) -> bool {
+        self.is_income
+    }
+    pub fn get_base_amount(&self) -> i64 {
+        self.base_amount
+    }
+    pub fn get_sub_id(&self) -> i32 {
+        self.sub_id
+    }
+    pub fn get_sub_name(&self) -> &str {
+        &self.sub_name
+    }
+    pub fn get_amount(&self) -> i64 {
+        self.amount
+    }
+}
+
+/// CSV 가져오기에서 이름으로 찾을 수 있는 소분류
+#[derive(Debug, sqlx::FromRow, Clone, PartialEq)]
+pub struct ImportCategory {
+    id: i32,
+    base_name: String,
+    name: String,
+}
+
+impl ImportCategory {
+    pub fn new(id: i32, base_name: String, name: String) -> Self {
+        Self {
+            id,
+            base_name,
+            name,
+        }
+    }
+
+    pub fn get_id(&self) -> i32 {
+        self.id
+    }
+    pub fn get_base_name(&self) -> &str {
+        &self.base_name
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Xlsx,
+}
+
+impl FromStr for ExportFormat {
+    type Err = Box<CustomError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "xlsx" => Ok(ExportFormat::Xlsx),
+            _ => Err(Box::new(CustomError::ValidationError(
+                "ExportFormat".to_string(),
+            ))),
+        }
+    }
+}
+
+impl ExportFormat {
+    pub fn get_content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+        }
+    }
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
+
+/// 내보내기 한 행: id 대신 카테고리, 자산, 커넥트 이름
+#[derive(Debug, sqlx::FromRow, Clone, PartialEq)]
+pub struct ExportRow {
+    target_dt: NaiveDateTime,
+    is_income: bool,
+    is_transfer: bool,
+    base_name: String,
+    sub_name: String,
+    amount: i32,
+    memo: Option<String>,
+    asset_name: Option<String>,
+    connect_names: Option<String>,
+}
+
+impl ExportRow {
+    pub fn new(
+        target_dt: NaiveDateTime,
+        is_income: bool,
+        base_name: String,
+        sub_name: String,
+        amount: i32,
+    ) -> Self {
+        Self {
+            target_dt,
+            is_income,
+            is_transfer: false,
+            base_name,
+            sub_name,
+            amount,
+            memo: None,
+            asset_name: None,
+            connect_names: None,
+        }
+    }
+
+    pub fn memo(mut self, memo: Option<String>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    pub fn asset_name(mut self, asset_name: Option<String>) -> Self {
+        self.asset_name = asset_name;
+        self
+    }
+
+    pub fn connect_names(mut self, connect_names: Option<String>) -> Self {
+        self.connect_names = connect_names;
+        self
+    }
+
+    pub fn get_target_dt(&self) -> &NaiveDateTime {
+        &self.target_dt
+    }
+    pub fn get_kind(&self) -> &'static str {
+        if self.is_transfer {
+            "이체"
+        } else if self.is_income {
+            "수입"
+        } else {
+            "지출"
+        }
+    }
+    pub fn get_base_name(&self) -> &str {
+        &self.base_name
+    }
+    pub fn get_sub_name(&self) -> &str {
+        &self.sub_name
+    }
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+    pub fn get_memo(&self) -> &str {
+        self.memo.as_deref().unwrap_or_default()
+    }
+    pub fn get_asset_name(&self) -> &str {
+        self.asset_name.as_deref().unwrap_or_default()
+    }
+    pub fn get_connect_names(&self) -> &str {
+        self.connect_names.as_deref().unwrap_or_default()
+    }
+}
+
+pub const DEFAULT_PAGE_LIMIT: i64 = 50;
+pub const MAX_PAGE_LIMIT: i64 = 100;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RecordSort {
+    TargetDt,
+    Amount,
+    CreatedAt,
+}
+
+impl RecordSort {
+    pub fn parse(sort: &str) -> Option<Self> {
+        match sort.to_lowercase().as_str() {
+            "target_dt" => Some(Self::TargetDt),
+            "amount" => Some(Self::Amount),
+            "created_at" => Some(Self::CreatedAt),
+            _ => None,
+        }
+    }
+
+    pub fn get_column(&self) -> &str {
+        match self {
+            Self::TargetDt => "r.target_dt",
+            Self::Amount => "r.amount",
+            Self::CreatedAt => "r.created_at",
+        }
+    }
+    pub fn get_sql_type(&self) -> &str {
+        match self {
+            Self::Amount => "INT",
+            _ => "TIMESTAMP",
+        }
+    }
+
+    // 커서 값은 정렬 기준 컬럼과 id 조합, 정렬 값이 없으면 커서를 만들지 않음
+    pub fn to_cursor(self, record: &Record) -> Option<RecordCursor> {
+        let value = match self {
+            Self::TargetDt => record.get_target_dt().format(CURSOR_DT_FORMAT).to_string(),
+            Self::Amount => record.get_amount().to_string(),
+            Self::CreatedAt => record
+                .get_created_at()?
+                .format(CURSOR_DT_FORMAT)
+                .to_string(),
+        };
+        Some(RecordCursor::new(value, record.get_id()))
+    }
+}
+
+const CURSOR_DT_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecordCursor {
+    value: String,
+    id: i64,
+}
+
+impl RecordCursor {
+    pub fn new(value: String, id: i64) -> Self {
+        Self { value, id }
+    }
+
+    // ex) 2024-09-08T15:30:27_12, 15000_12
+    pub fn decode(sort: RecordSort, cursor: &str) -> Option<Self> {
+        let (value, id) = cursor.rsplit_once('_')?;
+        let id = id.parse::<i64>().ok()?;
+        let is_valid = match sort {
+            RecordSort::Amount => value.parse::<i32>().is_ok(),
+            _ => NaiveDateTime::parse_from_str(value, CURSOR_DT_FORMAT).is_ok(),
+        };
+
+        if is_valid {
+            Some(Self::new(value.to_string(), id))
+        } else {
+            None
+        }
+    }
+    pub fn encode(&self) -> String {
+        format!("{}_{}", self.value, self.id)
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn get_id(&self) -> i64 {
+        self.id
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -106,6 +546,15 @@ pub struct Search {
     end_dt: NaiveDate,
     base_id: Option<i16>,
     sub_id: Option<i32>,
+    memo: Option<String>,
+    min_amount: Option<i32>,
+    max_amount: Option<i32>,
+    asset_id: Option<i32>,
+    connect_id: Option<i32>,
+    sort: RecordSort,
+    is_asc: bool,
+    cursor: Option<RecordCursor>,
+    limit: i64,
 }
 
 impl Search {
@@ -120,9 +569,55 @@ impl Search {
             end_dt,
             base_id,
             sub_id,
+            memo: None,
+            min_amount: None,
+            max_amount: None,
+            asset_id: None,
+            connect_id: None,
+            sort: RecordSort::TargetDt,
+            is_asc: false,
+            cursor: None,
+            limit: DEFAULT_PAGE_LIMIT,
         }
     }
 
+    pub fn memo(mut self, memo: Option<String>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    pub fn amount_range(mut self, min_amount: Option<i32>, max_amount: Option<i32>) -> Self {
+        self.min_amount = min_amount;
+        self.max_amount = max_amount;
+        self
+    }
+
+    pub fn asset_id(mut self, asset_id: Option<i32>) -> Self {
+        self.asset_id = asset_id;
+        self
+    }
+
+    pub fn connect_id(mut self, connect_id: Option<i32>) -> Self {
+        self.connect_id = connect_id;
+        self
+    }
+
+    pub fn sort(mut self, sort: RecordSort, is_asc: bool) -> Self {
+        self.sort = sort;
+        self.is_asc = is_asc;
+        self
+    }
+
+    pub fn cursor(mut self, cursor: Option<RecordCursor>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = limit;
+        self
+    }
+
     pub fn get_start_dt(&self) -> &NaiveDate {
         &self.start_dt
     }
@@ -135,6 +630,33 @@ impl Search {
     pub fn get_sub_id(&self) -> &Option<i32> {
         &self.sub_id
     }
+    pub fn get_memo(&self) -> &Option<String> {
+        &self.memo
+    }
+    pub fn get_min_amount(&self) -> &Option<i32> {
+        &self.min_amount
+    }
+    pub fn get_max_amount(&self) -> &Option<i32> {
+        &self.max_amount
+    }
+    pub fn get_asset_id(&self) -> &Option<i32> {
+        &self.asset_id
+    }
+    pub fn get_connect_id(&self) -> &Option<i32> {
+        &self.connect_id
+    }
+    pub fn get_sort(&self) -> RecordSort {
+        self.sort
+    }
+    pub fn get_asc(&self) -> bool {
+        self.is_asc
+    }
+    pub fn get_cursor(&self) -> &Option<RecordCursor> {
+        &self.cursor
+    }
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -144,6 +666,7 @@ pub struct UpdateRecord {
     memo: FieldUpdate<String>,
     target_dt: FieldUpdate<NaiveDateTime>,
     asset_id: FieldUpdate<i32>,
+    lines: FieldUpdate<Vec<RecordLine>>,
 }
 
 impl UpdateRecord {
@@ -160,9 +683,15 @@ impl UpdateRecord {
             memo,
             target_dt,
             asset_id,
+            lines: FieldUpdate::NoChange,
         }
     }
 
+    pub fn lines(mut self, lines: FieldUpdate<Vec<RecordLine>>) -> Self {
+        self.lines = lines;
+        self
+    }
+
     pub fn get_sub_category_id(&self) -> &FieldUpdate<i32> {
         &self.sub_category_id
     }
@@ -178,4 +707,7 @@ impl UpdateRecord {
     pub fn get_asset_id(&self) -> &FieldUpdate<i32> {
         &self.asset_id
     }
+    pub fn get_lines(&self) -> &FieldUpdate<Vec<RecordLine>> {
+        &self.lines
+    }
 }
@@ -4,7 +4,10 @@ use axum::{response::IntoResponse, Extension, Json};
 use hyper::StatusCode;
 use serde_json::json;
 
-use crate::domain::record::{dto::request::NewRecord, usecase::create::CreateRecordUsecase};
+use crate::domain::record::{
+    dto::request::{NewRecord, NewTransfer},
+    usecase::create::CreateRecordUsecase,
+};
 
 pub async fn create_record<T>(
     Extension(usecase): Extension<Arc<T>>,
@@ -24,6 +27,24 @@ where
     }
 }
 
+pub async fn create_transfer<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Json(new_transfer): Json<NewTransfer>,
+) -> impl IntoResponse
+where
+    T: CreateRecordUsecase,
+{
+    match usecase.create_transfer(user_id, new_transfer).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "성공", "record_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -35,9 +56,12 @@ mod tests {
     use serde_json::{to_string, Value};
     use tower::ServiceExt;
 
-    use super::create_record;
+    use super::{create_record, create_transfer};
     use crate::{
-        domain::record::{dto::request::NewRecord, usecase::create::CreateRecordUsecase},
+        domain::record::{
+            dto::request::{NewRecord, NewTransfer},
+            usecase::create::CreateRecordUsecase,
+        },
         global::errors::CustomError,
     };
 
@@ -47,6 +71,7 @@ mod tests {
         #[async_trait]
         impl CreateRecordUsecase for CreateRecordUsecaseImpl {
             async fn create_record(&self, user_id: i32, new_record: NewRecord) -> Result<i64, Box<CustomError>>;
+            async fn create_transfer(&self, user_id: i32, new_transfer: NewTransfer) -> Result<i64, Box<CustomError>>;
         }
     }
 
@@ -203,4 +228,45 @@ mod tests {
         // Assert
         assert_eq!(response.status(), 401)
     }
+
+    #[tokio::test]
+    async fn check_create_transfer_status() {
+        // Arrange
+        let user_id = 1;
+        let new_transfer = NewTransfer::new(
+            1,
+            28,
+            30000,
+            None,
+            NaiveDateTime::parse_from_str("2024-09-08 18:39:27", "%Y-%m-%d %H:%M:%S").unwrap(),
+            1,
+            2,
+        );
+
+        let mut mock_usecase = MockCreateRecordUsecaseImpl::new();
+        mock_usecase
+            .expect_create_transfer()
+            .with(predicate::eq(user_id), predicate::eq(new_transfer.clone()))
+            .returning(|_, _| Ok(5));
+
+        let app = Router::new()
+            .route(
+                "/api/v1/record/transfer",
+                post(create_transfer::<MockCreateRecordUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id));
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/record/transfer")
+            .header("content-type", "application/json")
+            .body(to_string(&new_transfer).unwrap())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 201);
+    }
 }
@@ -0,0 +1,206 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, Query},
+    http::header,
+    response::IntoResponse,
+    Extension,
+};
+use hyper::StatusCode;
+
+use crate::{
+    domain::record::{
+        dto::request::{ExportParams, SearchParams},
+        usecase::export::ExportRecordUsecase,
+    },
+    global::errors::CustomError,
+};
+
+pub async fn export_records<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    params: Query<SearchParams>,
+    export_params: Query<ExportParams>,
+) -> impl IntoResponse
+where
+    T: ExportRecordUsecase,
+{
+    let params = params.0;
+    if !params.is_valid_period() {
+        return CustomError::ValidationError("RecordPeriod".to_string()).into_response();
+    }
+    let format = match export_params.to_format() {
+        Ok(format) => format,
+        Err(err) => return err.into_response(),
+    };
+
+    let file = match usecase
+        .export_records(user_id, book_id, params, format)
+        .await
+    {
+        Ok(file) => file,
+        Err(err) => return err.into_response(),
+    };
+
+    let content_type = file.get_format().get_content_type();
+    let content_disposition = format!(
+        "attachment; filename=\"records-{}.{}\"",
+        book_id,
+        file.get_format().get_extension()
+    );
+
+    // 응답을 보내는 중 오류가 나면 연결을 끊어 불완전한 파일임을 알림
+    let stream = futures_util::stream::unfold(file.into_chunks(), |mut chunks| async move {
+        let chunk = chunks.recv().await?;
+        Some((
+            chunk.map_err(|e| std::io::Error::other(format!("{:?}", e))),
+            chunks,
+        ))
+    });
+
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, content_disposition),
+        ],
+        Body::from_stream(stream),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::get, Extension, Router};
+    use chrono::NaiveDate;
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use tokio::sync::mpsc;
+    use tower::ServiceExt;
+
+    use super::export_records;
+    use crate::{
+        domain::record::{
+            dto::{request::SearchParams, response::ExportFile},
+            entity::ExportFormat,
+            usecase::export::ExportRecordUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        ExportRecordUsecaseImpl {}
+
+        #[async_trait]
+        impl ExportRecordUsecase for ExportRecordUsecaseImpl {
+            async fn export_records(&self, user_id: i32, book_id: i32, params: SearchParams, format: ExportFormat) -> Result<ExportFile, Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockExportRecordUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/record/export/:book_id",
+                get(export_records::<MockExportRecordUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(book_id: i32, query: &str) -> Request {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/api/v1/record/export/{}?{}", book_id, query))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_export_csv() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let params = SearchParams::new(start_dt, "M".to_string(), None, Some(17));
+
+        let mut mock_usecase = MockExportRecordUsecaseImpl::new();
+        mock_usecase
+            .expect_export_records()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(params),
+                predicate::eq(ExportFormat::Csv),
+            )
+            .returning(|_, _, _, format| {
+                let (sender, receiver) = mpsc::channel(2);
+                sender.try_send(Ok("날짜,".as_bytes().to_vec())).unwrap();
+                sender.try_send(Ok("구분\n".as_bytes().to_vec())).unwrap();
+                Ok(ExportFile::new(format, receiver))
+            });
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(book_id, "start_dt=2024-09-01&period=M&sub_id=17&format=csv");
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers()["content-disposition"],
+            "attachment; filename=\"records-1.csv\""
+        );
+
+        let body_bytes = response
+            .into_body()
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        // Assert
+        assert_eq!(
+            String::from_utf8(body_bytes.to_vec()).unwrap(),
+            "날짜,구분\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn check_invalid_format() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let mut mock_usecase = MockExportRecordUsecaseImpl::new();
+        mock_usecase.expect_export_records().never();
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(book_id, "start_dt=2024-09-01&period=M&format=pdf");
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn check_invalid_period() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let mut mock_usecase = MockExportRecordUsecaseImpl::new();
+        mock_usecase.expect_export_records().never();
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(book_id, "start_dt=2024-09-01&period=X&format=xlsx");
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+}
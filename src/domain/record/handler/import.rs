@@ -0,0 +1,291 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Multipart, Path},
+    response::IntoResponse,
+    Extension, Json,
+};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::record::{
+        dto::request::{ColumnMapping, ImportRecords},
+        usecase::import::ImportRecordUsecase,
+    },
+    global::errors::CustomError,
+};
+
+// multipart 필드: file(CSV), target_dt, amount, memo, category(열 이름), dry_run
+async fn read_import(mut multipart: Multipart) -> Result<ImportRecords, Box<CustomError>> {
+    let invalid = || Box::new(CustomError::ValidationError("RecordImport".to_string()));
+
+    let mut content = None;
+    let mut target_dt = None;
+    let mut amount = None;
+    let mut memo = None;
+    let mut category = None;
+    let mut dry_run = false;
+
+    while let Some(field) = multipart.next_field().await.map_err(|_| invalid())? {
+        let name = field.name().unwrap_or_default().to_string();
+        let value = field.text().await.map_err(|_| invalid())?;
+        let column = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+
+        match name.as_str() {
+            "file" => content = Some(value),
+            "target_dt" => target_dt = column,
+            "amount" => amount = column,
+            "memo" => memo = column,
+            "category" => category = column,
+            "dry_run" => dry_run = value.trim().parse::<bool>().map_err(|_| invalid())?,
+            _ => {}
+        }
+    }
+
+    match (content, target_dt, amount, category) {
+        (Some(content), Some(target_dt), Some(amount), Some(category)) => {
+            let mapping = ColumnMapping::new(target_dt, amount, memo, category);
+            Ok(ImportRecords::new(content, mapping).dry_run(dry_run))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+pub async fn import_records<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    multipart: Multipart,
+) -> impl IntoResponse
+where
+    T: ImportRecordUsecase,
+{
+    let import = match read_import(multipart).await {
+        Ok(import) => import,
+        Err(err) => return err.into_response(),
+    };
+
+    match usecase.import_records(user_id, book_id, import).await {
+        Ok(result) if result.is_dry_run() => (StatusCode::OK, Json(json!(result))).into_response(),
+        Ok(result) if !result.get_errors().is_empty() => {
+            (StatusCode::BAD_REQUEST, Json(json!(result))).into_response()
+        }
+        Ok(result) => (StatusCode::CREATED, Json(json!(result))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::post, Extension, Router};
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::import_records;
+    use crate::{
+        domain::record::{
+            dto::{
+                request::{ColumnMapping, ImportRecords},
+                response::{ImportError, ImportResult},
+            },
+            usecase::import::ImportRecordUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        ImportRecordUsecaseImpl {}
+
+        #[async_trait]
+        impl ImportRecordUsecase for ImportRecordUsecaseImpl {
+            async fn import_records(&self, user_id: i32, book_id: i32, import: ImportRecords) -> Result<ImportResult, Box<CustomError>>;
+        }
+    }
+
+    const BOUNDARY: &str = "----record-import";
+    const CONTENT: &str = "date,amount,category\n2024-09-01,15000,식비\n";
+
+    fn _create_app(user_id: i32, mock_usecase: MockImportRecordUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/record/import/:book_id",
+                post(import_records::<MockImportRecordUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(book_id: i32, fields: &[(&str, &str)]) -> Request {
+        let mut body = String::new();
+        for (name, value) in fields {
+            let filename = if *name == "file" {
+                "; filename=\"records.csv\"\r\nContent-Type: text/csv"
+            } else {
+                ""
+            };
+            body.push_str(&format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"{}\r\n\r\n{}\r\n",
+                BOUNDARY, name, filename, value
+            ));
+        }
+        body.push_str(&format!("--{}--\r\n", BOUNDARY));
+
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/v1/record/import/{}", book_id))
+            .header(
+                "content-type",
+                format!("multipart/form-data; boundary={}", BOUNDARY),
+            )
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    fn _import(dry_run: bool) -> ImportRecords {
+        let mapping = ColumnMapping::new(
+            "date".to_string(),
+            "amount".to_string(),
+            None,
+            "category".to_string(),
+        );
+        ImportRecords::new(CONTENT.to_string(), mapping).dry_run(dry_run)
+    }
+
+    #[tokio::test]
+    async fn check_import_status() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let mut mock_usecase = MockImportRecordUsecaseImpl::new();
+        mock_usecase
+            .expect_import_records()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(_import(false)),
+            )
+            .returning(|_, _, _| Ok(ImportResult::new(false, vec![], vec![]).imported(1)));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(
+            book_id,
+            &[
+                ("file", CONTENT),
+                ("target_dt", "date"),
+                ("amount", "amount"),
+                ("category", "category"),
+            ],
+        );
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 201);
+    }
+
+    #[tokio::test]
+    async fn check_dry_run_body() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let mut mock_usecase = MockImportRecordUsecaseImpl::new();
+        mock_usecase
+            .expect_import_records()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(_import(true)),
+            )
+            .returning(|_, _, _| {
+                Ok(ImportResult::new(
+                    true,
+                    vec![],
+                    vec![ImportError::new(2, "category", "NotFound")],
+                ))
+            });
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(
+            book_id,
+            &[
+                ("file", CONTENT),
+                ("target_dt", "date"),
+                ("amount", "amount"),
+                ("category", "category"),
+                ("dry_run", "true"),
+            ],
+        );
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body_bytes = response
+            .into_body()
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+        let body_json: Value = serde_json::from_slice(&body_bytes).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["errors"][0]["row"], 2);
+        assert_eq!(body_json["errors"][0]["field"], "category");
+    }
+
+    #[tokio::test]
+    async fn check_row_error_status() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let mut mock_usecase = MockImportRecordUsecaseImpl::new();
+        mock_usecase.expect_import_records().returning(|_, _, _| {
+            Ok(ImportResult::new(
+                false,
+                vec![],
+                vec![ImportError::new(2, "amount", "Invalid")],
+            ))
+        });
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(
+            book_id,
+            &[
+                ("file", CONTENT),
+                ("target_dt", "date"),
+                ("amount", "amount"),
+                ("category", "category"),
+            ],
+        );
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn check_missing_mapping() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let mut mock_usecase = MockImportRecordUsecaseImpl::new();
+        mock_usecase.expect_import_records().never();
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(book_id, &[("file", CONTENT), ("target_dt", "date")]);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+}
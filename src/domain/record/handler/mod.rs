@@ -9,22 +9,27 @@ use sqlx::PgPool;
 
 mod create;
 mod delete;
+mod export;
+mod import;
 mod read;
 mod update;
 
-use create::create_record;
+use create::{create_record, create_transfer};
 use delete::delete_record;
-use read::{read_record, read_records};
+use export::export_records;
+use import::import_records;
+use read::{read_calendar, read_record, read_records, read_summary, read_total};
 use update::update_record;
 
 use super::{
     repository::{
-        delete::DeleteRecordRepoImpl, get_record::GetRecordRepoImpl, save::SaveRecordRepoImpl,
-        update::UpdateRecordRepoImpl,
+        delete::DeleteRecordRepoImpl, export::ExportRecordRepoImpl, get_record::GetRecordRepoImpl,
+        import::ImportRecordRepoImpl, save::SaveRecordRepoImpl, update::UpdateRecordRepoImpl,
     },
     usecase::{
         create::CreateRecordUsecaseImpl, delete::DeleteRecordUsecaseImpl,
-        read::ReadRecordUsecaseImpl, update::UpdateRecordUsecaseImpl,
+        export::ExportRecordUsecaseImpl, import::ImportRecordUsecaseImpl, read::ReadRecordUsecaseImpl,
+        update::UpdateRecordUsecaseImpl,
     },
 };
 
@@ -37,6 +42,34 @@ pub fn create_router(pool: &Arc<PgPool>) -> Router {
             "/",
             post(create_record::<CreateRecordUsecaseImpl<SaveRecordRepoImpl>>),
         )
+        .route(
+            "/transfer",
+            post(create_transfer::<CreateRecordUsecaseImpl<SaveRecordRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn import_router(pool: &Arc<PgPool>) -> Router {
+    let repository = ImportRecordRepoImpl::new(&pool);
+    let usecase = ImportRecordUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/import/:book_id",
+            post(import_records::<ImportRecordUsecaseImpl<ImportRecordRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn export_router(pool: &Arc<PgPool>) -> Router {
+    let repository = ExportRecordRepoImpl::new(&pool);
+    let usecase = ExportRecordUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/export/:book_id",
+            get(export_records::<ExportRecordUsecaseImpl<ExportRecordRepoImpl>>),
+        )
         .layer(Extension(Arc::new(usecase)))
 }
 
@@ -49,6 +82,18 @@ pub fn read_router(pool: &Arc<PgPool>) -> Router {
             "/list/:book_id",
             get(read_records::<ReadRecordUsecaseImpl<GetRecordRepoImpl>>),
         )
+        .route(
+            "/total/:book_id",
+            get(read_total::<ReadRecordUsecaseImpl<GetRecordRepoImpl>>),
+        )
+        .route(
+            "/summary/:book_id",
+            get(read_summary::<ReadRecordUsecaseImpl<GetRecordRepoImpl>>),
+        )
+        .route(
+            "/calendar/:book_id",
+            get(read_calendar::<ReadRecordUsecaseImpl<GetRecordRepoImpl>>),
+        )
         .route(
             "/:record_id",
             get(read_record::<ReadRecordUsecaseImpl<GetRecordRepoImpl>>),
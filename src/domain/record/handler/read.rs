@@ -9,7 +9,10 @@ use hyper::StatusCode;
 use serde_json::json;
 
 use crate::{
-    domain::record::{dto::request::SearchParams, usecase::read::ReadRecordUsecase},
+    domain::record::{
+        dto::request::{CalendarParams, SearchParams},
+        usecase::read::ReadRecordUsecase,
+    },
     global::errors::CustomError,
 };
 
@@ -23,12 +26,15 @@ where
     T: ReadRecordUsecase,
 {
     let params = params.0;
-    if !"md".contains(params.get_period().to_lowercase().as_str()) {
+    if !params.is_valid_period() {
         return CustomError::ValidationError("RecordPeriod".to_string()).into_response();
     }
+    if !params.is_valid_page() {
+        return CustomError::ValidationError("RecordPage".to_string()).into_response();
+    }
 
     match usecase.read_records(user_id, book_id, params).await {
-        Ok(records) => (StatusCode::OK, Json(json!(records))).into_response(),
+        Ok(page) => (StatusCode::OK, Json(json!(page))).into_response(),
         Err(err) => err.into_response(),
     }
 }
@@ -47,6 +53,61 @@ where
     }
 }
 
+pub async fn read_total<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    params: Query<SearchParams>,
+) -> impl IntoResponse
+where
+    T: ReadRecordUsecase,
+{
+    let params = params.0;
+    if !params.is_valid_period() {
+        return CustomError::ValidationError("RecordPeriod".to_string()).into_response();
+    }
+
+    match usecase.read_total(user_id, book_id, params).await {
+        Ok(total) => (StatusCode::OK, Json(json!(total))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+pub async fn read_summary<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    params: Query<SearchParams>,
+) -> impl IntoResponse
+where
+    T: ReadRecordUsecase,
+{
+    let params = params.0;
+    if !params.is_valid_period() {
+        return CustomError::ValidationError("RecordPeriod".to_string()).into_response();
+    }
+
+    match usecase.read_summary(user_id, book_id, params).await {
+        Ok(summary) => (StatusCode::OK, Json(json!(summary))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+pub async fn read_calendar<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    params: Query<CalendarParams>,
+) -> impl IntoResponse
+where
+    T: ReadRecordUsecase,
+{
+    match usecase.read_calendar(user_id, book_id, params.0).await {
+        Ok(days) => (StatusCode::OK, Json(json!(days))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -58,10 +119,15 @@ mod tests {
     use serde_json::Value;
     use tower::ServiceExt;
 
-    use super::{read_record, read_records};
+    use super::{read_calendar, read_record, read_records, read_summary, read_total};
     use crate::{
         domain::record::{
-            dto::request::SearchParams, entity::Record, usecase::read::ReadRecordUsecase,
+            dto::{
+                request::{CalendarParams, SearchParams},
+                response::{RecordDetail, RecordPage, RecordSummary},
+            },
+            entity::{CategorySum, DailyTotal, Record, RecordLine, RecordTotal},
+            usecase::read::ReadRecordUsecase,
         },
         global::errors::CustomError,
     };
@@ -71,12 +137,15 @@ mod tests {
 
         #[async_trait]
         impl ReadRecordUsecase for ReadRecordUsecaseImpl {
-            async fn read_records(&self, user_id: i32, book_id: i32, params: SearchParams) -> Result<Vec<Record>, Box<CustomError>>;
-            async fn read_record(&self, user_id: i32, record_id: i64) -> Result<Record, Box<CustomError>>;
+            async fn read_records(&self, user_id: i32, book_id: i32, params: SearchParams) -> Result<RecordPage, Box<CustomError>>;
+            async fn read_record(&self, user_id: i32, record_id: i64) -> Result<RecordDetail, Box<CustomError>>;
+            async fn read_total(&self, user_id: i32, book_id: i32, params: SearchParams) -> Result<RecordTotal, Box<CustomError>>;
+            async fn read_summary(&self, user_id: i32, book_id: i32, params: SearchParams) -> Result<RecordSummary, Box<CustomError>>;
+            async fn read_calendar(&self, user_id: i32, book_id: i32, params: CalendarParams) -> Result<Vec<DailyTotal>, Box<CustomError>>;
         }
     }
-    fn test_records() -> Vec<Record> {
-        vec![
+    fn test_page() -> RecordPage {
+        let records = vec![
             Record::new(
                 1,
                 18,
@@ -104,7 +173,8 @@ mod tests {
             )
             .id(Some(3))
             .build(),
-        ]
+        ];
+        RecordPage::new(records, Some("2024-09-08T15:30:27_3".to_string()))
     }
 
     fn _create_list_app(user_id: i32, mock_usecase: MockReadRecordUsecaseImpl) -> Router {
@@ -162,7 +232,7 @@ mod tests {
                 predicate::eq(book_id),
                 predicate::eq(search_params.clone()),
             )
-            .returning(|_, _, _| Ok(test_records()));
+            .returning(|_, _, _| Ok(test_page()));
 
         let app = _create_list_app(user_id, mock_usecase);
         let req = _create_list_req(book_id, &search_params);
@@ -190,7 +260,7 @@ mod tests {
                 predicate::eq(book_id),
                 predicate::eq(search_params.clone()),
             )
-            .returning(|_, _, _| Ok(test_records()));
+            .returning(|_, _, _| Ok(test_page()));
 
         let app = _create_list_app(user_id, mock_usecase);
         let req = _create_list_req(book_id, &search_params);
@@ -213,7 +283,40 @@ mod tests {
         let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
 
         // Assert
-        assert_eq!(body_json[0].get("id").unwrap(), Some(1).unwrap());
+        assert_eq!(body_json["records"][0]["id"], 1);
+        assert_eq!(body_json["next_cursor"], "2024-09-08T15:30:27_3");
+    }
+
+    #[tokio::test]
+    async fn check_read_records_filter() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let search_params = SearchParams::new(start_dt, "M".to_string(), None, None)
+            .memo(Some("감자탕".to_string()))
+            .amount_range(Some(10000), Some(20000))
+            .asset_id(Some(1))
+            .connect_id(Some(1));
+
+        let mut mock_usecase = MockReadRecordUsecaseImpl::new();
+        mock_usecase
+            .expect_read_records()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(search_params.clone()),
+            )
+            .returning(|_, _, _| Ok(test_page()));
+
+        let app = _create_list_app(user_id, mock_usecase);
+        let req = _create_list_req(book_id, &search_params);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
     }
 
     #[tokio::test]
@@ -232,7 +335,52 @@ mod tests {
                 predicate::eq(book_id),
                 predicate::eq(search_params.clone()),
             )
-            .returning(|_, _, _| Ok(test_records()));
+            .returning(|_, _, _| Ok(test_page()));
+
+        let app = _create_list_app(user_id, mock_usecase);
+        let req = _create_list_req(book_id, &search_params);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400)
+    }
+
+    #[tokio::test]
+    async fn check_invalid_week_start() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let search_params = SearchParams::new(start_dt, "W".to_string(), None, None)
+            .week_start(Some("someday".to_string()));
+
+        let mut mock_usecase = MockReadRecordUsecaseImpl::new();
+        mock_usecase.expect_read_records().never();
+
+        let app = _create_list_app(user_id, mock_usecase);
+        let req = _create_list_req(book_id, &search_params);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400)
+    }
+
+    #[tokio::test]
+    async fn check_invalid_page() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let search_params = SearchParams::new(start_dt, "M".to_string(), None, None)
+            .sort(Some("amount".to_string()), None)
+            .cursor(Some("2024-09-08T15:30:27_3".to_string()));
+
+        let mut mock_usecase = MockReadRecordUsecaseImpl::new();
+        mock_usecase.expect_read_records().never();
 
         let app = _create_list_app(user_id, mock_usecase);
         let req = _create_list_req(book_id, &search_params);
@@ -254,15 +402,18 @@ mod tests {
             .expect_read_record()
             .with(predicate::eq(user_id), predicate::eq(record_id))
             .returning(|_, i| {
-                Ok(Record::new(
-                    1,
-                    18,
-                    15200,
-                    NaiveDateTime::parse_from_str("2024-09-07 15:30:27", "%Y-%m-%d %H:%M:%S")
-                        .unwrap(),
-                    None,
-                )
-                .id(Some(i)))
+                Ok(RecordDetail::new(
+                    Record::new(
+                        1,
+                        18,
+                        15200,
+                        NaiveDateTime::parse_from_str("2024-09-07 15:30:27", "%Y-%m-%d %H:%M:%S")
+                            .unwrap(),
+                        None,
+                    )
+                    .id(Some(i)),
+                    vec![],
+                ))
             });
 
         let app = _create_app(user_id, mock_usecase);
@@ -285,16 +436,19 @@ mod tests {
             .expect_read_record()
             .with(predicate::eq(user_id), predicate::eq(record_id))
             .returning(|_, i| {
-                Ok(Record::new(
-                    1,
-                    18,
-                    15200,
-                    NaiveDateTime::parse_from_str("2024-09-07 15:30:27", "%Y-%m-%d %H:%M:%S")
-                        .unwrap(),
-                    None,
-                )
-                .id(Some(i))
-                .build())
+                Ok(RecordDetail::new(
+                    Record::new(
+                        1,
+                        18,
+                        15200,
+                        NaiveDateTime::parse_from_str("2024-09-07 15:30:27", "%Y-%m-%d %H:%M:%S")
+                            .unwrap(),
+                        None,
+                    )
+                    .id(Some(i))
+                    .build(),
+                    vec![RecordLine::new(17, 10000), RecordLine::new(18, 5200)],
+                ))
             });
 
         let app = _create_app(user_id, mock_usecase);
@@ -318,6 +472,7 @@ mod tests {
 
         // Assert
         assert_eq!(body_json["id"], record_id);
+        assert_eq!(body_json["lines"][1]["amount"], 5200);
     }
 
     #[tokio::test]
@@ -340,4 +495,183 @@ mod tests {
         // Assert
         assert_eq!(response.status(), 404);
     }
+
+    #[tokio::test]
+    async fn check_read_total_body() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let search_params = SearchParams::new(start_dt, "M".to_string(), None, None);
+
+        let mut mock_usecase = MockReadRecordUsecaseImpl::new();
+        mock_usecase
+            .expect_read_total()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(search_params.clone()),
+            )
+            .returning(|_, _, _| Ok(RecordTotal::new(20000, 15000)));
+
+        let app = Router::new()
+            .route(
+                "/api/v1/record/total/:book_id",
+                get(read_total::<MockReadRecordUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id));
+        let req = Request::builder()
+            .method("GET")
+            .uri(format!(
+                "/api/v1/record/total/{}?{}",
+                book_id,
+                search_params.encode_param()
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["income"], 20000);
+        assert_eq!(body_json["expense"], 15000);
+    }
+
+    #[tokio::test]
+    async fn check_read_summary_body() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let search_params = SearchParams::new(start_dt, "M".to_string(), None, None);
+
+        let mut mock_usecase = MockReadRecordUsecaseImpl::new();
+        mock_usecase
+            .expect_read_summary()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(search_params.clone()),
+            )
+            .returning(|_, _, _| {
+                Ok(RecordSummary::new(vec![CategorySum::new(
+                    9, 17, false, 15000,
+                )
+                .base("식비".to_string(), "FF0000".to_string(), 15000)
+                .sub_name("외식".to_string())]))
+            });
+
+        let app = Router::new()
+            .route(
+                "/api/v1/record/summary/:book_id",
+                get(read_summary::<MockReadRecordUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id));
+        let req = Request::builder()
+            .method("GET")
+            .uri(format!(
+                "/api/v1/record/summary/{}?{}",
+                book_id,
+                search_params.encode_param()
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json["income"].as_array().unwrap().len(), 0);
+        assert_eq!(body_json["expense"][0]["color"], "FF0000");
+        assert_eq!(body_json["expense"][0]["sub_categories"][0]["name"], "외식");
+    }
+
+    #[tokio::test]
+    async fn check_read_calendar_body() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let params = CalendarParams::new(2024, 9);
+
+        let mut mock_usecase = MockReadRecordUsecaseImpl::new();
+        mock_usecase
+            .expect_read_calendar()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(params.clone()),
+            )
+            .returning(|_, _, _| {
+                let day = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+                Ok(vec![DailyTotal::new(day, 0, 15000, 1)])
+            });
+
+        let app = Router::new()
+            .route(
+                "/api/v1/record/calendar/:book_id",
+                get(read_calendar::<MockReadRecordUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id));
+        let req = Request::builder()
+            .method("GET")
+            .uri(format!(
+                "/api/v1/record/calendar/{}?{}",
+                book_id,
+                params.encode_param()
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        // Assert
+        assert_eq!(body_json[0]["target_date"], "2024-09-01");
+        assert_eq!(body_json[0]["expense"], 15000);
+        assert_eq!(body_json[0]["count"], 1);
+    }
 }
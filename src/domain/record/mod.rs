@@ -1,7 +1,8 @@
 mod dto;
-mod entity;
+pub(crate) mod entity;
 mod handler;
 mod repository;
 mod usecase;
+mod utils;
 
 pub mod route;
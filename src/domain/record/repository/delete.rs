@@ -35,7 +35,7 @@ async fn delete_record(
     let result = sqlx::query_as::<_, DeleteResult>(
         r"
         WITH RecordExists AS (
-            SELECT book_id
+            SELECT book_id, transfer_id
             FROM tb_record
             WHERE id = $2
         ),
@@ -48,9 +48,26 @@ async fn delete_record(
         ),
         DeleteRecord AS (
             DELETE FROM tb_record 
-            WHERE id = $2
+            WHERE (id = $2 OR id = (SELECT transfer_id FROM RecordExists))
                 AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
-            RETURNING id
+            RETURNING id, asset_id, amount, sub_category_id, transfer_id, is_transfer_in
+        ),
+        BalanceDelta AS (
+            SELECT
+                r.asset_id,
+                SUM(CASE WHEN r.is_transfer_in OR (r.transfer_id IS NULL AND bc.is_income)
+                    THEN r.amount ELSE -r.amount END) AS delta
+            FROM DeleteRecord AS r
+            JOIN tb_sub_category AS sc ON sc.id = r.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            WHERE r.asset_id IS NOT NULL
+            GROUP BY r.asset_id
+        ),
+        UpdateBalance AS (
+            UPDATE tb_asset AS a
+            SET balance = a.balance - bd.delta
+            FROM BalanceDelta AS bd
+            WHERE a.id = bd.asset_id
         )
         SELECT
             EXISTS (SELECT 1 FROM RecordExists) AS is_exist,
@@ -92,8 +109,11 @@ mod tests {
     use crate::{
         config::database::create_connection_pool,
         domain::record::{
-            entity::Record,
-            repository::{get_record::get_by_id, save::save_record},
+            entity::{Record, Transfer},
+            repository::{
+                get_record::get_by_id,
+                save::{save_record, save_transfer},
+            },
         },
         global::errors::CustomError,
     };
@@ -123,7 +143,7 @@ mod tests {
             None,
         );
 
-        let new_id = save_record(&pool, user_id, record, None).await.unwrap();
+        let new_id = save_record(&pool, user_id, record, None, vec![]).await.unwrap();
 
         // Act
         let result = delete_record(&pool, user_id, new_id).await;
@@ -134,6 +154,90 @@ mod tests {
         assert!(row.is_err())
     }
 
+    #[tokio::test]
+    async fn check_asset_balance_restored() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let asset_id: i32 = sqlx::query_scalar(
+            "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (1, 1, 50000) RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let record = Record::new(
+            1,
+            18, // 생필품비
+            16300,
+            NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Some(asset_id),
+        );
+
+        let new_id = save_record(&pool, user_id, record, None, vec![]).await.unwrap();
+
+        // Act
+        let result = delete_record(&pool, user_id, new_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let balance: i64 = sqlx::query_scalar("SELECT balance FROM tb_asset WHERE id = $1")
+            .bind(asset_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(balance, 50000);
+    }
+
+    #[tokio::test]
+    async fn check_transfer_deleted_together() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let mut asset_ids: Vec<i32> = vec![];
+        for _ in 0..2 {
+            let asset_id = sqlx::query_scalar(
+                "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (1, 1, 10000) RETURNING id",
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            asset_ids.push(asset_id);
+        }
+        let transfer = Transfer::new(
+            1,
+            28, // 적금
+            5000,
+            NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            asset_ids[0],
+            asset_ids[1],
+        );
+        let from_id = save_transfer(&pool, user_id, transfer).await.unwrap();
+        let to_id = get_by_id(&pool, user_id, from_id)
+            .await
+            .unwrap()
+            .get_transfer_id()
+            .unwrap();
+
+        // Act
+        let result = delete_record(&pool, user_id, from_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, to_id).await;
+        let balances: Vec<i64> =
+            sqlx::query_scalar("SELECT balance FROM tb_asset WHERE id = ANY($1) ORDER BY id")
+                .bind(&asset_ids)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+
+        assert!(row.is_err());
+        assert_eq!(balances, vec![10000, 10000]);
+    }
+
     #[tokio::test]
     async fn check_record_not_found() {
         // Arrange
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use futures_util::StreamExt;
+use sqlx::PgPool;
+use tokio::sync::mpsc::{self, Receiver};
+
+use crate::{
+    domain::record::entity::{ExportRow, Search},
+    global::errors::CustomError,
+};
+
+use super::get_record::{bind_search_filter, push_search_filter};
+
+// 조회와 파일 변환 사이 버퍼 크기 (행 단위)
+const EXPORT_BUFFER_SIZE: usize = 256;
+
+pub struct ExportRecordRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait ExportRecordRepo: Send + Sync {
+    async fn get_rows(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        search_query: Search,
+    ) -> Result<Receiver<Result<ExportRow, Box<CustomError>>>, Box<CustomError>>;
+}
+
+impl ExportRecordRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl ExportRecordRepo for ExportRecordRepoImpl {
+    async fn get_rows(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        search_query: Search,
+    ) -> Result<Receiver<Result<ExportRow, Box<CustomError>>>, Box<CustomError>> {
+        get_rows(&self.pool, user_id, book_id, search_query).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+/// 권한 확인 후 조회 결과를 한 행씩 전달 (전체 결과를 메모리에 올리지 않음)
+pub async fn get_rows(
+    pool: &Arc<PgPool>,
+    user_id: i32,
+    book_id: i32,
+    search_query: Search,
+) -> Result<Receiver<Result<ExportRow, Box<CustomError>>>, Box<CustomError>> {
+    let is_authorized: bool = sqlx::query_scalar(
+        "
+        SELECT EXISTS (
+            SELECT 1
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2
+        )
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_one(pool.as_ref())
+    .await
+    .map_err(|e| to_custom_error("ExportRecordRole", e))?;
+
+    if !is_authorized {
+        return Err(Box::new(CustomError::Unauthorized(
+            "RecordRole".to_string(),
+        )));
+    }
+
+    let (sender, receiver) = mpsc::channel(EXPORT_BUFFER_SIZE);
+    let pool = pool.clone();
+
+    tokio::spawn(async move {
+        // 페이지 조건(cursor, limit)은 사용하지 않고 기간 전체를 날짜순으로
+        let mut query = "
+            SELECT
+                r.target_dt,
+                bc.is_income,
+                r.transfer_id IS NOT NULL AS is_transfer,
+                bc.name AS base_name,
+                sc.name AS sub_name,
+                r.amount,
+                r.memo,
+                COALESCE(a.memo, asset_sc.name) AS asset_name,
+                (
+                    SELECT STRING_AGG(c.name, ', ' ORDER BY c.name)
+                    FROM tb_record_connect AS rc
+                    JOIN tb_connect AS c ON c.id = rc.connect_id
+                    WHERE rc.record_id = r.id
+                ) AS connect_names
+            FROM tb_record AS r
+            JOIN tb_book AS b ON b.id = r.book_id
+            JOIN tb_user_book_role AS br ON b.id = br.book_id
+            JOIN tb_sub_category AS sc ON r.sub_category_id = sc.id
+            JOIN tb_base_category AS bc ON sc.base_id = bc.id
+            LEFT JOIN tb_asset AS a ON a.id = r.asset_id
+            LEFT JOIN tb_sub_category AS asset_sc ON asset_sc.id = a.sub_category_id
+            WHERE br.user_id = $1 AND b.id = $2
+                AND r.target_dt >= $3 AND r.target_dt < $4
+        "
+        .to_string();
+
+        push_search_filter(&mut query, &search_query, 5);
+        query.push_str("ORDER BY r.target_dt, r.id");
+
+        let query_builder = sqlx::query_as::<_, ExportRow>(&query)
+            .bind(user_id)
+            .bind(book_id)
+            .bind(search_query.get_start_dt())
+            .bind(search_query.get_end_dt());
+        let query_builder = bind_search_filter(query_builder, &search_query);
+
+        let mut rows = query_builder.fetch(pool.as_ref());
+        while let Some(row) = rows.next().await {
+            let row = row.map_err(|e| to_custom_error("ExportRecord", e));
+            let is_err = row.is_err();
+            // 받는 쪽이 끊기면(다운로드 취소) 조회 중단
+            if sender.send(row).await.is_err() || is_err {
+                break;
+            }
+        }
+    });
+
+    Ok(receiver)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveDate;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::record::{entity::Search, repository::export::get_rows},
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_get_rows_success() {
+        // Arrange
+        let pool = Arc::new(create_connection_pool().await);
+
+        let user_id = 3;
+        let book_id = 2;
+        let start_dt = NaiveDate::parse_from_str("2024-08-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None);
+
+        // Act
+        let result = get_rows(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let mut receiver = result.unwrap();
+
+        let mut rows = vec![];
+        while let Some(row) = receiver.recv().await {
+            rows.push(row.unwrap());
+        }
+
+        // Assert
+        // ref) init.sql, 날짜순 식비(08-05), 생필품비(09-10), 구독료(09-21)
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].get_sub_name(), "식비");
+        assert_eq!(rows[0].get_base_name(), "변동지출");
+        assert_eq!(rows[0].get_kind(), "지출");
+        assert_eq!(rows[2].get_sub_name(), "구독료");
+    }
+
+    #[tokio::test]
+    async fn check_get_rows_no_role() {
+        // Arrange
+        let pool = Arc::new(create_connection_pool().await);
+
+        // ref) init.sql
+        let user_id = 3;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None);
+
+        // Act
+        let result = get_rows(&pool, user_id, book_id, search_query).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
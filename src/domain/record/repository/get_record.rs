@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use axum::async_trait;
-use sqlx::PgPool;
+use chrono::NaiveDate;
+use sqlx::{postgres::PgArguments, query::QueryAs, PgPool, Postgres};
 
 use crate::{
-    domain::record::entity::{Record, Search},
+    domain::record::entity::{CategorySum, DailyTotal, Record, RecordLine, RecordTotal, Search},
     global::errors::CustomError,
 };
 
@@ -21,6 +22,26 @@ pub trait GetRecordRepo: Send + Sync {
         search_query: Search,
     ) -> Result<Vec<Record>, Box<CustomError>>;
     async fn get_by_id(&self, user_id: i32, record_id: i64) -> Result<Record, Box<CustomError>>;
+    async fn get_lines(&self, record_id: i64) -> Result<Vec<RecordLine>, Box<CustomError>>;
+    async fn get_total(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        search_query: Search,
+    ) -> Result<RecordTotal, Box<CustomError>>;
+    async fn get_summary(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        search_query: Search,
+    ) -> Result<Vec<CategorySum>, Box<CustomError>>;
+    async fn get_daily_total(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDate,
+        end_dt: NaiveDate,
+    ) -> Result<Vec<DailyTotal>, Box<CustomError>>;
 }
 
 impl GetRecordRepoImpl {
@@ -42,6 +63,112 @@ impl GetRecordRepo for GetRecordRepoImpl {
     async fn get_by_id(&self, user_id: i32, record_id: i64) -> Result<Record, Box<CustomError>> {
         get_by_id(&self.pool, user_id, record_id).await
     }
+    async fn get_lines(&self, record_id: i64) -> Result<Vec<RecordLine>, Box<CustomError>> {
+        get_lines(&self.pool, record_id).await
+    }
+    async fn get_total(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        search_query: Search,
+    ) -> Result<RecordTotal, Box<CustomError>> {
+        get_total(&self.pool, user_id, book_id, search_query).await
+    }
+    async fn get_summary(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        search_query: Search,
+    ) -> Result<Vec<CategorySum>, Box<CustomError>> {
+        get_summary(&self.pool, user_id, book_id, search_query).await
+    }
+    async fn get_daily_total(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDate,
+        end_dt: NaiveDate,
+    ) -> Result<Vec<DailyTotal>, Box<CustomError>> {
+        get_daily_total(&self.pool, user_id, book_id, start_dt, end_dt).await
+    }
+}
+
+// 검색 조건을 추가하고 다음 바인딩 인덱스를 반환
+pub(super) fn push_search_filter(
+    query: &mut String,
+    search_query: &Search,
+    mut bind_idx: i32,
+) -> i32 {
+    if search_query.get_sub_id().is_some() {
+        query.push_str(format!("AND sc.id = ${} ", bind_idx).as_str());
+        bind_idx += 1;
+    }
+    if search_query.get_base_id().is_some() {
+        query.push_str(format!("AND sc.base_id = ${} ", bind_idx).as_str());
+        bind_idx += 1;
+    }
+    if search_query.get_memo().is_some() {
+        query.push_str(format!("AND r.memo ILIKE ${} ", bind_idx).as_str());
+        bind_idx += 1;
+    }
+    if search_query.get_min_amount().is_some() {
+        query.push_str(format!("AND r.amount >= ${} ", bind_idx).as_str());
+        bind_idx += 1;
+    }
+    if search_query.get_max_amount().is_some() {
+        query.push_str(format!("AND r.amount <= ${} ", bind_idx).as_str());
+        bind_idx += 1;
+    }
+    if search_query.get_asset_id().is_some() {
+        query.push_str(format!("AND r.asset_id = ${} ", bind_idx).as_str());
+        bind_idx += 1;
+    }
+    if search_query.get_connect_id().is_some() {
+        query.push_str(
+            format!(
+                "AND EXISTS (SELECT 1 FROM tb_record_connect AS rc WHERE rc.record_id = r.id AND rc.connect_id = ${}) ",
+                bind_idx
+            )
+            .as_str(),
+        );
+        bind_idx += 1;
+    }
+
+    bind_idx
+}
+
+pub(super) fn bind_search_filter<'q, O>(
+    mut query_builder: QueryAs<'q, Postgres, O, PgArguments>,
+    search_query: &Search,
+) -> QueryAs<'q, Postgres, O, PgArguments> {
+    if let Some(sub_id) = search_query.get_sub_id() {
+        query_builder = query_builder.bind(*sub_id);
+    }
+    if let Some(base_id) = search_query.get_base_id() {
+        query_builder = query_builder.bind(*base_id);
+    }
+    if let Some(memo) = search_query.get_memo() {
+        // LIKE 특수문자는 그대로 검색
+        let memo = memo
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        query_builder = query_builder.bind(format!("%{}%", memo));
+    }
+    if let Some(min_amount) = search_query.get_min_amount() {
+        query_builder = query_builder.bind(*min_amount);
+    }
+    if let Some(max_amount) = search_query.get_max_amount() {
+        query_builder = query_builder.bind(*max_amount);
+    }
+    if let Some(asset_id) = search_query.get_asset_id() {
+        query_builder = query_builder.bind(*asset_id);
+    }
+    if let Some(connect_id) = search_query.get_connect_id() {
+        query_builder = query_builder.bind(*connect_id);
+    }
+
+    query_builder
 }
 
 async fn get_list(
@@ -57,31 +184,58 @@ async fn get_list(
         JOIN tb_user_book_role AS br ON b.id = br.book_id
         JOIN tb_sub_category AS sc ON r.sub_category_id = sc.id
         WHERE br.user_id = $1 AND b.id = $2
-            AND r.target_dt BETWEEN $3 AND $4
+            AND r.target_dt >= $3 AND r.target_dt < $4
     "
     .to_string();
 
-    let mut bind_idx = 5;
-    if let Some(_) = search_query.get_sub_id() {
-        query.push_str(format!("AND sc.id = ${} ", bind_idx).as_str());
-        bind_idx += 1;
-    }
-    if let Some(_) = search_query.get_base_id() {
-        query.push_str(format!("AND sc.base_id = ${}", bind_idx).as_str());
+    let mut bind_idx = push_search_filter(&mut query, &search_query, 5);
+
+    // (정렬 컬럼, id) 기준 커서 페이지네이션
+    let sort = search_query.get_sort();
+    let (direction, operator) = if search_query.get_asc() {
+        ("ASC", ">")
+    } else {
+        ("DESC", "<")
+    };
+    if search_query.get_cursor().is_some() {
+        query.push_str(
+            format!(
+                "AND ({}, r.id) {} (${}::{}, ${}) ",
+                sort.get_column(),
+                operator,
+                bind_idx,
+                sort.get_sql_type(),
+                bind_idx + 1
+            )
+            .as_str(),
+        );
+        bind_idx += 2;
     }
+    // 다음 페이지 존재 여부 확인을 위해 limit + 1개 조회
+    query.push_str(
+        format!(
+            "ORDER BY {} {}, r.id {} LIMIT ${}",
+            sort.get_column(),
+            direction,
+            direction,
+            bind_idx
+        )
+        .as_str(),
+    );
 
-    let mut query_builder = sqlx::query_as::<_, Record>(&query)
+    let query_builder = sqlx::query_as::<_, Record>(&query)
         .bind(user_id)
         .bind(book_id)
         .bind(search_query.get_start_dt())
         .bind(search_query.get_end_dt());
 
-    if let Some(sub_id) = search_query.get_sub_id() {
-        query_builder = query_builder.bind(sub_id);
-    }
-    if let Some(base_id) = search_query.get_base_id() {
-        query_builder = query_builder.bind(base_id);
+    let mut query_builder = bind_search_filter(query_builder, &search_query);
+    if let Some(cursor) = search_query.get_cursor() {
+        query_builder = query_builder
+            .bind(cursor.get_value().to_string())
+            .bind(cursor.get_id());
     }
+    query_builder = query_builder.bind(search_query.get_limit() + 1);
 
     let rows = query_builder.fetch_all(pool).await.map_err(|e| {
         let err_msg = format!("Error(GetRecords): {:?}", &e);
@@ -129,15 +283,210 @@ pub async fn get_by_id(
     Ok(row)
 }
 
+// 권한 확인은 get_by_id에서 수행
+pub async fn get_lines(pool: &PgPool, record_id: i64) -> Result<Vec<RecordLine>, Box<CustomError>> {
+    let rows = sqlx::query_as::<_, RecordLine>(
+        "
+        SELECT sub_category_id, amount
+        FROM tb_record_line
+        WHERE record_id = $1
+        ORDER BY id
+    ",
+    )
+    .bind(record_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetRecordLine {}): {:?}", record_id, &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(rows)
+}
+
+async fn get_total(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    search_query: Search,
+) -> Result<RecordTotal, Box<CustomError>> {
+    // 이체 기록은 수입, 지출 합계에서 제외
+    let mut query = "
+        SELECT
+            COALESCE(SUM(r.amount) FILTER (WHERE bc.is_income = true), 0)::BIGINT AS income,
+            COALESCE(SUM(r.amount) FILTER (WHERE bc.is_income = false), 0)::BIGINT AS expense
+        FROM tb_record AS r
+        JOIN tb_book AS b ON b.id = r.book_id
+        JOIN tb_user_book_role AS br ON b.id = br.book_id
+        JOIN tb_sub_category AS sc ON r.sub_category_id = sc.id
+        JOIN tb_base_category AS bc ON sc.base_id = bc.id
+        WHERE br.user_id = $1 AND b.id = $2
+            AND r.target_dt >= $3 AND r.target_dt < $4
+            AND r.transfer_id IS NULL
+    "
+    .to_string();
+
+    push_search_filter(&mut query, &search_query, 5);
+
+    let query_builder = sqlx::query_as::<_, RecordTotal>(&query)
+        .bind(user_id)
+        .bind(book_id)
+        .bind(search_query.get_start_dt())
+        .bind(search_query.get_end_dt());
+    let query_builder = bind_search_filter(query_builder, &search_query);
+
+    let row = query_builder.fetch_one(pool).await.map_err(|e| {
+        let err_msg = format!("Error(GetRecordTotal): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(row)
+}
+
+async fn get_summary(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    search_query: Search,
+) -> Result<Vec<CategorySum>, Box<CustomError>> {
+    // 이체 기록은 제외, 분할 기록은 분할 내역의 소분류로 집계, 대분류 합계 내림차순
+    let mut query = "
+        SELECT
+            bc.id AS base_id,
+            bc.name AS base_name,
+            bc.color,
+            bc.is_income,
+            SUM(SUM(COALESCE(rl.amount, r.amount))) OVER (PARTITION BY bc.id)::BIGINT AS base_amount,
+            sc.id AS sub_id,
+            sc.name AS sub_name,
+            SUM(COALESCE(rl.amount, r.amount))::BIGINT AS amount
+        FROM tb_record AS r
+        JOIN tb_book AS b ON b.id = r.book_id
+        JOIN tb_user_book_role AS br ON b.id = br.book_id
+        LEFT JOIN tb_record_line AS rl ON rl.record_id = r.id
+        JOIN tb_sub_category AS sc ON COALESCE(rl.sub_category_id, r.sub_category_id) = sc.id
+        JOIN tb_base_category AS bc ON sc.base_id = bc.id
+        WHERE br.user_id = $1 AND b.id = $2
+            AND r.target_dt >= $3 AND r.target_dt < $4
+            AND r.transfer_id IS NULL
+    "
+    .to_string();
+
+    push_search_filter(&mut query, &search_query, 5);
+    query.push_str(
+        "
+        GROUP BY bc.id, sc.id
+        ORDER BY bc.is_income, base_amount DESC, bc.id, amount DESC, sc.id
+    ",
+    );
+
+    let query_builder = sqlx::query_as::<_, CategorySum>(&query)
+        .bind(user_id)
+        .bind(book_id)
+        .bind(search_query.get_start_dt())
+        .bind(search_query.get_end_dt());
+    let query_builder = bind_search_filter(query_builder, &search_query);
+
+    let rows = query_builder.fetch_all(pool).await.map_err(|e| {
+        let err_msg = format!("Error(GetRecordSummary): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(rows)
+}
+
+async fn get_daily_total(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+) -> Result<Vec<DailyTotal>, Box<CustomError>> {
+    // 기록이 없는 날도 포함, 수입/지출 합계에서 이체 기록 제외
+    let rows = sqlx::query_as::<_, DailyTotal>(
+        "
+        WITH AuthorityCheck AS (
+            SELECT book_id
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2
+        ),
+        DailyRecord AS (
+            SELECT
+                r.target_dt::DATE AS target_date,
+                SUM(r.amount) FILTER (WHERE r.transfer_id IS NULL AND bc.is_income = true) AS income,
+                SUM(r.amount) FILTER (WHERE r.transfer_id IS NULL AND bc.is_income = false) AS expense,
+                COUNT(*) AS count
+            FROM tb_record AS r
+            JOIN AuthorityCheck AS ac ON ac.book_id = r.book_id
+            JOIN tb_sub_category AS sc ON r.sub_category_id = sc.id
+            JOIN tb_base_category AS bc ON sc.base_id = bc.id
+            WHERE r.target_dt >= $3 AND r.target_dt < $4
+            GROUP BY r.target_dt::DATE
+        )
+        SELECT
+            d.day::DATE AS target_date,
+            COALESCE(dr.income, 0)::BIGINT AS income,
+            COALESCE(dr.expense, 0)::BIGINT AS expense,
+            COALESCE(dr.count, 0) AS count
+        FROM generate_series($3::DATE, $4::DATE - 1, INTERVAL '1 day') AS d(day)
+        LEFT JOIN DailyRecord AS dr ON dr.target_date = d.day::DATE
+        WHERE EXISTS (SELECT 1 FROM AuthorityCheck)
+        ORDER BY d.day
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .bind(start_dt)
+    .bind(end_dt)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetDailyTotal): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(rows)
+}
+
 #[cfg(test)]
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use sqlx::PgPool;
 
     use crate::{
         config::database::create_connection_pool,
         domain::record::{
-            entity::{Record, Search},
-            repository::get_record::{get_by_id, get_list},
+            entity::{Record, RecordLine, RecordSort, Search, Transfer},
+            repository::{
+                get_record::{
+                    get_by_id, get_daily_total, get_lines, get_list, get_summary, get_total,
+                },
+                save::{save_record, save_transfer},
+            },
         },
         global::errors::CustomError,
     };
@@ -240,6 +589,259 @@ mod tests {
         assert_eq!(result.len(), 1);
     }
 
+    async fn _insert_record(pool: &PgPool, amount: i32, memo: &str, asset_id: Option<i32>) -> i64 {
+        sqlx::query_scalar(
+            "INSERT INTO tb_record (book_id, sub_category_id, amount, memo, target_dt, asset_id)
+            VALUES (2, 17, $1, $2, '2024-05-15T12:00:00', $3) RETURNING id",
+        )
+        .bind(amount)
+        .bind(memo)
+        .bind(asset_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    fn _may_search() -> Search {
+        let start_dt = NaiveDate::parse_from_str("2024-05-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-06-01", "%Y-%m-%d").unwrap();
+        Search::new(start_dt, end_dt, None, None)
+    }
+
+    #[tokio::test]
+    async fn check_period_boundary() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let id = _insert_record(&pool, 7000, "기간 경계", None).await;
+        sqlx::query("UPDATE tb_record SET target_dt = '2024-06-01T00:00:00' WHERE id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let memo = Some("기간 경계".to_string());
+
+        // Act
+        let may_list = get_list(&pool, user_id, book_id, _may_search().memo(memo.clone())).await;
+        let may_total = get_total(&pool, user_id, book_id, _may_search().memo(memo.clone())).await;
+        let may_summary =
+            get_summary(&pool, user_id, book_id, _may_search().memo(memo.clone())).await;
+
+        let start_dt = NaiveDate::parse_from_str("2024-06-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-07-01", "%Y-%m-%d").unwrap();
+        let june_search = Search::new(start_dt, end_dt, None, None).memo(memo);
+        let june_list = get_list(&pool, user_id, book_id, june_search).await;
+
+        // Assert
+        // 다음 기간 시작 시각의 기록은 다음 기간에만 포함
+        assert!(may_list.unwrap().is_empty());
+        let may_total = may_total.unwrap();
+        assert_eq!(may_total.get_income() + may_total.get_expense(), 0);
+        assert!(may_summary.unwrap().is_empty());
+
+        let june_list = june_list.unwrap();
+        assert_eq!(june_list.len(), 1);
+        assert_eq!(june_list[0].get_id(), id);
+    }
+
+    #[tokio::test]
+    async fn check_memo_search() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let start_dt = NaiveDate::parse_from_str("2024-08-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None).memo(Some("자탕".to_string()));
+
+        // Act
+        let result = get_list(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        // ref) init.sql
+        assert_eq!(result.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn check_memo_case_insensitive() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        _insert_record(&pool, 4500, "Iced Americano", None).await;
+        _insert_record(&pool, 4500, "100%_americano", None).await;
+
+        // Act
+        let lower = get_list(
+            &pool,
+            user_id,
+            book_id,
+            _may_search().memo(Some("americano".to_string())),
+        )
+        .await
+        .unwrap();
+        let wildcard = get_list(
+            &pool,
+            user_id,
+            book_id,
+            _may_search().memo(Some("0%_A".to_string())),
+        )
+        .await
+        .unwrap();
+
+        // Assert
+        assert_eq!(lower.len(), 2);
+        assert_eq!(wildcard.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_amount_range() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        for amount in [3000, 8000, 13000] {
+            _insert_record(&pool, amount, "범위 테스트", None).await;
+        }
+        let search_query = _may_search()
+            .memo(Some("범위 테스트".to_string()))
+            .amount_range(Some(5000), Some(10000));
+
+        // Act
+        let result = get_list(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].get_amount(), 8000);
+    }
+
+    #[tokio::test]
+    async fn check_asset_filter() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let asset_id: i32 = sqlx::query_scalar(
+            "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (2, 1, 0) RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let record_id = _insert_record(&pool, 12000, "자산 필터", Some(asset_id)).await;
+        _insert_record(&pool, 12000, "자산 필터", None).await;
+
+        // Act
+        let result = get_list(
+            &pool,
+            user_id,
+            book_id,
+            _may_search().asset_id(Some(asset_id)),
+        )
+        .await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].get_id(), record_id);
+    }
+
+    #[tokio::test]
+    async fn check_connect_filter() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let connect_id = 1; // ref) init.sql
+        let record_id = _insert_record(&pool, 7000, "커넥트 필터", None).await;
+        _insert_record(&pool, 7000, "커넥트 필터", None).await;
+        sqlx::query("INSERT INTO tb_record_connect (record_id, connect_id) VALUES ($1, $2)")
+            .bind(record_id)
+            .bind(connect_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let search_query = _may_search()
+            .memo(Some("커넥트 필터".to_string()))
+            .connect_id(Some(connect_id));
+
+        // Act
+        let result = get_list(&pool, user_id, book_id, search_query.clone()).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+        let total = get_total(&pool, user_id, book_id, search_query)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].get_id(), record_id);
+        assert_eq!(total.get_expense(), 7000);
+    }
+
+    #[tokio::test]
+    async fn check_cursor_page() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None).limit(1);
+
+        // Act
+        let first = get_list(&pool, user_id, book_id, search_query.clone()).await;
+        assert!(first.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let first = first.unwrap();
+
+        let cursor = RecordSort::TargetDt.to_cursor(&first[0]).unwrap();
+        let second = get_list(&pool, user_id, book_id, search_query.cursor(Some(cursor))).await;
+        assert!(second.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let second = second.unwrap();
+
+        // Assert
+        // ref) init.sql, 다음 페이지 확인용으로 limit + 1개 조회
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 1);
+        assert!(first[0].get_target_dt() > second[0].get_target_dt());
+        assert_eq!(first[1].get_id(), second[0].get_id());
+    }
+
+    #[tokio::test]
+    async fn check_sort_amount_asc() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let start_dt = NaiveDate::parse_from_str("2024-08-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None).sort(RecordSort::Amount, true);
+
+        // Act
+        let result = get_list(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert!(result.len() > 1);
+        assert!(result
+            .windows(2)
+            .all(|pair| pair[0].get_amount() <= pair[1].get_amount()));
+    }
+
     #[tokio::test]
     async fn check_get_by_id_success() {
         // Arrange
@@ -284,4 +886,253 @@ mod tests {
         };
         assert!(err_type)
     }
+
+    #[tokio::test]
+    async fn check_get_total_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None);
+
+        // Act
+        let result = get_total(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        // ref) init.sql
+        assert_eq!(result.get_income(), 0);
+        assert_eq!(result.get_expense(), 30000);
+    }
+
+    #[tokio::test]
+    async fn check_total_without_transfer() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let mut asset_ids: Vec<i32> = vec![];
+        for _ in 0..2 {
+            let asset_id = sqlx::query_scalar(
+                "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (2, 1, 0) RETURNING id",
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            asset_ids.push(asset_id);
+        }
+        let transfer = Transfer::new(
+            book_id,
+            28, // 적금
+            50000,
+            NaiveDateTime::parse_from_str("2024-07-10 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            asset_ids[0],
+            asset_ids[1],
+        );
+        save_transfer(&pool, user_id, transfer).await.unwrap();
+        let income = Record::new(
+            book_id,
+            9, // 급여
+            20000,
+            NaiveDateTime::parse_from_str("2024-07-11 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+        );
+        save_record(&pool, user_id, income, None, vec![])
+            .await
+            .unwrap();
+
+        let start_dt = NaiveDate::parse_from_str("2024-07-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-08-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None);
+
+        // Act
+        let result = get_total(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.get_income(), 20000);
+        assert_eq!(result.get_expense(), 0);
+    }
+
+    #[tokio::test]
+    async fn check_get_summary_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let start_dt = NaiveDate::parse_from_str("2024-08-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None);
+
+        // Act
+        let result = get_summary(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        // ref) init.sql, base 9(17, 18) 30000, base 8(16) 15000
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].get_base_amount(), 30000);
+        assert_eq!(result[0].get_base_id(), result[1].get_base_id());
+        assert_eq!(result[2].get_sub_id(), 16);
+        assert_eq!(result[2].get_base_amount(), 15000);
+        assert!(!result[0].get_color().is_empty());
+    }
+
+    #[tokio::test]
+    async fn check_summary_split_record() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let record = Record::new(
+            book_id,
+            18, // 생필품비
+            30000,
+            NaiveDateTime::parse_from_str("2023-11-10 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+        );
+        let lines = vec![
+            RecordLine::new(17, 20000), // 식비
+            RecordLine::new(16, 10000), // 구독료
+        ];
+        save_record(&pool, user_id, record, None, lines)
+            .await
+            .unwrap();
+
+        let start_dt = NaiveDate::parse_from_str("2023-11-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2023-12-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None);
+
+        // Act
+        let result = get_summary(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        // 분할 내역의 소분류로 집계, 기록의 소분류(18)는 포함되지 않음
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].get_sub_id(), 17);
+        assert_eq!(result[0].get_amount(), 20000);
+        assert_eq!(result[1].get_sub_id(), 16);
+        assert_eq!(result[1].get_amount(), 10000);
+    }
+
+    #[tokio::test]
+    async fn check_get_lines_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let record = Record::new(
+            1,
+            18,
+            16300,
+            NaiveDateTime::parse_from_str("2024-09-09 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+        );
+        let lines = vec![RecordLine::new(17, 10000), RecordLine::new(18, 6300)];
+        let record_id = save_record(&pool, user_id, record, None, lines.clone())
+            .await
+            .unwrap();
+
+        // Act
+        let result = get_lines(&pool, record_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap(), lines);
+    }
+
+    #[tokio::test]
+    async fn check_get_summary_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+        let search_query = Search::new(start_dt, end_dt, None, None);
+
+        // Act
+        let result = get_summary(&pool, user_id, book_id, search_query).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn check_get_daily_total_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let book_id = 2;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+
+        // Act
+        let result = get_daily_total(&pool, user_id, book_id, start_dt, end_dt).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        // ref) init.sql, 09-10, 09-21 기록
+        assert_eq!(result.len(), 30);
+        assert_eq!(result[0].get_target_date(), start_dt);
+        assert_eq!(result[0].get_count(), 0);
+        assert_eq!(result[9].get_count(), 1);
+        assert_eq!(result[9].get_expense(), 15000);
+        assert_eq!(result[20].get_expense(), 15000);
+        assert_eq!(result.iter().map(|day| day.get_count()).sum::<i64>(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_get_daily_total_viewer() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2; // viewer
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-02-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-03-01", "%Y-%m-%d").unwrap();
+
+        // Act
+        let result = get_daily_total(&pool, user_id, book_id, start_dt, end_dt).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 29);
+    }
+
+    #[tokio::test]
+    async fn check_get_daily_total_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-10-01", "%Y-%m-%d").unwrap();
+
+        // Act
+        let result = get_daily_total(&pool, user_id, book_id, start_dt, end_dt).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 0);
+    }
 }
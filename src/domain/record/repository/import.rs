@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::{
+    domain::record::entity::{ImportCategory, Record},
+    global::errors::CustomError,
+};
+
+pub struct ImportRecordRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait ImportRecordRepo: Send + Sync {
+    async fn get_categories(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<ImportCategory>, Box<CustomError>>;
+    async fn save_records(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        records: Vec<Record>,
+    ) -> Result<u64, Box<CustomError>>;
+}
+
+impl ImportRecordRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl ImportRecordRepo for ImportRecordRepoImpl {
+    async fn get_categories(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<ImportCategory>, Box<CustomError>> {
+        get_categories(&self.pool, user_id, book_id).await
+    }
+    async fn save_records(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        records: Vec<Record>,
+    ) -> Result<u64, Box<CustomError>> {
+        save_records(&self.pool, user_id, book_id, records).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+async fn is_editor(
+    conn: &mut sqlx::PgConnection,
+    user_id: i32,
+    book_id: i32,
+) -> Result<bool, Box<CustomError>> {
+    sqlx::query_scalar(
+        "
+        SELECT EXISTS (
+            SELECT 1
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2 AND role != 'viewer'
+        )
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_one(conn)
+    .await
+    .map_err(|e| to_custom_error("ImportRecordRole", e))
+}
+
+/// 가져오기에 사용할 수 있는 기록용 소분류 (가계부 카테고리, 같은 유형의 공용 카테고리)
+pub async fn get_categories(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<ImportCategory>, Box<CustomError>> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| to_custom_error("ImportCategory", e))?;
+
+    if !is_editor(&mut conn, user_id, book_id).await? {
+        return Err(Box::new(CustomError::Unauthorized(
+            "RecordRole".to_string(),
+        )));
+    }
+
+    sqlx::query_as::<_, ImportCategory>(
+        "
+        SELECT sc.id, bc.name AS base_name, sc.name
+        FROM tb_book AS b
+        JOIN tb_base_category AS bc
+            ON bc.book_id = b.id OR (bc.book_id IS NULL AND bc.type_id = b.type_id)
+        JOIN tb_sub_category AS sc ON sc.base_id = bc.id
+        WHERE b.id = $1 AND bc.is_record = true
+        ORDER BY sc.id
+    ",
+    )
+    .bind(book_id)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| to_custom_error("ImportCategory", e))
+}
+
+/// 모든 행을 한 트랜잭션으로 저장, 하나라도 실패하면 전체 취소
+pub async fn save_records(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    records: Vec<Record>,
+) -> Result<u64, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("ImportRecord", e))?;
+
+    if !is_editor(&mut tx, user_id, book_id).await? {
+        return Err(Box::new(CustomError::Unauthorized(
+            "RecordRole".to_string(),
+        )));
+    }
+
+    let sub_category_ids: Vec<i32> = records.iter().map(|r| r.get_sub_category_id()).collect();
+    let amounts: Vec<i32> = records.iter().map(|r| r.get_amount()).collect();
+    let memos: Vec<Option<String>> = records.iter().map(|r| r.get_memo().clone()).collect();
+    let target_dts: Vec<_> = records.iter().map(|r| r.get_target_dt()).collect();
+
+    // 저장 시점에 카테고리를 다시 확인
+    let result = sqlx::query(
+        "
+        INSERT INTO tb_record (book_id, sub_category_id, amount, memo, target_dt, created_at)
+            SELECT b.id, r.sub_category_id, r.amount, r.memo, r.target_dt, NOW()
+            FROM UNNEST($2::int[], $3::int[], $4::varchar[], $5::timestamp[])
+                AS r(sub_category_id, amount, memo, target_dt)
+            JOIN tb_book AS b ON b.id = $1
+            JOIN tb_sub_category AS sc ON sc.id = r.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            WHERE bc.is_record = true
+                AND (bc.book_id = b.id OR (bc.book_id IS NULL AND bc.type_id = b.type_id))
+    ",
+    )
+    .bind(book_id)
+    .bind(sub_category_ids)
+    .bind(amounts)
+    .bind(memos)
+    .bind(target_dts)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("ImportRecord", e))?;
+
+    if result.rows_affected() != records.len() as u64 {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("ImportRecord", e))?;
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::record::{
+            entity::Record,
+            repository::import::{get_categories, save_records},
+        },
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    fn _record(sub_category_id: i32, memo: &str) -> Record {
+        Record::new(
+            1,
+            sub_category_id,
+            12000,
+            NaiveDateTime::parse_from_str("2024-09-12 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+        )
+        .memo(Some(memo.to_string()))
+        .build()
+    }
+
+    #[tokio::test]
+    async fn check_get_categories_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let book_id = 1;
+
+        // Act
+        let result = get_categories(&pool, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        // ref) init.sql, 자산용 카테고리(입출금)는 제외
+        assert!(result.iter().any(|c| c.get_id() == 17));
+        assert!(result.iter().all(|c| c.get_id() != 1));
+    }
+
+    #[tokio::test]
+    async fn check_get_categories_viewer() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2;
+        let book_id = 1;
+
+        // Act
+        let result = get_categories(&pool, user_id, book_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_save_records_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let book_id = 1;
+        let records = vec![_record(17, "가져오기 1"), _record(18, "가져오기 2")];
+
+        // Act
+        let result = save_records(&pool, user_id, book_id, records).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_save_records_rollback() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let book_id = 1;
+        let records = vec![
+            _record(17, "가져오기 취소"),
+            _record(1, "가져오기 취소"), // 자산용 카테고리
+        ];
+
+        // Act
+        let result = save_records(&pool, user_id, book_id, records).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM tb_record WHERE memo = '가져오기 취소'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(count, 0);
+    }
+}
@@ -1,4 +1,7 @@
 pub(super) mod delete;
+pub(super) mod export;
 pub(super) mod get_record;
+pub(super) mod import;
+mod record_line;
 pub(super) mod save;
 pub(super) mod update;
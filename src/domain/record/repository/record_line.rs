@@ -0,0 +1,125 @@
+use sqlx::PgConnection;
+
+use crate::{domain::record::entity::RecordLine, global::errors::CustomError};
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct InsertLineResult {
+    is_splittable: bool,
+    is_category_exist: bool,
+}
+
+/// 기록의 분할 내역을 교체 (빈 목록이면 분할 해제)
+pub(super) async fn replace_lines(
+    conn: &mut PgConnection,
+    record_id: i64,
+    lines: &[RecordLine],
+) -> Result<(), Box<CustomError>> {
+    sqlx::query("DELETE FROM tb_record_line WHERE record_id = $1")
+        .bind(record_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| to_custom_error("DeleteRecordLine", e))?;
+
+    if lines.is_empty() {
+        return Ok(());
+    }
+
+    let sub_category_ids: Vec<i32> = lines.iter().map(|l| l.get_sub_category_id()).collect();
+    let amounts: Vec<i32> = lines.iter().map(|l| l.get_amount()).collect();
+
+    // 이체 기록은 분할 불가, 분할 카테고리는 기록과 같은 가계부(또는 공용)의 같은 수입/지출 구분
+    let result = sqlx::query_as::<_, InsertLineResult>(
+        r#"
+        WITH Parent AS (
+            SELECT r.book_id, bc.is_income
+            FROM tb_record AS r
+            JOIN tb_sub_category AS sc ON sc.id = r.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            WHERE r.id = $1 AND r.transfer_id IS NULL
+        ),
+        Lines AS (
+            SELECT *
+            FROM UNNEST($2::int[], $3::int[]) AS l(sub_category_id, amount)
+        ),
+        CategoryCheck AS (
+            SELECT COUNT(*) = (SELECT COUNT(*) FROM Lines) AS is_category_exist
+            FROM Lines AS l
+            JOIN tb_sub_category AS sc ON sc.id = l.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            JOIN Parent AS p ON p.is_income = bc.is_income
+            WHERE bc.is_record = true
+                AND (bc.book_id = p.book_id OR bc.book_id IS NULL)
+        ),
+        InsertLine AS (
+            INSERT INTO tb_record_line (record_id, sub_category_id, amount)
+                SELECT $1, sub_category_id, amount
+                FROM Lines
+                WHERE EXISTS (SELECT 1 FROM Parent)
+                    AND (SELECT is_category_exist FROM CategoryCheck) = true
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM Parent) AS is_splittable,
+            (SELECT is_category_exist FROM CategoryCheck) AS is_category_exist;
+    "#,
+    )
+    .bind(record_id)
+    .bind(sub_category_ids)
+    .bind(amounts)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| to_custom_error("SaveRecordLine", e))?;
+
+    if !result.is_splittable {
+        return Err(Box::new(CustomError::ValidationError(
+            "RecordLine".to_string(),
+        )));
+    } else if !result.is_category_exist {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    }
+
+    Ok(())
+}
+
+/// 분할 내역이 있으면 합계가 기록 금액과 같고 수입/지출 구분이 일치해야 함
+pub(super) async fn check_lines(
+    conn: &mut PgConnection,
+    record_id: i64,
+) -> Result<(), Box<CustomError>> {
+    let is_valid: bool = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(l.id) = 0
+            OR (SUM(l.amount) = MIN(r.amount) AND BOOL_AND(lbc.is_income = bc.is_income))
+        FROM tb_record AS r
+        JOIN tb_sub_category AS sc ON sc.id = r.sub_category_id
+        JOIN tb_base_category AS bc ON bc.id = sc.base_id
+        LEFT JOIN tb_record_line AS l ON l.record_id = r.id
+        LEFT JOIN tb_sub_category AS lsc ON lsc.id = l.sub_category_id
+        LEFT JOIN tb_base_category AS lbc ON lbc.id = lsc.base_id
+        WHERE r.id = $1
+    "#,
+    )
+    .bind(record_id)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| to_custom_error("CheckRecordLine", e))?;
+
+    if !is_valid {
+        return Err(Box::new(CustomError::ValidationError(
+            "RecordLine".to_string(),
+        )));
+    }
+
+    Ok(())
+}
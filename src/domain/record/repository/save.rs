@@ -3,7 +3,12 @@ use std::sync::Arc;
 use axum::async_trait;
 use sqlx::{Error, PgPool};
 
-use crate::{domain::record::entity::Record, global::errors::CustomError};
+use crate::{
+    domain::record::entity::{Record, RecordLine, Transfer},
+    global::errors::CustomError,
+};
+
+use super::record_line::{check_lines, replace_lines};
 
 pub struct SaveRecordRepoImpl {
     pool: Arc<PgPool>,
@@ -16,6 +21,12 @@ pub trait SaveRecordRepo: Send + Sync {
         user_id: i32,
         record: Record,
         connect_ids: Option<Vec<i32>>,
+        lines: Vec<RecordLine>,
+    ) -> Result<i64, Box<CustomError>>;
+    async fn save_transfer(
+        &self,
+        user_id: i32,
+        transfer: Transfer,
     ) -> Result<i64, Box<CustomError>>;
 }
 
@@ -32,10 +43,17 @@ impl SaveRecordRepo for SaveRecordRepoImpl {
         user_id: i32,
         record: Record,
         connect_ids: Option<Vec<i32>>,
+        lines: Vec<RecordLine>,
     ) -> Result<i64, Box<CustomError>> {
-        save_record(&self.pool, user_id, record, connect_ids).await
+        save_record(&self.pool, user_id, record, connect_ids, lines).await
+    }
+    async fn save_transfer(
+        &self,
+        user_id: i32,
+        transfer: Transfer,
+    ) -> Result<i64, Box<CustomError>> {
+        save_transfer(&self.pool, user_id, transfer).await
     }
-    
 }
 
 #[derive(Debug, sqlx::FromRow)]
@@ -70,7 +88,15 @@ pub async fn save_record(
     user_id: i32,
     record: Record,
     connect_ids: Option<Vec<i32>>,
+    lines: Vec<RecordLine>,
 ) -> Result<i64, Box<CustomError>> {
+    // 기록과 분할 내역을 한 트랜잭션으로 저장
+    let mut tx = pool.begin().await.map_err(|e| {
+        let err_msg = format!("Save(Record): {:?}", e);
+        tracing::error!("{}", err_msg);
+        Box::new(CustomError::DatabaseError(e))
+    })?;
+
     let result = sqlx::query_as::<_, InsertRecord>(
         r#"
         WITH AuthorityCheck AS (
@@ -106,7 +132,15 @@ pub async fn save_record(
                     WHERE book_id IS NOT NULL
                         AND (SELECT is_category_exist FROM CategoryCheck) = true
                         AND (SELECT is_asset_exist FROM AssetCheck) = true
-            RETURNING id
+            RETURNING id, asset_id, amount, sub_category_id
+        ),
+        UpdateBalance AS (
+            UPDATE tb_asset AS a
+            SET balance = a.balance + CASE WHEN bc.is_income THEN r.amount ELSE -r.amount END
+            FROM InsertRecord AS r
+            JOIN tb_sub_category AS sc ON sc.id = r.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            WHERE a.id = r.asset_id
         ),
         ValidConnects AS (
             SELECT id
@@ -136,7 +170,7 @@ pub async fn save_record(
     .bind(record.get_target_dt())
     .bind(record.get_asset_id())
     .bind(connect_ids)
-    .fetch_one(pool)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
         let err_msg = format!("Save(Record): {:?}", e);
@@ -160,6 +194,137 @@ pub async fn save_record(
     // 커넥트 반환
     result.get_connects();
 
+    let record_id = result.get_record_id().unwrap();
+    if !lines.is_empty() {
+        replace_lines(&mut tx, record_id, &lines).await?;
+        check_lines(&mut tx, record_id).await?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        let err_msg = format!("Save(Record): {:?}", e);
+        tracing::error!("{}", err_msg);
+        Box::new(CustomError::DatabaseError(e))
+    })?;
+
+    Ok(record_id)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct InsertTransfer {
+    is_authorized: bool,
+    record_id: Option<i64>,
+    is_asset_exist: bool,
+    is_category_exist: bool,
+}
+
+impl InsertTransfer {
+    fn get_authorized(&self) -> bool {
+        self.is_authorized
+    }
+    fn get_record_id(&self) -> Option<i64> {
+        self.record_id
+    }
+    fn get_asset_exist(&self) -> bool {
+        self.is_asset_exist
+    }
+    fn get_category_exist(&self) -> bool {
+        self.is_category_exist
+    }
+}
+
+pub async fn save_transfer(
+    pool: &PgPool,
+    user_id: i32,
+    transfer: Transfer,
+) -> Result<i64, Box<CustomError>> {
+    // 출금, 입금 기록을 서로 연결해서 한 번에 저장 (출금 기록 id 반환)
+    let result = sqlx::query_as::<_, InsertTransfer>(
+        r#"
+        WITH AuthorityCheck AS (
+            SELECT book_id
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2 AND role != 'viewer'
+        ),
+        CategoryCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM tb_sub_category AS sc
+                JOIN tb_base_category AS bc ON bc.id = sc.base_id
+                LEFT JOIN AuthorityCheck AS ac ON bc.book_id = ac.book_id
+                WHERE sc.id = $3
+                    AND (ac.book_id IS NOT NULL OR bc.book_id IS NULL)
+            ) AS is_category_exist
+        ),
+        AssetCheck AS (
+            SELECT COUNT(*) = 2 AS is_asset_exist
+            FROM tb_asset AS a
+            JOIN AuthorityCheck AS ac ON ac.book_id = a.book_id
+            WHERE a.id IN ($7, $8)
+        ),
+        NewIds AS (
+            SELECT
+                nextval(pg_get_serial_sequence('tb_record', 'id')) AS from_id,
+                nextval(pg_get_serial_sequence('tb_record', 'id')) AS to_id
+        ),
+        InsertRecord AS (
+            INSERT INTO tb_record (id, book_id, sub_category_id, amount, memo, target_dt, created_at, asset_id, transfer_id, is_transfer_in)
+                SELECT n.from_id, ac.book_id, $3, $4, $5, $6, NOW(), $7, n.to_id, false
+                    FROM AuthorityCheck AS ac
+                    CROSS JOIN NewIds AS n
+                    WHERE (SELECT is_category_exist FROM CategoryCheck) = true
+                        AND (SELECT is_asset_exist FROM AssetCheck) = true
+                UNION ALL
+                SELECT n.to_id, ac.book_id, $3, $4, $5, $6, NOW(), $8, n.from_id, true
+                    FROM AuthorityCheck AS ac
+                    CROSS JOIN NewIds AS n
+                    WHERE (SELECT is_category_exist FROM CategoryCheck) = true
+                        AND (SELECT is_asset_exist FROM AssetCheck) = true
+            RETURNING id, asset_id, amount, is_transfer_in
+        ),
+        UpdateBalance AS (
+            UPDATE tb_asset AS a
+            SET balance = a.balance + CASE WHEN r.is_transfer_in THEN r.amount ELSE -r.amount END
+            FROM InsertRecord AS r
+            WHERE a.id = r.asset_id
+        )
+        SELECT
+            (SELECT id FROM InsertRecord WHERE is_transfer_in = false) AS record_id,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT is_category_exist FROM CategoryCheck) AS is_category_exist,
+            (SELECT is_asset_exist FROM AssetCheck) AS is_asset_exist;
+    "#,
+    )
+    .bind(user_id)
+    .bind(transfer.get_book_id())
+    .bind(transfer.get_sub_category_id())
+    .bind(transfer.get_amount())
+    .bind(transfer.get_memo())
+    .bind(transfer.get_target_dt())
+    .bind(transfer.get_from_asset_id())
+    .bind(transfer.get_to_asset_id())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Save(Transfer): {:?}", e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized(
+            "RecordRole".to_string(),
+        )));
+    } else if !result.get_asset_exist() {
+        return Err(Box::new(CustomError::NotFound("Asset".to_string())));
+    } else if !result.get_category_exist() {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    }
+
     Ok(result.get_record_id().unwrap())
 }
 
@@ -169,7 +334,11 @@ mod tests {
 
     use crate::{
         config::database::create_connection_pool,
-        domain::record::{entity::Record, repository::save::save_record}, global::errors::CustomError,
+        domain::record::{
+            entity::{Record, RecordLine, Transfer},
+            repository::save::{save_record, save_transfer},
+        },
+        global::errors::CustomError,
     };
 
     #[tokio::test]
@@ -196,7 +365,7 @@ mod tests {
         );
 
         // Act
-        let result = save_record(&pool, user_id, record, Some(vec![1])).await;
+        let result = save_record(&pool, user_id, record, Some(vec![1]), vec![]).await;
         let inserted_id = result.map_err(|e| println!("{:?}", e)).unwrap();
 
         // Assert
@@ -225,7 +394,7 @@ mod tests {
         );
 
         // Act
-        let result = save_record(&pool, user_id, record, None).await;
+        let result = save_record(&pool, user_id, record, None, vec![]).await;
         let inserted_id = result.map_err(|e| println!("{:?}", e)).unwrap();
 
         // Assert
@@ -238,6 +407,49 @@ mod tests {
         assert_eq!(inserted_id, row.get_id());
     }
 
+    #[tokio::test]
+    async fn check_asset_balance_changed() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let asset_id: i32 = sqlx::query_scalar(
+            "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (1, 1, 50000) RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let expense = Record::new(
+            1,
+            18, // 생필품비
+            16300,
+            NaiveDateTime::parse_from_str("2024-08-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Some(asset_id),
+        );
+        let income = Record::new(
+            1,
+            9, // 급여
+            30000,
+            NaiveDateTime::parse_from_str("2024-08-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Some(asset_id),
+        );
+
+        // Act
+        let result = save_record(&pool, user_id, expense, None, vec![]).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+        let result = save_record(&pool, user_id, income, None, vec![]).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let balance: i64 = sqlx::query_scalar("SELECT balance FROM tb_asset WHERE id = $1")
+            .bind(asset_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(balance, 50000 - 16300 + 30000);
+    }
+
     #[tokio::test]
     async fn check_category_not_found() {
         // Arrange
@@ -253,7 +465,7 @@ mod tests {
         );
 
         // Act
-        let result = save_record(&pool, user_id, record, None).await;
+        let result = save_record(&pool, user_id, record, None, vec![]).await;
 
         // Assert
         // Not Found -> 권한 없는 카테고리 접근 제한
@@ -282,7 +494,7 @@ mod tests {
         );
 
         // Act
-        let result = save_record(&pool, user_id, record, None).await;
+        let result = save_record(&pool, user_id, record, None, vec![]).await;
 
         // Assert
         assert!(result.is_err());
@@ -308,7 +520,7 @@ mod tests {
         );
 
         // Act
-        let result = save_record(&pool, user_id, record, None).await;
+        let result = save_record(&pool, user_id, record, None, vec![]).await;
 
         // Assert
         assert!(result.is_err());
@@ -336,7 +548,7 @@ mod tests {
         );
 
         // Act
-        let result = save_record(&pool, user_id, record, None).await;
+        let result = save_record(&pool, user_id, record, None, vec![]).await;
 
         // Assert
         assert!(result.is_err());
@@ -346,4 +558,185 @@ mod tests {
         };
         assert!(err_type)
     }
+
+    #[tokio::test]
+    async fn check_save_split_record_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let record = Record::new(
+            1,
+            18, // 생필품비
+            16300,
+            NaiveDateTime::parse_from_str("2024-09-09 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+        );
+        let lines = vec![
+            RecordLine::new(17, 10000), // 식비
+            RecordLine::new(18, 6300),
+        ];
+
+        // Act
+        let result = save_record(&pool, user_id, record, None, lines.clone()).await;
+        let inserted_id = result.map_err(|e| println!("{:?}", e)).unwrap();
+
+        // Assert
+        let rows = sqlx::query_as::<_, RecordLine>(
+            "SELECT sub_category_id, amount FROM tb_record_line WHERE record_id = $1 ORDER BY id",
+        )
+        .bind(inserted_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(rows, lines);
+    }
+
+    #[tokio::test]
+    async fn check_split_amount_mismatch() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let record = Record::new(
+            1,
+            18,
+            16300,
+            NaiveDateTime::parse_from_str("2024-09-09 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+        )
+        .memo(Some("분할 합계 불일치".to_string()));
+        let lines = vec![RecordLine::new(17, 10000), RecordLine::new(18, 6000)];
+
+        // Act
+        let result = save_record(&pool, user_id, record, None, lines).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+
+        // 기록도 저장되지 않음 (rollback)
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tb_record WHERE memo = '분할 합계 불일치'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn check_split_income_category() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let record = Record::new(
+            1,
+            18,
+            16300,
+            NaiveDateTime::parse_from_str("2024-09-09 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+        );
+        let lines = vec![
+            RecordLine::new(9, 10000), // 급여 (수입)
+            RecordLine::new(18, 6300),
+        ];
+
+        // Act
+        let result = save_record(&pool, user_id, record, None, lines).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    async fn _save_asset(pool: &sqlx::PgPool, balance: i64) -> i32 {
+        sqlx::query_scalar(
+            "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (1, 1, $1) RETURNING id",
+        )
+        .bind(balance)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_save_transfer_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let from_asset_id = _save_asset(&pool, 100000).await;
+        let to_asset_id = _save_asset(&pool, 0).await;
+        let transfer = Transfer::new(
+            1,
+            28, // 적금
+            30000,
+            NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            from_asset_id,
+            to_asset_id,
+        );
+
+        // Act
+        let result = save_transfer(&pool, user_id, transfer).await;
+        let inserted_id = result.map_err(|e| println!("{:?}", e)).unwrap();
+
+        // Assert
+        let from_record = sqlx::query_as::<_, Record>("SELECT * FROM tb_record WHERE id = $1")
+            .bind(inserted_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let to_record = sqlx::query_as::<_, Record>("SELECT * FROM tb_record WHERE id = $1")
+            .bind(from_record.get_transfer_id().unwrap())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let balances: Vec<i64> =
+            sqlx::query_scalar("SELECT balance FROM tb_asset WHERE id = ANY($1) ORDER BY id")
+                .bind(vec![from_asset_id, to_asset_id])
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+
+        assert_eq!(to_record.get_transfer_id(), &Some(inserted_id));
+        assert_eq!(to_record.get_asset_id(), &Some(to_asset_id));
+        assert_eq!(balances, vec![70000, 30000]);
+    }
+
+    #[tokio::test]
+    async fn check_transfer_asset_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let from_asset_id = _save_asset(&pool, 0).await;
+        let transfer = Transfer::new(
+            1,
+            28,
+            30000,
+            NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            from_asset_id,
+            -32, // 없는 자산, 다른 가계부 자산
+        );
+
+        // Act
+        let result = save_transfer(&pool, user_id, transfer).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
 }
@@ -8,6 +8,8 @@ use crate::{
     global::{constants::FieldUpdate, errors::CustomError},
 };
 
+use super::record_line::{check_lines, replace_lines};
+
 pub struct UpdateRecordRepoImpl {
     pool: Arc<PgPool>,
 }
@@ -45,6 +47,15 @@ fn make_query(index: &mut i32, field_name: &str) -> String {
     format!("{} = ${}, ", field_name, index)
 }
 
+// 이체의 경우 자산은 요청한 쪽 기록만 변경
+fn make_asset_query(index: &mut i32) -> String {
+    *index += 1;
+    format!(
+        "asset_id = CASE WHEN id = $2 THEN ${} ELSE asset_id END, ",
+        index
+    )
+}
+
 #[derive(Debug, sqlx::FromRow)]
 struct UpdateRecordResult {
     is_exist: bool,
@@ -52,6 +63,7 @@ struct UpdateRecordResult {
     update_count: i64,
     is_asset_exist: bool,
     is_category_exist: bool,
+    is_transfer_valid: bool,
 }
 
 impl UpdateRecordResult {
@@ -61,12 +73,18 @@ impl UpdateRecordResult {
     fn get_authorized(&self) -> bool {
         self.is_authorized
     }
+    fn get_count(&self) -> i64 {
+        self.update_count
+    }
     fn get_asset_exist(&self) -> bool {
         self.is_asset_exist
     }
     fn get_category_exist(&self) -> bool {
         self.is_category_exist
     }
+    fn get_transfer_valid(&self) -> bool {
+        self.is_transfer_valid
+    }
 }
 
 async fn update_record(
@@ -77,7 +95,7 @@ async fn update_record(
 ) -> Result<(), Box<CustomError>> {
     let mut query = r"
         WITH RecordExists AS (
-            SELECT book_id
+            SELECT book_id, transfer_id
             FROM tb_record
             WHERE id = $2
         ),
@@ -93,6 +111,7 @@ async fn update_record(
     let mut index = 2;
     let mut ctg_check = false;
     let mut asset_check = false;
+    let mut transfer_check = false;
 
     let mut update_query = r"
         UpdateRecord AS (
@@ -128,7 +147,7 @@ async fn update_record(
     };
     match edit_record.get_asset_id() {
         FieldUpdate::Set(_) => {
-            update_query.push_str(&make_query(&mut index, "asset_id"));
+            update_query.push_str(&make_asset_query(&mut index));
             query.push_str(&format!(
                 r"
                 AssetCheck AS (
@@ -144,19 +163,36 @@ async fn update_record(
             asset_check = true;
         }
         FieldUpdate::SetNone => {
-            update_query.push_str(&make_query(&mut index, "asset_id"));
+            update_query.push_str(&make_asset_query(&mut index));
         }
         _ => {}
     }
+    // 이체는 양쪽 자산이 모두 있어야 하고 서로 달라야 함
+    if !matches!(edit_record.get_asset_id(), FieldUpdate::NoChange) {
+        query.push_str(&format!(
+            r"
+            TransferCheck AS (
+                SELECT NOT EXISTS (
+                    SELECT 1
+                    FROM RecordExists AS r
+                    JOIN tb_record AS t ON t.id = r.transfer_id
+                    WHERE ${0}::INT IS NULL OR t.asset_id = ${0}::INT
+                ) AS is_transfer_valid
+            ),",
+            index
+        ));
+        transfer_check = true;
+    }
 
-    if index == 2 {
+    // 분할 내역만 변경하는 경우 updated_at만 갱신
+    if index == 2 && matches!(edit_record.get_lines(), FieldUpdate::NoChange) {
         return Err(Box::new(CustomError::NoFieldUpdate("Record".to_string())));
     }
 
     update_query.push_str(
         &("
             updated_at = NOW() 
-            WHERE id = $2
+            WHERE (id = $2 OR id = (SELECT transfer_id FROM RecordExists))
                 AND EXISTS (SELECT book_id FROM AuthorityCheck) = true
             "
         .to_string()
@@ -169,12 +205,47 @@ async fn update_record(
                 "AND (SELECT is_asset_exist FROM AssetCheck) = true\n"
             } else {
                 ""
+            }
+            + if transfer_check {
+                "AND (SELECT is_transfer_valid FROM TransferCheck) = true\n"
+            } else {
+                ""
             }),
     );
 
+    // 이체는 연결된 기록도 함께 변경
+    // 변경 전 기록은 되돌리고 변경 후 기록을 자산 잔액에 반영
     query.push_str(
         &(update_query
-            + "RETURNING id
+            + "RETURNING id, asset_id, amount, sub_category_id, transfer_id, is_transfer_in
+        ),
+        PrevRecord AS (
+            SELECT id, asset_id, amount, sub_category_id, transfer_id, is_transfer_in
+            FROM tb_record
+            WHERE id IN (SELECT id FROM UpdateRecord)
+        ),
+        BalanceDelta AS (
+            SELECT
+                d.asset_id,
+                SUM(CASE WHEN d.is_transfer_in OR (d.transfer_id IS NULL AND bc.is_income)
+                    THEN d.amount ELSE -d.amount END) AS delta
+            FROM (
+                SELECT asset_id, -amount AS amount, sub_category_id, transfer_id, is_transfer_in
+                FROM PrevRecord
+                UNION ALL
+                SELECT asset_id, amount, sub_category_id, transfer_id, is_transfer_in
+                FROM UpdateRecord
+            ) AS d
+            JOIN tb_sub_category AS sc ON sc.id = d.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            WHERE d.asset_id IS NOT NULL
+            GROUP BY d.asset_id
+        ),
+        UpdateBalance AS (
+            UPDATE tb_asset AS a
+            SET balance = a.balance + bd.delta
+            FROM BalanceDelta AS bd
+            WHERE a.id = bd.asset_id
         )" + "
             SELECT
                 EXISTS (SELECT 1 FROM RecordExists) AS is_exist,
@@ -190,6 +261,11 @@ async fn update_record(
             } else {
                 ",true AS is_asset_exist"
             }
+            + if transfer_check {
+                ",(SELECT is_transfer_valid FROM TransferCheck) AS is_transfer_valid\n"
+            } else {
+                ",true AS is_transfer_valid"
+            }
             + ";"),
     );
 
@@ -221,7 +297,14 @@ async fn update_record(
         _ => {}
     }
 
-    let result = query_builder.fetch_one(pool).await.map_err(|e| {
+    // 기록과 분할 내역을 한 트랜잭션으로 변경
+    let mut tx = pool.begin().await.map_err(|e| {
+        let err_msg = format!("Update(Record {}): {}", record_id, e);
+        tracing::error!("{}", err_msg);
+        Box::new(CustomError::DatabaseError(e))
+    })?;
+
+    let result = query_builder.fetch_one(&mut *tx).await.map_err(|e| {
         let err_msg = format!("Update(Record {}): {}", record_id, e);
         tracing::error!("{}", err_msg);
 
@@ -242,8 +325,27 @@ async fn update_record(
         return Err(Box::new(CustomError::NotFound("Category".to_string())));
     } else if !result.get_asset_exist() {
         return Err(Box::new(CustomError::NotFound("Asset".to_string())));
+    } else if !result.get_transfer_valid() {
+        return Err(Box::new(CustomError::ValidationError(
+            "TransferAsset".to_string(),
+        )));
+    } else if result.get_count() == 0 {
+        return Err(Box::new(CustomError::NotFound("Record".to_string())));
     }
 
+    match edit_record.get_lines() {
+        FieldUpdate::Set(lines) => replace_lines(&mut tx, record_id, lines).await?,
+        FieldUpdate::SetNone => replace_lines(&mut tx, record_id, &[]).await?,
+        FieldUpdate::NoChange => {}
+    }
+    check_lines(&mut tx, record_id).await?;
+
+    tx.commit().await.map_err(|e| {
+        let err_msg = format!("Update(Record {}): {}", record_id, e);
+        tracing::error!("{}", err_msg);
+        Box::new(CustomError::DatabaseError(e))
+    })?;
+
     Ok(())
 }
 
@@ -255,8 +357,12 @@ mod tests {
     use crate::{
         config::database::create_connection_pool,
         domain::record::{
-            entity::{Record, UpdateRecord},
-            repository::{get_record::get_by_id, save::save_record, update::update_record},
+            entity::{Record, RecordLine, Transfer, UpdateRecord},
+            repository::{
+                get_record::{get_by_id, get_lines},
+                save::{save_record, save_transfer},
+                update::update_record,
+            },
         },
         global::{constants::FieldUpdate, errors::CustomError},
     };
@@ -278,7 +384,9 @@ mod tests {
             NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
             None,
         );
-        let new_id = save_record(&pool, user_id, record, None).await.unwrap();
+        let new_id = save_record(&pool, user_id, record, None, vec![])
+            .await
+            .unwrap();
         new_id
     }
 
@@ -310,6 +418,178 @@ mod tests {
         assert_eq!(row.get_memo(), &None);
     }
 
+    #[tokio::test]
+    async fn check_asset_balance_changed() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let mut asset_ids: Vec<i32> = vec![];
+        for _ in 0..2 {
+            let asset_id = sqlx::query_scalar(
+                "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (1, 1, 0) RETURNING id",
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            asset_ids.push(asset_id);
+        }
+        let record = Record::new(
+            1,
+            18, // 생필품비
+            16300,
+            NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Some(asset_ids[0]),
+        );
+        let new_id = save_record(&pool, user_id, record, None, vec![])
+            .await
+            .unwrap();
+
+        // 자산 변경 + 수입 카테고리로 변경
+        let edit_record = UpdateRecord::new(
+            FieldUpdate::Set(9), // 급여
+            FieldUpdate::Set(20000),
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(asset_ids[1]),
+        );
+
+        // Act
+        let result = update_record(&pool, user_id, new_id, edit_record).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let balances: Vec<i64> =
+            sqlx::query_scalar("SELECT balance FROM tb_asset WHERE id = ANY($1) ORDER BY id")
+                .bind(&asset_ids)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+
+        assert_eq!(balances, vec![0, 20000]);
+    }
+
+    #[tokio::test]
+    async fn check_transfer_updated_together() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let mut asset_ids: Vec<i32> = vec![];
+        for _ in 0..3 {
+            let asset_id = sqlx::query_scalar(
+                "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (1, 1, 0) RETURNING id",
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            asset_ids.push(asset_id);
+        }
+        let transfer = Transfer::new(
+            1,
+            28, // 적금
+            30000,
+            NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            asset_ids[0],
+            asset_ids[1],
+        );
+        let from_id = save_transfer(&pool, user_id, transfer).await.unwrap();
+        let to_id = get_by_id(&pool, user_id, from_id)
+            .await
+            .unwrap()
+            .get_transfer_id()
+            .unwrap();
+
+        // 입금 쪽 금액, 자산 변경
+        let edit_record = UpdateRecord::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(50000),
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(asset_ids[2]),
+        );
+
+        // Act
+        let result = update_record(&pool, user_id, to_id, edit_record).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let from_record = get_by_id(&pool, user_id, from_id).await.unwrap();
+        let balances: Vec<i64> =
+            sqlx::query_scalar("SELECT balance FROM tb_asset WHERE id = ANY($1) ORDER BY id")
+                .bind(&asset_ids)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+
+        assert_eq!(from_record.get_amount(), 50000);
+        assert_eq!(from_record.get_asset_id(), &Some(asset_ids[0]));
+        assert_eq!(balances, vec![-50000, 0, 50000]);
+    }
+
+    #[tokio::test]
+    async fn check_transfer_invalid_asset() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let mut asset_ids: Vec<i32> = vec![];
+        for _ in 0..2 {
+            let asset_id = sqlx::query_scalar(
+                "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (1, 1, 0) RETURNING id",
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            asset_ids.push(asset_id);
+        }
+        let transfer = Transfer::new(
+            1,
+            28, // 적금
+            30000,
+            NaiveDateTime::parse_from_str("2024-09-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            asset_ids[0],
+            asset_ids[1],
+        );
+        let from_id = save_transfer(&pool, user_id, transfer).await.unwrap();
+
+        // 상대 기록과 같은 자산, 자산 해제
+        let same_asset = UpdateRecord::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(asset_ids[1]),
+        );
+        let no_asset = UpdateRecord::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::SetNone,
+        );
+
+        // Act
+        let same_result = update_record(&pool, user_id, from_id, same_asset).await;
+        let none_result = update_record(&pool, user_id, from_id, no_asset).await;
+
+        // Assert
+        for result in [same_result, none_result] {
+            let err_type = match *result.err().unwrap() {
+                CustomError::ValidationError(_) => true,
+                _ => false,
+            };
+            assert!(err_type)
+        }
+        let balances: Vec<i64> =
+            sqlx::query_scalar("SELECT balance FROM tb_asset WHERE id = ANY($1) ORDER BY id")
+                .bind(&asset_ids)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(balances, vec![-30000, 30000]);
+    }
+
     #[tokio::test]
     async fn check_no_field_to_update() {
         // Arrange
@@ -448,4 +728,116 @@ mod tests {
         let updated_user = get_by_id(&pool, user_id, new_id).await.unwrap();
         assert!(last_time < updated_user.get_updated_at().unwrap())
     }
+
+    #[tokio::test]
+    async fn check_update_lines_only() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let lines = vec![RecordLine::new(17, 10000), RecordLine::new(18, 6300)];
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_record = UpdateRecord::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        )
+        .lines(FieldUpdate::Set(lines.clone()));
+
+        // Act
+        let result = update_record(&pool, user_id, new_id, edit_record).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let rows = get_lines(&pool, new_id).await.unwrap();
+        assert_eq!(rows, lines);
+    }
+
+    #[tokio::test]
+    async fn check_amount_mismatch_with_lines() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let split = UpdateRecord::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        )
+        .lines(FieldUpdate::Set(vec![
+            RecordLine::new(17, 10000),
+            RecordLine::new(18, 6300),
+        ]));
+        update_record(&pool, user_id, new_id, split).await.unwrap();
+
+        // 분할 내역은 그대로 두고 금액만 변경
+        let edit_record = UpdateRecord::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(20000),
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+
+        // Act
+        let result = update_record(&pool, user_id, new_id, edit_record).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type);
+
+        // 변경 취소 (rollback)
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+        assert_eq!(row.get_amount(), 16300);
+    }
+
+    #[tokio::test]
+    async fn check_remove_lines() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+
+        let new_id = _save_sample(&pool, user_id).await;
+        let split = UpdateRecord::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        )
+        .lines(FieldUpdate::Set(vec![
+            RecordLine::new(17, 10000),
+            RecordLine::new(18, 6300),
+        ]));
+        update_record(&pool, user_id, new_id, split).await.unwrap();
+
+        let edit_record = UpdateRecord::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(20000),
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        )
+        .lines(FieldUpdate::SetNone);
+
+        // Act
+        let result = update_record(&pool, user_id, new_id, edit_record).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let rows = get_lines(&pool, new_id).await.unwrap();
+        assert!(rows.is_empty());
+    }
 }
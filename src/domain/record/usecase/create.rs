@@ -1,7 +1,10 @@
 use axum::async_trait;
 
 use crate::{
-    domain::record::{dto::request::NewRecord, repository::save::SaveRecordRepo},
+    domain::record::{
+        dto::request::{NewRecord, NewTransfer},
+        repository::save::SaveRecordRepo,
+    },
     global::errors::CustomError,
 };
 
@@ -19,6 +22,11 @@ pub trait CreateRecordUsecase: Send + Sync {
         user_id: i32,
         new_record: NewRecord,
     ) -> Result<i64, Box<CustomError>>;
+    async fn create_transfer(
+        &self,
+        user_id: i32,
+        new_transfer: NewTransfer,
+    ) -> Result<i64, Box<CustomError>>;
 }
 
 impl<T> CreateRecordUsecaseImpl<T>
@@ -42,6 +50,14 @@ where
     ) -> Result<i64, Box<CustomError>> {
         create_record(&self.repository, user_id, new_record).await
     }
+
+    async fn create_transfer(
+        &self,
+        user_id: i32,
+        new_transfer: NewTransfer,
+    ) -> Result<i64, Box<CustomError>> {
+        create_transfer(&self.repository, user_id, new_transfer).await
+    }
 }
 
 async fn create_record<T>(
@@ -52,10 +68,38 @@ async fn create_record<T>(
 where
     T: SaveRecordRepo,
 {
+    if !new_record.is_valid_lines() {
+        return Err(Box::new(CustomError::ValidationError(
+            "RecordLine".to_string(),
+        )));
+    }
+
     let record = new_record.to_entity();
     let connect_ids = new_record.get_connect_ids();
+    let lines = new_record.get_lines();
+
+    repository
+        .save_record(user_id, record, connect_ids, lines)
+        .await
+}
+
+async fn create_transfer<T>(
+    repository: &T,
+    user_id: i32,
+    new_transfer: NewTransfer,
+) -> Result<i64, Box<CustomError>>
+where
+    T: SaveRecordRepo,
+{
+    if new_transfer.is_same_asset() {
+        return Err(Box::new(CustomError::ValidationError(
+            "TransferAsset".to_string(),
+        )));
+    }
 
-    repository.save_record(user_id, record, connect_ids).await
+    repository
+        .save_transfer(user_id, new_transfer.to_entity())
+        .await
 }
 
 #[cfg(test)]
@@ -65,8 +109,10 @@ mod tests {
     use mockall::{mock, predicate};
 
     use crate::domain::record::{
-        dto::request::NewRecord, entity::Record, repository::save::SaveRecordRepo,
-        usecase::create::create_record,
+        dto::request::{NewRecord, NewTransfer},
+        entity::{Record, RecordLine, Transfer},
+        repository::save::SaveRecordRepo,
+        usecase::create::{create_record, create_transfer},
     };
     use crate::global::errors::CustomError;
 
@@ -75,7 +121,8 @@ mod tests {
 
         #[async_trait]
         impl SaveRecordRepo for SaveRecordRepoImpl {
-            async fn save_record(&self, user_id: i32, record: Record, connect_ids: Option<Vec<i32>>) -> Result<i64, Box<CustomError>>;
+            async fn save_record(&self, user_id: i32, record: Record, connect_ids: Option<Vec<i32>>, lines: Vec<RecordLine>) -> Result<i64, Box<CustomError>>;
+            async fn save_transfer(&self, user_id: i32, transfer: Transfer) -> Result<i64, Box<CustomError>>;
         }
     }
 
@@ -100,8 +147,9 @@ mod tests {
                 predicate::eq(user_id),
                 predicate::eq(new_record.to_entity()),
                 predicate::eq(None),
+                predicate::eq(vec![]),
             )
-            .returning(|_, _, _| Ok(1));
+            .returning(|_, _, _, _| Ok(1));
 
         // Act
         let result = create_record(&mock_repo, user_id, new_record).await;
@@ -111,4 +159,130 @@ mod tests {
         // Assert
         assert_eq!(inserted_id, 1);
     }
+
+    #[tokio::test]
+    async fn check_create_split_record_success() {
+        // Arrange
+        let user_id = 1;
+        let lines = vec![RecordLine::new(17, 10000), RecordLine::new(18, 6500)];
+        let new_record = NewRecord::new(
+            1,
+            18,
+            16500,
+            Some("마트".to_string()),
+            NaiveDateTime::parse_from_str("2024-09-08 15:30:37", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+            None,
+        )
+        .lines(Some(lines.clone()));
+
+        let mut mock_repo = MockSaveRecordRepoImpl::new();
+        mock_repo
+            .expect_save_record()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(new_record.to_entity()),
+                predicate::eq(None),
+                predicate::eq(lines),
+            )
+            .returning(|_, _, _, _| Ok(1));
+
+        // Act
+        let result = create_record(&mock_repo, user_id, new_record).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_split_amount_mismatch() {
+        // Arrange
+        let user_id = 1;
+        let new_record = NewRecord::new(
+            1,
+            18,
+            16500,
+            None,
+            NaiveDateTime::parse_from_str("2024-09-08 15:30:37", "%Y-%m-%d %H:%M:%S").unwrap(),
+            None,
+            None,
+        )
+        .lines(Some(vec![
+            RecordLine::new(17, 10000),
+            RecordLine::new(18, 5000),
+        ]));
+
+        let mut mock_repo = MockSaveRecordRepoImpl::new();
+        mock_repo.expect_save_record().never();
+
+        // Act
+        let result = create_record(&mock_repo, user_id, new_record).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_create_transfer_success() {
+        // Arrange
+        let user_id = 1;
+        let new_transfer = NewTransfer::new(
+            1,
+            28,
+            30000,
+            None,
+            NaiveDateTime::parse_from_str("2024-09-08 15:30:37", "%Y-%m-%d %H:%M:%S").unwrap(),
+            1,
+            2,
+        );
+
+        let mut mock_repo = MockSaveRecordRepoImpl::new();
+        mock_repo
+            .expect_save_transfer()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(new_transfer.to_entity()),
+            )
+            .returning(|_, _| Ok(5));
+
+        // Act
+        let result = create_transfer(&mock_repo, user_id, new_transfer).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn check_transfer_same_asset() {
+        // Arrange
+        let user_id = 1;
+        let new_transfer = NewTransfer::new(
+            1,
+            28,
+            30000,
+            None,
+            NaiveDateTime::parse_from_str("2024-09-08 15:30:37", "%Y-%m-%d %H:%M:%S").unwrap(),
+            1,
+            1,
+        );
+
+        let mut mock_repo = MockSaveRecordRepoImpl::new();
+        mock_repo.expect_save_transfer().never();
+
+        // Act
+        let result = create_transfer(&mock_repo, user_id, new_transfer).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
 }
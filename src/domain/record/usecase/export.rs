@@ -0,0 +1,168 @@
+use axum::async_trait;
+
+use crate::{
+    domain::record::{
+        dto::{request::SearchParams, response::ExportFile},
+        entity::ExportFormat,
+        repository::export::ExportRecordRepo,
+        utils::export::{stream_csv, stream_xlsx},
+    },
+    global::errors::CustomError,
+};
+
+pub struct ExportRecordUsecaseImpl<T>
+where
+    T: ExportRecordRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait ExportRecordUsecase: Send + Sync {
+    async fn export_records(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: SearchParams,
+        format: ExportFormat,
+    ) -> Result<ExportFile, Box<CustomError>>;
+}
+
+impl<T> ExportRecordUsecaseImpl<T>
+where
+    T: ExportRecordRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> ExportRecordUsecase for ExportRecordUsecaseImpl<T>
+where
+    T: ExportRecordRepo,
+{
+    async fn export_records(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: SearchParams,
+        format: ExportFormat,
+    ) -> Result<ExportFile, Box<CustomError>> {
+        export_records(&self.repository, user_id, book_id, params, format).await
+    }
+}
+
+async fn export_records<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    params: SearchParams,
+    format: ExportFormat,
+) -> Result<ExportFile, Box<CustomError>>
+where
+    T: ExportRecordRepo,
+{
+    let rows = repository
+        .get_rows(user_id, book_id, params.to_query()?)
+        .await?;
+
+    let chunks = match format {
+        ExportFormat::Csv => stream_csv(rows),
+        ExportFormat::Xlsx => stream_xlsx(rows),
+    };
+
+    Ok(ExportFile::new(format, chunks))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use mockall::{mock, predicate};
+    use tokio::sync::mpsc::{self, Receiver};
+
+    use crate::{
+        domain::record::{
+            dto::request::SearchParams,
+            entity::{ExportFormat, ExportRow, Search},
+            repository::export::ExportRecordRepo,
+            usecase::export::export_records,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        ExportRecordRepoImpl {}
+
+        #[async_trait]
+        impl ExportRecordRepo for ExportRecordRepoImpl {
+            async fn get_rows(&self, user_id: i32, book_id: i32, search_query: Search) -> Result<Receiver<Result<ExportRow, Box<CustomError>>>, Box<CustomError>>;
+        }
+    }
+
+    fn _params() -> SearchParams {
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        SearchParams::new(start_dt, "M".to_string(), None, None)
+    }
+
+    #[tokio::test]
+    async fn check_export_csv_success() {
+        // Arrange
+        let mut mock_repo = MockExportRecordRepoImpl::new();
+        mock_repo
+            .expect_get_rows()
+            .with(predicate::eq(1), predicate::eq(1), predicate::always())
+            .returning(|_, _, _| {
+                let (sender, receiver) = mpsc::channel(1);
+                let row = ExportRow::new(
+                    NaiveDateTime::parse_from_str("2024-09-08 18:30:00", "%Y-%m-%d %H:%M:%S")
+                        .unwrap(),
+                    false,
+                    "변동지출".to_string(),
+                    "식비".to_string(),
+                    15000,
+                );
+                sender.try_send(Ok(row)).unwrap();
+                Ok(receiver)
+            });
+
+        // Act
+        let result = export_records(&mock_repo, 1, 1, _params(), ExportFormat::Csv).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+        assert_eq!(result.get_format(), &ExportFormat::Csv);
+
+        let mut chunks = result.into_chunks();
+        let mut body = vec![];
+        while let Some(chunk) = chunks.recv().await {
+            body.extend(chunk.unwrap());
+        }
+
+        // Assert
+        let body = String::from_utf8(body).unwrap();
+        assert!(body.contains("2024-09-08 18:30:00,지출,변동지출,식비,15000"));
+    }
+
+    #[tokio::test]
+    async fn check_export_no_role() {
+        // Arrange
+        let mut mock_repo = MockExportRecordRepoImpl::new();
+        mock_repo.expect_get_rows().returning(|_, _, _| {
+            Err(Box::new(CustomError::Unauthorized(
+                "RecordRole".to_string(),
+            )))
+        });
+
+        // Act
+        let result = export_records(&mock_repo, 1, 1, _params(), ExportFormat::Xlsx).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
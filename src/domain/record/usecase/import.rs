@@ -0,0 +1,187 @@
+use axum::async_trait;
+
+use crate::{
+    domain::record::{
+        dto::{request::ImportRecords, response::ImportResult},
+        repository::import::ImportRecordRepo,
+        utils::import::{read_rows, validate_rows, CategoryResolver},
+    },
+    global::errors::CustomError,
+};
+
+pub struct ImportRecordUsecaseImpl<T>
+where
+    T: ImportRecordRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait ImportRecordUsecase: Send + Sync {
+    async fn import_records(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        import: ImportRecords,
+    ) -> Result<ImportResult, Box<CustomError>>;
+}
+
+impl<T> ImportRecordUsecaseImpl<T>
+where
+    T: ImportRecordRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> ImportRecordUsecase for ImportRecordUsecaseImpl<T>
+where
+    T: ImportRecordRepo,
+{
+    async fn import_records(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        import: ImportRecords,
+    ) -> Result<ImportResult, Box<CustomError>> {
+        import_records(&self.repository, user_id, book_id, import).await
+    }
+}
+
+async fn import_records<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    import: ImportRecords,
+) -> Result<ImportResult, Box<CustomError>>
+where
+    T: ImportRecordRepo,
+{
+    let rows = read_rows(import.get_content(), import.get_mapping())?;
+    let categories = repository.get_categories(user_id, book_id).await?;
+
+    let (rows, errors) = validate_rows(rows, &CategoryResolver::new(&categories));
+    let result = ImportResult::new(import.is_dry_run(), rows, errors);
+
+    // 미리보기이거나 오류가 있는 행이 있으면 저장하지 않음
+    if import.is_dry_run() || !result.get_errors().is_empty() {
+        return Ok(result);
+    }
+
+    let records = result
+        .get_rows()
+        .iter()
+        .map(|row| row.to_entity(book_id))
+        .collect();
+    let imported = repository.save_records(user_id, book_id, records).await?;
+
+    Ok(result.imported(imported))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::record::{
+            dto::request::{ColumnMapping, ImportRecords},
+            entity::{ImportCategory, Record},
+            repository::import::ImportRecordRepo,
+            usecase::import::import_records,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        ImportRecordRepoImpl {}
+
+        #[async_trait]
+        impl ImportRecordRepo for ImportRecordRepoImpl {
+            async fn get_categories(&self, user_id: i32, book_id: i32) -> Result<Vec<ImportCategory>, Box<CustomError>>;
+            async fn save_records(&self, user_id: i32, book_id: i32, records: Vec<Record>) -> Result<u64, Box<CustomError>>;
+        }
+    }
+
+    fn _import(content: &str) -> ImportRecords {
+        let mapping = ColumnMapping::new(
+            "date".to_string(),
+            "amount".to_string(),
+            None,
+            "category".to_string(),
+        );
+        ImportRecords::new(content.to_string(), mapping)
+    }
+
+    fn _mock_repo() -> MockImportRecordRepoImpl {
+        let mut mock_repo = MockImportRecordRepoImpl::new();
+        mock_repo
+            .expect_get_categories()
+            .with(predicate::eq(1), predicate::eq(1))
+            .returning(|_, _| {
+                Ok(vec![ImportCategory::new(
+                    17,
+                    "변동지출".to_string(),
+                    "식비".to_string(),
+                )])
+            });
+        mock_repo
+    }
+
+    #[tokio::test]
+    async fn check_import_success() {
+        // Arrange
+        let import = _import("date,amount,category\n2024-09-01,15000,식비\n2024-09-02,3000,식비\n");
+
+        let mut mock_repo = _mock_repo();
+        mock_repo
+            .expect_save_records()
+            .withf(|user_id, book_id, records| *user_id == 1 && *book_id == 1 && records.len() == 2)
+            .returning(|_, _, records| Ok(records.len() as u64));
+
+        // Act
+        let result = import_records(&mock_repo, 1, 1, import).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().get_imported(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_dry_run_not_saved() {
+        // Arrange
+        let import = _import("date,amount,category\n2024-09-01,15000,식비\n").dry_run(true);
+
+        let mut mock_repo = _mock_repo();
+        mock_repo.expect_save_records().never();
+
+        // Act
+        let result = import_records(&mock_repo, 1, 1, import).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.get_rows().len(), 1);
+        assert_eq!(result.get_imported(), 0);
+    }
+
+    #[tokio::test]
+    async fn check_row_error_not_saved() {
+        // Arrange
+        let import = _import("date,amount,category\n2024-09-01,15000,식비\n2024-09-02,3000,없음\n");
+
+        let mut mock_repo = _mock_repo();
+        mock_repo.expect_save_records().never();
+
+        // Act
+        let result = import_records(&mock_repo, 1, 1, import).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.get_errors().len(), 1);
+        assert_eq!(result.get_imported(), 0);
+    }
+}
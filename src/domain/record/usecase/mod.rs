@@ -1,4 +1,6 @@
 pub(super) mod create;
 pub(super) mod delete;
+pub(super) mod export;
+pub(super) mod import;
 pub(super) mod read;
 pub(super) mod update;
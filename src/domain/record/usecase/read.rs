@@ -2,7 +2,12 @@ use axum::async_trait;
 
 use crate::{
     domain::record::{
-        dto::request::SearchParams, entity::Record, repository::get_record::GetRecordRepo,
+        dto::{
+            request::{CalendarParams, SearchParams},
+            response::{RecordDetail, RecordPage, RecordSummary},
+        },
+        entity::{DailyTotal, RecordTotal},
+        repository::get_record::GetRecordRepo,
     },
     global::errors::CustomError,
 };
@@ -21,8 +26,30 @@ pub trait ReadRecordUsecase: Send + Sync {
         user_id: i32,
         book_id: i32,
         params: SearchParams,
-    ) -> Result<Vec<Record>, Box<CustomError>>;
-    async fn read_record(&self, user_id: i32, record_id: i64) -> Result<Record, Box<CustomError>>;
+    ) -> Result<RecordPage, Box<CustomError>>;
+    async fn read_record(
+        &self,
+        user_id: i32,
+        record_id: i64,
+    ) -> Result<RecordDetail, Box<CustomError>>;
+    async fn read_total(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: SearchParams,
+    ) -> Result<RecordTotal, Box<CustomError>>;
+    async fn read_summary(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: SearchParams,
+    ) -> Result<RecordSummary, Box<CustomError>>;
+    async fn read_calendar(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: CalendarParams,
+    ) -> Result<Vec<DailyTotal>, Box<CustomError>>;
 }
 
 impl<T> ReadRecordUsecaseImpl<T>
@@ -44,13 +71,44 @@ where
         user_id: i32,
         book_id: i32,
         params: SearchParams,
-    ) -> Result<Vec<Record>, Box<CustomError>> {
+    ) -> Result<RecordPage, Box<CustomError>> {
         read_records(&self.repository, user_id, book_id, params).await
     }
 
-    async fn read_record(&self, user_id: i32, record_id: i64) -> Result<Record, Box<CustomError>> {
+    async fn read_record(
+        &self,
+        user_id: i32,
+        record_id: i64,
+    ) -> Result<RecordDetail, Box<CustomError>> {
         read_record(&self.repository, user_id, record_id).await
     }
+
+    async fn read_total(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: SearchParams,
+    ) -> Result<RecordTotal, Box<CustomError>> {
+        read_total(&self.repository, user_id, book_id, params).await
+    }
+
+    async fn read_summary(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: SearchParams,
+    ) -> Result<RecordSummary, Box<CustomError>> {
+        read_summary(&self.repository, user_id, book_id, params).await
+    }
+
+    async fn read_calendar(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        params: CalendarParams,
+    ) -> Result<Vec<DailyTotal>, Box<CustomError>> {
+        read_calendar(&self.repository, user_id, book_id, params).await
+    }
 }
 
 async fn read_records<T>(
@@ -58,25 +116,93 @@ async fn read_records<T>(
     user_id: i32,
     book_id: i32,
     params: SearchParams,
-) -> Result<Vec<Record>, Box<CustomError>>
+) -> Result<RecordPage, Box<CustomError>>
 where
     T: GetRecordRepo,
 {
     // params 처리
-    repository
-        .get_list(user_id, book_id, params.to_query())
-        .await
+    let search_query = params.to_query()?;
+    let sort = search_query.get_sort();
+    let limit = search_query.get_limit() as usize;
+
+    let mut records = repository.get_list(user_id, book_id, search_query).await?;
+
+    // limit보다 많이 조회되면 다음 페이지 존재
+    let next_cursor = if records.len() > limit {
+        records.truncate(limit);
+        records
+            .last()
+            .and_then(|record| sort.to_cursor(record))
+            .map(|cursor| cursor.encode())
+    } else {
+        None
+    };
+
+    Ok(RecordPage::new(records, next_cursor))
 }
 
 async fn read_record<T>(
     repository: &T,
     user_id: i32,
     record_id: i64,
-) -> Result<Record, Box<CustomError>>
+) -> Result<RecordDetail, Box<CustomError>>
 where
     T: GetRecordRepo,
 {
-    repository.get_by_id(user_id, record_id).await
+    let record = repository.get_by_id(user_id, record_id).await?;
+    let lines = repository.get_lines(record_id).await?;
+
+    Ok(RecordDetail::new(record, lines))
+}
+
+async fn read_total<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    params: SearchParams,
+) -> Result<RecordTotal, Box<CustomError>>
+where
+    T: GetRecordRepo,
+{
+    repository
+        .get_total(user_id, book_id, params.to_query()?)
+        .await
+}
+
+async fn read_summary<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    params: SearchParams,
+) -> Result<RecordSummary, Box<CustomError>>
+where
+    T: GetRecordRepo,
+{
+    let rows = repository
+        .get_summary(user_id, book_id, params.to_query()?)
+        .await?;
+
+    Ok(RecordSummary::new(rows))
+}
+
+async fn read_calendar<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    params: CalendarParams,
+) -> Result<Vec<DailyTotal>, Box<CustomError>>
+where
+    T: GetRecordRepo,
+{
+    let (start_dt, end_dt) = params
+        .get_range()
+        .ok_or(Box::new(CustomError::ValidationError(
+            "RecordMonth".to_string(),
+        )))?;
+
+    repository
+        .get_daily_total(user_id, book_id, start_dt, end_dt)
+        .await
 }
 
 #[cfg(test)]
@@ -87,10 +213,10 @@ mod tests {
 
     use crate::{
         domain::record::{
-            dto::request::SearchParams,
-            entity::{Record, Search},
+            dto::request::{CalendarParams, SearchParams},
+            entity::{CategorySum, DailyTotal, Record, RecordLine, RecordTotal, Search},
             repository::get_record::GetRecordRepo,
-            usecase::read::{read_record, read_records},
+            usecase::read::{read_calendar, read_record, read_records, read_summary, read_total},
         },
         global::errors::CustomError,
     };
@@ -102,6 +228,10 @@ mod tests {
         impl GetRecordRepo for GetRecordRepoImpl {
             async fn get_list(&self, user_id: i32, book_id: i32, search_query: Search) -> Result<Vec<Record>, Box<CustomError>>;
             async fn get_by_id(&self, user_id: i32, record_id: i64) -> Result<Record, Box<CustomError>>;
+            async fn get_lines(&self, record_id: i64) -> Result<Vec<RecordLine>, Box<CustomError>>;
+            async fn get_total(&self, user_id: i32, book_id: i32, search_query: Search) -> Result<RecordTotal, Box<CustomError>>;
+            async fn get_summary(&self, user_id: i32, book_id: i32, search_query: Search) -> Result<Vec<CategorySum>, Box<CustomError>>;
+            async fn get_daily_total(&self, user_id: i32, book_id: i32, start_dt: NaiveDate, end_dt: NaiveDate) -> Result<Vec<DailyTotal>, Box<CustomError>>;
         }
     }
 
@@ -119,7 +249,7 @@ mod tests {
             .with(
                 predicate::eq(user_id),
                 predicate::eq(book_id),
-                predicate::eq(params.to_query()),
+                predicate::eq(params.to_query().unwrap()),
             )
             .returning(|_, _, _| {
                 Ok(vec![
@@ -163,7 +293,87 @@ mod tests {
         let result = result.unwrap();
 
         // Assert
-        assert_eq!(result.len(), 3);
+        assert_eq!(result.get_records().len(), 3);
+        assert_eq!(result.get_next_cursor(), &None);
+    }
+
+    #[tokio::test]
+    async fn check_next_cursor() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let params = SearchParams::new(start_dt, "M".to_string(), None, None)
+            .sort(Some("amount".to_string()), Some("desc".to_string()))
+            .limit(Some(2));
+
+        let mut mock_repo = MockGetRecordRepoImpl::new();
+        mock_repo
+            .expect_get_list()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(params.to_query().unwrap()),
+            )
+            .returning(|_, _, _| {
+                let target_dt =
+                    NaiveDateTime::parse_from_str("2024-09-08 15:30:27", "%Y-%m-%d %H:%M:%S")
+                        .unwrap();
+                Ok(vec![
+                    Record::new(1, 18, 30000, target_dt, None)
+                        .id(Some(3))
+                        .build(),
+                    Record::new(1, 18, 20000, target_dt, None)
+                        .id(Some(2))
+                        .build(),
+                    Record::new(1, 18, 10000, target_dt, None)
+                        .id(Some(1))
+                        .build(),
+                ])
+            });
+
+        // Act
+        let result = read_records(&mock_repo, user_id, book_id, params).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.get_records().len(), 2);
+        assert_eq!(result.get_next_cursor(), &Some("20000_2".to_string()));
+    }
+
+    #[tokio::test]
+    async fn check_next_cursor_without_created_at() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let params = SearchParams::new(start_dt, "M".to_string(), None, None)
+            .sort(Some("created_at".to_string()), Some("desc".to_string()))
+            .limit(Some(1));
+
+        let mut mock_repo = MockGetRecordRepoImpl::new();
+        mock_repo.expect_get_list().returning(|_, _, _| {
+            let target_dt =
+                NaiveDateTime::parse_from_str("2024-09-08 15:30:27", "%Y-%m-%d %H:%M:%S").unwrap();
+            Ok(vec![
+                Record::new(1, 18, 30000, target_dt, None)
+                    .id(Some(2))
+                    .build(),
+                Record::new(1, 18, 20000, target_dt, None)
+                    .id(Some(1))
+                    .build(),
+            ])
+        });
+
+        // Act
+        let result = read_records(&mock_repo, user_id, book_id, params).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.get_records().len(), 1);
+        assert_eq!(result.get_next_cursor(), &None);
     }
 
     #[tokio::test]
@@ -188,6 +398,10 @@ mod tests {
                 .id(Some(i))
                 .build())
             });
+        mock_repo
+            .expect_get_lines()
+            .with(predicate::eq(record_id))
+            .returning(|_| Ok(vec![RecordLine::new(17, 10000), RecordLine::new(18, 5200)]));
 
         // Act
         let result = read_record(&mock_repo, user_id, record_id).await;
@@ -195,6 +409,253 @@ mod tests {
         let result = result.unwrap();
 
         // Assert
-        assert_eq!(result.get_id(), record_id);
+        assert_eq!(result.get_record().get_id(), record_id);
+        assert_eq!(result.get_lines().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_invalid_period() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let params = SearchParams::new(start_dt, "K".to_string(), None, None);
+
+        let mut mock_repo = MockGetRecordRepoImpl::new();
+        mock_repo.expect_get_list().never();
+
+        // Act
+        let result = read_records(&mock_repo, user_id, book_id, params).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_period_range() {
+        // Arrange
+        let date = |dt: &str| NaiveDate::parse_from_str(dt, "%Y-%m-%d").unwrap();
+        let start_dt = date("2024-08-14"); // 수요일
+        let cases = vec![
+            (
+                SearchParams::new(start_dt, "W".to_string(), None, None),
+                date("2024-08-12"),
+                date("2024-08-19"),
+            ),
+            (
+                SearchParams::new(start_dt, "W".to_string(), None, None)
+                    .week_start(Some("sun".to_string())),
+                date("2024-08-11"),
+                date("2024-08-18"),
+            ),
+            (
+                SearchParams::new(start_dt, "Q".to_string(), None, None),
+                date("2024-07-01"),
+                date("2024-10-01"),
+            ),
+            (
+                SearchParams::new(start_dt, "Y".to_string(), None, None),
+                date("2024-01-01"),
+                date("2025-01-01"),
+            ),
+            (
+                SearchParams::new(start_dt, "".to_string(), None, None)
+                    .end_dt(Some(date("2024-09-03"))),
+                date("2024-08-14"),
+                date("2024-09-04"),
+            ),
+        ];
+
+        for (params, expected_start, expected_end) in cases {
+            // Act
+            let result = params.to_query();
+            assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+            let result = result.unwrap();
+
+            // Assert
+            assert_eq!(result.get_start_dt(), &expected_start);
+            assert_eq!(result.get_end_dt(), &expected_end);
+        }
+    }
+
+    #[tokio::test]
+    async fn check_invalid_range() {
+        // Arrange
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-08-01", "%Y-%m-%d").unwrap();
+        let reversed =
+            SearchParams::new(start_dt, "M".to_string(), None, None).end_dt(Some(end_dt));
+        let wrong_weekday = SearchParams::new(start_dt, "W".to_string(), None, None)
+            .week_start(Some("someday".to_string()));
+
+        // Act, Assert
+        assert!(reversed.to_query().is_err());
+        assert!(wrong_weekday.to_query().is_err());
+    }
+
+    #[tokio::test]
+    async fn check_invalid_amount_range() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let params = SearchParams::new(start_dt, "M".to_string(), None, None)
+            .amount_range(Some(20000), Some(10000));
+
+        let mut mock_repo = MockGetRecordRepoImpl::new();
+        mock_repo.expect_get_list().never();
+
+        // Act
+        let result = read_records(&mock_repo, user_id, book_id, params).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_read_total_success() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let params = SearchParams::new(start_dt, "M".to_string(), None, None);
+
+        let mut mock_repo = MockGetRecordRepoImpl::new();
+        mock_repo
+            .expect_get_total()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(params.to_query().unwrap()),
+            )
+            .returning(|_, _, _| Ok(RecordTotal::new(20000, 15000)));
+
+        // Act
+        let result = read_total(&mock_repo, user_id, book_id, params).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.get_income(), 20000);
+        assert_eq!(result.get_expense(), 15000);
+    }
+
+    #[tokio::test]
+    async fn check_read_summary_success() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt = NaiveDate::parse_from_str("2024-09-01", "%Y-%m-%d").unwrap();
+        let params = SearchParams::new(start_dt, "M".to_string(), None, None);
+
+        let mut mock_repo = MockGetRecordRepoImpl::new();
+        mock_repo
+            .expect_get_summary()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(params.to_query().unwrap()),
+            )
+            .returning(|_, _, _| {
+                Ok(vec![
+                    CategorySum::new(9, 17, false, 20000).base(
+                        "식비".to_string(),
+                        "FF0000".to_string(),
+                        35000,
+                    ),
+                    CategorySum::new(9, 18, false, 15000).base(
+                        "식비".to_string(),
+                        "FF0000".to_string(),
+                        35000,
+                    ),
+                    CategorySum::new(8, 16, false, 10000).base(
+                        "고정비".to_string(),
+                        "00FF00".to_string(),
+                        10000,
+                    ),
+                    CategorySum::new(2, 9, true, 3000000).base(
+                        "급여".to_string(),
+                        "0000FF".to_string(),
+                        3000000,
+                    ),
+                ])
+            });
+
+        // Act
+        let result = read_summary(&mock_repo, user_id, book_id, params).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.get_income().len(), 1);
+        assert_eq!(result.get_expense().len(), 2);
+        assert_eq!(result.get_expense()[0].get_amount(), 35000);
+        assert_eq!(result.get_expense()[0].get_sub_categories().len(), 2);
+        assert_eq!(result.get_expense()[1].get_base_id(), 8);
+    }
+
+    #[tokio::test]
+    async fn check_read_calendar_success() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let params = CalendarParams::new(2024, 2);
+        let start_dt = NaiveDate::parse_from_str("2024-02-01", "%Y-%m-%d").unwrap();
+        let end_dt = NaiveDate::parse_from_str("2024-03-01", "%Y-%m-%d").unwrap();
+
+        let mut mock_repo = MockGetRecordRepoImpl::new();
+        mock_repo
+            .expect_get_daily_total()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(book_id),
+                predicate::eq(start_dt),
+                predicate::eq(end_dt),
+            )
+            .returning(|_, _, s, e| {
+                Ok(s.iter_days()
+                    .take_while(|day| day < &e)
+                    .map(|day| DailyTotal::new(day, 0, 0, 0))
+                    .collect())
+            });
+
+        // Act
+        let result = read_calendar(&mock_repo, user_id, book_id, params).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 29);
+    }
+
+    #[tokio::test]
+    async fn check_invalid_month() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let params = CalendarParams::new(2024, 13);
+
+        let mut mock_repo = MockGetRecordRepoImpl::new();
+        mock_repo.expect_get_daily_total().never();
+
+        // Act
+        let result = read_calendar(&mock_repo, user_id, book_id, params).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
     }
 }
@@ -55,6 +55,12 @@ async fn update_record<T>(
 where
     T: UpdateRecordRepo,
 {
+    if !edit_record.is_valid_lines() {
+        return Err(Box::new(CustomError::ValidationError(
+            "RecordLine".to_string(),
+        )));
+    }
+
     repository
         .update_record(user_id, record_id, edit_record.to_update())
         .await
@@ -67,7 +73,9 @@ mod tests {
 
     use crate::{
         domain::record::{
-            dto::request::EditRecord, entity::UpdateRecord, repository::update::UpdateRecordRepo,
+            dto::request::EditRecord,
+            entity::{RecordLine, UpdateRecord},
+            repository::update::UpdateRecordRepo,
         },
         global::errors::CustomError,
     };
@@ -107,4 +115,30 @@ mod tests {
         // Assert
         assert!(result.map_err(|e| println!("{:?}", e)).is_ok())
     }
+
+    #[tokio::test]
+    async fn check_split_amount_mismatch() {
+        // Arrange
+        let edit_record = EditRecord::new(None, Some(15000), None, None, None).lines(Some(vec![
+            RecordLine::new(17, 10000),
+            RecordLine::new(18, 4000),
+        ]));
+        let user_id = 1;
+
+        let record_id = 1i64;
+
+        let mut mock_repo = MockUpdateRecordRepoImpl::new();
+        mock_repo.expect_update_record().never();
+
+        // Act
+        let result = update_record(&mock_repo, user_id, record_id, edit_record).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
 }
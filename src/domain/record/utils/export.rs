@@ -0,0 +1,237 @@
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use tokio::sync::mpsc::{self, Receiver};
+
+use crate::{domain::record::entity::ExportRow, global::errors::CustomError};
+
+const HEADERS: [&str; 8] = [
+    "날짜",
+    "구분",
+    "대분류",
+    "소분류",
+    "금액",
+    "메모",
+    "자산",
+    "커넥트",
+];
+// 응답으로 보내는 조각 크기
+const CHUNK_SIZE: usize = 64 * 1024;
+const CHUNK_BUFFER_SIZE: usize = 16;
+
+fn to_fields(row: &ExportRow) -> [String; 8] {
+    [
+        row.get_target_dt().format("%Y-%m-%d %H:%M:%S").to_string(),
+        row.get_kind().to_string(),
+        row.get_base_name().to_string(),
+        row.get_sub_name().to_string(),
+        row.get_amount().to_string(),
+        row.get_memo().to_string(),
+        row.get_asset_name().to_string(),
+        row.get_connect_names().to_string(),
+    ]
+}
+
+fn to_csv_error(e: csv::Error) -> Box<CustomError> {
+    tracing::error!("Error(ExportCsv): {:?}", e);
+    Box::new(CustomError::Unexpected(e.into()))
+}
+
+fn to_xlsx_error(e: XlsxError) -> Box<CustomError> {
+    tracing::error!("Error(ExportXlsx): {:?}", e);
+    Box::new(CustomError::Unexpected(e.into()))
+}
+
+// 한 행을 CSV로 변환 (따옴표, 구분자 처리)
+fn to_csv_line(fields: &[String]) -> Result<Vec<u8>, Box<CustomError>> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(fields).map_err(to_csv_error)?;
+    writer
+        .into_inner()
+        .map_err(|e| to_csv_error(e.into_error().into()))
+}
+
+/// 행을 받는 대로 CSV로 변환해 조각 단위로 전달
+pub fn stream_csv(
+    mut rows: Receiver<Result<ExportRow, Box<CustomError>>>,
+) -> Receiver<Result<Vec<u8>, Box<CustomError>>> {
+    let (sender, receiver) = mpsc::channel(CHUNK_BUFFER_SIZE);
+
+    tokio::spawn(async move {
+        // 엑셀에서 한글이 깨지지 않도록 BOM 추가
+        let mut buffer = "\u{feff}".as_bytes().to_vec();
+        let headers = HEADERS.map(|header| header.to_string());
+
+        let mut line = to_csv_line(&headers);
+        loop {
+            match line {
+                Ok(line) => buffer.extend(line),
+                Err(e) => {
+                    let _ = sender.send(Err(e)).await;
+                    return;
+                }
+            }
+            if buffer.len() >= CHUNK_SIZE && sender.send(Ok(buffer.split_off(0))).await.is_err() {
+                return;
+            }
+
+            line = match rows.recv().await {
+                Some(row) => row.and_then(|row| to_csv_line(&to_fields(&row))),
+                None => break,
+            };
+        }
+
+        if !buffer.is_empty() {
+            let _ = sender.send(Ok(buffer)).await;
+        }
+    });
+
+    receiver
+}
+
+fn write_xlsx(
+    rows: &mut Receiver<Result<ExportRow, Box<CustomError>>>,
+) -> Result<Vec<u8>, Box<CustomError>> {
+    let mut workbook = Workbook::new();
+    let datetime_format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+    let amount_format = Format::new().set_num_format("#,##0");
+
+    // 작성한 행은 임시 파일로 내려 메모리 사용량을 일정하게 유지
+    let worksheet = workbook.add_worksheet_with_constant_memory();
+    worksheet.set_name("기록").map_err(to_xlsx_error)?;
+    for (col, header) in HEADERS.iter().enumerate() {
+        worksheet
+            .write_string(0, col as u16, *header)
+            .map_err(to_xlsx_error)?;
+    }
+
+    let mut row_num = 1;
+    while let Some(row) = rows.blocking_recv() {
+        let row = row?;
+        worksheet
+            .write_datetime_with_format(row_num, 0, row.get_target_dt(), &datetime_format)
+            .map_err(to_xlsx_error)?;
+        worksheet
+            .write_string(row_num, 1, row.get_kind())
+            .map_err(to_xlsx_error)?;
+        worksheet
+            .write_string(row_num, 2, row.get_base_name())
+            .map_err(to_xlsx_error)?;
+        worksheet
+            .write_string(row_num, 3, row.get_sub_name())
+            .map_err(to_xlsx_error)?;
+        worksheet
+            .write_number_with_format(row_num, 4, row.get_amount(), &amount_format)
+            .map_err(to_xlsx_error)?;
+        worksheet
+            .write_string(row_num, 5, row.get_memo())
+            .map_err(to_xlsx_error)?;
+        worksheet
+            .write_string(row_num, 6, row.get_asset_name())
+            .map_err(to_xlsx_error)?;
+        worksheet
+            .write_string(row_num, 7, row.get_connect_names())
+            .map_err(to_xlsx_error)?;
+        row_num += 1;
+    }
+
+    workbook.save_to_buffer().map_err(to_xlsx_error)
+}
+
+/// XLSX는 압축 파일이라 다 작성한 뒤 조각 단위로 전달
+pub fn stream_xlsx(
+    mut rows: Receiver<Result<ExportRow, Box<CustomError>>>,
+) -> Receiver<Result<Vec<u8>, Box<CustomError>>> {
+    let (sender, receiver) = mpsc::channel(CHUNK_BUFFER_SIZE);
+
+    tokio::task::spawn_blocking(move || match write_xlsx(&mut rows) {
+        Ok(buffer) => {
+            for chunk in buffer.chunks(CHUNK_SIZE) {
+                if sender.blocking_send(Ok(chunk.to_vec())).is_err() {
+                    return;
+                }
+            }
+        }
+        Err(e) => {
+            let _ = sender.blocking_send(Err(e));
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use tokio::sync::mpsc::{self, Receiver};
+
+    use crate::{
+        domain::record::{
+            entity::ExportRow,
+            utils::export::{stream_csv, stream_xlsx},
+        },
+        global::errors::CustomError,
+    };
+
+    fn _rows() -> Receiver<Result<ExportRow, Box<CustomError>>> {
+        let (sender, receiver) = mpsc::channel(4);
+        let row = ExportRow::new(
+            NaiveDateTime::parse_from_str("2024-09-08 18:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            false,
+            "변동지출".to_string(),
+            "식비".to_string(),
+            15000,
+        )
+        .memo(Some("감자탕, 소주".to_string()))
+        .asset_name(Some("월급통장".to_string()))
+        .connect_names(Some("테스트 커넥트".to_string()));
+        sender.try_send(Ok(row)).unwrap();
+        receiver
+    }
+
+    async fn _collect(mut chunks: Receiver<Result<Vec<u8>, Box<CustomError>>>) -> Vec<u8> {
+        let mut body = vec![];
+        while let Some(chunk) = chunks.recv().await {
+            body.extend(chunk.unwrap());
+        }
+        body
+    }
+
+    #[tokio::test]
+    async fn check_stream_csv() {
+        // Arrange, Act
+        let body = _collect(stream_csv(_rows())).await;
+        let body = String::from_utf8(body).unwrap();
+
+        // Assert
+        let lines: Vec<&str> = body.trim_start_matches('\u{feff}').lines().collect();
+        assert_eq!(lines[0], "날짜,구분,대분류,소분류,금액,메모,자산,커넥트");
+        assert_eq!(
+            lines[1],
+            "2024-09-08 18:30:00,지출,변동지출,식비,15000,\"감자탕, 소주\",월급통장,테스트 커넥트"
+        );
+    }
+
+    #[tokio::test]
+    async fn check_stream_xlsx() {
+        // Arrange, Act
+        let body = _collect(stream_xlsx(_rows())).await;
+
+        // Assert
+        // xlsx는 zip 파일
+        assert!(body.starts_with(b"PK"));
+    }
+
+    #[tokio::test]
+    async fn check_stream_error() {
+        // Arrange
+        let (sender, receiver) = mpsc::channel(1);
+        sender
+            .try_send(Err(Box::new(CustomError::NotFound("Record".to_string()))))
+            .unwrap();
+
+        // Act
+        let mut chunks = stream_xlsx(receiver);
+
+        // Assert
+        assert!(chunks.recv().await.unwrap().is_err());
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::{
+    domain::record::{
+        dto::{
+            request::ColumnMapping,
+            response::{ImportError, ImportedRow},
+        },
+        entity::ImportCategory,
+    },
+    global::errors::CustomError,
+};
+
+// 한 번에 가져올 수 있는 최대 행 수
+pub const MAX_IMPORT_ROWS: usize = 1000;
+// tb_record.memo VARCHAR(32)
+const MAX_MEMO_LEN: usize = 32;
+
+const DATETIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y.%m.%d %H:%M",
+];
+const DATE_FORMATS: [&str; 3] = ["%Y-%m-%d", "%Y.%m.%d", "%Y/%m/%d"];
+
+/// CSV 한 행의 원본 값
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvRow {
+    row: u64,
+    target_dt: String,
+    amount: String,
+    memo: Option<String>,
+    category: String,
+}
+
+fn find_column(headers: &csv::StringRecord, name: &str) -> Result<usize, Box<CustomError>> {
+    headers
+        .iter()
+        .position(|header| header.trim() == name.trim())
+        .ok_or(Box::new(CustomError::ValidationError(
+            "ImportColumn".to_string(),
+        )))
+}
+
+/// 헤더 기준으로 매핑된 열만 읽음
+pub fn read_rows(content: &str, mapping: &ColumnMapping) -> Result<Vec<CsvRow>, Box<CustomError>> {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.as_bytes());
+
+    let invalid_file = || Box::new(CustomError::ValidationError("ImportFile".to_string()));
+
+    let headers = reader.headers().map_err(|_| invalid_file())?.clone();
+    let target_dt_idx = find_column(&headers, mapping.get_target_dt())?;
+    let amount_idx = find_column(&headers, mapping.get_amount())?;
+    let category_idx = find_column(&headers, mapping.get_category())?;
+    let memo_idx = match mapping.get_memo() {
+        Some(memo) => Some(find_column(&headers, memo)?),
+        None => None,
+    };
+
+    let mut rows = vec![];
+    for record in reader.records() {
+        let record = record.map_err(|_| invalid_file())?;
+        // 값이 모두 빈 행은 건너뜀
+        if record.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        if rows.len() == MAX_IMPORT_ROWS {
+            return Err(Box::new(CustomError::ValidationError(
+                "ImportRows".to_string(),
+            )));
+        }
+
+        let cell = |idx: usize| record.get(idx).unwrap_or("").trim().to_string();
+        rows.push(CsvRow {
+            row: record.position().map_or(0, |pos| pos.line()),
+            target_dt: cell(target_dt_idx),
+            amount: cell(amount_idx),
+            memo: memo_idx.map(cell).filter(|memo| !memo.is_empty()),
+            category: cell(category_idx),
+        });
+    }
+
+    if rows.is_empty() {
+        return Err(invalid_file());
+    }
+
+    Ok(rows)
+}
+
+pub fn parse_target_dt(value: &str) -> Option<NaiveDateTime> {
+    DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+}
+
+// 천 단위 구분자, 원 표시 허용
+pub fn parse_amount(value: &str) -> Option<i32> {
+    let amount: String = value
+        .trim_end_matches('원')
+        .chars()
+        .filter(|c| *c != ',')
+        .collect();
+    amount
+        .trim()
+        .parse::<i32>()
+        .ok()
+        .filter(|amount| *amount > 0)
+}
+
+/// 소분류 이름 또는 "대분류/소분류"로 찾음, 같은 이름이 여러 개면 None
+pub struct CategoryResolver {
+    names: HashMap<String, Vec<i32>>,
+}
+
+impl CategoryResolver {
+    pub fn new(categories: &[ImportCategory]) -> Self {
+        let mut names: HashMap<String, Vec<i32>> = HashMap::new();
+        for category in categories {
+            names
+                .entry(category.get_name().to_string())
+                .or_default()
+                .push(category.get_id());
+            names
+                .entry(format!(
+                    "{}/{}",
+                    category.get_base_name(),
+                    category.get_name()
+                ))
+                .or_default()
+                .push(category.get_id());
+        }
+        Self { names }
+    }
+
+    pub fn resolve(&self, name: &str) -> Result<i32, &'static str> {
+        match self.names.get(name).map(|ids| ids.as_slice()) {
+            Some([id]) => Ok(*id),
+            Some([_, ..]) => Err("Ambiguous"),
+            _ => Err("NotFound"),
+        }
+    }
+}
+
+/// 행마다 값을 검증, 오류가 없는 행만 반환
+pub fn validate_rows(
+    rows: Vec<CsvRow>,
+    resolver: &CategoryResolver,
+) -> (Vec<ImportedRow>, Vec<ImportError>) {
+    let mut imported = vec![];
+    let mut errors = vec![];
+
+    for row in rows {
+        let target_dt = parse_target_dt(&row.target_dt);
+        if target_dt.is_none() {
+            errors.push(ImportError::new(row.row, "target_dt", "Invalid"));
+        }
+        let amount = parse_amount(&row.amount);
+        if amount.is_none() {
+            errors.push(ImportError::new(row.row, "amount", "Invalid"));
+        }
+        let is_valid_memo = row
+            .memo
+            .as_ref()
+            .is_none_or(|memo| memo.chars().count() <= MAX_MEMO_LEN);
+        if !is_valid_memo {
+            errors.push(ImportError::new(row.row, "memo", "TooLong"));
+        }
+        let sub_category_id = if row.category.is_empty() {
+            errors.push(ImportError::new(row.row, "category", "Required"));
+            None
+        } else {
+            resolver
+                .resolve(&row.category)
+                .map_err(|reason| errors.push(ImportError::new(row.row, "category", reason)))
+                .ok()
+        };
+
+        if let (Some(target_dt), Some(amount), Some(sub_category_id), true) =
+            (target_dt, amount, sub_category_id, is_valid_memo)
+        {
+            imported.push(ImportedRow::new(
+                row.row,
+                target_dt,
+                amount,
+                row.memo,
+                row.category,
+                sub_category_id,
+            ));
+        }
+    }
+
+    (imported, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        domain::record::{
+            dto::request::ColumnMapping,
+            entity::ImportCategory,
+            utils::import::{
+                parse_amount, parse_target_dt, read_rows, validate_rows, CategoryResolver,
+            },
+        },
+        global::errors::CustomError,
+    };
+
+    fn _mapping() -> ColumnMapping {
+        ColumnMapping::new(
+            "날짜".to_string(),
+            "금액".to_string(),
+            Some("내용".to_string()),
+            "분류".to_string(),
+        )
+    }
+
+    fn _categories() -> Vec<ImportCategory> {
+        // ref) init.sql
+        vec![
+            ImportCategory::new(11, "수입".to_string(), "기타".to_string()),
+            ImportCategory::new(17, "변동지출".to_string(), "식비".to_string()),
+            ImportCategory::new(26, "변동지출".to_string(), "기타".to_string()),
+        ]
+    }
+
+    #[test]
+    fn check_read_rows_success() {
+        // Arrange
+        let content = "\u{feff}분류,날짜,금액,내용\n식비,2024-09-01,\"15,000\",감자탕\n\n기타,2024-09-02,3000,\n";
+
+        // Act
+        let result = read_rows(content, &_mapping());
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].row, 2);
+        assert_eq!(result[0].amount, "15,000");
+        // 빈 줄은 행 번호에 포함되지 않음
+        assert_eq!(result[1].row, 3);
+        assert_eq!(result[1].memo, None);
+    }
+
+    #[test]
+    fn check_missing_column() {
+        // Arrange
+        let content = "날짜,금액\n2024-09-01,15000\n";
+
+        // Act
+        let result = read_rows(content, &_mapping());
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[test]
+    fn check_parse_values() {
+        assert_eq!(
+            parse_target_dt("2024.09.01"),
+            NaiveDateTime::parse_from_str("2024-09-01 00:00:00", "%Y-%m-%d %H:%M:%S").ok()
+        );
+        assert_eq!(
+            parse_target_dt("2024-09-01 18:30"),
+            NaiveDateTime::parse_from_str("2024-09-01 18:30:00", "%Y-%m-%d %H:%M:%S").ok()
+        );
+        assert_eq!(parse_target_dt("09/01"), None);
+        assert_eq!(parse_amount("15,000원"), Some(15000));
+        assert_eq!(parse_amount("-3000"), None);
+    }
+
+    #[test]
+    fn check_validate_rows() {
+        // Arrange
+        let content = "분류,날짜,금액,내용\n식비,2024-09-01,15000,감자탕\n기타,2024-09-02,3000,\n변동지출/기타,2024-09-03,abc,\n없는분류,2024-13-01,1000,\n";
+        let rows = read_rows(content, &_mapping()).unwrap();
+        let resolver = CategoryResolver::new(&_categories());
+
+        // Act
+        let (imported, errors) = validate_rows(rows, &resolver);
+
+        // Assert
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].get_sub_category_id(), 17);
+        // 기타: 수입/지출에 모두 있음
+        assert_eq!(errors[0].get_row(), 3);
+        assert_eq!(errors[0].get_reason(), "Ambiguous");
+        assert_eq!(errors[1].get_field(), "amount");
+        assert_eq!(errors.len(), 4);
+    }
+}
@@ -0,0 +1,145 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::recurring::entity::{Cycle, Recurring, UpdateRecurring},
+    global::constants::FieldUpdate,
+};
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct NewRecurring {
+    book_id: i32,
+    sub_category_id: i32,
+    amount: i32,
+    memo: Option<String>,
+    asset_id: Option<i32>,
+    cycle: Cycle,
+    cycle_interval: Option<i16>,
+    start_dt: NaiveDateTime,
+    end_dt: Option<NaiveDateTime>,
+}
+
+impl NewRecurring {
+    pub fn new(
+        book_id: i32,
+        sub_category_id: i32,
+        amount: i32,
+        cycle: Cycle,
+        start_dt: NaiveDateTime,
+    ) -> Self {
+        Self {
+            book_id,
+            sub_category_id,
+            amount,
+            memo: None,
+            asset_id: None,
+            cycle,
+            cycle_interval: None,
+            start_dt,
+            end_dt: None,
+        }
+    }
+
+    pub fn memo(mut self, memo: Option<String>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    pub fn asset_id(mut self, asset_id: Option<i32>) -> Self {
+        self.asset_id = asset_id;
+        self
+    }
+
+    pub fn cycle_interval(mut self, cycle_interval: Option<i16>) -> Self {
+        self.cycle_interval = cycle_interval;
+        self
+    }
+
+    pub fn end_dt(mut self, end_dt: Option<NaiveDateTime>) -> Self {
+        self.end_dt = end_dt;
+        self
+    }
+
+    pub fn is_valid(&self) -> bool {
+        let interval = self.cycle_interval.unwrap_or(1);
+        let period = match self.end_dt {
+            Some(end_dt) => self.start_dt <= end_dt,
+            None => true,
+        };
+        interval >= 1 && period
+    }
+
+    pub fn to_entity(&self) -> Recurring {
+        Recurring::new(
+            self.book_id,
+            self.sub_category_id,
+            self.amount,
+            self.cycle,
+            self.start_dt,
+        )
+        .memo(self.memo.clone())
+        .asset_id(self.asset_id)
+        .cycle_interval(self.cycle_interval.unwrap_or(1))
+        .end_dt(self.end_dt)
+        .build()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct EditRecurring {
+    sub_category_id: Option<i32>,
+    amount: Option<i32>,
+    memo: Option<String>,
+    asset_id: Option<i32>,
+    end_dt: Option<NaiveDateTime>,
+    is_paused: Option<bool>,
+}
+
+impl EditRecurring {
+    pub fn new(
+        sub_category_id: Option<i32>,
+        amount: Option<i32>,
+        memo: Option<String>,
+        asset_id: Option<i32>,
+        end_dt: Option<NaiveDateTime>,
+        is_paused: Option<bool>,
+    ) -> Self {
+        Self {
+            sub_category_id,
+            amount,
+            memo,
+            asset_id,
+            end_dt,
+            is_paused,
+        }
+    }
+
+    pub fn to_update(self) -> UpdateRecurring {
+        let sub_category_id = match self.sub_category_id {
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        let amount = match self.amount {
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        let memo = match &self.memo {
+            Some(v) if v == "NULL" => FieldUpdate::SetNone,
+            Some(v) => FieldUpdate::Set(v.to_string()),
+            None => FieldUpdate::NoChange,
+        };
+        let asset_id = match self.asset_id {
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        let end_dt = match self.end_dt {
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        let is_paused = match self.is_paused {
+            Some(v) => FieldUpdate::Set(v),
+            None => FieldUpdate::NoChange,
+        };
+        UpdateRecurring::new(sub_category_id, amount, memo, asset_id, end_dt, is_paused)
+    }
+}
@@ -0,0 +1,223 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{Days, Months, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::global::{constants::FieldUpdate, errors::CustomError};
+
+// 한 번의 실행에서 규칙 하나당 생성할 최대 회차 (밀린 회차 따라잡기)
+pub const MAX_CATCH_UP: i32 = 100;
+
+#[derive(Deserialize, Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Cycle {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl FromStr for Cycle {
+    type Err = Box<CustomError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "daily" => Ok(Cycle::Daily),
+            "weekly" => Ok(Cycle::Weekly),
+            "monthly" => Ok(Cycle::Monthly),
+            "yearly" => Ok(Cycle::Yearly),
+            _ => Err(Box::new(CustomError::ValidationError("Cycle".to_string()))),
+        }
+    }
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cycle = match self {
+            Cycle::Daily => "daily",
+            Cycle::Weekly => "weekly",
+            Cycle::Monthly => "monthly",
+            Cycle::Yearly => "yearly",
+        };
+        write!(f, "{}", cycle)
+    }
+}
+
+#[derive(Deserialize, Debug, sqlx::FromRow, Serialize, Clone, PartialEq)]
+pub struct Recurring {
+    id: Option<i32>,
+    book_id: i32,
+    sub_category_id: i32,
+    amount: i32,
+    memo: Option<String>,
+    asset_id: Option<i32>,
+    cycle: String,
+    cycle_interval: i16,
+    start_dt: NaiveDateTime,
+    end_dt: Option<NaiveDateTime>,
+    next_seq: i32,
+    next_dt: NaiveDateTime,
+    is_paused: bool,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+impl Recurring {
+    pub fn new(
+        book_id: i32,
+        sub_category_id: i32,
+        amount: i32,
+        cycle: Cycle,
+        start_dt: NaiveDateTime,
+    ) -> Self {
+        Self {
+            id: None,
+            book_id,
+            sub_category_id,
+            amount,
+            memo: None,
+            asset_id: None,
+            cycle: cycle.to_string(),
+            cycle_interval: 1,
+            start_dt,
+            end_dt: None,
+            next_seq: 0,
+            next_dt: start_dt,
+            is_paused: false,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn memo(mut self, memo: Option<String>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    pub fn asset_id(mut self, asset_id: Option<i32>) -> Self {
+        self.asset_id = asset_id;
+        self
+    }
+
+    pub fn cycle_interval(mut self, cycle_interval: i16) -> Self {
+        self.cycle_interval = cycle_interval;
+        self
+    }
+
+    pub fn end_dt(mut self, end_dt: Option<NaiveDateTime>) -> Self {
+        self.end_dt = end_dt;
+        self
+    }
+
+    pub fn build(&self) -> Self {
+        self.clone()
+    }
+
+    pub fn get_id(&self) -> i32 {
+        self.id.unwrap_or(-1)
+    }
+    pub fn get_book_id(&self) -> i32 {
+        self.book_id
+    }
+    pub fn get_sub_category_id(&self) -> i32 {
+        self.sub_category_id
+    }
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+    pub fn get_memo(&self) -> &Option<String> {
+        &self.memo
+    }
+    pub fn get_asset_id(&self) -> Option<i32> {
+        self.asset_id
+    }
+    pub fn get_cycle(&self) -> &str {
+        &self.cycle
+    }
+    pub fn get_cycle_interval(&self) -> i16 {
+        self.cycle_interval
+    }
+    pub fn get_start_dt(&self) -> &NaiveDateTime {
+        &self.start_dt
+    }
+    pub fn get_end_dt(&self) -> &Option<NaiveDateTime> {
+        &self.end_dt
+    }
+    pub fn get_next_seq(&self) -> i32 {
+        self.next_seq
+    }
+    pub fn get_next_dt(&self) -> &NaiveDateTime {
+        &self.next_dt
+    }
+    pub fn get_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// seq번째 회차의 날짜 (start_dt 기준으로 계산해 월말 보정이 누적되지 않음)
+    pub fn get_occurrence(&self, seq: i32) -> Option<NaiveDateTime> {
+        let step = u32::try_from(seq).ok()? * u32::try_from(self.cycle_interval).ok()?;
+        match self.cycle.parse::<Cycle>().ok()? {
+            Cycle::Daily => self.start_dt.checked_add_days(Days::new(step.into())),
+            Cycle::Weekly => self
+                .start_dt
+                .checked_add_days(Days::new(u64::from(step) * 7)),
+            Cycle::Monthly => self.start_dt.checked_add_months(Months::new(step)),
+            Cycle::Yearly => self.start_dt.checked_add_months(Months::new(step * 12)),
+        }
+    }
+
+    pub fn is_ended(&self, target_dt: &NaiveDateTime) -> bool {
+        match &self.end_dt {
+            Some(end_dt) => target_dt > end_dt,
+            None => false,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct UpdateRecurring {
+    sub_category_id: FieldUpdate<i32>,
+    amount: FieldUpdate<i32>,
+    memo: FieldUpdate<String>,
+    asset_id: FieldUpdate<i32>,
+    end_dt: FieldUpdate<NaiveDateTime>,
+    is_paused: FieldUpdate<bool>,
+}
+
+impl UpdateRecurring {
+    pub fn new(
+        sub_category_id: FieldUpdate<i32>,
+        amount: FieldUpdate<i32>,
+        memo: FieldUpdate<String>,
+        asset_id: FieldUpdate<i32>,
+        end_dt: FieldUpdate<NaiveDateTime>,
+        is_paused: FieldUpdate<bool>,
+    ) -> Self {
+        Self {
+            sub_category_id,
+            amount,
+            memo,
+            asset_id,
+            end_dt,
+            is_paused,
+        }
+    }
+
+    pub fn get_sub_category_id(&self) -> &FieldUpdate<i32> {
+        &self.sub_category_id
+    }
+    pub fn get_amount(&self) -> &FieldUpdate<i32> {
+        &self.amount
+    }
+    pub fn get_memo(&self) -> &FieldUpdate<String> {
+        &self.memo
+    }
+    pub fn get_asset_id(&self) -> &FieldUpdate<i32> {
+        &self.asset_id
+    }
+    pub fn get_end_dt(&self) -> &FieldUpdate<NaiveDateTime> {
+        &self.end_dt
+    }
+    pub fn get_paused(&self) -> &FieldUpdate<bool> {
+        &self.is_paused
+    }
+}
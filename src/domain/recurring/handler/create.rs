@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use axum::{response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::recurring::{dto::request::NewRecurring, usecase::create::CreateRecurringUsecase},
+    global::errors::CustomError,
+};
+
+pub async fn create_recurring<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Json(new_recurring): Json<NewRecurring>,
+) -> impl IntoResponse
+where
+    T: CreateRecurringUsecase,
+{
+    if !new_recurring.is_valid() {
+        return CustomError::ValidationError("RecurringRule".to_string()).into_response();
+    }
+
+    match usecase.create_recurring(user_id, new_recurring).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "성공", "recurring_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, extract::Request, routing::post, Extension, Router};
+    use chrono::NaiveDateTime;
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::create_recurring;
+    use crate::{
+        domain::recurring::{
+            dto::request::NewRecurring, entity::Cycle, usecase::create::CreateRecurringUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        CreateRecurringUsecaseImpl {}
+
+        #[async_trait]
+        impl CreateRecurringUsecase for CreateRecurringUsecaseImpl {
+            async fn create_recurring(&self, user_id: i32, new_recurring: NewRecurring) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockCreateRecurringUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/recurring",
+                post(create_recurring::<MockCreateRecurringUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+    fn _create_req(new_recurring: &NewRecurring) -> Request<String> {
+        Request::builder()
+            .method("POST")
+            .uri("/api/v1/recurring")
+            .header("content-type", "application/json")
+            .body(to_string(&new_recurring).unwrap())
+            .unwrap()
+    }
+    fn _start_dt() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2024-09-25 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_create_recurring_body() {
+        // Arrange
+        let user_id = 1;
+        let new_recurring = NewRecurring::new(1, 16, 17000, Cycle::Monthly, _start_dt());
+
+        let mut mock_usecase = MockCreateRecurringUsecaseImpl::new();
+        mock_usecase
+            .expect_create_recurring()
+            .with(predicate::eq(user_id), predicate::eq(new_recurring.clone()))
+            .returning(|_, _| Ok(1));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_recurring);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 201);
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json["recurring_id"], 1);
+    }
+
+    #[tokio::test]
+    async fn check_invalid_rule() {
+        // Arrange
+        let user_id = 1;
+        let new_recurring =
+            NewRecurring::new(1, 16, 17000, Cycle::Weekly, _start_dt()).cycle_interval(Some(0));
+
+        let mock_usecase = MockCreateRecurringUsecaseImpl::new();
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_recurring);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400)
+    }
+
+    #[tokio::test]
+    async fn check_no_role() {
+        // Arrange
+        let user_id = 2;
+        let new_recurring = NewRecurring::new(1, 16, 17000, Cycle::Monthly, _start_dt());
+
+        let mut mock_usecase = MockCreateRecurringUsecaseImpl::new();
+        mock_usecase
+            .expect_create_recurring()
+            .with(predicate::eq(user_id), predicate::eq(new_recurring.clone()))
+            .returning(|_, _| {
+                Err(Box::new(CustomError::Unauthorized(
+                    "RecurringRole".to_string(),
+                )))
+            });
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(&new_recurring);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+}
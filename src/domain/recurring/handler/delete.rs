@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::recurring::usecase::delete::DeleteRecurringUsecase;
+
+pub async fn delete_recurring<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(recurring_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: DeleteRecurringUsecase,
+{
+    match usecase.delete_recurring(user_id, recurring_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::delete, Extension, Router};
+    use mockall::{mock, predicate};
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::recurring::{
+            handler::delete::delete_recurring, usecase::delete::DeleteRecurringUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        DeleteRecurringUsecaseImpl {}
+
+        #[async_trait]
+        impl DeleteRecurringUsecase for DeleteRecurringUsecaseImpl {
+            async fn delete_recurring(&self, user_id: i32, recurring_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockDeleteRecurringUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/recurring/:recurring_id",
+                delete(delete_recurring::<MockDeleteRecurringUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(recurring_id: i32) -> Request {
+        Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/v1/recurring/{}", recurring_id))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_delete_recurring_status() {
+        // Arrange
+        let user_id = 1;
+        let recurring_id = 1;
+        let mut mock_usecase = MockDeleteRecurringUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_recurring()
+            .with(predicate::eq(user_id), predicate::eq(recurring_id))
+            .returning(|_, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(recurring_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_recurring_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+        let mut mock_usecase = MockDeleteRecurringUsecaseImpl::new();
+        mock_usecase
+            .expect_delete_recurring()
+            .with(predicate::eq(user_id), predicate::eq(no_id))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Recurring".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(no_id);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404)
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{delete, get, patch, post},
+    Extension, Router,
+};
+
+use sqlx::PgPool;
+
+mod create;
+mod delete;
+mod read;
+mod update;
+
+use create::create_recurring;
+use delete::delete_recurring;
+use read::{read_recurring, read_recurrings};
+use update::update_recurring;
+
+use super::{
+    repository::{
+        delete::DeleteRecurringRepoImpl, get_recurring::GetRecurringRepoImpl,
+        save::SaveRecurringRepoImpl, update::UpdateRecurringRepoImpl,
+    },
+    usecase::{
+        create::CreateRecurringUsecaseImpl, delete::DeleteRecurringUsecaseImpl,
+        read::ReadRecurringUsecaseImpl, update::UpdateRecurringUsecaseImpl,
+    },
+};
+
+pub fn create_router(pool: &Arc<PgPool>) -> Router {
+    let repository = SaveRecurringRepoImpl::new(pool);
+    let usecase = CreateRecurringUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/",
+            post(create_recurring::<CreateRecurringUsecaseImpl<SaveRecurringRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn read_router(pool: &Arc<PgPool>) -> Router {
+    let repository = GetRecurringRepoImpl::new(pool);
+    let usecase = ReadRecurringUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/list/:book_id",
+            get(read_recurrings::<ReadRecurringUsecaseImpl<GetRecurringRepoImpl>>),
+        )
+        .route(
+            "/:recurring_id",
+            get(read_recurring::<ReadRecurringUsecaseImpl<GetRecurringRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn update_router(pool: &Arc<PgPool>) -> Router {
+    let repository = UpdateRecurringRepoImpl::new(pool);
+    let usecase = UpdateRecurringUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:recurring_id",
+            patch(update_recurring::<UpdateRecurringUsecaseImpl<UpdateRecurringRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn delete_router(pool: &Arc<PgPool>) -> Router {
+    let repository = DeleteRecurringRepoImpl::new(pool);
+    let usecase = DeleteRecurringUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:recurring_id",
+            delete(delete_recurring::<DeleteRecurringUsecaseImpl<DeleteRecurringRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::recurring::usecase::read::ReadRecurringUsecase;
+
+pub async fn read_recurrings<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: ReadRecurringUsecase,
+{
+    match usecase.read_recurrings(user_id, book_id).await {
+        Ok(recurrings) => (StatusCode::OK, Json(json!(recurrings))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+pub async fn read_recurring<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(recurring_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: ReadRecurringUsecase,
+{
+    match usecase.read_recurring(user_id, recurring_id).await {
+        Ok(recurring) => (StatusCode::OK, Json(json!(recurring))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::get, Extension, Router};
+    use chrono::NaiveDateTime;
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::recurring::{
+            entity::{Cycle, Recurring},
+            usecase::read::ReadRecurringUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    use super::{read_recurring, read_recurrings};
+
+    mock! {
+        ReadRecurringUsecaseImpl {}
+
+        #[async_trait]
+        impl ReadRecurringUsecase for ReadRecurringUsecaseImpl {
+            async fn read_recurrings(&self, user_id: i32, book_id: i32) -> Result<Vec<Recurring>, Box<CustomError>>;
+            async fn read_recurring(&self, user_id: i32, recurring_id: i32) -> Result<Recurring, Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockReadRecurringUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/recurring/list/:book_id",
+                get(read_recurrings::<MockReadRecurringUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/recurring/:recurring_id",
+                get(read_recurring::<MockReadRecurringUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(uri: String) -> Request {
+        Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_recurrings_body() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+        let start_dt =
+            NaiveDateTime::parse_from_str("2024-09-25 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut mock_usecase = MockReadRecurringUsecaseImpl::new();
+        mock_usecase
+            .expect_read_recurrings()
+            .with(predicate::eq(user_id), predicate::eq(book_id))
+            .returning(move |_, b| {
+                Ok(vec![Recurring::new(b, 16, 17000, Cycle::Monthly, start_dt)])
+            });
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(format!("/api/v1/recurring/list/{}", book_id));
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body();
+
+        let body_bytes = body
+            .collect()
+            .await
+            .expect("failed to read body")
+            .to_bytes();
+
+        let body_str =
+            String::from_utf8(body_bytes.to_vec()).expect("failed to convert body to string");
+
+        let body_json: Value = serde_json::from_str(&body_str).expect("failed to parse JSON");
+
+        assert_eq!(body_json[0]["cycle"], "monthly");
+    }
+
+    #[tokio::test]
+    async fn check_read_recurring_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+
+        let mut mock_usecase = MockReadRecurringUsecaseImpl::new();
+        mock_usecase
+            .expect_read_recurring()
+            .with(predicate::eq(user_id), predicate::eq(no_id))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Recurring".to_string()))));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(format!("/api/v1/recurring/{}", no_id));
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 404)
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::recurring::{
+    dto::request::EditRecurring, usecase::update::UpdateRecurringUsecase,
+};
+
+pub async fn update_recurring<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(recurring_id): Path<i32>,
+    Json(edit_recurring): Json<EditRecurring>,
+) -> impl IntoResponse
+where
+    T: UpdateRecurringUsecase,
+{
+    match usecase
+        .update_recurring(user_id, recurring_id, edit_recurring)
+        .await
+    {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, extract::Request, routing::patch, Extension, Router};
+    use mockall::{mock, predicate};
+    use serde_json::to_string;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::recurring::{dto::request::EditRecurring, usecase::update::UpdateRecurringUsecase},
+        global::errors::CustomError,
+    };
+
+    use super::update_recurring;
+
+    mock! {
+        UpdateRecurringUsecaseImpl {}
+
+        #[async_trait]
+        impl UpdateRecurringUsecase for UpdateRecurringUsecaseImpl {
+            async fn update_recurring(&self, user_id: i32, recurring_id: i32, edit_recurring: EditRecurring) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockUpdateRecurringUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/recurring/:recurring_id",
+                patch(update_recurring::<MockUpdateRecurringUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _create_req(recurring_id: i32, edit_recurring: &EditRecurring) -> Request<String> {
+        Request::builder()
+            .method("PATCH")
+            .uri(format!("/api/v1/recurring/{}", recurring_id))
+            .header("content-type", "application/json")
+            .body(to_string(edit_recurring).unwrap())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_pause_recurring_status() {
+        // Arrange
+        let user_id = 1;
+        let recurring_id = 1;
+        let edit_recurring = EditRecurring::new(None, None, None, None, None, Some(true));
+
+        let mut mock_usecase = MockUpdateRecurringUsecaseImpl::new();
+        mock_usecase
+            .expect_update_recurring()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(recurring_id),
+                predicate::eq(edit_recurring.clone()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        let app = _create_app(user_id, mock_usecase);
+        let req = _create_req(recurring_id, &edit_recurring);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_no_role() {
+        // Arrange
+        let viewer_id = 2;
+        let recurring_id = 1;
+        let edit_recurring = EditRecurring::new(None, Some(12000), None, None, None, None);
+
+        let mut mock_usecase = MockUpdateRecurringUsecaseImpl::new();
+        mock_usecase
+            .expect_update_recurring()
+            .with(
+                predicate::eq(viewer_id),
+                predicate::eq(recurring_id),
+                predicate::eq(edit_recurring.clone()),
+            )
+            .returning(|_, _, _| {
+                Err(Box::new(CustomError::Unauthorized(
+                    "RecurringRole".to_string(),
+                )))
+            });
+
+        let app = _create_app(viewer_id, mock_usecase);
+        let req = _create_req(recurring_id, &edit_recurring);
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 401)
+    }
+}
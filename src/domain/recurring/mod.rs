@@ -0,0 +1,8 @@
+mod dto;
+mod entity;
+mod handler;
+mod repository;
+mod usecase;
+
+pub mod route;
+pub mod scheduler;
@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::global::{constants::DeleteResult, errors::CustomError};
+
+pub struct DeleteRecurringRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait DeleteRecurringRepo: Send + Sync {
+    async fn delete_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl DeleteRecurringRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl DeleteRecurringRepo for DeleteRecurringRepoImpl {
+    async fn delete_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+    ) -> Result<(), Box<CustomError>> {
+        delete_recurring(&self.pool, user_id, recurring_id).await
+    }
+}
+
+async fn delete_recurring(
+    pool: &PgPool,
+    user_id: i32,
+    recurring_id: i32,
+) -> Result<(), Box<CustomError>> {
+    // 이미 생성된 기록은 남겨둠 (recurring_id는 ON DELETE SET NULL)
+    let result = sqlx::query_as::<_, DeleteResult>(
+        r"
+        WITH RecurringExists AS (
+            SELECT book_id
+            FROM tb_recurring_record
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT rr.book_id
+            FROM RecurringExists AS rr
+            JOIN tb_user_book_role AS br ON rr.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role != 'viewer'
+        ),
+        DeleteRecurring AS (
+            DELETE FROM tb_recurring_record
+            WHERE id = $2
+                AND EXISTS (SELECT 1 FROM AuthorityCheck) = true
+            RETURNING id
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM RecurringExists) AS is_exist,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT COUNT(*) FROM DeleteRecurring) AS delete_count
+        ",
+    )
+    .bind(user_id)
+    .bind(recurring_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(DeleteRecurring {}): {:?}", recurring_id, &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Recurring".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized(
+            "RecurringRole".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::recurring::{
+            entity::{Cycle, Recurring},
+            repository::{get_recurring::get_by_id, save::save_recurring},
+        },
+        global::errors::CustomError,
+    };
+
+    use super::delete_recurring;
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_delete_recurring_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let start_dt =
+            NaiveDateTime::parse_from_str("2024-03-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let recurring = Recurring::new(2, 16, 9900, Cycle::Daily, start_dt);
+        let new_id = save_recurring(&pool, user_id, recurring).await.unwrap();
+
+        // Act
+        let result = delete_recurring(&pool, user_id, new_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, new_id).await;
+        assert!(row.is_err())
+    }
+
+    #[tokio::test]
+    async fn check_recurring_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+
+        // Act
+        let result = delete_recurring(&pool, user_id, no_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let viewer_id = 2;
+        let recurring_id = 1;
+
+        // Act
+        let result = delete_recurring(&pool, viewer_id, recurring_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::{domain::recurring::entity::Recurring, global::errors::CustomError};
+
+pub struct GetRecurringRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait GetRecurringRepo: Send + Sync {
+    async fn get_list(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Recurring>, Box<CustomError>>;
+    async fn get_by_id(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+    ) -> Result<Recurring, Box<CustomError>>;
+}
+
+impl GetRecurringRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl GetRecurringRepo for GetRecurringRepoImpl {
+    async fn get_list(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Recurring>, Box<CustomError>> {
+        get_list(&self.pool, user_id, book_id).await
+    }
+    async fn get_by_id(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+    ) -> Result<Recurring, Box<CustomError>> {
+        get_by_id(&self.pool, user_id, recurring_id).await
+    }
+}
+
+async fn get_list(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<Recurring>, Box<CustomError>> {
+    let rows = sqlx::query_as::<_, Recurring>(
+        "
+        SELECT rr.*
+        FROM tb_recurring_record AS rr
+        JOIN tb_user_book_role AS br ON rr.book_id = br.book_id
+        WHERE br.user_id = $1 AND rr.book_id = $2
+        ORDER BY rr.id
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetRecurrings): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(rows)
+}
+
+pub async fn get_by_id(
+    pool: &PgPool,
+    user_id: i32,
+    recurring_id: i32,
+) -> Result<Recurring, Box<CustomError>> {
+    let row = sqlx::query_as::<_, Recurring>(
+        "
+        SELECT rr.* FROM tb_recurring_record AS rr
+        JOIN tb_user_book_role AS br ON rr.book_id = br.book_id
+        WHERE br.user_id = $1 AND rr.id = $2
+    ",
+    )
+    .bind(user_id)
+    .bind(recurring_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(GetRecurring {}): {:?}", recurring_id, &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            sqlx::Error::RowNotFound => CustomError::NotFound("Recurring".to_string()),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::database::create_connection_pool,
+        domain::recurring::{
+            entity::Recurring,
+            repository::get_recurring::{get_by_id, get_list},
+        },
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_get_list_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let book_id = 1;
+
+        // Act
+        let result = get_list(&pool, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+        let result = result.unwrap();
+
+        // Assert
+        let rows =
+            sqlx::query_as::<_, Recurring>("SELECT * FROM tb_recurring_record WHERE book_id = $1")
+                .bind(book_id)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+
+        assert_eq!(result.len(), rows.len());
+    }
+
+    #[tokio::test]
+    async fn check_get_list_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let book_id = 1;
+
+        // Act
+        let result = get_list(&pool, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn check_get_by_id_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2; // viewer
+        let recurring_id = 1;
+
+        // Act
+        let result = get_by_id(&pool, user_id, recurring_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().get_memo(), &Some("넷플릭스".to_string()));
+    }
+
+    #[tokio::test]
+    async fn check_get_by_id_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let no_id = -32;
+
+        // Act
+        let result = get_by_id(&pool, user_id, no_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,363 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::{Acquire, PgPool};
+
+use crate::{
+    domain::recurring::entity::{Recurring, MAX_CATCH_UP},
+    global::errors::CustomError,
+};
+
+pub struct MaterializeRecurringRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait MaterializeRecurringRepo: Send + Sync {
+    async fn materialize_recurring(&self, now: NaiveDateTime) -> Result<i64, Box<CustomError>>;
+}
+
+impl MaterializeRecurringRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl MaterializeRecurringRepo for MaterializeRecurringRepoImpl {
+    async fn materialize_recurring(&self, now: NaiveDateTime) -> Result<i64, Box<CustomError>> {
+        materialize_recurring(&self.pool, now).await
+    }
+}
+
+fn to_custom_error(context: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Materialize(Recurring{}): {:?}", context, &e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+/// now 시점까지 도래한 반복 회차를 기록으로 생성하고 생성된 개수를 반환
+///
+/// 같은 회차는 (recurring_id, target_dt) 유니크 인덱스로 한 번만 생성되고,
+/// 일시정지된 규칙은 회차만 넘겨서 재개 시 밀린 기록이 쌓이지 않음
+/// 규칙마다 세이브포인트로 처리해서 실패한 규칙은 건너뛰고 다음 실행에서 다시 시도
+pub async fn materialize_recurring(
+    pool: &PgPool,
+    now: NaiveDateTime,
+) -> Result<i64, Box<CustomError>> {
+    let mut tx = pool.begin().await.map_err(|e| to_custom_error("", e))?;
+
+    // 여러 인스턴스가 동시에 실행돼도 같은 규칙을 두 번 처리하지 않도록 잠금
+    let rules = sqlx::query_as::<_, Recurring>(
+        "
+        SELECT *
+        FROM tb_recurring_record
+        WHERE next_dt <= $1
+            AND (end_dt IS NULL OR next_dt <= end_dt)
+        ORDER BY id
+        FOR UPDATE SKIP LOCKED
+    ",
+    )
+    .bind(now)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("", e))?;
+
+    let mut created = 0;
+    for rule in rules {
+        let context = format!(" {}", rule.get_id());
+        let mut savepoint = tx.begin().await.map_err(|e| to_custom_error(&context, e))?;
+
+        match materialize_rule(&mut savepoint, &rule, now).await {
+            Ok(count) => {
+                savepoint
+                    .commit()
+                    .await
+                    .map_err(|e| to_custom_error(&context, e))?;
+                created += count;
+            }
+            Err(e) => {
+                tracing::error!("Materialize(Recurring{}) skipped: {:?}", context, e);
+                savepoint
+                    .rollback()
+                    .await
+                    .map_err(|e| to_custom_error(&context, e))?;
+            }
+        }
+    }
+
+    tx.commit().await.map_err(|e| to_custom_error("", e))?;
+
+    Ok(created)
+}
+
+// 한 규칙의 도래한 회차를 생성하고 다음 회차로 넘김
+async fn materialize_rule(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    rule: &Recurring,
+    now: NaiveDateTime,
+) -> Result<i64, sqlx::Error> {
+    let mut created = 0;
+    let mut seq = rule.get_next_seq();
+    let mut next_dt = Some(*rule.get_next_dt());
+    let mut count = 0;
+
+    while let Some(target_dt) = next_dt {
+        if target_dt > now || rule.is_ended(&target_dt) || count >= MAX_CATCH_UP {
+            break;
+        }
+        if !rule.get_paused() {
+            created += insert_occurrence(tx, rule.get_id(), target_dt).await?;
+        }
+        seq += 1;
+        count += 1;
+        next_dt = rule.get_occurrence(seq);
+    }
+
+    // 더 이상 계산할 수 없는 날짜면 종료된 규칙으로 취급
+    let next_dt = next_dt.unwrap_or(NaiveDateTime::MAX);
+    sqlx::query("UPDATE tb_recurring_record SET next_seq = $2, next_dt = $3 WHERE id = $1")
+        .bind(rule.get_id())
+        .bind(seq)
+        .bind(next_dt)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(created)
+}
+
+async fn insert_occurrence(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    recurring_id: i32,
+    target_dt: NaiveDateTime,
+) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar::<_, i64>(
+        r"
+        WITH InsertRecord AS (
+            INSERT INTO tb_record (book_id, sub_category_id, amount, memo, target_dt, created_at,
+                    asset_id, recurring_id)
+                SELECT book_id, sub_category_id, amount, memo, $2, NOW(), asset_id, id
+                    FROM tb_recurring_record
+                    WHERE id = $1
+            ON CONFLICT (recurring_id, target_dt) DO NOTHING
+            RETURNING id, asset_id, amount, sub_category_id
+        ),
+        UpdateBalance AS (
+            UPDATE tb_asset AS a
+            SET balance = a.balance + CASE WHEN bc.is_income THEN r.amount ELSE -r.amount END
+            FROM InsertRecord AS r
+            JOIN tb_sub_category AS sc ON sc.id = r.sub_category_id
+            JOIN tb_base_category AS bc ON bc.id = sc.base_id
+            WHERE a.id = r.asset_id
+        )
+        SELECT COUNT(*) FROM InsertRecord;
+        ",
+    )
+    .bind(recurring_id)
+    .bind(target_dt)
+    .fetch_one(&mut **tx)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::recurring::{
+            entity::{Cycle, Recurring},
+            repository::{get_recurring::get_by_id, save::save_recurring},
+        },
+    };
+
+    use super::materialize_recurring;
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    async fn _get_target_dts(pool: &PgPool, recurring_id: i32) -> Vec<NaiveDateTime> {
+        sqlx::query_scalar(
+            "SELECT target_dt FROM tb_record WHERE recurring_id = $1 ORDER BY target_dt",
+        )
+        .bind(recurring_id)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_materialize_weekly() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let recurring = Recurring::new(2, 17, 5000, Cycle::Weekly, _parse("2024-03-04 12:00:00"))
+            .cycle_interval(2);
+        let new_id = save_recurring(&pool, user_id, recurring).await.unwrap();
+
+        // Act
+        let result = materialize_recurring(&pool, _parse("2024-03-31 00:00:00")).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let target_dts = _get_target_dts(&pool, new_id).await;
+        assert_eq!(
+            target_dts,
+            vec![_parse("2024-03-04 12:00:00"), _parse("2024-03-18 12:00:00")]
+        );
+
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+        assert_eq!(row.get_next_seq(), 2);
+        assert_eq!(row.get_next_dt(), &_parse("2024-04-01 12:00:00"));
+    }
+
+    #[tokio::test]
+    async fn check_materialize_idempotent() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let recurring = Recurring::new(2, 17, 5000, Cycle::Daily, _parse("2024-03-10 12:00:00"))
+            .end_dt(Some(_parse("2024-03-12 12:00:00")));
+        let new_id = save_recurring(&pool, user_id, recurring).await.unwrap();
+
+        // Act
+        materialize_recurring(&pool, _parse("2024-03-31 00:00:00"))
+            .await
+            .unwrap();
+        // 회차를 되돌려도 같은 날짜의 기록은 다시 생성되지 않음
+        sqlx::query(
+            "UPDATE tb_recurring_record SET next_seq = 0, next_dt = start_dt WHERE id = $1",
+        )
+        .bind(new_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        materialize_recurring(&pool, _parse("2024-03-31 00:00:00"))
+            .await
+            .unwrap();
+
+        // Assert
+        let target_dts = _get_target_dts(&pool, new_id).await;
+        assert_eq!(target_dts.len(), 3); // end_dt 포함
+    }
+
+    #[tokio::test]
+    async fn check_materialize_month_end() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let recurring = Recurring::new(2, 16, 5000, Cycle::Monthly, _parse("2024-01-31 09:00:00"));
+        let new_id = save_recurring(&pool, user_id, recurring).await.unwrap();
+
+        // Act
+        materialize_recurring(&pool, _parse("2024-03-31 23:00:00"))
+            .await
+            .unwrap();
+
+        // Assert
+        let target_dts = _get_target_dts(&pool, new_id).await;
+        assert_eq!(
+            target_dts,
+            vec![
+                _parse("2024-01-31 09:00:00"),
+                _parse("2024-02-29 09:00:00"),
+                _parse("2024-03-31 09:00:00"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_failed_rule_skipped() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        // 잔액이 BIGINT 범위를 벗어나서 기록 생성이 실패하는 자산
+        let asset_id: i32 = sqlx::query_scalar(
+            "INSERT INTO tb_asset (book_id, sub_category_id, balance) VALUES (2, 1, $1) RETURNING id",
+        )
+        .bind(i64::MIN)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let invalid = Recurring::new(2, 17, 5000, Cycle::Daily, _parse("2024-04-01 12:00:00"))
+            .asset_id(Some(asset_id));
+        let invalid_id = save_recurring(&pool, user_id, invalid).await.unwrap();
+        let valid = Recurring::new(2, 17, 5000, Cycle::Daily, _parse("2024-04-01 12:00:00"));
+        let valid_id = save_recurring(&pool, user_id, valid).await.unwrap();
+
+        // Act
+        let result = materialize_recurring(&pool, _parse("2024-04-02 00:00:00")).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(_get_target_dts(&pool, invalid_id).await.len(), 0);
+        let row = get_by_id(&pool, user_id, invalid_id).await.unwrap();
+        assert_eq!(row.get_next_dt(), &_parse("2024-04-01 12:00:00"));
+
+        assert_eq!(
+            _get_target_dts(&pool, valid_id).await,
+            vec![_parse("2024-04-01 12:00:00")]
+        );
+        let row = get_by_id(&pool, user_id, valid_id).await.unwrap();
+        assert_eq!(row.get_next_dt(), &_parse("2024-04-02 12:00:00"));
+
+        sqlx::query("DELETE FROM tb_recurring_record WHERE id = $1")
+            .bind(invalid_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_paused_skipped() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let recurring = Recurring::new(2, 17, 5000, Cycle::Daily, _parse("2024-03-20 12:00:00"));
+        let new_id = save_recurring(&pool, user_id, recurring).await.unwrap();
+        sqlx::query("UPDATE tb_recurring_record SET is_paused = TRUE WHERE id = $1")
+            .bind(new_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // Act
+        materialize_recurring(&pool, _parse("2024-03-22 00:00:00"))
+            .await
+            .unwrap();
+
+        // Assert
+        let target_dts = _get_target_dts(&pool, new_id).await;
+        assert_eq!(target_dts.len(), 0);
+
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+        assert_eq!(row.get_next_dt(), &_parse("2024-03-22 12:00:00"));
+    }
+}
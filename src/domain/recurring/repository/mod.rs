@@ -0,0 +1,5 @@
+pub(super) mod delete;
+pub(super) mod get_recurring;
+pub(super) mod materialize;
+pub(super) mod save;
+pub(super) mod update;
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::{Error, PgPool};
+
+use crate::{domain::recurring::entity::Recurring, global::errors::CustomError};
+
+pub struct SaveRecurringRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait SaveRecurringRepo: Send + Sync {
+    async fn save_recurring(
+        &self,
+        user_id: i32,
+        recurring: Recurring,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl SaveRecurringRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl SaveRecurringRepo for SaveRecurringRepoImpl {
+    async fn save_recurring(
+        &self,
+        user_id: i32,
+        recurring: Recurring,
+    ) -> Result<i32, Box<CustomError>> {
+        save_recurring(&self.pool, user_id, recurring).await
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct InsertRecurring {
+    is_authorized: bool,
+    recurring_id: Option<i32>,
+    is_asset_exist: bool,
+    is_category_exist: bool,
+}
+
+impl InsertRecurring {
+    fn get_authorized(&self) -> bool {
+        self.is_authorized
+    }
+    fn get_recurring_id(&self) -> Option<i32> {
+        self.recurring_id
+    }
+    fn get_asset_exist(&self) -> bool {
+        self.is_asset_exist
+    }
+    fn get_category_exist(&self) -> bool {
+        self.is_category_exist
+    }
+}
+
+pub async fn save_recurring(
+    pool: &PgPool,
+    user_id: i32,
+    recurring: Recurring,
+) -> Result<i32, Box<CustomError>> {
+    let result = sqlx::query_as::<_, InsertRecurring>(
+        r#"
+        WITH AuthorityCheck AS (
+            SELECT book_id
+            FROM tb_user_book_role
+            WHERE user_id = $1 AND book_id = $2 AND role != 'viewer'
+        ),
+        CategoryCheck AS (
+            SELECT EXISTS (
+                SELECT 1
+                FROM tb_sub_category AS sc
+                JOIN tb_base_category AS bc ON bc.id = sc.base_id
+                LEFT JOIN AuthorityCheck AS ac ON bc.book_id = ac.book_id
+                WHERE sc.id = $3
+                    AND bc.is_record = true
+                    AND (ac.book_id IS NOT NULL OR bc.book_id IS NULL)
+            ) AS is_category_exist
+        ),
+        AssetCheck AS (
+            SELECT CASE
+                WHEN $6::INT IS NULL THEN TRUE
+                ELSE EXISTS (
+                    SELECT 1
+                    FROM tb_asset AS a
+                    JOIN AuthorityCheck AS ac ON ac.book_id = a.book_id
+                    WHERE a.id = $6
+                )
+            END AS is_asset_exist
+        ),
+        InsertRecurring AS (
+            INSERT INTO tb_recurring_record (book_id, sub_category_id, amount, memo, asset_id,
+                    cycle, cycle_interval, start_dt, end_dt, next_dt, created_at)
+                SELECT book_id, $3, $4, $5, $6, $7, $8, $9, $10, $9, NOW()
+                    FROM AuthorityCheck
+                    WHERE book_id IS NOT NULL
+                        AND (SELECT is_category_exist FROM CategoryCheck) = true
+                        AND (SELECT is_asset_exist FROM AssetCheck) = true
+            RETURNING id
+        )
+        SELECT
+            (SELECT id FROM InsertRecurring) AS recurring_id,
+            EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+            (SELECT is_category_exist FROM CategoryCheck) AS is_category_exist,
+            (SELECT is_asset_exist FROM AssetCheck) AS is_asset_exist;
+    "#,
+    )
+    .bind(user_id)
+    .bind(recurring.get_book_id())
+    .bind(recurring.get_sub_category_id())
+    .bind(recurring.get_amount())
+    .bind(recurring.get_memo())
+    .bind(recurring.get_asset_id())
+    .bind(recurring.get_cycle())
+    .bind(recurring.get_cycle_interval())
+    .bind(recurring.get_start_dt())
+    .bind(recurring.get_end_dt())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Save(Recurring): {:?}", e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized(
+            "RecurringRole".to_string(),
+        )));
+    } else if !result.get_category_exist() {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    } else if !result.get_asset_exist() {
+        return Err(Box::new(CustomError::NotFound("Asset".to_string())));
+    }
+
+    Ok(result.get_recurring_id().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::recurring::{
+            entity::{Cycle, Recurring},
+            repository::save::save_recurring,
+        },
+        global::errors::CustomError,
+    };
+
+    fn _start_dt() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2024-03-31 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_save_recurring_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let recurring = Recurring::new(2, 16, 9900, Cycle::Monthly, _start_dt())
+            .memo(Some("구독료".to_string()));
+
+        // Act
+        let result = save_recurring(&pool, user_id, recurring).await;
+        let inserted_id = result.map_err(|e| println!("{:?}", e)).unwrap();
+
+        // Assert
+        let row = sqlx::query_as::<_, Recurring>("SELECT * FROM tb_recurring_record WHERE id = $1")
+            .bind(inserted_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(row.get_cycle(), "monthly");
+        assert_eq!(row.get_next_seq(), 0);
+        assert_eq!(row.get_next_dt(), &_start_dt());
+    }
+
+    #[tokio::test]
+    async fn check_asset_category() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 1;
+        let asset_category_id = 1; // 입출금, 기록 카테고리가 아님
+        let recurring = Recurring::new(1, asset_category_id, 9900, Cycle::Monthly, _start_dt());
+
+        // Act
+        let result = save_recurring(&pool, user_id, recurring).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_asset_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let other_asset_id = 1; // 1번 가계부 자산
+        let recurring =
+            Recurring::new(2, 16, 9900, Cycle::Monthly, _start_dt()).asset_id(Some(other_asset_id));
+
+        // Act
+        let result = save_recurring(&pool, user_id, recurring).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 2;
+        let book_id = 1; // 읽기전용 가계부
+        let recurring = Recurring::new(book_id, 16, 9900, Cycle::Monthly, _start_dt());
+
+        // Act
+        let result = save_recurring(&pool, user_id, recurring).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,424 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::{
+    domain::recurring::entity::UpdateRecurring,
+    global::{constants::FieldUpdate, errors::CustomError},
+};
+
+pub struct UpdateRecurringRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait UpdateRecurringRepo: Send + Sync {
+    async fn update_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+        edit_recurring: UpdateRecurring,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl UpdateRecurringRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl UpdateRecurringRepo for UpdateRecurringRepoImpl {
+    async fn update_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+        edit_recurring: UpdateRecurring,
+    ) -> Result<(), Box<CustomError>> {
+        update_recurring(&self.pool, user_id, recurring_id, edit_recurring).await
+    }
+}
+
+fn make_query(index: &mut i32, field_name: &str) -> String {
+    *index += 1;
+    format!("{} = ${}, ", field_name, index)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct UpdateRecurringResult {
+    is_exist: bool,
+    is_authorized: bool,
+    update_count: i64,
+    is_category_exist: bool,
+    is_asset_exist: bool,
+}
+
+impl UpdateRecurringResult {
+    fn get_exist(&self) -> bool {
+        self.is_exist
+    }
+    fn get_authorized(&self) -> bool {
+        self.is_authorized
+    }
+    fn get_count(&self) -> i64 {
+        self.update_count
+    }
+    fn get_category_exist(&self) -> bool {
+        self.is_category_exist
+    }
+    fn get_asset_exist(&self) -> bool {
+        self.is_asset_exist
+    }
+}
+
+async fn update_recurring(
+    pool: &PgPool,
+    user_id: i32,
+    recurring_id: i32,
+    edit_recurring: UpdateRecurring,
+) -> Result<(), Box<CustomError>> {
+    let mut query = r"
+        WITH RecurringExists AS (
+            SELECT book_id
+            FROM tb_recurring_record
+            WHERE id = $2
+        ),
+        AuthorityCheck AS (
+            SELECT rr.book_id
+            FROM RecurringExists AS rr
+            JOIN tb_user_book_role AS br ON rr.book_id = br.book_id
+            WHERE br.user_id = $1 AND br.role != 'viewer'
+        ),"
+    .to_string();
+
+    let mut index = 2;
+    let mut ctg_check = false;
+    let mut asset_check = false;
+
+    let mut update_query = r"
+        UpdateRecurring AS (
+            UPDATE tb_recurring_record SET "
+        .to_string();
+
+    if let FieldUpdate::Set(_) = edit_recurring.get_sub_category_id() {
+        update_query.push_str(&make_query(&mut index, "sub_category_id"));
+        query.push_str(&format!(
+            r"
+            CategoryCheck AS (
+                SELECT EXISTS (
+                    SELECT 1
+                    FROM tb_sub_category AS sc
+                    JOIN tb_base_category AS bc ON bc.id = sc.base_id
+                    LEFT JOIN RecurringExists AS rr ON bc.book_id = rr.book_id
+                    WHERE sc.id = ${}
+                        AND bc.is_record = true
+                        AND (rr.book_id IS NOT NULL OR bc.book_id IS NULL)
+                ) AS is_category_exist
+            ),",
+            index
+        ));
+        ctg_check = true;
+    };
+    if let FieldUpdate::Set(_) = edit_recurring.get_amount() {
+        update_query.push_str(&make_query(&mut index, "amount"));
+    };
+    match edit_recurring.get_memo() {
+        FieldUpdate::Set(_) | FieldUpdate::SetNone => {
+            update_query.push_str(&make_query(&mut index, "memo"));
+        }
+        _ => {}
+    }
+    if let FieldUpdate::Set(_) = edit_recurring.get_asset_id() {
+        update_query.push_str(&make_query(&mut index, "asset_id"));
+        query.push_str(&format!(
+            r"
+            AssetCheck AS (
+                SELECT EXISTS (
+                    SELECT 1
+                    FROM tb_asset AS a
+                    JOIN RecurringExists AS rr ON rr.book_id = a.book_id
+                    WHERE a.id = ${}
+                ) AS is_asset_exist
+            ),",
+            index
+        ));
+        asset_check = true;
+    };
+    if let FieldUpdate::Set(_) = edit_recurring.get_end_dt() {
+        update_query.push_str(&make_query(&mut index, "end_dt"));
+    };
+    if let FieldUpdate::Set(_) = edit_recurring.get_paused() {
+        update_query.push_str(&make_query(&mut index, "is_paused"));
+    };
+
+    if index == 2 {
+        return Err(Box::new(CustomError::NoFieldUpdate(
+            "Recurring".to_string(),
+        )));
+    }
+
+    update_query.push_str(
+        &("
+            updated_at = NOW()
+            WHERE id = $2
+                AND EXISTS (SELECT book_id FROM AuthorityCheck) = true
+            "
+        .to_string()
+            + if ctg_check {
+                "AND (SELECT is_category_exist FROM CategoryCheck) = true\n"
+            } else {
+                ""
+            }
+            + if asset_check {
+                "AND (SELECT is_asset_exist FROM AssetCheck) = true\n"
+            } else {
+                ""
+            }),
+    );
+
+    query.push_str(
+        &(update_query
+            + "RETURNING id
+        )" + "
+            SELECT
+                EXISTS (SELECT 1 FROM RecurringExists) AS is_exist,
+                EXISTS (SELECT 1 FROM AuthorityCheck) AS is_authorized,
+                (SELECT COUNT(*) FROM UpdateRecurring) AS update_count\n"
+            + if ctg_check {
+                ",(SELECT is_category_exist FROM CategoryCheck) AS is_category_exist\n"
+            } else {
+                ",true AS is_category_exist\n"
+            }
+            + if asset_check {
+                ",(SELECT is_asset_exist FROM AssetCheck) AS is_asset_exist\n"
+            } else {
+                ",true AS is_asset_exist"
+            }
+            + ";"),
+    );
+
+    let mut query_builder = sqlx::query_as::<_, UpdateRecurringResult>(&query)
+        .bind(user_id)
+        .bind(recurring_id);
+
+    if let FieldUpdate::Set(v) = edit_recurring.get_sub_category_id() {
+        query_builder = query_builder.bind(v);
+    }
+    if let FieldUpdate::Set(v) = edit_recurring.get_amount() {
+        query_builder = query_builder.bind(v);
+    }
+    match edit_recurring.get_memo() {
+        FieldUpdate::Set(v) => {
+            query_builder = query_builder.bind(v);
+        }
+        FieldUpdate::SetNone => {
+            query_builder = query_builder.bind(None::<String>);
+        }
+        _ => {}
+    }
+    if let FieldUpdate::Set(v) = edit_recurring.get_asset_id() {
+        query_builder = query_builder.bind(v);
+    }
+    if let FieldUpdate::Set(v) = edit_recurring.get_end_dt() {
+        query_builder = query_builder.bind(v);
+    }
+    if let FieldUpdate::Set(v) = edit_recurring.get_paused() {
+        query_builder = query_builder.bind(v);
+    }
+
+    let result = query_builder.fetch_one(pool).await.map_err(|e| {
+        let err_msg = format!("Update(Recurring {}): {}", recurring_id, e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Box::new(err)
+    })?;
+
+    if !result.get_exist() {
+        return Err(Box::new(CustomError::NotFound("Recurring".to_string())));
+    } else if !result.get_authorized() {
+        return Err(Box::new(CustomError::Unauthorized(
+            "RecurringRole".to_string(),
+        )));
+    } else if !result.get_category_exist() {
+        return Err(Box::new(CustomError::NotFound("Category".to_string())));
+    } else if !result.get_asset_exist() {
+        return Err(Box::new(CustomError::NotFound("Asset".to_string())));
+    } else if result.get_count() == 0 {
+        return Err(Box::new(CustomError::NotFound("Recurring".to_string())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::recurring::{
+            entity::{Cycle, Recurring, UpdateRecurring},
+            repository::{
+                get_recurring::get_by_id, save::save_recurring, update::update_recurring,
+            },
+        },
+        global::{constants::FieldUpdate, errors::CustomError},
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    async fn _save_sample(pool: &PgPool, user_id: i32) -> i32 {
+        let start_dt =
+            NaiveDateTime::parse_from_str("2024-03-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let recurring = Recurring::new(2, 16, 9900, Cycle::Weekly, start_dt)
+            .memo(Some("수정용 반복".to_string()));
+        save_recurring(&pool, user_id, recurring).await.unwrap()
+    }
+
+    fn _edit(is_paused: FieldUpdate<bool>) -> UpdateRecurring {
+        UpdateRecurring::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            is_paused,
+        )
+    }
+
+    #[tokio::test]
+    async fn check_update_recurring_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_recurring = UpdateRecurring::new(
+            FieldUpdate::Set(17),
+            FieldUpdate::Set(12000),
+            FieldUpdate::SetNone,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+
+        // Act
+        let result = update_recurring(&pool, user_id, new_id, edit_recurring).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+
+        assert_eq!(row.get_sub_category_id(), 17);
+        assert_eq!(row.get_amount(), 12000);
+        assert_eq!(row.get_memo(), &None);
+    }
+
+    #[tokio::test]
+    async fn check_pause_recurring() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let new_id = _save_sample(&pool, user_id).await;
+
+        // Act
+        let result = update_recurring(&pool, user_id, new_id, _edit(FieldUpdate::Set(true))).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let row = get_by_id(&pool, user_id, new_id).await.unwrap();
+        assert!(row.get_paused());
+    }
+
+    #[tokio::test]
+    async fn check_no_field_to_update() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        let user_id = 3;
+        let new_id = _save_sample(&pool, user_id).await;
+
+        // Act
+        let result = update_recurring(&pool, user_id, new_id, _edit(FieldUpdate::NoChange)).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NoFieldUpdate(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_asset_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let user_id = 3;
+        let new_id = _save_sample(&pool, user_id).await;
+        let edit_recurring = UpdateRecurring::new(
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+            FieldUpdate::Set(1), // 1번 가계부 자산
+            FieldUpdate::NoChange,
+            FieldUpdate::NoChange,
+        );
+
+        // Act
+        let result = update_recurring(&pool, user_id, new_id, edit_recurring).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // ref) init.sql
+        let viewer_id = 2;
+        let recurring_id = 1;
+
+        // Act
+        let result = update_recurring(
+            &pool,
+            viewer_id,
+            recurring_id,
+            _edit(FieldUpdate::Set(true)),
+        )
+        .await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
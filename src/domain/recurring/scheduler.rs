@@ -0,0 +1,35 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Local;
+use sqlx::PgPool;
+use tokio::task::JoinHandle;
+
+use super::repository::materialize::{MaterializeRecurringRepo, MaterializeRecurringRepoImpl};
+
+const DEFAULT_INTERVAL_SECS: u64 = 600;
+
+/// 반복 기록을 주기적으로 생성하는 백그라운드 작업
+///
+/// 실행 주기는 RECURRING_INTERVAL_SECS 환경변수 (기본 600초)
+pub fn spawn_scheduler(pool: &Arc<PgPool>) -> JoinHandle<()> {
+    let repository = MaterializeRecurringRepoImpl::new(pool);
+    let interval_secs = std::env::var("RECURRING_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+
+            let now = Local::now().naive_local();
+            match repository.materialize_recurring(now).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("recurring records created: {}", count),
+                Err(e) => tracing::error!("recurring scheduler failed: {:?}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,140 @@
+use axum::async_trait;
+
+use crate::{
+    domain::recurring::{dto::request::NewRecurring, repository::save::SaveRecurringRepo},
+    global::errors::CustomError,
+};
+
+pub struct CreateRecurringUsecaseImpl<T>
+where
+    T: SaveRecurringRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait CreateRecurringUsecase: Send + Sync {
+    async fn create_recurring(
+        &self,
+        user_id: i32,
+        new_recurring: NewRecurring,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl<T> CreateRecurringUsecaseImpl<T>
+where
+    T: SaveRecurringRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> CreateRecurringUsecase for CreateRecurringUsecaseImpl<T>
+where
+    T: SaveRecurringRepo,
+{
+    async fn create_recurring(
+        &self,
+        user_id: i32,
+        new_recurring: NewRecurring,
+    ) -> Result<i32, Box<CustomError>> {
+        create_recurring(&self.repository, user_id, new_recurring).await
+    }
+}
+
+async fn create_recurring<T>(
+    repository: &T,
+    user_id: i32,
+    new_recurring: NewRecurring,
+) -> Result<i32, Box<CustomError>>
+where
+    T: SaveRecurringRepo,
+{
+    repository
+        .save_recurring(user_id, new_recurring.to_entity())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::NaiveDateTime;
+    use mockall::{mock, predicate};
+
+    use crate::domain::recurring::{
+        dto::request::NewRecurring,
+        entity::{Cycle, Recurring},
+        repository::save::SaveRecurringRepo,
+        usecase::create::create_recurring,
+    };
+    use crate::global::errors::CustomError;
+
+    mock! {
+        SaveRecurringRepoImpl {}
+
+        #[async_trait]
+        impl SaveRecurringRepo for SaveRecurringRepoImpl {
+            async fn save_recurring(&self, user_id: i32, recurring: Recurring) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _start_dt() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2024-09-25 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_create_recurring_success() {
+        // Arrange
+        let user_id = 1;
+        let new_recurring = NewRecurring::new(1, 16, 17000, Cycle::Monthly, _start_dt())
+            .memo(Some("넷플릭스".to_string()));
+
+        let mut mock_repo = MockSaveRecurringRepoImpl::new();
+        mock_repo
+            .expect_save_recurring()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(new_recurring.to_entity()),
+            )
+            .returning(|_, _| Ok(1));
+
+        // Act
+        let result = create_recurring(&mock_repo, user_id, new_recurring).await;
+        assert!(result.is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_default_rule() {
+        // Arrange
+        let new_recurring = NewRecurring::new(1, 16, 17000, Cycle::Monthly, _start_dt());
+
+        // Act
+        let recurring = new_recurring.to_entity();
+
+        // Assert
+        assert_eq!(recurring.get_cycle_interval(), 1);
+        assert_eq!(recurring.get_next_dt(), &_start_dt());
+    }
+
+    #[tokio::test]
+    async fn check_invalid_rule() {
+        // Arrange
+        let before_start =
+            NaiveDateTime::parse_from_str("2024-09-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        // Act
+        let zero_interval =
+            NewRecurring::new(1, 16, 17000, Cycle::Daily, _start_dt()).cycle_interval(Some(0));
+        let reversed =
+            NewRecurring::new(1, 16, 17000, Cycle::Daily, _start_dt()).end_dt(Some(before_start));
+
+        // Assert
+        assert!(!zero_interval.is_valid());
+        assert!(!reversed.is_valid());
+    }
+}
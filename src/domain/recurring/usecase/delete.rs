@@ -0,0 +1,124 @@
+use axum::async_trait;
+
+use crate::{
+    domain::recurring::repository::delete::DeleteRecurringRepo, global::errors::CustomError,
+};
+
+pub struct DeleteRecurringUsecaseImpl<T>
+where
+    T: DeleteRecurringRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait DeleteRecurringUsecase: Send + Sync {
+    async fn delete_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> DeleteRecurringUsecaseImpl<T>
+where
+    T: DeleteRecurringRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> DeleteRecurringUsecase for DeleteRecurringUsecaseImpl<T>
+where
+    T: DeleteRecurringRepo,
+{
+    async fn delete_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+    ) -> Result<(), Box<CustomError>> {
+        delete_recurring(&self.repository, user_id, recurring_id).await
+    }
+}
+
+async fn delete_recurring<T>(
+    repository: &T,
+    user_id: i32,
+    recurring_id: i32,
+) -> Result<(), Box<CustomError>>
+where
+    T: DeleteRecurringRepo,
+{
+    repository.delete_recurring(user_id, recurring_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::recurring::{
+            repository::delete::DeleteRecurringRepo, usecase::delete::delete_recurring,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        DeleteRecurringRepoImpl {}
+
+        #[async_trait]
+        impl DeleteRecurringRepo for DeleteRecurringRepoImpl {
+            async fn delete_recurring(&self, user_id: i32, recurring_id: i32) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_delete_recurring_success() {
+        // Arrange
+        let user_id = 1;
+        let recurring_id = 1;
+
+        let mut mock_repo = MockDeleteRecurringRepoImpl::new();
+        mock_repo
+            .expect_delete_recurring()
+            .with(predicate::eq(user_id), predicate::eq(recurring_id))
+            .returning(|_, _| Ok(()));
+
+        // Act
+        let result = delete_recurring(&mock_repo, user_id, recurring_id).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_unauthorized() {
+        // Arrange
+        let viewer_id = 2;
+        let recurring_id = 1;
+
+        let mut mock_repo = MockDeleteRecurringRepoImpl::new();
+        mock_repo
+            .expect_delete_recurring()
+            .with(predicate::eq(viewer_id), predicate::eq(recurring_id))
+            .returning(|_, _| {
+                Err(Box::new(CustomError::Unauthorized(
+                    "RecurringRole".to_string(),
+                )))
+            });
+
+        // Act
+        let result = delete_recurring(&mock_repo, viewer_id, recurring_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,159 @@
+use axum::async_trait;
+
+use crate::{
+    domain::recurring::{entity::Recurring, repository::get_recurring::GetRecurringRepo},
+    global::errors::CustomError,
+};
+
+pub struct ReadRecurringUsecaseImpl<T>
+where
+    T: GetRecurringRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait ReadRecurringUsecase: Send + Sync {
+    async fn read_recurrings(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Recurring>, Box<CustomError>>;
+    async fn read_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+    ) -> Result<Recurring, Box<CustomError>>;
+}
+
+impl<T> ReadRecurringUsecaseImpl<T>
+where
+    T: GetRecurringRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> ReadRecurringUsecase for ReadRecurringUsecaseImpl<T>
+where
+    T: GetRecurringRepo,
+{
+    async fn read_recurrings(
+        &self,
+        user_id: i32,
+        book_id: i32,
+    ) -> Result<Vec<Recurring>, Box<CustomError>> {
+        read_recurrings(&self.repository, user_id, book_id).await
+    }
+
+    async fn read_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+    ) -> Result<Recurring, Box<CustomError>> {
+        read_recurring(&self.repository, user_id, recurring_id).await
+    }
+}
+
+async fn read_recurrings<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+) -> Result<Vec<Recurring>, Box<CustomError>>
+where
+    T: GetRecurringRepo,
+{
+    repository.get_list(user_id, book_id).await
+}
+
+async fn read_recurring<T>(
+    repository: &T,
+    user_id: i32,
+    recurring_id: i32,
+) -> Result<Recurring, Box<CustomError>>
+where
+    T: GetRecurringRepo,
+{
+    repository.get_by_id(user_id, recurring_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::NaiveDateTime;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::recurring::{
+            entity::{Cycle, Recurring},
+            repository::get_recurring::GetRecurringRepo,
+            usecase::read::{read_recurring, read_recurrings},
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        GetRecurringRepoImpl {}
+
+        #[async_trait]
+        impl GetRecurringRepo for GetRecurringRepoImpl {
+            async fn get_list(&self, user_id: i32, book_id: i32) -> Result<Vec<Recurring>, Box<CustomError>>;
+            async fn get_by_id(&self, user_id: i32, recurring_id: i32) -> Result<Recurring, Box<CustomError>>;
+        }
+    }
+
+    fn _start_dt() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2024-09-25 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_recurrings_success() {
+        // Arrange
+        let user_id = 1;
+        let book_id = 1;
+
+        let mut mock_repo = MockGetRecurringRepoImpl::new();
+        mock_repo
+            .expect_get_list()
+            .with(predicate::eq(user_id), predicate::eq(book_id))
+            .returning(|_, b| {
+                Ok(vec![
+                    Recurring::new(b, 16, 17000, Cycle::Monthly, _start_dt()),
+                    Recurring::new(b, 9, 3000000, Cycle::Monthly, _start_dt()),
+                ])
+            });
+
+        // Act
+        let result = read_recurrings(&mock_repo, user_id, book_id).await;
+        assert!(result.as_ref().map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        assert_eq!(result.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_read_recurring_not_found() {
+        // Arrange
+        let user_id = 1;
+        let no_id = -32;
+
+        let mut mock_repo = MockGetRecurringRepoImpl::new();
+        mock_repo
+            .expect_get_by_id()
+            .with(predicate::eq(user_id), predicate::eq(no_id))
+            .returning(|_, _| Err(Box::new(CustomError::NotFound("Recurring".to_string()))));
+
+        // Act
+        let result = read_recurring(&mock_repo, user_id, no_id).await;
+
+        // Assert
+        assert!(result.is_err());
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
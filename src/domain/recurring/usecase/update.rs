@@ -0,0 +1,122 @@
+use axum::async_trait;
+
+use crate::{
+    domain::recurring::{dto::request::EditRecurring, repository::update::UpdateRecurringRepo},
+    global::errors::CustomError,
+};
+
+pub struct UpdateRecurringUsecaseImpl<T>
+where
+    T: UpdateRecurringRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait UpdateRecurringUsecase: Send + Sync {
+    async fn update_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+        edit_recurring: EditRecurring,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> UpdateRecurringUsecaseImpl<T>
+where
+    T: UpdateRecurringRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> UpdateRecurringUsecase for UpdateRecurringUsecaseImpl<T>
+where
+    T: UpdateRecurringRepo,
+{
+    async fn update_recurring(
+        &self,
+        user_id: i32,
+        recurring_id: i32,
+        edit_recurring: EditRecurring,
+    ) -> Result<(), Box<CustomError>> {
+        update_recurring(&self.repository, user_id, recurring_id, edit_recurring).await
+    }
+}
+
+async fn update_recurring<T>(
+    repository: &T,
+    user_id: i32,
+    recurring_id: i32,
+    edit_recurring: EditRecurring,
+) -> Result<(), Box<CustomError>>
+where
+    T: UpdateRecurringRepo,
+{
+    repository
+        .update_recurring(user_id, recurring_id, edit_recurring.to_update())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::recurring::{
+            dto::request::EditRecurring, entity::UpdateRecurring,
+            repository::update::UpdateRecurringRepo, usecase::update::update_recurring,
+        },
+        global::{constants::FieldUpdate, errors::CustomError},
+    };
+
+    mock! {
+        UpdateRecurringRepoImpl {}
+
+        #[async_trait]
+        impl UpdateRecurringRepo for UpdateRecurringRepoImpl {
+            async fn update_recurring(&self, user_id: i32, recurring_id: i32, edit_recurring: UpdateRecurring) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_pause_recurring_success() {
+        // Arrange
+        let user_id = 1;
+        let recurring_id = 1;
+        let edit_recurring = EditRecurring::new(None, None, None, None, None, Some(true));
+
+        let mut mock_repo = MockUpdateRecurringRepoImpl::new();
+        mock_repo
+            .expect_update_recurring()
+            .with(
+                predicate::eq(user_id),
+                predicate::eq(recurring_id),
+                predicate::eq(edit_recurring.clone().to_update()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        // Act
+        let result = update_recurring(&mock_repo, user_id, recurring_id, edit_recurring).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_memo_set_none() {
+        // Arrange
+        let edit_recurring =
+            EditRecurring::new(None, None, Some("NULL".to_string()), None, None, None);
+
+        // Act
+        let update = edit_recurring.to_update();
+
+        // Assert
+        assert_eq!(update.get_memo(), &FieldUpdate::SetNone);
+        assert_eq!(update.get_paused(), &FieldUpdate::NoChange);
+    }
+}
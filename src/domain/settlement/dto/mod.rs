@@ -0,0 +1,2 @@
+pub(super) mod request;
+pub(super) mod response;
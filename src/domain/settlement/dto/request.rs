@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use chrono::{Days, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::settlement::entity::{RecordSplit, SplitType},
+    global::errors::CustomError,
+};
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct ShareItem {
+    user_id: i32,
+    value: Option<i32>,
+}
+
+impl ShareItem {
+    pub fn new(user_id: i32, value: Option<i32>) -> Self {
+        Self { user_id, value }
+    }
+}
+
+/// 결제자와 분담 방식: equal은 value 생략, percent는 합계 100, fixed는 금액
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct EditSplit {
+    payer_id: i32,
+    split_type: String,
+    shares: Vec<ShareItem>,
+}
+
+impl EditSplit {
+    pub fn new(payer_id: i32, split_type: String, shares: Vec<ShareItem>) -> Self {
+        Self {
+            payer_id,
+            split_type,
+            shares,
+        }
+    }
+
+    pub fn to_entity(&self) -> Result<RecordSplit, Box<CustomError>> {
+        let invalid = || Box::new(CustomError::ValidationError("RecordSplit".to_string()));
+        let split_type: SplitType = self.split_type.parse()?;
+
+        let user_ids: HashSet<i32> = self.shares.iter().map(|s| s.user_id).collect();
+        if self.shares.is_empty() || user_ids.len() != self.shares.len() {
+            return Err(invalid());
+        }
+
+        let shares = self
+            .shares
+            .iter()
+            .map(|share| match (split_type, share.value) {
+                (SplitType::Equal, _) => Ok((share.user_id, 0)),
+                (SplitType::Percent, Some(value)) if (0..=100).contains(&value) => {
+                    Ok((share.user_id, value))
+                }
+                (SplitType::Fixed, Some(value)) if value >= 0 => Ok((share.user_id, value)),
+                _ => Err(invalid()),
+            })
+            .collect::<Result<Vec<(i32, i32)>, Box<CustomError>>>()?;
+
+        if split_type == SplitType::Percent && shares.iter().map(|(_, v)| v).sum::<i32>() != 100 {
+            return Err(invalid());
+        }
+
+        Ok(RecordSplit::new(self.payer_id, split_type, shares))
+    }
+}
+
+/// 정산 기간, 종료일 포함
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq)]
+pub struct SettlementPeriod {
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+}
+
+impl SettlementPeriod {
+    pub fn new(start_dt: NaiveDate, end_dt: NaiveDate) -> Self {
+        Self { start_dt, end_dt }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.start_dt <= self.end_dt
+    }
+
+    pub fn get_start_dt(&self) -> NaiveDate {
+        self.start_dt
+    }
+    pub fn get_end_dt(&self) -> NaiveDate {
+        self.end_dt
+    }
+
+    // [시작일 0시, 종료일 다음 날 0시)
+    pub fn get_range(&self) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let end_dt = self.end_dt.checked_add_days(Days::new(1))?;
+        Some((
+            self.start_dt.and_hms_opt(0, 0, 0)?,
+            end_dt.and_hms_opt(0, 0, 0)?,
+        ))
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// 구성원별 결제 금액, 분담 금액, 받을 금액(음수면 줄 금액)
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct MemberBalance {
+    user_id: i32,
+    paid: i64,
+    owed: i64,
+    net: i64,
+}
+
+impl MemberBalance {
+    pub fn new(user_id: i32, paid: i64, owed: i64) -> Self {
+        Self {
+            user_id,
+            paid,
+            owed,
+            net: paid - owed,
+        }
+    }
+
+    pub fn get_user_id(&self) -> i32 {
+        self.user_id
+    }
+    pub fn get_net(&self) -> i64 {
+        self.net
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Transfer {
+    from_id: i32,
+    to_id: i32,
+    amount: i64,
+}
+
+impl Transfer {
+    pub fn new(from_id: i32, to_id: i32, amount: i64) -> Self {
+        Self {
+            from_id,
+            to_id,
+            amount,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct SettlementBalance {
+    start_dt: NaiveDate,
+    end_dt: NaiveDate,
+    members: Vec<MemberBalance>,
+    transfers: Vec<Transfer>,
+}
+
+impl SettlementBalance {
+    pub fn new(
+        start_dt: NaiveDate,
+        end_dt: NaiveDate,
+        members: Vec<MemberBalance>,
+        transfers: Vec<Transfer>,
+    ) -> Self {
+        Self {
+            start_dt,
+            end_dt,
+            members,
+            transfers,
+        }
+    }
+
+    pub fn get_members(&self) -> &[MemberBalance] {
+        &self.members
+    }
+    pub fn get_transfers(&self) -> &[Transfer] {
+        &self.transfers
+    }
+}
@@ -0,0 +1,118 @@
+use std::{fmt, str::FromStr};
+
+use crate::global::errors::CustomError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplitType {
+    Equal,
+    Percent,
+    Fixed,
+}
+
+impl FromStr for SplitType {
+    type Err = Box<CustomError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "equal" => Ok(SplitType::Equal),
+            "percent" => Ok(SplitType::Percent),
+            "fixed" => Ok(SplitType::Fixed),
+            _ => Err(Box::new(CustomError::ValidationError(
+                "SplitType".to_string(),
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for SplitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let split_type = match self {
+            SplitType::Equal => "equal",
+            SplitType::Percent => "percent",
+            SplitType::Fixed => "fixed",
+        };
+        write!(f, "{}", split_type)
+    }
+}
+
+/// 기록 하나의 결제자와 분담 구성원
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordSplit {
+    payer_id: i32,
+    split_type: SplitType,
+    // (user_id, value), user_id 오름차순
+    shares: Vec<(i32, i32)>,
+}
+
+impl RecordSplit {
+    pub fn new(payer_id: i32, split_type: SplitType, mut shares: Vec<(i32, i32)>) -> Self {
+        shares.sort();
+        Self {
+            payer_id,
+            split_type,
+            shares,
+        }
+    }
+
+    pub fn get_payer_id(&self) -> i32 {
+        self.payer_id
+    }
+    pub fn get_split_type(&self) -> SplitType {
+        self.split_type
+    }
+    pub fn get_shares(&self) -> &[(i32, i32)] {
+        &self.shares
+    }
+    pub fn get_user_ids(&self) -> Vec<i32> {
+        self.shares.iter().map(|(user_id, _)| *user_id).collect()
+    }
+}
+
+/// 정산 대상 기록의 분담 구성원 한 행
+#[derive(Debug, sqlx::FromRow, Clone, PartialEq)]
+pub struct SplitRow {
+    record_id: i64,
+    amount: i32,
+    payer_id: i32,
+    split_type: String,
+    user_id: i32,
+    value: i32,
+}
+
+impl SplitRow {
+    pub fn new(
+        record_id: i64,
+        amount: i32,
+        payer_id: i32,
+        split_type: SplitType,
+        user_id: i32,
+        value: i32,
+    ) -> Self {
+        Self {
+            record_id,
+            amount,
+            payer_id,
+            split_type: split_type.to_string(),
+            user_id,
+            value,
+        }
+    }
+
+    pub fn get_record_id(&self) -> i64 {
+        self.record_id
+    }
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+    pub fn get_payer_id(&self) -> i32 {
+        self.payer_id
+    }
+    pub fn get_split_type(&self) -> &str {
+        &self.split_type
+    }
+    pub fn get_user_id(&self) -> i32 {
+        self.user_id
+    }
+    pub fn get_value(&self) -> i32 {
+        self.value
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, patch},
+    Extension, Router,
+};
+
+use sqlx::PgPool;
+
+mod settlement;
+mod split;
+
+use settlement::{read_balance, settle};
+use split::update_split;
+
+use super::{
+    repository::{settlement::SettlementRepoImpl, split::RecordSplitRepoImpl},
+    usecase::{settlement::SettlementUsecaseImpl, split::RecordSplitUsecaseImpl},
+};
+
+pub fn split_router(pool: &Arc<PgPool>) -> Router {
+    let repository = RecordSplitRepoImpl::new(pool);
+    let usecase = RecordSplitUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/record/:record_id",
+            patch(update_split::<RecordSplitUsecaseImpl<RecordSplitRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
+
+pub fn settlement_router(pool: &Arc<PgPool>) -> Router {
+    let repository = SettlementRepoImpl::new(pool);
+    let usecase = SettlementUsecaseImpl::new(repository);
+
+    Router::new()
+        .route(
+            "/:book_id",
+            get(read_balance::<SettlementUsecaseImpl<SettlementRepoImpl>>)
+                .post(settle::<SettlementUsecaseImpl<SettlementRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
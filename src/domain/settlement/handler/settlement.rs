@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    response::IntoResponse,
+    Extension, Json,
+};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    domain::settlement::{dto::request::SettlementPeriod, usecase::settlement::SettlementUsecase},
+    global::errors::CustomError,
+};
+
+pub async fn read_balance<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    period: Query<SettlementPeriod>,
+) -> impl IntoResponse
+where
+    T: SettlementUsecase,
+{
+    if !period.is_valid() {
+        return CustomError::ValidationError("SettlementPeriod".to_string()).into_response();
+    }
+
+    match usecase.read_balance(user_id, book_id, period.0).await {
+        Ok(balance) => (StatusCode::OK, Json(json!(balance))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn settle<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(book_id): Path<i32>,
+    Json(period): Json<SettlementPeriod>,
+) -> impl IntoResponse
+where
+    T: SettlementUsecase,
+{
+    tracing::debug!("CALL: Settle Book");
+
+    if !period.is_valid() {
+        return CustomError::ValidationError("SettlementPeriod".to_string()).into_response();
+    }
+
+    match usecase.settle(user_id, book_id, period).await {
+        Ok(id) => (
+            StatusCode::CREATED,
+            Json(json!({"message": "정산 완료", "settlement_id": id})),
+        )
+            .into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::get, Extension, Router};
+    use chrono::NaiveDate;
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{to_string, Value};
+    use tower::ServiceExt;
+
+    use super::{read_balance, settle};
+    use crate::{
+        domain::settlement::{
+            dto::{
+                request::SettlementPeriod,
+                response::{MemberBalance, SettlementBalance, Transfer},
+            },
+            usecase::settlement::SettlementUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        SettlementUsecaseImpl {}
+
+        #[async_trait]
+        impl SettlementUsecase for SettlementUsecaseImpl {
+            async fn read_balance(&self, user_id: i32, book_id: i32, period: SettlementPeriod) -> Result<SettlementBalance, Box<CustomError>>;
+            async fn settle(&self, user_id: i32, book_id: i32, period: SettlementPeriod) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _get_router(user_id: i32, mock_usecase: MockSettlementUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/settlement/:book_id",
+                get(read_balance::<MockSettlementUsecaseImpl>)
+                    .post(settle::<MockSettlementUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_read_balance() {
+        // Arrange
+        let period = SettlementPeriod::new(_date(2024, 9, 1), _date(2024, 9, 30));
+        let mut mock_usecase = MockSettlementUsecaseImpl::new();
+        mock_usecase
+            .expect_read_balance()
+            .with(predicate::eq(1), predicate::eq(5), predicate::eq(period))
+            .returning(|_, _, period| {
+                Ok(SettlementBalance::new(
+                    period.get_start_dt(),
+                    period.get_end_dt(),
+                    vec![
+                        MemberBalance::new(1, 10000, 5000),
+                        MemberBalance::new(3, 0, 5000),
+                    ],
+                    vec![Transfer::new(3, 1, 5000)],
+                ))
+            });
+
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/settlement/5?start_dt=2024-09-01&end_dt=2024-09-30")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body["members"][0]["net"], 5000);
+        assert_eq!(body["transfers"][0]["from_id"], 3);
+        assert_eq!(body["transfers"][0]["to_id"], 1);
+    }
+
+    #[tokio::test]
+    async fn check_read_balance_invalid_period() {
+        // Arrange
+        let mut mock_usecase = MockSettlementUsecaseImpl::new();
+        mock_usecase.expect_read_balance().never();
+
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/settlement/5?start_dt=2024-09-30&end_dt=2024-09-01")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn check_settle() {
+        // Arrange
+        let period = SettlementPeriod::new(_date(2024, 9, 1), _date(2024, 9, 30));
+        let mut mock_usecase = MockSettlementUsecaseImpl::new();
+        mock_usecase
+            .expect_settle()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(period.clone()),
+            )
+            .returning(|_, _, _| Ok(2));
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/settlement/5")
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(&period).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = _get_router(1, mock_usecase).oneshot(req).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        // Assert
+        assert_eq!(body["settlement_id"], 2);
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::settlement::{dto::request::EditSplit, usecase::split::RecordSplitUsecase};
+
+pub async fn update_split<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(record_id): Path<i64>,
+    Json(edit_split): Json<EditSplit>,
+) -> impl IntoResponse
+where
+    T: RecordSplitUsecase,
+{
+    tracing::debug!("CALL: Update Record Split");
+
+    match usecase.update_split(user_id, record_id, edit_split).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{async_trait, body::Body, extract::Request, routing::patch, Extension, Router};
+    use mockall::{mock, predicate};
+    use serde_json::to_string;
+    use tower::ServiceExt;
+
+    use super::update_split;
+    use crate::{
+        domain::settlement::{
+            dto::request::{EditSplit, ShareItem},
+            usecase::split::RecordSplitUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        RecordSplitUsecaseImpl {}
+
+        #[async_trait]
+        impl RecordSplitUsecase for RecordSplitUsecaseImpl {
+            async fn update_split(&self, user_id: i32, record_id: i64, edit_split: EditSplit) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    fn _get_router(user_id: i32, mock_usecase: MockRecordSplitUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/settlement/record/:record_id",
+                patch(update_split::<MockRecordSplitUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    fn _get_request(record_id: i64, edit_split: &EditSplit) -> Request<Body> {
+        Request::builder()
+            .method("PATCH")
+            .uri(format!("/api/v1/settlement/record/{}", record_id))
+            .header("content-type", "application/json")
+            .body(Body::from(to_string(edit_split).unwrap()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_update_split() {
+        // Arrange
+        let edit_split = EditSplit::new(
+            1,
+            "equal".to_string(),
+            vec![ShareItem::new(1, None), ShareItem::new(3, None)],
+        );
+        let mut mock_usecase = MockRecordSplitUsecaseImpl::new();
+        mock_usecase
+            .expect_update_split()
+            .with(
+                predicate::eq(1),
+                predicate::eq(10),
+                predicate::eq(edit_split.clone()),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        // Act
+        let response = _get_router(1, mock_usecase)
+            .oneshot(_get_request(10, &edit_split))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn check_update_split_not_couple() {
+        // Arrange
+        let edit_split = EditSplit::new(1, "equal".to_string(), vec![ShareItem::new(1, None)]);
+        let mut mock_usecase = MockRecordSplitUsecaseImpl::new();
+        mock_usecase.expect_update_split().returning(|_, _, _| {
+            Err(Box::new(CustomError::ValidationError(
+                "BookType".to_string(),
+            )))
+        });
+
+        // Act
+        let response = _get_router(1, mock_usecase)
+            .oneshot(_get_request(10, &edit_split))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -0,0 +1,385 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::{domain::settlement::entity::SplitRow, global::errors::CustomError};
+
+pub struct SettlementRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait SettlementRepo: Send + Sync {
+    async fn get_split_rows(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<SplitRow>, Box<CustomError>>;
+    async fn save_settlement(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl SettlementRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl SettlementRepo for SettlementRepoImpl {
+    async fn get_split_rows(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<SplitRow>, Box<CustomError>> {
+        get_split_rows(&self.pool, user_id, book_id, start_dt, end_dt).await
+    }
+    async fn save_settlement(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<i32, Box<CustomError>> {
+        save_settlement(&self.pool, user_id, book_id, start_dt, end_dt).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CoupleBook {
+    is_couple: bool,
+    role: Option<String>,
+}
+
+/// 커플 가계부인지, 요청한 사용자의 권한 확인
+async fn check_couple_book(
+    tx: &mut Transaction<'_, Postgres>,
+    user_id: i32,
+    book_id: i32,
+    is_write: bool,
+) -> Result<(), Box<CustomError>> {
+    let book = sqlx::query_as::<_, CoupleBook>(
+        "
+        SELECT bt.name = '커플' AS is_couple, br.role
+        FROM tb_book AS b
+        JOIN tb_book_type AS bt ON bt.id = b.type_id
+        LEFT JOIN tb_user_book_role AS br ON br.book_id = b.id AND br.user_id = $1
+        WHERE b.id = $2
+        FOR UPDATE OF b
+    ",
+    )
+    .bind(user_id)
+    .bind(book_id)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| to_custom_error("CheckCoupleBook", e))?;
+
+    let book = match book {
+        Some(book) => book,
+        None => return Err(Box::new(CustomError::NotFound("Book".to_string()))),
+    };
+
+    match book.role.as_deref() {
+        None => return Err(Box::new(CustomError::Unauthorized("BookRole".to_string()))),
+        Some("viewer") if is_write => {
+            return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())))
+        }
+        _ => {}
+    }
+    if !book.is_couple {
+        return Err(Box::new(CustomError::ValidationError(
+            "BookType".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+/// 기간 내 분담 정보가 있는 기록, 이미 정산된 기간의 기록은 제외
+pub async fn get_split_rows(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    start_dt: NaiveDateTime,
+    end_dt: NaiveDateTime,
+) -> Result<Vec<SplitRow>, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("GetSplitRows", e))?;
+
+    check_couple_book(&mut tx, user_id, book_id, false).await?;
+
+    let rows = sqlx::query_as::<_, SplitRow>(
+        "
+        SELECT r.id AS record_id, r.amount, rs.payer_id, rs.split_type, sh.user_id, sh.value
+        FROM tb_record AS r
+        JOIN tb_record_split AS rs ON rs.record_id = r.id
+        JOIN tb_record_share AS sh ON sh.record_id = r.id
+        WHERE r.book_id = $1
+            AND r.transfer_id IS NULL
+            AND r.target_dt >= $2 AND r.target_dt < $3
+            AND NOT EXISTS (
+                SELECT 1
+                FROM tb_settlement AS s
+                WHERE s.book_id = r.book_id
+                    AND r.target_dt >= s.start_dt AND r.target_dt < s.end_dt
+            )
+        ORDER BY r.id, sh.user_id
+    ",
+    )
+    .bind(book_id)
+    .bind(start_dt)
+    .bind(end_dt)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("GetSplitRows", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("GetSplitRows", e))?;
+
+    Ok(rows)
+}
+
+/// 정산 완료 기간 저장, 이미 정산된 기간과 겹치면 Duplicated
+pub async fn save_settlement(
+    pool: &PgPool,
+    user_id: i32,
+    book_id: i32,
+    start_dt: NaiveDateTime,
+    end_dt: NaiveDateTime,
+) -> Result<i32, Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("SaveSettlement", e))?;
+
+    // 가계부 행 잠금으로 겹치는 기간이 동시에 저장되지 않음
+    check_couple_book(&mut tx, user_id, book_id, true).await?;
+
+    let settlement_id = sqlx::query_scalar::<_, i32>(
+        "
+        INSERT INTO tb_settlement (book_id, start_dt, end_dt, settled_by)
+        SELECT $1, $2, $3, $4
+        WHERE NOT EXISTS (
+            SELECT 1
+            FROM tb_settlement
+            WHERE book_id = $1 AND start_dt < $3 AND $2 < end_dt
+        )
+        RETURNING id
+    ",
+    )
+    .bind(book_id)
+    .bind(start_dt)
+    .bind(end_dt)
+    .bind(user_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("SaveSettlement", e))?;
+
+    let settlement_id = match settlement_id {
+        Some(id) => id,
+        None => return Err(Box::new(CustomError::Duplicated("Settlement".to_string()))),
+    };
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("SaveSettlement", e))?;
+
+    Ok(settlement_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::settlement::{
+            entity::{RecordSplit, SplitType},
+            repository::{
+                settlement::{get_split_rows, save_settlement},
+                split::save_split,
+            },
+        },
+        global::errors::CustomError,
+    };
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    // 1번(소유자), 3번(편집자) 사용자가 있는 커플 가계부
+    async fn _couple_book(pool: &PgPool, name: &str) -> i32 {
+        let book_id: i32 =
+            sqlx::query_scalar("INSERT INTO tb_book (name, type_id) VALUES ($1, 2) RETURNING id")
+                .bind(name)
+                .fetch_one(pool)
+                .await
+                .unwrap();
+        sqlx::query(
+            "INSERT INTO tb_user_book_role (user_id, book_id, role) VALUES (1, $1, 'owner'), (3, $1, 'editor')",
+        )
+        .bind(book_id)
+        .execute(pool)
+        .await
+        .unwrap();
+        book_id
+    }
+
+    async fn _split_record(pool: &PgPool, book_id: i32, amount: i32, target_dt: &str) {
+        let record_id: i64 = sqlx::query_scalar(
+            "INSERT INTO tb_record (book_id, sub_category_id, amount, target_dt) VALUES ($1, 1, $2, $3) RETURNING id",
+        )
+        .bind(book_id)
+        .bind(amount)
+        .bind(_parse(target_dt))
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        let split = RecordSplit::new(1, SplitType::Equal, vec![(1, 0), (3, 0)]);
+        save_split(pool, 1, record_id, split).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_get_split_rows() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _couple_book(&pool, "정산 조회 가계부").await;
+        _split_record(&pool, book_id, 10000, "2024-09-01 12:00:00").await;
+        _split_record(&pool, book_id, 20000, "2024-10-01 12:00:00").await;
+
+        // Act
+        let result = get_split_rows(
+            &pool,
+            3,
+            book_id,
+            _parse("2024-09-01 00:00:00"),
+            _parse("2024-10-01 00:00:00"),
+        )
+        .await;
+
+        // Assert
+        let rows = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get_amount(), 10000);
+        assert_eq!(rows[1].get_user_id(), 3);
+    }
+
+    #[tokio::test]
+    async fn check_save_settlement() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _couple_book(&pool, "정산 완료 가계부").await;
+        _split_record(&pool, book_id, 10000, "2024-09-01 12:00:00").await;
+        let (start_dt, end_dt) = (_parse("2024-09-01 00:00:00"), _parse("2024-10-01 00:00:00"));
+
+        // Act
+        let result = save_settlement(&pool, 1, book_id, start_dt, end_dt).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        // 정산된 기간의 기록은 제외
+        let rows = get_split_rows(&pool, 1, book_id, start_dt, end_dt)
+            .await
+            .unwrap();
+        assert!(rows.is_empty());
+
+        // 겹치는 기간은 다시 정산할 수 없음
+        let result = save_settlement(
+            &pool,
+            1,
+            book_id,
+            _parse("2024-09-15 00:00:00"),
+            _parse("2024-10-15 00:00:00"),
+        )
+        .await;
+        let err_type = match *result.err().unwrap() {
+            CustomError::Duplicated(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_save_settlement_no_role() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let book_id = _couple_book(&pool, "권한 없는 정산").await;
+
+        // Act
+        // ref) init.sql, 2번 사용자는 구성원이 아님
+        let result = save_settlement(
+            &pool,
+            2,
+            book_id,
+            _parse("2024-09-01 00:00:00"),
+            _parse("2024-10-01 00:00:00"),
+        )
+        .await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_get_split_rows_not_couple() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // Act
+        // ref) init.sql, 1번 가계부는 개인 가계부
+        let result = get_split_rows(
+            &pool,
+            1,
+            1,
+            _parse("2024-09-01 00:00:00"),
+            _parse("2024-10-01 00:00:00"),
+        )
+        .await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
@@ -0,0 +1,323 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::{
+    domain::settlement::entity::{RecordSplit, SplitType},
+    global::errors::CustomError,
+};
+
+pub struct RecordSplitRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait RecordSplitRepo: Send + Sync {
+    async fn save_split(
+        &self,
+        user_id: i32,
+        record_id: i64,
+        split: RecordSplit,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl RecordSplitRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl RecordSplitRepo for RecordSplitRepoImpl {
+    async fn save_split(
+        &self,
+        user_id: i32,
+        record_id: i64,
+        split: RecordSplit,
+    ) -> Result<(), Box<CustomError>> {
+        save_split(&self.pool, user_id, record_id, split).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Box<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Box::new(err)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct SplitTarget {
+    book_id: i32,
+    amount: i32,
+    is_transfer: bool,
+    is_couple: bool,
+    role: Option<String>,
+}
+
+/// 기록의 결제자, 분담 구성원을 새로 저장
+pub async fn save_split(
+    pool: &PgPool,
+    user_id: i32,
+    record_id: i64,
+    split: RecordSplit,
+) -> Result<(), Box<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("SaveRecordSplit", e))?;
+
+    let target = sqlx::query_as::<_, SplitTarget>(
+        "
+        SELECT
+            r.book_id,
+            r.amount,
+            r.transfer_id IS NOT NULL AS is_transfer,
+            bt.name = '커플' AS is_couple,
+            br.role
+        FROM tb_record AS r
+        JOIN tb_book AS b ON b.id = r.book_id
+        JOIN tb_book_type AS bt ON bt.id = b.type_id
+        LEFT JOIN tb_user_book_role AS br ON br.book_id = r.book_id AND br.user_id = $1
+        WHERE r.id = $2
+        FOR UPDATE OF r
+    ",
+    )
+    .bind(user_id)
+    .bind(record_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("SaveRecordSplit", e))?;
+
+    let target = match target {
+        Some(target) => target,
+        None => return Err(Box::new(CustomError::NotFound("Record".to_string()))),
+    };
+
+    match target.role.as_deref() {
+        Some("viewer") | None => {
+            return Err(Box::new(CustomError::Unauthorized("BookRole".to_string())))
+        }
+        _ => {}
+    }
+    if !target.is_couple {
+        return Err(Box::new(CustomError::ValidationError(
+            "BookType".to_string(),
+        )));
+    } else if target.is_transfer {
+        return Err(Box::new(CustomError::ValidationError("Record".to_string())));
+    }
+
+    let fixed_sum: i64 = split.get_shares().iter().map(|(_, v)| *v as i64).sum();
+    if split.get_split_type() == SplitType::Fixed && fixed_sum != target.amount as i64 {
+        return Err(Box::new(CustomError::ValidationError(
+            "RecordSplit".to_string(),
+        )));
+    }
+
+    // 결제자, 분담 구성원 모두 가계부 구성원이어야 함
+    let mut user_ids = split.get_user_ids();
+    user_ids.push(split.get_payer_id());
+    user_ids.sort();
+    user_ids.dedup();
+
+    let member_count = sqlx::query_scalar::<_, i64>(
+        "
+        SELECT COUNT(*)
+        FROM tb_user_book_role
+        WHERE book_id = $1 AND user_id = ANY($2)
+    ",
+    )
+    .bind(target.book_id)
+    .bind(&user_ids)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("SaveRecordSplit", e))?;
+
+    if member_count != user_ids.len() as i64 {
+        return Err(Box::new(CustomError::NotFound("BookMember".to_string())));
+    }
+
+    sqlx::query(
+        "
+        INSERT INTO tb_record_split (record_id, payer_id, split_type)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (record_id)
+        DO UPDATE SET payer_id = EXCLUDED.payer_id, split_type = EXCLUDED.split_type
+    ",
+    )
+    .bind(record_id)
+    .bind(split.get_payer_id())
+    .bind(split.get_split_type().to_string())
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("SaveRecordSplit", e))?;
+
+    sqlx::query("DELETE FROM tb_record_share WHERE record_id = $1")
+        .bind(record_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| to_custom_error("SaveRecordSplit", e))?;
+
+    let (share_ids, values): (Vec<i32>, Vec<i32>) = split.get_shares().iter().cloned().unzip();
+    sqlx::query(
+        "
+        INSERT INTO tb_record_share (record_id, user_id, value)
+        SELECT $1, user_id, value
+        FROM UNNEST($2::INT[], $3::INT[]) AS s(user_id, value)
+    ",
+    )
+    .bind(record_id)
+    .bind(&share_ids)
+    .bind(&values)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("SaveRecordSplit", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("SaveRecordSplit", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::settlement::{
+            entity::{RecordSplit, SplitType},
+            repository::split::save_split,
+        },
+        global::errors::CustomError,
+    };
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    // 1번(소유자), 3번(편집자) 사용자가 있는 가계부와 기록 하나
+    async fn _new_record(pool: &PgPool, name: &str, type_id: i16, amount: i32) -> i64 {
+        let book_id: i32 =
+            sqlx::query_scalar("INSERT INTO tb_book (name, type_id) VALUES ($1, $2) RETURNING id")
+                .bind(name)
+                .bind(type_id)
+                .fetch_one(pool)
+                .await
+                .unwrap();
+        sqlx::query(
+            "INSERT INTO tb_user_book_role (user_id, book_id, role) VALUES (1, $1, 'owner'), (3, $1, 'editor')",
+        )
+        .bind(book_id)
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query_scalar(
+            "INSERT INTO tb_record (book_id, sub_category_id, amount, target_dt) VALUES ($1, 1, $2, NOW()) RETURNING id",
+        )
+        .bind(book_id)
+        .bind(amount)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_save_split_success() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let record_id = _new_record(&pool, "분담 가계부", 2, 10000).await;
+        let split = RecordSplit::new(1, SplitType::Percent, vec![(1, 40), (3, 60)]);
+
+        // Act
+        let result = save_split(&pool, 3, record_id, split).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // 다시 저장하면 덮어씀
+        let split = RecordSplit::new(3, SplitType::Equal, vec![(1, 0), (3, 0)]);
+        let result = save_split(&pool, 1, record_id, split).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let (payer_id, count): (i32, i64) = sqlx::query_as(
+            "
+            SELECT rs.payer_id, (SELECT COUNT(*) FROM tb_record_share WHERE record_id = $1)
+            FROM tb_record_split AS rs
+            WHERE rs.record_id = $1
+        ",
+        )
+        .bind(record_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(payer_id, 3);
+        assert_eq!(count, 2);
+    }
+
+    #[tokio::test]
+    async fn check_save_split_not_member() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let record_id = _new_record(&pool, "비구성원 가계부", 2, 10000).await;
+        // ref) init.sql, 2번 사용자는 이 가계부의 구성원이 아님
+        let split = RecordSplit::new(1, SplitType::Equal, vec![(1, 0), (2, 0)]);
+
+        // Act
+        let result = save_split(&pool, 1, record_id, split).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_save_split_fixed_sum() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let record_id = _new_record(&pool, "고정 분담 가계부", 2, 10000).await;
+        let split = RecordSplit::new(1, SplitType::Fixed, vec![(1, 3000), (3, 3000)]);
+
+        // Act
+        let result = save_split(&pool, 1, record_id, split).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_save_split_not_couple() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let record_id = _new_record(&pool, "개인 분담 가계부", 1, 10000).await;
+        let split = RecordSplit::new(1, SplitType::Equal, vec![(1, 0)]);
+
+        // Act
+        let result = save_split(&pool, 1, record_id, split).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
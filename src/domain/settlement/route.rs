@@ -0,0 +1,12 @@
+use std::sync::Arc;
+
+use axum::Router;
+use sqlx::PgPool;
+
+use super::handler::{settlement_router, split_router};
+
+pub fn get_router(pool: &Arc<PgPool>) -> Router {
+    Router::new()
+        .merge(split_router(pool))
+        .merge(settlement_router(pool))
+}
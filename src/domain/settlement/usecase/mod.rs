@@ -0,0 +1,2 @@
+pub(super) mod settlement;
+pub(super) mod split;
@@ -0,0 +1,211 @@
+use axum::async_trait;
+
+use crate::{
+    domain::settlement::{
+        dto::{request::SettlementPeriod, response::SettlementBalance},
+        repository::settlement::SettlementRepo,
+        utils::balance::{sum_balances, to_transfers},
+    },
+    global::errors::CustomError,
+};
+
+pub struct SettlementUsecaseImpl<T>
+where
+    T: SettlementRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait SettlementUsecase: Send + Sync {
+    async fn read_balance(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: SettlementPeriod,
+    ) -> Result<SettlementBalance, Box<CustomError>>;
+    async fn settle(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: SettlementPeriod,
+    ) -> Result<i32, Box<CustomError>>;
+}
+
+impl<T> SettlementUsecaseImpl<T>
+where
+    T: SettlementRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> SettlementUsecase for SettlementUsecaseImpl<T>
+where
+    T: SettlementRepo,
+{
+    async fn read_balance(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: SettlementPeriod,
+    ) -> Result<SettlementBalance, Box<CustomError>> {
+        read_balance(&self.repository, user_id, book_id, period).await
+    }
+    async fn settle(
+        &self,
+        user_id: i32,
+        book_id: i32,
+        period: SettlementPeriod,
+    ) -> Result<i32, Box<CustomError>> {
+        settle(&self.repository, user_id, book_id, period).await
+    }
+}
+
+async fn read_balance<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    period: SettlementPeriod,
+) -> Result<SettlementBalance, Box<CustomError>>
+where
+    T: SettlementRepo,
+{
+    let (start_dt, end_dt) = period
+        .get_range()
+        .ok_or(Box::new(CustomError::ValidationError(
+            "SettlementPeriod".to_string(),
+        )))?;
+
+    let rows = repository
+        .get_split_rows(user_id, book_id, start_dt, end_dt)
+        .await?;
+    let members = sum_balances(&rows)?;
+    let transfers = to_transfers(&members);
+
+    Ok(SettlementBalance::new(
+        period.get_start_dt(),
+        period.get_end_dt(),
+        members,
+        transfers,
+    ))
+}
+
+async fn settle<T>(
+    repository: &T,
+    user_id: i32,
+    book_id: i32,
+    period: SettlementPeriod,
+) -> Result<i32, Box<CustomError>>
+where
+    T: SettlementRepo,
+{
+    let (start_dt, end_dt) = period
+        .get_range()
+        .ok_or(Box::new(CustomError::ValidationError(
+            "SettlementPeriod".to_string(),
+        )))?;
+
+    repository
+        .save_settlement(user_id, book_id, start_dt, end_dt)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::settlement::{
+            dto::{
+                request::SettlementPeriod,
+                response::{MemberBalance, Transfer},
+            },
+            entity::{SplitRow, SplitType},
+            repository::settlement::SettlementRepo,
+            usecase::settlement::{read_balance, settle},
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        SettlementRepoImpl {}
+
+        #[async_trait]
+        impl SettlementRepo for SettlementRepoImpl {
+            async fn get_split_rows(&self, user_id: i32, book_id: i32, start_dt: NaiveDateTime, end_dt: NaiveDateTime) -> Result<Vec<SplitRow>, Box<CustomError>>;
+            async fn save_settlement(&self, user_id: i32, book_id: i32, start_dt: NaiveDateTime, end_dt: NaiveDateTime) -> Result<i32, Box<CustomError>>;
+        }
+    }
+
+    fn _parse(dt: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(dt, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn _period() -> SettlementPeriod {
+        SettlementPeriod::new(
+            NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 30).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn check_read_balance() {
+        // Arrange
+        let mut mock_repo = MockSettlementRepoImpl::new();
+        mock_repo
+            .expect_get_split_rows()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(_parse("2024-09-01 00:00:00")),
+                predicate::eq(_parse("2024-10-01 00:00:00")),
+            )
+            .returning(|_, _, _, _| {
+                Ok(vec![
+                    SplitRow::new(1, 10000, 1, SplitType::Equal, 1, 0),
+                    SplitRow::new(1, 10000, 1, SplitType::Equal, 3, 0),
+                    SplitRow::new(2, 4000, 3, SplitType::Percent, 1, 100),
+                ])
+            });
+
+        // Act
+        let result = read_balance(&mock_repo, 1, 5, _period()).await;
+
+        // Assert
+        let balance = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(
+            balance.get_members(),
+            &[
+                MemberBalance::new(1, 10000, 9000),
+                MemberBalance::new(3, 4000, 5000),
+            ]
+        );
+        assert_eq!(balance.get_transfers(), &[Transfer::new(3, 1, 1000)]);
+    }
+
+    #[tokio::test]
+    async fn check_settle() {
+        // Arrange
+        let mut mock_repo = MockSettlementRepoImpl::new();
+        mock_repo
+            .expect_save_settlement()
+            .with(
+                predicate::eq(1),
+                predicate::eq(5),
+                predicate::eq(_parse("2024-09-01 00:00:00")),
+                predicate::eq(_parse("2024-10-01 00:00:00")),
+            )
+            .returning(|_, _, _, _| Ok(2));
+
+        // Act
+        let result = settle(&mock_repo, 1, 5, _period()).await;
+
+        // Assert
+        assert_eq!(result.map_err(|e| println!("{:?}", e)).unwrap(), 2);
+    }
+}
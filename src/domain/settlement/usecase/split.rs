@@ -0,0 +1,152 @@
+use axum::async_trait;
+
+use crate::{
+    domain::settlement::{dto::request::EditSplit, repository::split::RecordSplitRepo},
+    global::errors::CustomError,
+};
+
+pub struct RecordSplitUsecaseImpl<T>
+where
+    T: RecordSplitRepo,
+{
+    repository: T,
+}
+
+#[async_trait]
+pub trait RecordSplitUsecase: Send + Sync {
+    async fn update_split(
+        &self,
+        user_id: i32,
+        record_id: i64,
+        edit_split: EditSplit,
+    ) -> Result<(), Box<CustomError>>;
+}
+
+impl<T> RecordSplitUsecaseImpl<T>
+where
+    T: RecordSplitRepo,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> RecordSplitUsecase for RecordSplitUsecaseImpl<T>
+where
+    T: RecordSplitRepo,
+{
+    async fn update_split(
+        &self,
+        user_id: i32,
+        record_id: i64,
+        edit_split: EditSplit,
+    ) -> Result<(), Box<CustomError>> {
+        update_split(&self.repository, user_id, record_id, edit_split).await
+    }
+}
+
+async fn update_split<T>(
+    repository: &T,
+    user_id: i32,
+    record_id: i64,
+    edit_split: EditSplit,
+) -> Result<(), Box<CustomError>>
+where
+    T: RecordSplitRepo,
+{
+    let split = edit_split.to_entity()?;
+
+    repository.save_split(user_id, record_id, split).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::settlement::{
+            dto::request::{EditSplit, ShareItem},
+            entity::{RecordSplit, SplitType},
+            repository::split::RecordSplitRepo,
+            usecase::split::update_split,
+        },
+        global::errors::CustomError,
+    };
+
+    mock! {
+        RecordSplitRepoImpl {}
+
+        #[async_trait]
+        impl RecordSplitRepo for RecordSplitRepoImpl {
+            async fn save_split(&self, user_id: i32, record_id: i64, split: RecordSplit) -> Result<(), Box<CustomError>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn check_update_split_success() {
+        // Arrange
+        let edit_split = EditSplit::new(
+            1,
+            "percent".to_string(),
+            vec![ShareItem::new(3, Some(60)), ShareItem::new(1, Some(40))],
+        );
+        let mut mock_repo = MockRecordSplitRepoImpl::new();
+        mock_repo
+            .expect_save_split()
+            .with(
+                predicate::eq(1),
+                predicate::eq(10),
+                predicate::eq(RecordSplit::new(
+                    1,
+                    SplitType::Percent,
+                    vec![(1, 40), (3, 60)],
+                )),
+            )
+            .returning(|_, _, _| Ok(()));
+
+        // Act
+        let result = update_split(&mock_repo, 1, 10, edit_split).await;
+
+        // Assert
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_update_split_invalid() {
+        // Arrange
+        let mut mock_repo = MockRecordSplitRepoImpl::new();
+        mock_repo.expect_save_split().never();
+
+        let edit_splits = [
+            // 비율 합계가 100이 아님
+            EditSplit::new(
+                1,
+                "percent".to_string(),
+                vec![ShareItem::new(1, Some(40)), ShareItem::new(3, Some(50))],
+            ),
+            // 같은 구성원 중복
+            EditSplit::new(
+                1,
+                "equal".to_string(),
+                vec![ShareItem::new(1, None), ShareItem::new(1, None)],
+            ),
+            EditSplit::new(1, "equal".to_string(), vec![]),
+            EditSplit::new(1, "fixed".to_string(), vec![ShareItem::new(1, None)]),
+            EditSplit::new(1, "half".to_string(), vec![ShareItem::new(1, None)]),
+        ];
+
+        for edit_split in edit_splits {
+            // Act
+            let result = update_split(&mock_repo, 1, 10, edit_split).await;
+
+            // Assert
+            let err_type = match *result.err().unwrap() {
+                CustomError::ValidationError(_) => true,
+                _ => false,
+            };
+            assert!(err_type)
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    domain::settlement::{
+        dto::response::{MemberBalance, Transfer},
+        entity::{RecordSplit, SplitRow, SplitType},
+    },
+    global::errors::CustomError,
+};
+
+/// 구성원별 분담 금액, 나누어 떨어지지 않는 금액은 앞 순서부터 1씩 배분
+/// fixed 합계가 기록 금액과 다르면 결제자가 차액을 부담
+pub fn allocate(split: &RecordSplit, amount: i64) -> Vec<(i32, i64)> {
+    let shares = split.get_shares();
+    let mut result: Vec<(i32, i64)> = match split.get_split_type() {
+        SplitType::Equal => {
+            let count = shares.len() as i64;
+            shares
+                .iter()
+                .map(|(user_id, _)| (*user_id, amount / count))
+                .collect()
+        }
+        SplitType::Percent => shares
+            .iter()
+            .map(|(user_id, value)| (*user_id, amount * *value as i64 / 100))
+            .collect(),
+        SplitType::Fixed => shares
+            .iter()
+            .map(|(user_id, value)| (*user_id, *value as i64))
+            .collect(),
+    };
+
+    let mut remain = amount - result.iter().map(|(_, v)| v).sum::<i64>();
+    if split.get_split_type() == SplitType::Fixed {
+        let payer_id = split.get_payer_id();
+        match result.iter_mut().find(|(user_id, _)| *user_id == payer_id) {
+            Some((_, v)) => *v += remain,
+            None => result.push((payer_id, remain)),
+        }
+        return result;
+    }
+
+    for (_, v) in result.iter_mut() {
+        if remain == 0 {
+            break;
+        }
+        *v += remain.signum();
+        remain -= remain.signum();
+    }
+
+    result
+}
+
+/// 기록별 행을 모아 구성원별 결제, 분담 금액 합계 계산
+/// rows는 record_id 순으로 정렬되어 있어야 함
+pub fn sum_balances(rows: &[SplitRow]) -> Result<Vec<MemberBalance>, Box<CustomError>> {
+    let mut balances: BTreeMap<i32, (i64, i64)> = BTreeMap::new();
+
+    for chunk in rows.chunk_by(|a, b| a.get_record_id() == b.get_record_id()) {
+        let first = &chunk[0];
+        let split = RecordSplit::new(
+            first.get_payer_id(),
+            first.get_split_type().parse()?,
+            chunk
+                .iter()
+                .map(|row| (row.get_user_id(), row.get_value()))
+                .collect(),
+        );
+        let amount = first.get_amount() as i64;
+
+        balances.entry(split.get_payer_id()).or_default().0 += amount;
+        for (user_id, owed) in allocate(&split, amount) {
+            balances.entry(user_id).or_default().1 += owed;
+        }
+    }
+
+    Ok(balances
+        .into_iter()
+        .map(|(user_id, (paid, owed))| MemberBalance::new(user_id, paid, owed))
+        .collect())
+}
+
+/// 줄 사람이 받을 사람에게 보낼 금액, 큰 금액끼리 먼저 맞춤
+pub fn to_transfers(balances: &[MemberBalance]) -> Vec<Transfer> {
+    let mut creditors: Vec<(i32, i64)> = balances
+        .iter()
+        .filter(|b| b.get_net() > 0)
+        .map(|b| (b.get_user_id(), b.get_net()))
+        .collect();
+    let mut debtors: Vec<(i32, i64)> = balances
+        .iter()
+        .filter(|b| b.get_net() < 0)
+        .map(|b| (b.get_user_id(), -b.get_net()))
+        .collect();
+    creditors.sort_by_key(|(user_id, amount)| (-amount, *user_id));
+    debtors.sort_by_key(|(user_id, amount)| (-amount, *user_id));
+
+    let mut transfers = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < debtors.len() && j < creditors.len() {
+        let amount = debtors[i].1.min(creditors[j].1);
+        transfers.push(Transfer::new(debtors[i].0, creditors[j].0, amount));
+
+        debtors[i].1 -= amount;
+        creditors[j].1 -= amount;
+        if debtors[i].1 == 0 {
+            i += 1;
+        }
+        if creditors[j].1 == 0 {
+            j += 1;
+        }
+    }
+
+    transfers
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::settlement::{
+        dto::response::{MemberBalance, Transfer},
+        entity::{RecordSplit, SplitRow, SplitType},
+        utils::balance::{allocate, sum_balances, to_transfers},
+    };
+
+    #[test]
+    fn check_allocate_equal() {
+        // Arrange
+        let split = RecordSplit::new(1, SplitType::Equal, vec![(3, 0), (1, 0)]);
+
+        // Act
+        let result = allocate(&split, 10001);
+
+        // Assert
+        assert_eq!(result, vec![(1, 5001), (3, 5000)]);
+    }
+
+    #[test]
+    fn check_allocate_percent() {
+        // Arrange
+        let split = RecordSplit::new(1, SplitType::Percent, vec![(1, 30), (2, 70)]);
+
+        // Act
+        let result = allocate(&split, 999);
+
+        // Assert
+        assert_eq!(result, vec![(1, 300), (2, 699)]);
+    }
+
+    #[test]
+    fn check_allocate_fixed() {
+        // Arrange
+        let split = RecordSplit::new(1, SplitType::Fixed, vec![(1, 3000), (2, 7000)]);
+
+        // Act
+        // 기록 금액이 바뀌어 합계가 맞지 않으면 결제자가 차액 부담
+        let result = allocate(&split, 12000);
+
+        // Assert
+        assert_eq!(result, vec![(1, 5000), (2, 7000)]);
+    }
+
+    #[test]
+    fn check_sum_balances() {
+        // Arrange
+        let rows = vec![
+            SplitRow::new(1, 10000, 1, SplitType::Equal, 1, 0),
+            SplitRow::new(1, 10000, 1, SplitType::Equal, 2, 0),
+            SplitRow::new(2, 3000, 2, SplitType::Fixed, 1, 3000),
+        ];
+
+        // Act
+        let result = sum_balances(&rows).unwrap();
+
+        // Assert
+        assert_eq!(
+            result,
+            vec![
+                MemberBalance::new(1, 10000, 8000),
+                MemberBalance::new(2, 3000, 5000),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_to_transfers() {
+        // Arrange
+        let balances = vec![
+            MemberBalance::new(1, 10000, 8000),
+            MemberBalance::new(2, 3000, 5000),
+            MemberBalance::new(3, 0, 0),
+        ];
+
+        // Act
+        let result = to_transfers(&balances);
+
+        // Assert
+        assert_eq!(result, vec![Transfer::new(2, 1, 2000)]);
+    }
+}
@@ -234,14 +234,27 @@ impl LoginInfo {
     pub fn get_access_token(&self) -> &Option<String> {
         &self.access_token
     }
+}
 
-    pub fn to_entity(&self) -> User {
-        User::new(
-            self.username.to_string(),
-            self.password.to_string(),
-            self.nickname.as_ref().unwrap().to_string(),
-            self.email.as_ref().unwrap().to_string(),
-            self.login_type.to_string(),
-        )
+/// 소셜 로그인 연결, 제공자에게 받은 access token으로 확인
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub struct LinkIdentity {
+    login_type: LoginType,
+    access_token: String,
+}
+
+impl LinkIdentity {
+    pub fn new(login_type: LoginType, access_token: &str) -> Self {
+        Self {
+            login_type,
+            access_token: access_token.to_string(),
+        }
+    }
+
+    pub fn get_login_type(&self) -> &LoginType {
+        &self.login_type
+    }
+    pub fn get_access_token(&self) -> &str {
+        &self.access_token
     }
 }
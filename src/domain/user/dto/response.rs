@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
 use super::request::LoginType;
@@ -42,3 +43,19 @@ impl UserInfo {
         &self.username
     }
 }
+
+/// 로그인 수단, 제공자의 사용자 식별자는 노출하지 않음
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct IdentityInfo {
+    login_type: LoginType,
+    created_at: Option<NaiveDateTime>,
+}
+
+impl IdentityInfo {
+    pub fn new(login_type: LoginType, created_at: Option<NaiveDateTime>) -> Self {
+        Self {
+            login_type,
+            created_at,
+        }
+    }
+}
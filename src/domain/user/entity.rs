@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::global::constants::FieldUpdate;
 
-use super::dto::response::UserInfo;
+use super::dto::response::{IdentityInfo, UserInfo};
 
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow, PartialEq, Clone)]
 pub struct User {
@@ -20,6 +20,7 @@ pub struct User {
 
     is_active: bool,
     is_admin: bool,
+    email_verified_at: Option<NaiveDateTime>,
 
     created_at: Option<NaiveDateTime>,
     updated_at: Option<NaiveDateTime>,
@@ -47,6 +48,7 @@ impl User {
 
             is_active: true,
             is_admin: false,
+            email_verified_at: None,
 
             created_at: None,
             updated_at: None,
@@ -69,6 +71,10 @@ impl User {
         self.profile_id = profile_id;
         self
     }
+    pub fn email_verified_at(mut self, email_verified_at: Option<NaiveDateTime>) -> Self {
+        self.email_verified_at = email_verified_at;
+        self
+    }
 
     pub fn build(self) -> Self {
         Self {
@@ -85,6 +91,7 @@ impl User {
 
             is_active: self.is_active,
             is_admin: self.is_admin,
+            email_verified_at: self.email_verified_at,
 
             created_at: self.created_at,
             updated_at: self.updated_at,
@@ -121,6 +128,9 @@ impl User {
     pub fn get_is_active(&self) -> bool {
         self.is_active
     }
+    pub fn get_email_verified_at(&self) -> &Option<NaiveDateTime> {
+        &self.email_verified_at
+    }
     pub fn get_updated_at(&self) -> &Option<NaiveDateTime> {
         &self.updated_at
     }
@@ -174,3 +184,32 @@ impl UpdateUser {
         &self.nickname
     }
 }
+
+/// 사용자에 연결된 로그인 수단
+#[derive(Debug, sqlx::FromRow, PartialEq, Clone)]
+pub struct Identity {
+    provider: String,
+    provider_subject: String,
+    created_at: Option<NaiveDateTime>,
+}
+
+impl Identity {
+    pub fn new(provider: &str, provider_subject: &str) -> Self {
+        Self {
+            provider: provider.to_string(),
+            provider_subject: provider_subject.to_string(),
+            created_at: None,
+        }
+    }
+
+    pub fn get_provider(&self) -> &str {
+        &self.provider
+    }
+    pub fn get_provider_subject(&self) -> &str {
+        &self.provider_subject
+    }
+
+    pub fn to_info(&self) -> IdentityInfo {
+        IdentityInfo::new(self.provider.parse().unwrap(), self.created_at)
+    }
+}
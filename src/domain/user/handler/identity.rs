@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, response::IntoResponse, Extension, Json};
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::domain::user::{
+    dto::request::{LinkIdentity, LoginType},
+    usecase::identity::IdentityUsecase,
+};
+
+pub async fn read_identities<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: IdentityUsecase,
+{
+    match usecase.read_identities(user_id).await {
+        Ok(result) => (StatusCode::OK, Json(json!(result))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn link_identity<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Json(link): Json<LinkIdentity>,
+) -> impl IntoResponse
+where
+    T: IdentityUsecase,
+{
+    tracing::debug!("CALL: Link Identity");
+
+    match usecase.link_identity(user_id, link).await {
+        Ok(_) => (StatusCode::CREATED, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+pub async fn unlink_identity<T>(
+    Extension(usecase): Extension<Arc<T>>,
+    Extension(user_id): Extension<i32>,
+    Path(login_type): Path<LoginType>,
+) -> impl IntoResponse
+where
+    T: IdentityUsecase,
+{
+    tracing::debug!("CALL: Unlink Identity");
+
+    match usecase.unlink_identity(user_id, login_type).await {
+        Ok(_) => (StatusCode::OK, Json(json!({"message": "성공"}))).into_response(),
+        Err(err) => err.as_ref().into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        async_trait,
+        body::Body,
+        extract::Request,
+        routing::{delete, get},
+        Extension, Router,
+    };
+    use http_body_util::BodyExt;
+    use mockall::{mock, predicate};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::user::{
+            dto::{
+                request::{LinkIdentity, LoginType},
+                response::IdentityInfo,
+            },
+            usecase::identity::IdentityUsecase,
+        },
+        global::errors::CustomError,
+    };
+
+    use super::{link_identity, read_identities, unlink_identity};
+
+    mock! {
+        IdentityUsecaseImpl {}
+
+        #[async_trait]
+        impl IdentityUsecase for IdentityUsecaseImpl {
+            async fn read_identities(&self, user_id: i32) -> Result<Vec<IdentityInfo>, Arc<CustomError>>;
+            async fn link_identity(&self, user_id: i32, link: LinkIdentity) -> Result<(), Arc<CustomError>>;
+            async fn unlink_identity(&self, user_id: i32, login_type: LoginType) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    fn _create_app(user_id: i32, mock_usecase: MockIdentityUsecaseImpl) -> Router {
+        Router::new()
+            .route(
+                "/api/v1/user/identity",
+                get(read_identities::<MockIdentityUsecaseImpl>)
+                    .post(link_identity::<MockIdentityUsecaseImpl>),
+            )
+            .route(
+                "/api/v1/user/identity/:login_type",
+                delete(unlink_identity::<MockIdentityUsecaseImpl>),
+            )
+            .layer(Extension(Arc::new(mock_usecase)))
+            .layer(Extension(user_id))
+    }
+
+    #[tokio::test]
+    async fn check_read_identities() {
+        // Arrange
+        let mut mock_usecase = MockIdentityUsecaseImpl::new();
+        mock_usecase
+            .expect_read_identities()
+            .with(predicate::eq(1))
+            .returning(|_| {
+                Ok(vec![
+                    IdentityInfo::new(LoginType::Email, None),
+                    IdentityInfo::new(LoginType::Kakao, None),
+                ])
+            });
+        let app = _create_app(1, mock_usecase);
+        let req = Request::builder()
+            .method("GET")
+            .uri("/api/v1/user/identity")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body[1]["login_type"], "kakao");
+    }
+
+    #[tokio::test]
+    async fn check_link_identity() {
+        // Arrange
+        let mut mock_usecase = MockIdentityUsecaseImpl::new();
+        mock_usecase
+            .expect_link_identity()
+            .with(
+                predicate::eq(1),
+                predicate::eq(LinkIdentity::new(LoginType::Naver, "valid_token")),
+            )
+            .returning(|_, _| Ok(()));
+        let app = _create_app(1, mock_usecase);
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/user/identity")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({"login_type": "naver", "access_token": "valid_token"}).to_string(),
+            ))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 201)
+    }
+
+    #[tokio::test]
+    async fn check_link_identity_duplicated() {
+        // Arrange
+        let mut mock_usecase = MockIdentityUsecaseImpl::new();
+        mock_usecase
+            .expect_link_identity()
+            .returning(|_, _| Err(Arc::new(CustomError::Duplicated("Identity".to_string()))));
+        let app = _create_app(1, mock_usecase);
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/v1/user/identity")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({"login_type": "naver", "access_token": "valid_token"}).to_string(),
+            ))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400)
+    }
+
+    #[tokio::test]
+    async fn check_unlink_identity() {
+        // Arrange
+        let mut mock_usecase = MockIdentityUsecaseImpl::new();
+        mock_usecase
+            .expect_unlink_identity()
+            .with(predicate::eq(1), predicate::eq(LoginType::Google))
+            .returning(|_, _| Ok(()));
+        let app = _create_app(1, mock_usecase);
+        let req = Request::builder()
+            .method("DELETE")
+            .uri("/api/v1/user/identity/google")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200)
+    }
+
+    #[tokio::test]
+    async fn check_unlink_last_identity() {
+        // Arrange
+        let mut mock_usecase = MockIdentityUsecaseImpl::new();
+        mock_usecase.expect_unlink_identity().returning(|_, _| {
+            Err(Arc::new(CustomError::ValidationError(
+                "LastIdentity".to_string(),
+            )))
+        });
+        let app = _create_app(1, mock_usecase);
+        let req = Request::builder()
+            .method("DELETE")
+            .uri("/api/v1/user/identity/email")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(req).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 400)
+    }
+}
@@ -8,19 +8,25 @@ use axum::{
 use sqlx::PgPool;
 
 mod delete;
+mod identity;
 mod read;
 mod update;
 
 use delete::delete_user;
+use identity::{link_identity, read_identities, unlink_identity};
 use read::read_user;
 use update::update_user;
 
+use crate::{config::oauth::OAuthConfig, domain::auth::utils::oauth::OAuthProviders};
+
 use super::{
     repository::{
-        delete::DeleteUserRepoImpl, get_by_id::GetUserByIdRepoImpl, update::UpdateUserRepoImpl,
+        delete::DeleteUserRepoImpl, get_by_id::GetUserByIdRepoImpl, identity::IdentityRepoImpl,
+        update::UpdateUserRepoImpl,
     },
     usecase::{
-        delete::DeleteUserUsecaseImpl, read::ReadUserUsecaseImpl, update::UpdateUserUsecaseImpl,
+        delete::DeleteUserUsecaseImpl, identity::IdentityUsecaseImpl, read::ReadUserUsecaseImpl,
+        update::UpdateUserUsecaseImpl,
     },
 };
 
@@ -59,3 +65,21 @@ pub fn delete_router(pool: &Arc<PgPool>) -> Router {
         )
         .layer(Extension(Arc::new(usecase)))
 }
+
+pub fn identity_router(pool: &Arc<PgPool>, oauth_config: &Arc<OAuthConfig>) -> Router {
+    let repository = IdentityRepoImpl::new(pool);
+    let providers = OAuthProviders::new(oauth_config);
+    let usecase = IdentityUsecaseImpl::new(repository, providers);
+
+    Router::new()
+        .route(
+            "/identity",
+            get(read_identities::<IdentityUsecaseImpl<IdentityRepoImpl>>)
+                .post(link_identity::<IdentityUsecaseImpl<IdentityRepoImpl>>),
+        )
+        .route(
+            "/identity/:login_type",
+            delete(unlink_identity::<IdentityUsecaseImpl<IdentityRepoImpl>>),
+        )
+        .layer(Extension(Arc::new(usecase)))
+}
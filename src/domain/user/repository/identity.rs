@@ -0,0 +1,383 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::{
+    domain::user::{
+        entity::{Identity, User},
+        repository::save::save_user_with_identity,
+    },
+    global::errors::CustomError,
+};
+
+pub struct IdentityRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait IdentityRepo: Send + Sync {
+    async fn get_by_identity(
+        &self,
+        provider: &str,
+        provider_subject: &str,
+    ) -> Result<User, Arc<CustomError>>;
+    async fn save_user(&self, user: User, provider_subject: &str) -> Result<i32, Arc<CustomError>>;
+    async fn get_identities(&self, user_id: i32) -> Result<Vec<Identity>, Arc<CustomError>>;
+    async fn link_identity(
+        &self,
+        user_id: i32,
+        identity: &Identity,
+    ) -> Result<(), Arc<CustomError>>;
+    async fn unlink_identity(&self, user_id: i32, provider: &str) -> Result<(), Arc<CustomError>>;
+}
+
+impl IdentityRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl IdentityRepo for IdentityRepoImpl {
+    async fn get_by_identity(
+        &self,
+        provider: &str,
+        provider_subject: &str,
+    ) -> Result<User, Arc<CustomError>> {
+        get_by_identity(&self.pool, provider, provider_subject).await
+    }
+    async fn save_user(&self, user: User, provider_subject: &str) -> Result<i32, Arc<CustomError>> {
+        save_user_with_identity(&self.pool, user, provider_subject).await
+    }
+    async fn get_identities(&self, user_id: i32) -> Result<Vec<Identity>, Arc<CustomError>> {
+        get_identities(&self.pool, user_id).await
+    }
+    async fn link_identity(
+        &self,
+        user_id: i32,
+        identity: &Identity,
+    ) -> Result<(), Arc<CustomError>> {
+        link_identity(&self.pool, user_id, identity).await
+    }
+    async fn unlink_identity(&self, user_id: i32, provider: &str) -> Result<(), Arc<CustomError>> {
+        unlink_identity(&self.pool, user_id, provider).await
+    }
+}
+
+fn to_custom_error(target: &str, e: sqlx::Error) -> Arc<CustomError> {
+    let err_msg = format!("Error({}): {:?}", target, e);
+    tracing::error!("{}", err_msg);
+
+    let err = match e {
+        sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+        _ => CustomError::Unexpected(e.into()),
+    };
+    Arc::new(err)
+}
+
+/// 로그인 수단으로 사용자 조회
+pub async fn get_by_identity(
+    pool: &PgPool,
+    provider: &str,
+    provider_subject: &str,
+) -> Result<User, Arc<CustomError>> {
+    let user = sqlx::query_as::<_, User>(
+        "
+        SELECT u.*
+        FROM tb_user u
+        JOIN tb_user_identity i ON i.user_id = u.id
+        WHERE i.provider = $1 AND i.provider_subject = $2
+    ",
+    )
+    .bind(provider)
+    .bind(provider_subject)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| to_custom_error("GetByIdentity", e))?;
+
+    user.ok_or_else(|| Arc::new(CustomError::NotFound("User".to_string())))
+}
+
+pub async fn get_identities(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Vec<Identity>, Arc<CustomError>> {
+    sqlx::query_as::<_, Identity>(
+        "
+        SELECT provider, provider_subject, created_at
+        FROM tb_user_identity
+        WHERE user_id = $1
+        ORDER BY id
+    ",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| to_custom_error("GetIdentities", e))
+}
+
+/// 이미 다른 계정에 연결됐거나 같은 제공자가 연결돼 있으면 중복
+pub async fn link_identity(
+    pool: &PgPool,
+    user_id: i32,
+    identity: &Identity,
+) -> Result<(), Arc<CustomError>> {
+    let result = sqlx::query(
+        "
+        INSERT INTO tb_user_identity (user_id, provider, provider_subject)
+        VALUES ($1, $2, $3)
+        ON CONFLICT DO NOTHING
+    ",
+    )
+    .bind(user_id)
+    .bind(identity.get_provider())
+    .bind(identity.get_provider_subject())
+    .execute(pool)
+    .await
+    .map_err(|e| to_custom_error("LinkIdentity", e))?;
+
+    if result.rows_affected() == 0 {
+        return Err(Arc::new(CustomError::Duplicated("Identity".to_string())));
+    }
+
+    Ok(())
+}
+
+/// 마지막 로그인 수단은 해제 불가
+pub async fn unlink_identity(
+    pool: &PgPool,
+    user_id: i32,
+    provider: &str,
+) -> Result<(), Arc<CustomError>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| to_custom_error("UnlinkIdentity", e))?;
+
+    // 동시에 해제하면 행 잠금으로 순서대로 처리
+    let providers: Vec<String> = sqlx::query_scalar(
+        "
+        SELECT provider
+        FROM tb_user_identity
+        WHERE user_id = $1
+        FOR UPDATE
+    ",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("UnlinkIdentity", e))?;
+
+    if !providers.iter().any(|p| p == provider) {
+        return Err(Arc::new(CustomError::NotFound("Identity".to_string())));
+    } else if providers.len() <= 1 {
+        return Err(Arc::new(CustomError::ValidationError(
+            "LastIdentity".to_string(),
+        )));
+    }
+
+    sqlx::query(
+        "
+        DELETE FROM tb_user_identity
+        WHERE user_id = $1 AND provider = $2
+    ",
+    )
+    .bind(user_id)
+    .bind(provider)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| to_custom_error("UnlinkIdentity", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| to_custom_error("UnlinkIdentity", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use crate::{
+        config::database::create_connection_pool,
+        domain::user::{
+            entity::{Identity, User},
+            repository::save::{save_user, save_user_with_identity},
+        },
+        global::errors::CustomError,
+    };
+
+    use super::{get_by_identity, get_identities, link_identity, unlink_identity};
+
+    async fn _save_email_user(pool: &PgPool, username: &str) -> i32 {
+        let user = User::new(
+            username.to_string(),
+            "test_password".to_string(),
+            "nickname".to_string(),
+            username.to_string(),
+            "email".to_string(),
+        );
+        save_user(pool, user).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_get_by_identity() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = _save_email_user(&pool, "get_identity@test.test").await;
+
+        // Act
+        let result = get_by_identity(&pool, "email", "get_identity@test.test").await;
+
+        // Assert
+        let user = result.map_err(|e| println!("{:?}", e)).unwrap();
+        assert_eq!(user.get_id().unwrap(), user_id)
+    }
+
+    #[tokio::test]
+    async fn check_get_by_identity_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+
+        // Act
+        // 다른 로그인 방식의 식별자로는 조회되지 않음
+        let result = get_by_identity(&pool, "kakao", "test_user").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_save_oauth_user() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user = User::new(
+            "naver_identity_subject".to_string(),
+            "test_password".to_string(),
+            "nickname".to_string(),
+            "naver_identity@test.test".to_string(),
+            "naver".to_string(),
+        );
+
+        // Act
+        let result = save_user_with_identity(&pool, user, "identity_subject").await;
+        let user_id = result.map_err(|e| println!("{:?}", e)).unwrap();
+
+        // Assert
+        let user = get_by_identity(&pool, "naver", "identity_subject")
+            .await
+            .unwrap();
+        assert_eq!(user.get_id().unwrap(), user_id)
+    }
+
+    #[tokio::test]
+    async fn check_link_identity() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = _save_email_user(&pool, "link_identity@test.test").await;
+
+        // Act
+        let result = link_identity(&pool, user_id, &Identity::new("kakao", "link_subject")).await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        // 이메일로 가입한 사용자도 카카오로 로그인 가능
+        let user = get_by_identity(&pool, "kakao", "link_subject")
+            .await
+            .unwrap();
+        assert_eq!(user.get_id().unwrap(), user_id);
+        let identities = get_identities(&pool, user_id).await.unwrap();
+        assert_eq!(identities.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn check_link_identity_duplicated() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = _save_email_user(&pool, "link_dupl_1@test.test").await;
+        let other_id = _save_email_user(&pool, "link_dupl_2@test.test").await;
+        link_identity(&pool, user_id, &Identity::new("google", "dupl_subject"))
+            .await
+            .unwrap();
+
+        // Act
+        // 다른 계정에 연결된 식별자
+        let result = link_identity(&pool, other_id, &Identity::new("google", "dupl_subject")).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Duplicated(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unlink_identity() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = _save_email_user(&pool, "unlink_identity@test.test").await;
+        link_identity(&pool, user_id, &Identity::new("meta", "unlink_subject"))
+            .await
+            .unwrap();
+
+        // Act
+        let result = unlink_identity(&pool, user_id, "email").await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let result = get_by_identity(&pool, "email", "unlink_identity@test.test").await;
+        assert!(result.is_err());
+        let identities = get_identities(&pool, user_id).await.unwrap();
+        assert_eq!(identities.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_unlink_last_identity() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = _save_email_user(&pool, "unlink_last@test.test").await;
+
+        // Act
+        let result = unlink_identity(&pool, user_id, "email").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_unlink_identity_not_found() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = _save_email_user(&pool, "unlink_not_found@test.test").await;
+
+        // Act
+        let result = unlink_identity(&pool, user_id, "kakao").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
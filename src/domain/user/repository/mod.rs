@@ -1,5 +1,6 @@
 pub(super) mod delete;
 pub(crate) mod get_by_id;
-pub(crate) mod get_by_username;
+pub(crate) mod identity;
 pub(crate) mod save;
 pub(super) mod update;
+pub(crate) mod verify_email;
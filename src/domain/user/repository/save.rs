@@ -28,12 +28,28 @@ impl SaveUserRepo for SaveUserRepoImpl {
 }
 
 pub async fn save_user(pool: &PgPool, user: User) -> Result<i32, Arc<CustomError>> {
+    let subject = user.get_username().to_string();
+    save_user_with_identity(pool, user, &subject).await
+}
+
+/// 사용자와 가입한 로그인 수단을 함께 저장
+pub async fn save_user_with_identity(
+    pool: &PgPool,
+    user: User,
+    provider_subject: &str,
+) -> Result<i32, Arc<CustomError>> {
     let result = sqlx::query(
-        "INSERT INTO tb_user (username, password, nickname, phone, 
-                                        login_type, email, access_token) 
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "
+        WITH NewUser AS (
+            INSERT INTO tb_user (username, password, nickname, phone,
+                                        login_type, email, access_token, email_verified_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $9)
             ON CONFLICT (username) DO NOTHING
-            RETURNING id;
+            RETURNING id
+        )
+        INSERT INTO tb_user_identity (user_id, provider, provider_subject)
+        SELECT id, $5, $8 FROM NewUser
+        RETURNING user_id AS id;
     ",
     )
     .bind(user.get_username())
@@ -43,6 +59,8 @@ pub async fn save_user(pool: &PgPool, user: User) -> Result<i32, Arc<CustomError
     .bind(user.get_login_type().to_string())
     .bind(user.get_email())
     .bind(user.get_access_token())
+    .bind(provider_subject)
+    .bind(user.get_email_verified_at())
     .fetch_one(pool)
     .await
     .map_err(|e| {
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use sqlx::PgPool;
+
+use crate::global::errors::CustomError;
+
+pub struct VerifyEmailRepoImpl {
+    pool: Arc<PgPool>,
+}
+
+#[async_trait]
+pub trait VerifyEmailRepo: Send + Sync {
+    async fn verify_email(&self, user_id: i32, email: &str) -> Result<(), Arc<CustomError>>;
+}
+
+impl VerifyEmailRepoImpl {
+    pub fn new(pool: &Arc<PgPool>) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl VerifyEmailRepo for VerifyEmailRepoImpl {
+    async fn verify_email(&self, user_id: i32, email: &str) -> Result<(), Arc<CustomError>> {
+        verify_email(&self.pool, user_id, email).await
+    }
+}
+
+/// 인증 메일을 보낸 이메일 그대로일 때만 인증, 탈퇴한 사용자는 제외
+pub async fn verify_email(
+    pool: &PgPool,
+    user_id: i32,
+    email: &str,
+) -> Result<(), Arc<CustomError>> {
+    let result = sqlx::query(
+        "
+        UPDATE tb_user
+        SET email_verified_at = COALESCE(email_verified_at, NOW())
+        WHERE id = $1 AND email = $2 AND is_active = TRUE
+    ",
+    )
+    .bind(user_id)
+    .bind(email)
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        let err_msg = format!("Error(VerifyEmail): {:?}", &e);
+        tracing::error!("{}", err_msg);
+
+        let err = match e {
+            sqlx::Error::Database(_) => CustomError::DatabaseError(e),
+            _ => CustomError::Unexpected(e.into()),
+        };
+        Arc::new(err)
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Err(Arc::new(CustomError::NotFound("User".to_string())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::database::create_connection_pool,
+        domain::user::{
+            entity::User,
+            repository::{get_by_id::get_by_id, save::save_user},
+        },
+        global::errors::CustomError,
+    };
+
+    use super::verify_email;
+
+    fn _email_user(username: &str) -> User {
+        User::new(
+            username.to_string(),
+            "test_password".to_string(),
+            "nickname".to_string(),
+            username.to_string(),
+            "email".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn check_database_connectivity() {
+        // Arrange, Act
+        let pool = create_connection_pool().await;
+
+        // Assert
+        assert_eq!(pool.is_closed(), false)
+    }
+
+    #[tokio::test]
+    async fn check_verify_email() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = save_user(&pool, _email_user("verify_email@test.test"))
+            .await
+            .unwrap();
+        let user = get_by_id(&pool, user_id).await.unwrap();
+        assert!(user.get_email_verified_at().is_none());
+
+        // Act
+        let result = verify_email(&pool, user_id, "verify_email@test.test").await;
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok());
+
+        // Assert
+        let user = get_by_id(&pool, user_id).await.unwrap();
+        assert!(user.get_email_verified_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn check_verify_changed_email() {
+        // Arrange
+        let pool = create_connection_pool().await;
+        let user_id = save_user(&pool, _email_user("verify_changed@test.test"))
+            .await
+            .unwrap();
+
+        // Act
+        // 토큰 발급 이후 이메일이 바뀐 경우
+        let result = verify_email(&pool, user_id, "verify_before@test.test").await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::NotFound(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
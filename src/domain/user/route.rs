@@ -3,11 +3,14 @@ use std::sync::Arc;
 use axum::Router;
 use sqlx::PgPool;
 
-use super::handler::{delete_router, read_router, update_router};
+use crate::config::oauth::OAuthConfig;
 
-pub fn get_router(pool: &Arc<PgPool>) -> Router {
+use super::handler::{delete_router, identity_router, read_router, update_router};
+
+pub fn get_router(pool: &Arc<PgPool>, oauth_config: &Arc<OAuthConfig>) -> Router {
     Router::new()
         .merge(read_router(&pool))
         .merge(update_router(&pool))
         .merge(delete_router(&pool))
+        .merge(identity_router(&pool, &oauth_config))
 }
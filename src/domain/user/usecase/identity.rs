@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::{
+    domain::{
+        auth::utils::oauth::{OAuthProvider, OAuthProviders},
+        user::{
+            dto::{
+                request::{LinkIdentity, LoginType},
+                response::IdentityInfo,
+            },
+            entity::Identity,
+            repository::identity::IdentityRepo,
+        },
+    },
+    global::errors::CustomError,
+};
+
+pub struct IdentityUsecaseImpl<T>
+where
+    T: IdentityRepo,
+{
+    repository: T,
+    providers: OAuthProviders,
+}
+
+#[async_trait]
+pub trait IdentityUsecase: Send + Sync {
+    async fn read_identities(&self, user_id: i32) -> Result<Vec<IdentityInfo>, Arc<CustomError>>;
+    async fn link_identity(&self, user_id: i32, link: LinkIdentity)
+        -> Result<(), Arc<CustomError>>;
+    async fn unlink_identity(
+        &self,
+        user_id: i32,
+        login_type: LoginType,
+    ) -> Result<(), Arc<CustomError>>;
+}
+
+impl<T> IdentityUsecaseImpl<T>
+where
+    T: IdentityRepo,
+{
+    pub fn new(repository: T, providers: OAuthProviders) -> Self {
+        Self {
+            repository,
+            providers,
+        }
+    }
+}
+
+#[async_trait]
+impl<T> IdentityUsecase for IdentityUsecaseImpl<T>
+where
+    T: IdentityRepo,
+{
+    async fn read_identities(&self, user_id: i32) -> Result<Vec<IdentityInfo>, Arc<CustomError>> {
+        let identities = self.repository.get_identities(user_id).await?;
+        Ok(identities.iter().map(|i| i.to_info()).collect())
+    }
+    async fn link_identity(
+        &self,
+        user_id: i32,
+        link: LinkIdentity,
+    ) -> Result<(), Arc<CustomError>> {
+        let provider = self.providers.get(link.get_login_type());
+        link_identity(&self.repository, provider, user_id, link).await
+    }
+    async fn unlink_identity(
+        &self,
+        user_id: i32,
+        login_type: LoginType,
+    ) -> Result<(), Arc<CustomError>> {
+        self.repository
+            .unlink_identity(user_id, &login_type.to_string())
+            .await
+    }
+}
+
+/// 제공자가 확인한 식별자만 연결, 이메일은 가입으로만 추가 가능
+async fn link_identity<T>(
+    repository: &T,
+    provider: Option<&dyn OAuthProvider>,
+    user_id: i32,
+    link: LinkIdentity,
+) -> Result<(), Arc<CustomError>>
+where
+    T: IdentityRepo,
+{
+    let provider = match provider {
+        Some(provider) => provider,
+        None => {
+            return Err(Arc::new(CustomError::ValidationError(
+                "LoginType".to_string(),
+            )))
+        }
+    };
+
+    let oauth_user = provider.verify(link.get_access_token()).await?;
+    let identity = Identity::new(&link.get_login_type().to_string(), oauth_user.get_subject());
+
+    repository.link_identity(user_id, &identity).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::async_trait;
+    use mockall::{mock, predicate};
+
+    use crate::{
+        domain::{
+            auth::utils::oauth::{OAuthProvider, OAuthUser},
+            user::{
+                dto::request::{LinkIdentity, LoginType},
+                entity::{Identity, User},
+                repository::identity::IdentityRepo,
+            },
+        },
+        global::errors::CustomError,
+    };
+
+    use super::link_identity;
+
+    mock! {
+        IdentityRepoImpl {}
+
+        #[async_trait]
+        impl IdentityRepo for IdentityRepoImpl {
+            async fn get_by_identity(&self, provider: &str, provider_subject: &str) -> Result<User, Arc<CustomError>>;
+            async fn save_user(&self, user: User, provider_subject: &str) -> Result<i32, Arc<CustomError>>;
+            async fn get_identities(&self, user_id: i32) -> Result<Vec<Identity>, Arc<CustomError>>;
+            async fn link_identity(&self, user_id: i32, identity: &Identity) -> Result<(), Arc<CustomError>>;
+            async fn unlink_identity(&self, user_id: i32, provider: &str) -> Result<(), Arc<CustomError>>;
+        }
+    }
+
+    mock! {
+        OAuthProviderImpl {}
+
+        #[async_trait]
+        impl OAuthProvider for OAuthProviderImpl {
+            async fn verify(&self, access_token: &str) -> Result<OAuthUser, Arc<CustomError>>;
+        }
+    }
+
+    fn _get_provider() -> MockOAuthProviderImpl {
+        let mut provider = MockOAuthProviderImpl::new();
+        provider
+            .expect_verify()
+            .with(predicate::eq("valid_token"))
+            .returning(|_| Ok(OAuthUser::new("1234567890", "kakao@test.test")));
+        provider
+            .expect_verify()
+            .returning(|_| Err(Arc::new(CustomError::Unauthorized("OAuth".to_string()))));
+
+        provider
+    }
+
+    #[tokio::test]
+    async fn check_link_identity() {
+        // Arrange
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo
+            .expect_link_identity()
+            .with(
+                predicate::eq(1),
+                predicate::eq(Identity::new("kakao", "1234567890")),
+            )
+            .returning(|_, _| Ok(()));
+        let provider = _get_provider();
+        let link = LinkIdentity::new(LoginType::Kakao, "valid_token");
+
+        // Act
+        let result = link_identity(&mock_repo, Some(&provider), 1, link).await;
+
+        // Assert
+        assert!(result.map_err(|e| println!("{:?}", e)).is_ok())
+    }
+
+    #[tokio::test]
+    async fn check_link_identity_invalid_token() {
+        // Arrange
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo.expect_link_identity().never();
+        let provider = _get_provider();
+        let link = LinkIdentity::new(LoginType::Kakao, "forged_token");
+
+        // Act
+        let result = link_identity(&mock_repo, Some(&provider), 1, link).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::Unauthorized(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+
+    #[tokio::test]
+    async fn check_link_email_identity() {
+        // Arrange
+        let mut mock_repo = MockIdentityRepoImpl::new();
+        mock_repo.expect_link_identity().never();
+        let link = LinkIdentity::new(LoginType::Email, "valid_token");
+
+        // Act
+        let result = link_identity(&mock_repo, None, 1, link).await;
+
+        // Assert
+        let err_type = match *result.err().unwrap() {
+            CustomError::ValidationError(_) => true,
+            _ => false,
+        };
+        assert!(err_type)
+    }
+}
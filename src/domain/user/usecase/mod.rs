@@ -1,3 +1,4 @@
 pub(super) mod delete;
+pub(super) mod identity;
 pub(super) mod read;
 pub(super) mod update;
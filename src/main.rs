@@ -20,6 +20,8 @@ pub mod config {
     pub mod aws;
     pub mod database;
     pub mod jwt;
+    pub mod mail;
+    pub mod oauth;
 }
 
 pub mod global {
@@ -29,12 +31,18 @@ pub mod global {
 }
 
 pub mod domain {
+    pub mod asset;
     pub mod auth;
     pub mod book;
+    pub mod budget;
     pub mod category;
     pub mod connect;
+    pub mod diary;
     pub mod image;
+    pub mod journal;
     pub mod record;
+    pub mod recurring;
+    pub mod settlement;
     pub mod user;
 }
 
@@ -43,12 +51,19 @@ pub mod middleware {
 }
 
 use crate::domain::{
-    auth::route::get_router as auth_router, book::route::get_router as book_router,
-    category::route::get_router as category_router, connect::route::get_router as connect_router,
-    image::route::get_router as image_router, record::route::get_router as record_router,
+    asset::route::get_router as asset_router, auth::route::get_router as auth_router,
+    book::route::get_router as book_router, budget::route::get_router as budget_router,
+    category::route::get_router as category_router,
+    connect::route::get_router as connect_router, diary::route::get_router as diary_router,
+    image::route::get_router as image_router, journal::route::get_router as journal_router,
+    record::route::get_router as record_router,
+    recurring::{route::get_router as recurring_router, scheduler::spawn_scheduler},
+    settlement::route::get_router as settlement_router,
     user::route::get_router as user_router,
 };
-use config::{aws::get_bucket, jwt::get_config};
+use config::{
+    aws::get_bucket, jwt::get_config, mail::get_mail_config, oauth::get_oauth_config,
+};
 use middleware::auth::verify;
 
 #[tokio::main]
@@ -67,19 +82,30 @@ async fn main() {
     let pool = config::database::create_connection_pool().await;
     let pool = Arc::new(pool);
     let auth_config = Arc::new(get_config());
+    let oauth_config = Arc::new(get_oauth_config());
+    let mail_config = Arc::new(get_mail_config());
     let aws_bucket = Arc::new(get_bucket());
 
+    // 반복 기록 생성
+    spawn_scheduler(&pool);
+
     // public router
-    let auth_router = auth_router(&pool, &auth_config);
+    let auth_router = auth_router(&pool, &auth_config, &oauth_config, &mail_config);
     let public_router = Router::new().nest("/api/v1/auth", auth_router);
 
     // private router
     let book_router = book_router(&pool);
     let record_router = record_router(&pool);
-    let user_router = user_router(&pool);
+    let user_router = user_router(&pool, &oauth_config);
     let image_router = image_router(&pool, &aws_bucket);
     let category_router = category_router(&pool);
     let connect_router = connect_router(&pool);
+    let asset_router = asset_router(&pool);
+    let diary_router = diary_router(&pool);
+    let recurring_router = recurring_router(&pool);
+    let budget_router = budget_router(&pool);
+    let settlement_router = settlement_router(&pool);
+    let journal_router = journal_router(&pool);
 
     let private_router = Router::new()
         .nest("/api/v1/book", book_router)
@@ -88,6 +114,12 @@ async fn main() {
         .nest("/api/v1/image", image_router)
         .nest("/api/v1/category", category_router)
         .nest("/api/v1/connect", connect_router)
+        .nest("/api/v1/asset", asset_router)
+        .nest("/api/v1/diary", diary_router)
+        .nest("/api/v1/recurring", recurring_router)
+        .nest("/api/v1/budget", budget_router)
+        .nest("/api/v1/settlement", settlement_router)
+        .nest("/api/v1/journal", journal_router)
         .layer(axum::middleware::from_fn_with_state(auth_config, verify));
 
     let cors = CorsLayer::new()